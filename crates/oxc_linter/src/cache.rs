@@ -0,0 +1,255 @@
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    path::Path,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use dashmap::DashMap;
+use oxc_diagnostics::{
+    miette::{Diagnostic, LabeledSpan},
+    Error, Severity,
+};
+use rustc_hash::FxHasher;
+
+/// Diagnostics of previously linted files, for skipping files which have not changed since
+/// they were last linted, e.g. when an editor re-lints an unchanged file.
+///
+/// An entry is keyed by the file path, a hash of the file content and a fingerprint of the
+/// enabled rules and their options, so editing the file or changing the configuration
+/// invalidates it. Only the latest entry of each file is kept.
+///
+/// Pass it to [`crate::LintService::new_with_cache`] to use it.
+#[derive(Debug, Default)]
+pub struct LintCache {
+    entries: DashMap<Box<Path>, CacheEntry>,
+    hits: AtomicUsize,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    content_hash: u64,
+    fingerprint: u64,
+    diagnostics: Vec<CachedDiagnostic>,
+}
+
+impl LintCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of times diagnostics were served from the cache.
+    pub fn hits(&self) -> usize {
+        self.hits.load(Ordering::SeqCst)
+    }
+
+    /// Forgets the diagnostics of all files.
+    pub fn clear(&self) {
+        self.entries.clear();
+    }
+
+    pub(crate) fn get(
+        &self,
+        path: &Path,
+        source_text: &str,
+        fingerprint: u64,
+    ) -> Option<Vec<Error>> {
+        let entry = self.entries.get(path)?;
+        if entry.content_hash != hash(source_text) || entry.fingerprint != fingerprint {
+            return None;
+        }
+        self.hits.fetch_add(1, Ordering::SeqCst);
+        Some(entry.diagnostics.iter().cloned().map(Error::new).collect())
+    }
+
+    pub(crate) fn insert(
+        &self,
+        path: &Path,
+        source_text: &str,
+        fingerprint: u64,
+        diagnostics: &[Error],
+    ) {
+        let entry = CacheEntry {
+            content_hash: hash(source_text),
+            fingerprint,
+            diagnostics: diagnostics
+                .iter()
+                .map(|error| CachedDiagnostic::new(error.as_ref()))
+                .collect(),
+        };
+        self.entries.insert(path.to_path_buf().into_boxed_path(), entry);
+    }
+}
+
+fn hash(source_text: &str) -> u64 {
+    let mut hasher = FxHasher::default();
+    source_text.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// An owned copy of a diagnostic, as [`Error`] can't be cloned.
+#[derive(Debug, Clone)]
+struct CachedDiagnostic {
+    message: String,
+    code: Option<String>,
+    severity: Option<Severity>,
+    help: Option<String>,
+    url: Option<String>,
+    labels: Vec<LabeledSpan>,
+    related: Vec<Self>,
+}
+
+impl CachedDiagnostic {
+    fn new(diagnostic: &dyn Diagnostic) -> Self {
+        Self {
+            message: diagnostic.to_string(),
+            code: diagnostic.code().map(|code| code.to_string()),
+            severity: diagnostic.severity(),
+            help: diagnostic.help().map(|help| help.to_string()),
+            url: diagnostic.url().map(|url| url.to_string()),
+            labels: diagnostic.labels().map(Iterator::collect).unwrap_or_default(),
+            related: diagnostic
+                .related()
+                .map(|related| related.map(Self::new).collect())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl fmt::Display for CachedDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for CachedDiagnostic {}
+
+impl Diagnostic for CachedDiagnostic {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.code.as_ref().map(|code| Box::new(code) as Box<dyn fmt::Display>)
+    }
+
+    fn severity(&self) -> Option<Severity> {
+        self.severity
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.help.as_ref().map(|help| Box::new(help) as Box<dyn fmt::Display>)
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        self.url.as_ref().map(|url| Box::new(url) as Box<dyn fmt::Display>)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        if self.labels.is_empty() {
+            return None;
+        }
+        Some(Box::new(self.labels.iter().cloned()))
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        if self.related.is_empty() {
+            return None;
+        }
+        Some(Box::new(self.related.iter().map(|related| related as &dyn Diagnostic)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+        sync::{mpsc, Arc},
+    };
+
+    use serde_json::json;
+
+    use super::LintCache;
+    use crate::{AllowWarnDeny, LintOptions, LintService};
+
+    /// A directory of the system temp directory for a single test, removed when dropped.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir()
+                .join(format!("oxc_linter_cache_{name}_{}", std::process::id()));
+            // Left over from a previous run with the same process id.
+            let _ = fs::remove_dir_all(&dir);
+            fs::create_dir_all(&dir).unwrap();
+            Self(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn lint(cache: &Arc<LintCache>, options: LintOptions, cwd: &Path, path: &Path) -> Vec<String> {
+        let service = LintService::new_with_cache(
+            cwd.to_path_buf().into_boxed_path(),
+            &[path.to_path_buf().into_boxed_path()],
+            options,
+            Arc::clone(cache),
+        );
+        let (tx_error, rx_error) = mpsc::channel();
+        service.run(&tx_error);
+        rx_error
+            .iter()
+            .map_while(|diagnostics| diagnostics)
+            .flat_map(|(_, errors)| errors)
+            .map(|error| error.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn serves_unchanged_file_from_cache() {
+        let dir = TempDir::new("serves_unchanged_file_from_cache");
+        let path = dir.0.join("debugger.js");
+        fs::write(&path, "debugger;").unwrap();
+
+        let cache = Arc::new(LintCache::new());
+        let first = lint(&cache, LintOptions::default(), &dir.0, &path);
+        assert_eq!(first.len(), 1);
+        assert_eq!(cache.hits(), 0);
+
+        let second = lint(&cache, LintOptions::default(), &dir.0, &path);
+        assert_eq!(second, first);
+        assert_eq!(cache.hits(), 1);
+
+        fs::write(&path, "debugger; debugger;").unwrap();
+        let third = lint(&cache, LintOptions::default(), &dir.0, &path);
+        assert_eq!(third.len(), 2);
+        assert_eq!(cache.hits(), 1);
+    }
+
+    #[test]
+    fn misses_cache_when_rule_configuration_changes() {
+        let dir = TempDir::new("misses_cache_when_rule_configuration_changes");
+        let path = dir.0.join("empty_function.js");
+        fs::write(&path, "function foo() {}").unwrap();
+        let options = |configuration| {
+            LintOptions::default()
+                .with_filter(vec![(AllowWarnDeny::Deny, "no-empty-function".to_string())])
+                .with_rule_configuration("no-empty-function", configuration)
+                .unwrap()
+        };
+
+        let cache = Arc::new(LintCache::new());
+        let reported = lint(&cache, options(json!([])), &dir.0, &path);
+        assert_eq!(reported.len(), 1);
+
+        let allowed = lint(&cache, options(json!([{ "allow": ["functions"] }])), &dir.0, &path);
+        assert!(allowed.is_empty());
+        assert_eq!(cache.hits(), 0);
+
+        let allowed_again =
+            lint(&cache, options(json!([{ "allow": ["functions"] }])), &dir.0, &path);
+        assert!(allowed_again.is_empty());
+        assert_eq!(cache.hits(), 1);
+    }
+}
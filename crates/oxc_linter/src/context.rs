@@ -1,23 +1,45 @@
-use std::{cell::RefCell, path::Path, rc::Rc};
+use std::{
+    cell::{Cell, RefCell},
+    ops::ControlFlow,
+    path::Path,
+    rc::Rc,
+};
 
 use oxc_diagnostics::Error;
 use oxc_formatter::{Formatter, FormatterOptions};
-use oxc_semantic::{AstNodes, JSDocComment, ScopeTree, Semantic, SymbolTable};
+use oxc_semantic::{
+    AstNodes, JSDocComment, Reference, ScopeId, ScopeTree, Semantic, SymbolId, SymbolTable,
+};
 use oxc_span::SourceType;
 
 use crate::{
     disable_directives::{DisableDirectives, DisableDirectivesBuilder},
-    fixer::{Fix, Message},
+    fixer::{Fix, Message, RuleFixer},
+    globals::Globals,
     AstNode,
 };
 
+/// Receives each diagnostic as soon as it is reported, and returns [`ControlFlow::Break`] to
+/// stop linting.
+type DiagnosticCallback<'a> = Box<dyn FnMut(Error) -> ControlFlow<()> + 'a>;
+
 pub struct LintContext<'a> {
     semantic: Rc<Semantic<'a>>,
 
     diagnostics: RefCell<Vec<Message<'a>>>,
 
+    /// Receives the diagnostics instead of `diagnostics`, see
+    /// [`LintContext::with_diagnostic_callback`].
+    on_diagnostic: Option<RefCell<DiagnosticCallback<'a>>>,
+
+    /// Whether `on_diagnostic` asked to stop linting.
+    stopped: Cell<bool>,
+
     disable_directives: DisableDirectives<'a>,
 
+    /// Globals from the enabled environments, the configuration and `/* global */` comments.
+    globals: Globals,
+
     /// Whether or not to apply code fixes during linting.
     fix: bool,
 
@@ -30,10 +52,15 @@ impl<'a> LintContext<'a> {
     pub fn new(file_path: Box<Path>, semantic: &Rc<Semantic<'a>>) -> Self {
         let disable_directives =
             DisableDirectivesBuilder::new(semantic.source_text(), semantic.trivias()).build();
+        let mut globals = Globals::default();
+        globals.add_comment_directives(semantic.source_text(), semantic.trivias());
         Self {
             semantic: Rc::clone(semantic),
             diagnostics: RefCell::new(vec![]),
+            on_diagnostic: None,
+            stopped: Cell::new(false),
             disable_directives,
+            globals,
             fix: false,
             current_rule_name: "",
             file_path,
@@ -46,6 +73,34 @@ impl<'a> LintContext<'a> {
         self
     }
 
+    /// Forwards each diagnostic to `on_diagnostic` as soon as it is reported instead of
+    /// collecting them, so [`LintContext::into_message`] returns nothing.
+    /// Linting stops once `on_diagnostic` returns [`ControlFlow::Break`].
+    #[must_use]
+    pub fn with_diagnostic_callback<F>(mut self, on_diagnostic: F) -> Self
+    where
+        F: FnMut(Error) -> ControlFlow<()> + 'a,
+    {
+        self.on_diagnostic = Some(RefCell::new(Box::new(on_diagnostic)));
+        self
+    }
+
+    /// Predefines the globals of the given environments and configured globals.
+    /// Comments in the file take precedence over them.
+    #[must_use]
+    pub fn with_globals(mut self, env: &[String], globals: &[(String, bool)]) -> Self {
+        let mut configured = Globals::default();
+        for name in env {
+            configured.add_environment(name);
+        }
+        for (name, writable) in globals {
+            configured.add_global(name, *writable);
+        }
+        configured.merge(std::mem::take(&mut self.globals));
+        self.globals = configured;
+        self
+    }
+
     pub fn semantic(&self) -> &Rc<Semantic<'a>> {
         &self.semantic
     }
@@ -54,6 +109,10 @@ impl<'a> LintContext<'a> {
         &self.disable_directives
     }
 
+    pub fn globals(&self) -> &Globals {
+        &self.globals
+    }
+
     pub fn source_text(&self) -> &'a str {
         self.semantic().source_text()
     }
@@ -76,9 +135,24 @@ impl<'a> LintContext<'a> {
         self.diagnostics.into_inner()
     }
 
+    /// Whether the diagnostic callback asked to stop linting.
+    pub fn is_stopped(&self) -> bool {
+        self.stopped.get()
+    }
+
     fn add_diagnostic(&self, message: Message<'a>) {
-        if !self.disable_directives.contains(self.current_rule_name, message.start()) {
-            self.diagnostics.borrow_mut().push(message);
+        if self.is_stopped()
+            || self.disable_directives.contains(self.current_rule_name, message.start())
+        {
+            return;
+        }
+        match &self.on_diagnostic {
+            Some(on_diagnostic) => {
+                if (on_diagnostic.borrow_mut())(message.error).is_break() {
+                    self.stopped.set(true);
+                }
+            }
+            None => self.diagnostics.borrow_mut().push(message),
         }
     }
 
@@ -89,10 +163,11 @@ impl<'a> LintContext<'a> {
     pub fn diagnostic_with_fix<T, F>(&self, diagnostic: T, fix: F)
     where
         T: Into<Error>,
-        F: FnOnce() -> Fix<'a>,
+        F: FnOnce(RuleFixer<'a>) -> Fix<'a>,
     {
         if self.fix {
-            self.add_diagnostic(Message::new(diagnostic.into(), Some(fix())));
+            let fix = fix(RuleFixer::new(self.source_text()));
+            self.add_diagnostic(Message::new(diagnostic.into(), Some(fix)));
         } else {
             self.diagnostic(diagnostic);
         }
@@ -110,6 +185,17 @@ impl<'a> LintContext<'a> {
         self.semantic().symbols()
     }
 
+    /// The scope which contains the node.
+    pub fn scope_of(&self, node: &AstNode<'a>) -> ScopeId {
+        node.scope_id()
+    }
+
+    /// The resolved references to a symbol, use [`Reference::is_read`] and
+    /// [`Reference::is_write`] to tell how each of them accesses the symbol.
+    pub fn references_to(&self, symbol_id: SymbolId) -> impl Iterator<Item = &Reference> + '_ {
+        self.symbols().get_resolved_references(symbol_id)
+    }
+
     #[allow(clippy::unused_self)]
     pub fn formatter(&self) -> Formatter {
         Formatter::new(0, FormatterOptions::default())
@@ -120,3 +206,38 @@ impl<'a> LintContext<'a> {
         self.semantic().jsdoc().get_by_node(node)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, rc::Rc};
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::{Atom, SourceType};
+
+    use super::LintContext;
+
+    #[test]
+    fn references_to() {
+        let allocator = Allocator::default();
+        let source_text = "let a = 1; a = 2; a++; foo(a);";
+        let source_type = SourceType::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        let program = allocator.alloc(ret.program);
+        let semantic = SemanticBuilder::new(source_text, source_type).build(program).semantic;
+        let ctx = LintContext::new(Path::new("test.js").into(), &Rc::new(semantic));
+
+        let root_scope_id = ctx.scopes().root_scope_id();
+        let symbol_id = ctx.scopes().get_binding(root_scope_id, &Atom::from("a")).unwrap();
+        let declaration = ctx.nodes().get_node(ctx.symbols().get_declaration(symbol_id));
+        assert_eq!(ctx.scope_of(declaration), root_scope_id);
+
+        let flags = ctx
+            .references_to(symbol_id)
+            .map(|reference| (reference.is_read(), reference.is_write()))
+            .collect::<Vec<_>>();
+        // `a = 2` writes, `a++` reads and writes, `foo(a)` reads.
+        assert_eq!(flags, vec![(false, true), (true, true), (true, false)]);
+    }
+}
@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 use oxc_diagnostics::Error;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
 #[derive(Debug, Default)]
 pub struct Fix<'a> {
@@ -19,6 +19,49 @@ impl<'a> Fix<'a> {
     }
 }
 
+/// Builds [`Fix`]es for a rule. Passed into the closure of
+/// [`LintContext::diagnostic_with_fix`](crate::context::LintContext::diagnostic_with_fix).
+#[derive(Debug, Clone, Copy)]
+pub struct RuleFixer<'a> {
+    source_text: &'a str,
+}
+
+impl<'a> RuleFixer<'a> {
+    pub fn new(source_text: &'a str) -> Self {
+        Self { source_text }
+    }
+
+    /// The source text covered by `span`.
+    pub fn source_range(&self, span: Span) -> &'a str {
+        span.source_text(self.source_text)
+    }
+
+    /// Replaces the text covered by `span` with `text`.
+    pub fn replace<T: Into<Cow<'a, str>>>(&self, span: Span, text: T) -> Fix<'a> {
+        Fix::new(text, span)
+    }
+
+    /// Replaces the text of `node` with `text`.
+    pub fn replace_text<N: GetSpan, T: Into<Cow<'a, str>>>(&self, node: &N, text: T) -> Fix<'a> {
+        self.replace(node.span(), text)
+    }
+
+    /// Removes the text covered by `span`.
+    pub fn remove(&self, span: Span) -> Fix<'a> {
+        Fix::delete(span)
+    }
+
+    /// Inserts `text` right before `span`, leaving the text of `span` untouched.
+    pub fn insert_before<T: Into<Cow<'a, str>>>(&self, span: Span, text: T) -> Fix<'a> {
+        Fix::new(text, Span::new(span.start, span.start))
+    }
+
+    /// Inserts `text` right after `span`, leaving the text of `span` untouched.
+    pub fn insert_after<T: Into<Cow<'a, str>>>(&self, span: Span, text: T) -> Fix<'a> {
+        Fix::new(text, Span::new(span.end, span.end))
+    }
+}
+
 #[derive(Debug)]
 pub struct FixResult<'a> {
     pub fixed: bool,
@@ -118,9 +161,9 @@ mod test {
 
     use miette::{self, Diagnostic};
     use oxc_diagnostics::{thiserror::Error, Error};
-    use oxc_span::Span;
+    use oxc_span::{GetSpan, Span};
 
-    use super::{Fix, FixResult, Fixer, Message};
+    use super::{Fix, FixResult, Fixer, Message, RuleFixer};
 
     const TEST_CODE: &str = "var answer = 6 * 7;";
 
@@ -392,4 +435,57 @@ mod test {
         assert_eq!(result.messages[1].error.to_string(), "nofix2");
         assert!(result.fixed);
     }
+
+    #[test]
+    fn rule_fixer_replace() {
+        let fix = RuleFixer::new(TEST_CODE).replace(Span::new(4, 10), "foo");
+        assert_eq!(fix.span, REPLACE_ID.span);
+        assert_eq!(fix.content, REPLACE_ID.content);
+    }
+
+    #[test]
+    fn rule_fixer_replace_text() {
+        struct Node(Span);
+        impl GetSpan for Node {
+            fn span(&self) -> Span {
+                self.0
+            }
+        }
+        let fix = RuleFixer::new(TEST_CODE).replace_text(&Node(Span::new(0, 3)), "let");
+        assert_eq!(fix.span, REPLACE_VAR.span);
+        assert_eq!(fix.content, REPLACE_VAR.content);
+    }
+
+    #[test]
+    fn rule_fixer_remove() {
+        let fix = RuleFixer::new(TEST_CODE).remove(Span::new(14, 18));
+        assert_eq!(fix.span, REMOVE_END.span);
+        assert_eq!(fix.content, "");
+    }
+
+    #[test]
+    fn rule_fixer_insert_before() {
+        let fixer = RuleFixer::new(TEST_CODE);
+        let fix = fixer.insert_before(Span::new(13, 14), "5 *");
+        assert_eq!(fix.span, INSERT_AT_MIDDLE.span);
+        assert_eq!(fix.content, INSERT_AT_MIDDLE.content);
+        let result = get_fix_result(vec![create_message(InsertAtMiddle, Some(fix))]);
+        assert_eq!(result.fixed_code, "var answer = 5 *6 * 7;");
+    }
+
+    #[test]
+    fn rule_fixer_insert_after() {
+        let fixer = RuleFixer::new(TEST_CODE);
+        let fix = fixer.insert_after(Span::new(0, 19), "// end");
+        assert_eq!(fix.span, INSERT_AT_END.span);
+        assert_eq!(fix.content, INSERT_AT_END.content);
+        let result = get_fix_result(vec![create_message(InsertAtEnd, Some(fix))]);
+        assert_eq!(result.fixed_code, "var answer = 6 * 7;// end");
+    }
+
+    #[test]
+    fn rule_fixer_source_range() {
+        let fixer = RuleFixer::new(TEST_CODE);
+        assert_eq!(fixer.source_range(Span::new(4, 10)), "answer");
+    }
 }
@@ -3,7 +3,115 @@
 //! A value of true indicates that the variable may be overwritten.
 //! A value of false indicates that the variable should be considered read-only.
 
+use oxc_ast::TriviasMap;
 use phf::{phf_map, phf_set, Map};
+use rustc_hash::FxHashMap;
+
+/// The globals known to a linted file, merged from the built-in globals,
+/// the enabled environments and the user configured globals.
+#[derive(Debug, Default, Clone)]
+pub struct Globals {
+    environments: Vec<&'static Map<&'static str, bool>>,
+    /// User configured globals, mapped to whether they are writable,
+    /// or to `None` if they are turned off with `/* global a:off */`.
+    globals: FxHashMap<String, Option<bool>>,
+}
+
+impl Globals {
+    /// Enables the globals of an environment such as `browser` or `node`.
+    /// Unknown environments are ignored.
+    pub fn add_environment(&mut self, name: &str) {
+        let environment = match name {
+            "browser" => &BROWSER,
+            "node" => &NODE,
+            "es2021" => &BUILTINS,
+            "jest" => &JEST,
+            _ => return,
+        };
+        self.environments.push(environment);
+    }
+
+    pub fn add_global(&mut self, name: &str, writable: bool) {
+        self.globals.insert(name.to_string(), Some(writable));
+    }
+
+    /// Turns off a global, even if it is built-in or comes from an environment.
+    pub fn remove_global(&mut self, name: &str) {
+        self.globals.insert(name.to_string(), None);
+    }
+
+    /// Reads the `/* eslint-env browser */` and `/* global a, b:writable */` comments of a file.
+    pub fn add_comment_directives(&mut self, source_text: &str, trivias: &TriviasMap) {
+        for (comment, span) in trivias.comments_spans() {
+            if !comment.is_multi_line() {
+                continue;
+            }
+            let text = span.source_text(source_text).trim_start();
+            if let Some(text) = text.strip_prefix("eslint-env") {
+                for name in text.split(|c: char| c == ',' || c.is_whitespace()) {
+                    self.add_environment(name);
+                }
+            } else if let Some(text) =
+                text.strip_prefix("globals").or_else(|| text.strip_prefix("global"))
+            {
+                if !text.starts_with(char::is_whitespace) {
+                    continue;
+                }
+                for item in normalize_global_list(text).split([',', ' ']) {
+                    let (name, value) = item.split_once(':').unwrap_or((item, "readonly"));
+                    if name.is_empty() {
+                        continue;
+                    }
+                    match value {
+                        "off" => self.remove_global(name),
+                        value => self.add_global(name, matches!(value, "true" | "writable")),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Adds the environments and globals of `other`, which take precedence over those of `self`.
+    pub fn merge(&mut self, other: Self) {
+        self.environments.extend(other.environments);
+        self.globals.extend(other.globals);
+    }
+
+    /// Returns whether the global is writable, or `None` if it is not a known global.
+    pub fn get(&self, name: &str) -> Option<bool> {
+        if let Some(writable) = self.globals.get(name) {
+            return *writable;
+        }
+        self.environments
+            .iter()
+            .rev()
+            .find_map(|env| env.get(name).copied())
+            .or_else(|| BUILTINS.get(name).copied())
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.get(name).is_some()
+    }
+}
+
+/// Removes the whitespace around `:` and `,` like eslint, so `a : true, b` reads as `a:true,b`
+/// and the items can be split on `,` and the remaining whitespace.
+fn normalize_global_list(text: &str) -> String {
+    let mut normalized = String::with_capacity(text.len());
+    let mut after_whitespace = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            after_whitespace = true;
+            continue;
+        }
+        if after_whitespace && !matches!(c, ':' | ',') && !normalized.ends_with([':', ',']) {
+            normalized.push(' ');
+        }
+        after_whitespace = false;
+        normalized.push(c);
+    }
+    normalized
+}
 
 pub const BUILTINS: Map<&'static str, bool> = phf_map! {
     "AggregateError" => false,
@@ -88,3 +196,387 @@ pub const GLOBAL_OBJECT_NAMES: phf::Set<&'static str> = phf_set! {
     "self",
     "window",
 };
+
+/// Globals of the `browser` environment.
+pub const BROWSER: Map<&'static str, bool> = phf_map! {
+    "AbortController" => false,
+    "AbortSignal" => false,
+    "addEventListener" => false,
+    "alert" => false,
+    "AnalyserNode" => false,
+    "Animation" => false,
+    "atob" => false,
+    "Attr" => false,
+    "Audio" => false,
+    "AudioBuffer" => false,
+    "AudioContext" => false,
+    "AudioNode" => false,
+    "BarProp" => false,
+    "BeforeUnloadEvent" => false,
+    "Blob" => false,
+    "blur" => false,
+    "BroadcastChannel" => false,
+    "btoa" => false,
+    "ByteLengthQueuingStrategy" => false,
+    "caches" => false,
+    "cancelAnimationFrame" => false,
+    "cancelIdleCallback" => false,
+    "CanvasGradient" => false,
+    "CanvasPattern" => false,
+    "CanvasRenderingContext2D" => false,
+    "CDATASection" => false,
+    "CharacterData" => false,
+    "clearInterval" => false,
+    "clearTimeout" => false,
+    "clientInformation" => false,
+    "ClipboardEvent" => false,
+    "close" => false,
+    "closed" => false,
+    "CloseEvent" => false,
+    "Comment" => false,
+    "CompositionEvent" => false,
+    "confirm" => false,
+    "console" => false,
+    "Crypto" => false,
+    "crypto" => false,
+    "CryptoKey" => false,
+    "CSS" => false,
+    "CSSRule" => false,
+    "CSSStyleDeclaration" => false,
+    "CSSStyleSheet" => false,
+    "CustomElementRegistry" => false,
+    "customElements" => false,
+    "CustomEvent" => false,
+    "DataTransfer" => false,
+    "devicePixelRatio" => false,
+    "dispatchEvent" => false,
+    "Document" => false,
+    "document" => false,
+    "DocumentFragment" => false,
+    "DocumentType" => false,
+    "DOMException" => false,
+    "DOMImplementation" => false,
+    "DOMParser" => false,
+    "DOMRect" => false,
+    "DOMTokenList" => false,
+    "DragEvent" => false,
+    "Element" => false,
+    "ErrorEvent" => false,
+    "Event" => false,
+    "EventSource" => false,
+    "EventTarget" => false,
+    "fetch" => false,
+    "File" => false,
+    "FileList" => false,
+    "FileReader" => false,
+    "find" => false,
+    "focus" => false,
+    "FocusEvent" => false,
+    "FormData" => false,
+    "frameElement" => false,
+    "frames" => false,
+    "Gamepad" => false,
+    "Geolocation" => false,
+    "getComputedStyle" => false,
+    "getSelection" => false,
+    "HashChangeEvent" => false,
+    "Headers" => false,
+    "History" => false,
+    "history" => false,
+    "HTMLAnchorElement" => false,
+    "HTMLAudioElement" => false,
+    "HTMLBodyElement" => false,
+    "HTMLButtonElement" => false,
+    "HTMLCanvasElement" => false,
+    "HTMLCollection" => false,
+    "HTMLDivElement" => false,
+    "HTMLDocument" => false,
+    "HTMLElement" => false,
+    "HTMLFormElement" => false,
+    "HTMLIFrameElement" => false,
+    "HTMLImageElement" => false,
+    "HTMLInputElement" => false,
+    "HTMLLabelElement" => false,
+    "HTMLLinkElement" => false,
+    "HTMLMediaElement" => false,
+    "HTMLOptionElement" => false,
+    "HTMLScriptElement" => false,
+    "HTMLSelectElement" => false,
+    "HTMLSpanElement" => false,
+    "HTMLStyleElement" => false,
+    "HTMLTableElement" => false,
+    "HTMLTemplateElement" => false,
+    "HTMLTextAreaElement" => false,
+    "HTMLVideoElement" => false,
+    "IDBDatabase" => false,
+    "IDBFactory" => false,
+    "IDBKeyRange" => false,
+    "IDBObjectStore" => false,
+    "IDBRequest" => false,
+    "IDBTransaction" => false,
+    "Image" => false,
+    "ImageData" => false,
+    "indexedDB" => false,
+    "innerHeight" => false,
+    "innerWidth" => false,
+    "InputEvent" => false,
+    "IntersectionObserver" => false,
+    "Intl" => false,
+    "isSecureContext" => false,
+    "KeyboardEvent" => false,
+    "length" => false,
+    "localStorage" => false,
+    "Location" => false,
+    "location" => true,
+    "locationbar" => false,
+    "matchMedia" => false,
+    "MediaQueryList" => false,
+    "MediaRecorder" => false,
+    "MediaStream" => false,
+    "menubar" => false,
+    "MessageChannel" => false,
+    "MessageEvent" => false,
+    "MessagePort" => false,
+    "MouseEvent" => false,
+    "moveBy" => false,
+    "moveTo" => false,
+    "MutationObserver" => false,
+    "name" => true,
+    "Navigator" => false,
+    "navigator" => false,
+    "Node" => false,
+    "NodeFilter" => false,
+    "NodeList" => false,
+    "Notification" => false,
+    "onbeforeunload" => true,
+    "onblur" => true,
+    "onchange" => true,
+    "onclick" => true,
+    "onerror" => true,
+    "onfocus" => true,
+    "onhashchange" => true,
+    "oninput" => true,
+    "onkeydown" => true,
+    "onkeypress" => true,
+    "onkeyup" => true,
+    "onload" => true,
+    "onmessage" => true,
+    "onmousedown" => true,
+    "onmousemove" => true,
+    "onmouseout" => true,
+    "onmouseover" => true,
+    "onmouseup" => true,
+    "onpopstate" => true,
+    "onresize" => true,
+    "onscroll" => true,
+    "onstorage" => true,
+    "onsubmit" => true,
+    "onunload" => true,
+    "open" => false,
+    "opener" => false,
+    "Option" => false,
+    "origin" => false,
+    "outerHeight" => false,
+    "outerWidth" => false,
+    "PageTransitionEvent" => false,
+    "pageXOffset" => false,
+    "pageYOffset" => false,
+    "parent" => false,
+    "Path2D" => false,
+    "Performance" => false,
+    "performance" => false,
+    "PerformanceObserver" => false,
+    "personalbar" => false,
+    "PointerEvent" => false,
+    "PopStateEvent" => false,
+    "postMessage" => false,
+    "print" => false,
+    "ProgressEvent" => false,
+    "prompt" => false,
+    "queueMicrotask" => false,
+    "Range" => false,
+    "ReadableStream" => false,
+    "removeEventListener" => false,
+    "Request" => false,
+    "requestAnimationFrame" => false,
+    "requestIdleCallback" => false,
+    "ResizeObserver" => false,
+    "Response" => false,
+    "Screen" => false,
+    "screen" => false,
+    "screenLeft" => false,
+    "screenTop" => false,
+    "screenX" => false,
+    "screenY" => false,
+    "scroll" => false,
+    "scrollbars" => false,
+    "scrollBy" => false,
+    "scrollTo" => false,
+    "scrollX" => false,
+    "scrollY" => false,
+    "Selection" => false,
+    "self" => false,
+    "sessionStorage" => false,
+    "setInterval" => false,
+    "setTimeout" => false,
+    "ShadowRoot" => false,
+    "SharedWorker" => false,
+    "speechSynthesis" => false,
+    "status" => true,
+    "statusbar" => false,
+    "stop" => false,
+    "Storage" => false,
+    "StorageEvent" => false,
+    "structuredClone" => false,
+    "SubmitEvent" => false,
+    "SVGElement" => false,
+    "SVGSVGElement" => false,
+    "Text" => false,
+    "TextDecoder" => false,
+    "TextEncoder" => false,
+    "toolbar" => false,
+    "top" => false,
+    "TouchEvent" => false,
+    "TransformStream" => false,
+    "TreeWalker" => false,
+    "UIEvent" => false,
+    "URL" => false,
+    "URLSearchParams" => false,
+    "visualViewport" => false,
+    "WebSocket" => false,
+    "WheelEvent" => false,
+    "Window" => false,
+    "window" => false,
+    "Worker" => false,
+    "WritableStream" => false,
+    "XMLDocument" => false,
+    "XMLHttpRequest" => false,
+    "XMLSerializer" => false,
+    "XPathResult" => false
+};
+
+/// Globals of the `node` environment.
+pub const NODE: Map<&'static str, bool> = phf_map! {
+    "__dirname" => false,
+    "__filename" => false,
+    "AbortController" => false,
+    "AbortSignal" => false,
+    "atob" => false,
+    "Blob" => false,
+    "BroadcastChannel" => false,
+    "btoa" => false,
+    "Buffer" => false,
+    "clearImmediate" => false,
+    "clearInterval" => false,
+    "clearTimeout" => false,
+    "console" => false,
+    "crypto" => false,
+    "DOMException" => false,
+    "Event" => false,
+    "EventTarget" => false,
+    "exports" => true,
+    "fetch" => false,
+    "FormData" => false,
+    "global" => false,
+    "Headers" => false,
+    "Intl" => false,
+    "MessageChannel" => false,
+    "MessageEvent" => false,
+    "MessagePort" => false,
+    "module" => false,
+    "performance" => false,
+    "process" => false,
+    "queueMicrotask" => false,
+    "ReadableStream" => false,
+    "Request" => false,
+    "require" => false,
+    "Response" => false,
+    "setImmediate" => false,
+    "setInterval" => false,
+    "setTimeout" => false,
+    "structuredClone" => false,
+    "TextDecoder" => false,
+    "TextEncoder" => false,
+    "TransformStream" => false,
+    "URL" => false,
+    "URLSearchParams" => false,
+    "WebAssembly" => false,
+    "WritableStream" => false
+};
+
+/// Globals of the `jest` environment.
+pub const JEST: Map<&'static str, bool> = phf_map! {
+    "afterAll" => false,
+    "afterEach" => false,
+    "beforeAll" => false,
+    "beforeEach" => false,
+    "describe" => false,
+    "expect" => false,
+    "fit" => false,
+    "it" => false,
+    "jest" => false,
+    "pit" => false,
+    "require" => false,
+    "test" => false,
+    "xdescribe" => false,
+    "xit" => false,
+    "xtest" => false
+};
+
+#[cfg(test)]
+mod test {
+    use super::Globals;
+
+    #[test]
+    fn environments() {
+        let mut globals = Globals::default();
+        assert!(!globals.contains("window"));
+        assert!(globals.contains("Object"));
+
+        globals.add_environment("browser");
+        assert_eq!(globals.get("window"), Some(false));
+        assert_eq!(globals.get("onload"), Some(true));
+        assert!(!globals.contains("require"));
+
+        globals.add_environment("node");
+        assert!(globals.contains("require"));
+    }
+
+    #[test]
+    fn user_globals() {
+        let mut globals = Globals::default();
+        globals.add_environment("browser");
+        globals.add_global("window", true);
+        globals.add_global("foo", false);
+        assert_eq!(globals.get("window"), Some(true));
+        assert_eq!(globals.get("foo"), Some(false));
+    }
+
+    #[test]
+    fn merged_globals() {
+        let mut globals = Globals::default();
+        globals.add_global("foo", false);
+        globals.add_global("bar", false);
+        let mut comments = Globals::default();
+        comments.add_environment("browser");
+        comments.add_global("foo", true);
+        globals.merge(comments);
+        assert_eq!(globals.get("foo"), Some(true));
+        assert_eq!(globals.get("bar"), Some(false));
+        assert!(globals.contains("window"));
+    }
+
+    #[test]
+    fn normalize_global_list() {
+        assert_eq!(super::normalize_global_list(" a : true,  b c:off "), " a:true,b c:off");
+    }
+
+    #[test]
+    fn removed_globals() {
+        let mut globals = Globals::default();
+        globals.remove_global("Object");
+        assert!(!globals.contains("Object"));
+        globals.add_global("Object", false);
+        assert_eq!(globals.get("Object"), Some(false));
+    }
+}
@@ -5,6 +5,7 @@
 mod tester;
 
 mod ast_util;
+mod cache;
 mod context;
 mod disable_directives;
 mod fixer;
@@ -16,15 +17,18 @@ mod rules;
 mod service;
 mod utils;
 
-use std::{self, fs, io::Write, rc::Rc, time::Duration};
+use std::{self, fs, io::Write, ops::ControlFlow, rc::Rc, time::Duration};
 
+use oxc_diagnostics::Error;
 pub(crate) use oxc_semantic::AstNode;
 use rustc_hash::FxHashMap;
 
 pub use crate::{
+    cache::LintCache,
     context::LintContext,
     fixer::Fix,
-    fixer::{FixResult, Fixer, Message},
+    fixer::{FixResult, Fixer, Message, RuleFixer},
+    globals::Globals,
     options::{AllowWarnDeny, LintOptions},
     rule::RuleCategory,
     service::LintService,
@@ -58,6 +62,8 @@ impl Linter {
         Self { rules, options }
     }
 
+    /// Replaces the enabled rules. A [`LintCache`] only tells apart the rule configurations
+    /// given to [`LintOptions::with_rule_configuration`].
     #[must_use]
     pub fn with_rules(mut self, rules: Vec<RuleEnum>) -> Self {
         self.rules = rules;
@@ -89,16 +95,38 @@ impl Linter {
     }
 
     pub fn run<'a>(&self, ctx: LintContext<'a>) -> Vec<Message<'a>> {
+        self.lint(ctx).into_message()
+    }
+
+    /// Passes each diagnostic to `on_diagnostic` as soon as it is reported instead of collecting
+    /// them. Linting stops once `on_diagnostic` returns [`ControlFlow::Break`].
+    pub fn run_with_callback<'a, F>(&self, ctx: LintContext<'a>, on_diagnostic: F)
+    where
+        F: FnMut(Error) -> ControlFlow<()> + 'a,
+    {
+        self.lint(ctx.with_diagnostic_callback(on_diagnostic));
+    }
+
+    fn lint<'a>(&self, ctx: LintContext<'a>) -> LintContext<'a> {
         let timing = self.options.timing;
         let semantic = Rc::clone(ctx.semantic());
         let mut ctx = ctx.with_fix(self.options.fix);
+        if !self.options.env.is_empty() || !self.options.globals.is_empty() {
+            ctx = ctx.with_globals(&self.options.env, &self.options.globals);
+        }
 
         for rule in &self.rules {
+            if ctx.is_stopped() {
+                return ctx;
+            }
             ctx.with_rule_name(rule.name());
             rule.run_once(&ctx, timing);
         }
 
         for node in semantic.nodes().iter() {
+            if ctx.is_stopped() {
+                return ctx;
+            }
             for rule in &self.rules {
                 ctx.with_rule_name(rule.name());
                 rule.run(node, &ctx, timing);
@@ -106,13 +134,16 @@ impl Linter {
         }
 
         for symbol in semantic.symbols().iter() {
+            if ctx.is_stopped() {
+                return ctx;
+            }
             for rule in &self.rules {
                 ctx.with_rule_name(rule.name());
                 rule.run_on_symbol(symbol, &ctx, timing);
             }
         }
 
-        ctx.into_message()
+        ctx
     }
 
     #[allow(unused)]
@@ -166,7 +197,74 @@ impl Linter {
 
 #[cfg(test)]
 mod test {
-    use super::Linter;
+    use std::{ops::ControlFlow, path::Path, rc::Rc, time::Duration};
+
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_semantic::SemanticBuilder;
+    use oxc_span::SourceType;
+
+    use super::{LintContext, Linter, RULES};
+
+    /// Lints `source_text` with the correctness rules, and returns the start of each diagnostic
+    /// in the order they were received, until `limit` of them were received.
+    fn stream_diagnostics(source_text: &str, limit: usize) -> Vec<usize> {
+        let mut starts = vec![];
+        {
+            let allocator = Allocator::default();
+            let source_type = SourceType::default();
+            let ret = Parser::new(&allocator, source_text, source_type).parse();
+            let program = allocator.alloc(ret.program);
+            let semantic = SemanticBuilder::new(source_text, source_type).build(program).semantic;
+            let ctx = LintContext::new(Path::new("test.js").into(), &Rc::new(semantic));
+
+            Linter::new().run_with_callback(ctx, |error| {
+                let labels = error.labels().map_or(vec![], Iterator::collect);
+                starts.push(labels.iter().map(miette::LabeledSpan::offset).min().unwrap_or(0));
+                if starts.len() == limit {
+                    ControlFlow::Break(())
+                } else {
+                    ControlFlow::Continue(())
+                }
+            });
+        }
+        starts
+    }
+
+    #[test]
+    fn diagnostic_callback_in_source_order() {
+        let source_text = "debugger; if (a) { debugger; } function foo() { debugger; }";
+        assert_eq!(stream_diagnostics(source_text, usize::MAX), vec![0, 19, 48]);
+    }
+
+    #[test]
+    fn diagnostic_callback_stops_linting() {
+        let source_text = "debugger; if (a) { debugger; } function foo() { debugger; }";
+        assert_eq!(stream_diagnostics(source_text, 2), vec![0, 19]);
+    }
+
+    #[test]
+    fn diagnostic_callback_stops_run_once_rules() {
+        let rule = |name| RULES.iter().find(|rule| rule.name() == name).unwrap().clone();
+        // Timing is only enabled here, so the timer of a rule shows whether it was run.
+        let linter = Linter::new()
+            .with_rules(vec![rule("no-empty-file"), rule("ban-ts-comment")])
+            .with_print_execution_times(true);
+
+        let allocator = Allocator::default();
+        let source_type = SourceType::default();
+        let program = allocator.alloc(Parser::new(&allocator, "", source_type).parse().program);
+        let semantic = SemanticBuilder::new("", source_type).build(program).semantic;
+        let ctx = LintContext::new(Path::new("test.js").into(), &Rc::new(semantic));
+        let mut count = 0;
+        linter.run_with_callback(ctx, |_| {
+            count += 1;
+            ControlFlow::Break(())
+        });
+
+        assert_eq!(count, 1);
+        assert_eq!(linter.rules()[1].execute_time(), Duration::ZERO);
+    }
 
     #[test]
     fn print_rules() {
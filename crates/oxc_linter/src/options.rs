@@ -11,6 +11,14 @@ pub struct LintOptions {
     pub import_plugin: bool,
     pub jest_plugin: bool,
     pub jsx_a11y_plugin: bool,
+    /// Environments whose globals are predefined, e.g. `browser` or `node`.
+    pub env: Vec<String>,
+    /// Predefined globals, mapped to whether they are writable.
+    /// Like `env`, it is only set by embedders; the CLI has no configuration file for it yet.
+    pub globals: Vec<(String, bool)>,
+    /// Rules configured by [`LintOptions::with_rule_configuration`], with their eslint json
+    /// configuration.
+    rule_configurations: Vec<(RuleEnum, serde_json::Value)>,
 }
 
 impl Default for LintOptions {
@@ -22,6 +30,9 @@ impl Default for LintOptions {
             import_plugin: false,
             jest_plugin: false,
             jsx_a11y_plugin: false,
+            env: vec![],
+            globals: vec![],
+            rule_configurations: vec![],
         }
     }
 }
@@ -64,6 +75,47 @@ impl LintOptions {
         self.jsx_a11y_plugin = yes;
         self
     }
+
+    #[must_use]
+    pub fn with_env(mut self, env: Vec<String>) -> Self {
+        self.env = env;
+        self
+    }
+
+    #[must_use]
+    pub fn with_globals(mut self, globals: Vec<(String, bool)>) -> Self {
+        self.globals = globals;
+        self
+    }
+
+    /// Configures the rule `name` with its eslint json configuration,
+    /// e.g. `[{ "allow": ["functions"] }]` for `no-empty-function`.
+    /// The configuration is used when the rule is enabled by the filter.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when there is no rule named `name` or the configuration is invalid.
+    pub fn with_rule_configuration(
+        mut self,
+        name: &str,
+        configuration: serde_json::Value,
+    ) -> Result<Self, oxc_diagnostics::Error> {
+        let Some(rule) = RULES.iter().find(|rule| rule.name() == name) else {
+            return Err(oxc_diagnostics::Error::msg(format!("Unknown rule `{name}`")));
+        };
+        let rule = rule.read_json(Some(configuration.clone()))?;
+        self.rule_configurations.retain(|(configured, _)| *configured != rule);
+        self.rule_configurations.push((rule, configuration));
+        Ok(self)
+    }
+
+    /// The configuration of the rule `name` given to [`LintOptions::with_rule_configuration`].
+    pub(crate) fn rule_configuration(&self, name: &str) -> Option<&serde_json::Value> {
+        self.rule_configurations
+            .iter()
+            .find(|(rule, _)| rule.name() == name)
+            .map(|(_, configuration)| configuration)
+    }
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
@@ -87,6 +139,8 @@ const JEST_PLUGIN_NAME: &str = "jest";
 const JSX_A11Y_PLUGIN_NAME: &str = "jsx_a11y";
 
 impl LintOptions {
+    // Rules with a `Regex` option look mutable, but `RuleEnum` is hashed by the rule name only.
+    #[allow(clippy::mutable_key_type)]
     pub fn derive_rules(&self) -> Vec<RuleEnum> {
         let mut rules: FxHashSet<RuleEnum> = FxHashSet::default();
         let all_rules = self.get_filtered_rules();
@@ -128,7 +182,15 @@ impl LintOptions {
             }
         }
 
-        let mut rules = rules.into_iter().collect::<Vec<_>>();
+        let mut rules = rules
+            .into_iter()
+            .map(|rule| {
+                self.rule_configurations
+                    .iter()
+                    .find(|(configured, _)| *configured == rule)
+                    .map_or(rule, |(configured, _)| configured.clone())
+            })
+            .collect::<Vec<_>>();
         // for stable diagnostics output ordering
         rules.sort_unstable_by_key(RuleEnum::name);
         rules
@@ -1,6 +1,11 @@
 use std::fmt;
 
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
 use oxc_semantic::SymbolId;
+use regex::Regex;
 
 use crate::{context::LintContext, AstNode};
 
@@ -10,6 +15,19 @@ pub trait Rule: Sized + Default + fmt::Debug {
         Self::default()
     }
 
+    /// Initialize from eslint json configuration, reporting invalid options.
+    ///
+    /// Rules with options which can be invalid, such as regular expressions, implement this
+    /// and make [`Rule::from_configuration`] fall back to the default configuration.
+    /// `RuleEnum::read_json` calls this, so invalid options are reported to the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error describing the first invalid option.
+    fn try_from_configuration(value: serde_json::Value) -> Result<Self, oxc_diagnostics::Error> {
+        Ok(Self::from_configuration(value))
+    }
+
     /// Visit each AST Node
     fn run<'a>(&self, _node: &AstNode<'a>, _ctx: &LintContext<'a>) {}
 
@@ -20,6 +38,34 @@ pub trait Rule: Sized + Default + fmt::Debug {
     fn run_once(&self, _ctx: &LintContext) {}
 }
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("Invalid regular expression `{pattern}` in the `{option}` option: {error}")]
+#[diagnostic(severity(error))]
+pub struct InvalidRegexOption {
+    pub option: &'static str,
+    pub pattern: String,
+    pub error: regex::Error,
+}
+
+/// Compile a regular expression from a rule option.
+///
+/// Option regexes should be compiled once in [`Rule::try_from_configuration`] and stored on the rule,
+/// instead of being compiled for every node.
+///
+/// # Errors
+///
+/// Returns an error when `pattern` is not a valid regular expression.
+pub fn compile_option_regex(
+    option: &'static str,
+    pattern: &str,
+) -> Result<Regex, InvalidRegexOption> {
+    Regex::new(pattern).map_err(|error| InvalidRegexOption {
+        option,
+        pattern: pattern.to_string(),
+        error,
+    })
+}
+
 pub trait RuleMeta {
     const NAME: &'static str;
 
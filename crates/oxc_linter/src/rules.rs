@@ -8,9 +8,12 @@
 /// <https://github.com/import-js/eslint-plugin-import>
 mod import {
     pub mod default;
+    pub mod first;
     pub mod named;
     pub mod no_amd;
     pub mod no_cycle;
+    pub mod no_default_export;
+    pub mod no_duplicates;
     pub mod no_self_import;
 }
 
@@ -26,12 +29,22 @@ mod deepscan {
 }
 
 mod eslint {
+    pub mod accessor_pairs;
     pub mod array_callback_return;
+    pub mod camelcase;
+    pub mod complexity;
     pub mod constructor_super;
     pub mod default_case_last;
+    pub mod dot_notation;
     pub mod eqeqeq;
     pub mod for_direction;
     pub mod getter_return;
+    pub mod grouped_accessor_pairs;
+    pub mod id_length;
+    pub mod init_declarations;
+    pub mod max_depth;
+    pub mod max_nested_callbacks;
+    pub mod max_params;
     pub mod no_array_constructor;
     pub mod no_async_promise_executor;
     pub mod no_bitwise;
@@ -44,6 +57,8 @@ mod eslint {
     pub mod no_const_assign;
     pub mod no_constant_binary_expression;
     pub mod no_constant_condition;
+    pub mod no_constructor_return;
+    pub mod no_continue;
     pub mod no_control_regex;
     pub mod no_debugger;
     pub mod no_delete_var;
@@ -53,6 +68,7 @@ mod eslint {
     pub mod no_duplicate_case;
     pub mod no_empty;
     pub mod no_empty_character_class;
+    pub mod no_empty_function;
     pub mod no_empty_pattern;
     pub mod no_empty_static_block;
     pub mod no_eval;
@@ -61,31 +77,66 @@ mod eslint {
     pub mod no_fallthrough;
     pub mod no_func_assign;
     pub mod no_global_assign;
+    pub mod no_implicit_coercion;
     pub mod no_import_assign;
     pub mod no_inner_declarations;
+    pub mod no_invalid_regexp;
+    pub mod no_lone_blocks;
     pub mod no_loss_of_precision;
+    pub mod no_misleading_character_class;
     pub mod no_mixed_operators;
+    pub mod no_multi_assign;
+    pub mod no_multi_str;
+    pub mod no_new;
+    pub mod no_new_object;
     pub mod no_new_symbol;
     pub mod no_obj_calls;
+    pub mod no_octal;
+    pub mod no_octal_escape;
+    pub mod no_param_reassign;
+    pub mod no_plusplus;
     pub mod no_prototype_builtins;
     pub mod no_redeclare;
     pub mod no_regex_spaces;
+    pub mod no_restricted_globals;
+    pub mod no_restricted_imports;
+    pub mod no_restricted_syntax;
     pub mod no_return_await;
     pub mod no_self_assign;
     pub mod no_self_compare;
+    pub mod no_sequences;
     pub mod no_setter_return;
+    pub mod no_shadow;
     pub mod no_shadow_restricted_names;
     pub mod no_sparse_arrays;
+    pub mod no_ternary;
+    pub mod no_this_before_super;
     pub mod no_undef;
+    pub mod no_undef_init;
+    pub mod no_undefined;
+    pub mod no_underscore_dangle;
+    pub mod no_unreachable_loop;
     pub mod no_unsafe_finally;
     pub mod no_unsafe_negation;
     pub mod no_unsafe_optional_chaining;
+    pub mod no_unused_expressions;
     pub mod no_unused_labels;
+    pub mod no_unused_private_class_members;
+    pub mod no_use_before_define;
     pub mod no_useless_catch;
+    pub mod no_useless_constructor;
     pub mod no_useless_escape;
+    pub mod no_useless_return;
+    pub mod no_void;
+    pub mod no_with;
+    pub mod one_var;
     pub mod require_yield;
+    pub mod sort_imports;
+    pub mod sort_keys;
     pub mod use_isnan;
     pub mod valid_typeof;
+    pub mod vars_on_top;
+    pub mod yoda;
 }
 
 mod typescript {
@@ -213,6 +264,17 @@ mod jsx_a11y {
     pub mod anchor_is_valid;
     pub mod heading_has_content;
     pub mod html_has_lang;
+    pub mod img_redundant_alt;
+    pub mod label_has_associated_control;
+    pub mod no_access_key;
+    pub mod prefer_tag_over_role;
+    pub mod role_supports_aria_props;
+}
+
+/// <https://github.com/eslint-community/eslint-plugin-promise>
+mod promise {
+    pub mod always_return;
+    pub mod no_return_in_finally;
 }
 
 oxc_macros::declare_all_lint_rules! {
@@ -224,12 +286,22 @@ oxc_macros::declare_all_lint_rules! {
     deepscan::missing_throw,
     deepscan::number_arg_out_of_range,
     deepscan::uninvoked_array_callback,
+    eslint::accessor_pairs,
     eslint::array_callback_return,
+    eslint::camelcase,
+    eslint::complexity,
     eslint::constructor_super,
     eslint::default_case_last,
+    eslint::dot_notation,
     eslint::eqeqeq,
     eslint::for_direction,
     eslint::getter_return,
+    eslint::grouped_accessor_pairs,
+    eslint::id_length,
+    eslint::init_declarations,
+    eslint::max_depth,
+    eslint::max_nested_callbacks,
+    eslint::max_params,
     eslint::no_array_constructor,
     eslint::no_async_promise_executor,
     eslint::no_bitwise,
@@ -242,6 +314,8 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_const_assign,
     eslint::no_constant_binary_expression,
     eslint::no_constant_condition,
+    eslint::no_constructor_return,
+    eslint::no_continue,
     eslint::no_control_regex,
     eslint::no_debugger,
     eslint::no_delete_var,
@@ -251,6 +325,7 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_duplicate_case,
     eslint::no_empty,
     eslint::no_empty_character_class,
+    eslint::no_empty_function,
     eslint::no_empty_pattern,
     eslint::no_empty_static_block,
     eslint::no_eval,
@@ -259,31 +334,66 @@ oxc_macros::declare_all_lint_rules! {
     eslint::no_fallthrough,
     eslint::no_func_assign,
     eslint::no_global_assign,
+    eslint::no_implicit_coercion,
     eslint::no_import_assign,
     eslint::no_inner_declarations,
+    eslint::no_invalid_regexp,
+    eslint::no_lone_blocks,
     eslint::no_loss_of_precision,
+    eslint::no_misleading_character_class,
     eslint::no_mixed_operators,
+    eslint::no_multi_assign,
+    eslint::no_multi_str,
+    eslint::no_new,
+    eslint::no_new_object,
     eslint::no_new_symbol,
     eslint::no_obj_calls,
+    eslint::no_octal,
+    eslint::no_octal_escape,
+    eslint::no_param_reassign,
+    eslint::no_plusplus,
     eslint::no_prototype_builtins,
     eslint::no_redeclare,
     eslint::no_regex_spaces,
+    eslint::no_restricted_globals,
+    eslint::no_restricted_imports,
+    eslint::no_restricted_syntax,
     eslint::no_return_await,
     eslint::no_self_assign,
     eslint::no_self_compare,
+    eslint::no_sequences,
     eslint::no_setter_return,
+    eslint::no_shadow,
     eslint::no_shadow_restricted_names,
     eslint::no_sparse_arrays,
+    eslint::no_ternary,
+    eslint::no_this_before_super,
     eslint::no_undef,
+    eslint::no_undef_init,
+    eslint::no_undefined,
+    eslint::no_underscore_dangle,
+    eslint::no_unreachable_loop,
     eslint::no_unsafe_finally,
     eslint::no_unsafe_negation,
     eslint::no_unsafe_optional_chaining,
+    eslint::no_unused_expressions,
     eslint::no_unused_labels,
+    eslint::no_unused_private_class_members,
+    eslint::no_use_before_define,
     eslint::no_useless_catch,
+    eslint::no_useless_constructor,
     eslint::no_useless_escape,
+    eslint::no_useless_return,
+    eslint::no_void,
+    eslint::no_with,
+    eslint::one_var,
     eslint::require_yield,
+    eslint::sort_imports,
+    eslint::sort_keys,
     eslint::use_isnan,
     eslint::valid_typeof,
+    eslint::vars_on_top,
+    eslint::yoda,
     typescript::adjacent_overload_signatures,
     typescript::ban_ts_comment,
     typescript::ban_types,
@@ -396,9 +506,19 @@ oxc_macros::declare_all_lint_rules! {
     import::no_cycle,
     import::no_self_import,
     import::no_amd,
+    import::no_default_export,
+    import::no_duplicates,
+    import::first,
     jsx_a11y::alt_text,
     jsx_a11y::anchor_has_content,
     jsx_a11y::anchor_is_valid,
     jsx_a11y::html_has_lang,
-    jsx_a11y::heading_has_content
+    jsx_a11y::heading_has_content,
+    jsx_a11y::img_redundant_alt,
+    jsx_a11y::label_has_associated_control,
+    jsx_a11y::no_access_key,
+    jsx_a11y::prefer_tag_over_role,
+    jsx_a11y::role_supports_aria_props,
+    promise::always_return,
+    promise::no_return_in_finally
 }
@@ -0,0 +1,276 @@
+use oxc_ast::{
+    ast::{
+        Argument, CallExpression, Expression, ObjectExpression, ObjectPropertyKind, PropertyKind,
+    },
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    ast_util::is_method_call,
+    context::LintContext,
+    rule::Rule,
+    utils::{class_accessors, object_accessors, Accessor},
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum AccessorPairsDiagnostic {
+    #[error("eslint(accessor-pairs): Getter is not present for {0}.")]
+    #[diagnostic(severity(warning))]
+    Getter(String, #[label] Span),
+
+    #[error("eslint(accessor-pairs): Setter is not present for {0}.")]
+    #[diagnostic(severity(warning))]
+    Setter(String, #[label] Span),
+
+    #[error("eslint(accessor-pairs): Getter is not present in property descriptor.")]
+    #[diagnostic(severity(warning))]
+    GetterInPropertyDescriptor(#[label] Span),
+
+    #[error("eslint(accessor-pairs): Setter is not present in property descriptor.")]
+    #[diagnostic(severity(warning))]
+    SetterInPropertyDescriptor(#[label] Span),
+}
+
+#[derive(Debug, Clone)]
+pub struct AccessorPairs {
+    /// Report setters without a getter.
+    set_without_get: bool,
+    /// Report getters without a setter.
+    get_without_set: bool,
+    /// Also check getters and setters of classes.
+    enforce_for_class_members: bool,
+}
+
+impl Default for AccessorPairs {
+    fn default() -> Self {
+        Self { set_without_get: true, get_without_set: false, enforce_for_class_members: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce getter and setter pairs in objects and classes.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A property with a setter but without a getter can be written but always reads as `undefined`,
+    /// which is usually a mistake.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// var o = {
+    ///     set a(value) { this.val = value; }
+    /// };
+    /// Object.defineProperty(o, "b", { set(value) { this.val = value; } });
+    ///
+    /// // Good
+    /// var o = {
+    ///     set a(value) { this.val = value; },
+    ///     get a() { return this.val; }
+    /// };
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// - `setWithoutGet`: Report setters without a getter (`true` by default).
+    /// - `getWithoutSet`: Report getters without a setter (`false` by default).
+    /// - `enforceForClassMembers`: Also check class members (`true` by default).
+    AccessorPairs,
+    pedantic
+);
+
+impl Rule for AccessorPairs {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let default = Self::default();
+        let Some(config) = value.get(0) else { return default };
+        let get_bool = |key: &str, default: bool| {
+            config.get(key).and_then(serde_json::Value::as_bool).unwrap_or(default)
+        };
+        Self {
+            set_without_get: get_bool("setWithoutGet", default.set_without_get),
+            get_without_set: get_bool("getWithoutSet", default.get_without_set),
+            enforce_for_class_members: get_bool(
+                "enforceForClassMembers",
+                default.enforce_for_class_members,
+            ),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if !self.set_without_get && !self.get_without_set {
+            return;
+        }
+        match node.kind() {
+            AstKind::ObjectExpression(obj) => {
+                self.check_accessors(&object_accessors(obj, ctx), "", ctx);
+            }
+            AstKind::Class(class) if self.enforce_for_class_members => {
+                self.check_accessors(&class_accessors(class, ctx), "class ", ctx);
+            }
+            AstKind::CallExpression(call_expr) => {
+                for descriptor in property_descriptors(call_expr) {
+                    self.check_property_descriptor(descriptor, ctx);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl AccessorPairs {
+    fn check_accessors(&self, accessors: &[Accessor], prefix: &str, ctx: &LintContext) {
+        for accessor in accessors {
+            let has_pair = accessors.iter().any(|other| {
+                other.is_getter != accessor.is_getter
+                    && other.is_static == accessor.is_static
+                    && other.key == accessor.key
+            });
+            if has_pair {
+                continue;
+            }
+            let name = format!("{prefix}{}", accessor.name());
+            if accessor.is_getter && self.get_without_set {
+                ctx.diagnostic(AccessorPairsDiagnostic::Setter(name, accessor.span));
+            } else if !accessor.is_getter && self.set_without_get {
+                ctx.diagnostic(AccessorPairsDiagnostic::Getter(name, accessor.span));
+            }
+        }
+    }
+
+    /// Check `{ get() {}, set(value) {} }` passed to `Object.defineProperty` and similar functions.
+    fn check_property_descriptor(&self, descriptor: &ObjectExpression, ctx: &LintContext) {
+        let has_key = |name: &str| {
+            descriptor.properties.iter().any(|prop| {
+                matches!(prop, ObjectPropertyKind::ObjectProperty(prop)
+                    if prop.kind == PropertyKind::Init && prop.key.is_specific_static_name(name))
+            })
+        };
+        match (has_key("get"), has_key("set")) {
+            (false, true) if self.set_without_get => {
+                ctx.diagnostic(AccessorPairsDiagnostic::GetterInPropertyDescriptor(
+                    descriptor.span,
+                ));
+            }
+            (true, false) if self.get_without_set => {
+                ctx.diagnostic(AccessorPairsDiagnostic::SetterInPropertyDescriptor(
+                    descriptor.span,
+                ));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The property descriptors of `Object.defineProperty(obj, key, descriptor)`,
+/// `Reflect.defineProperty(obj, key, descriptor)`, `Object.defineProperties(obj, { key: descriptor })`
+/// and `Object.create(proto, { key: descriptor })`.
+fn property_descriptors<'a, 'b>(
+    call_expr: &'b CallExpression<'a>,
+) -> Vec<&'b ObjectExpression<'a>> {
+    let object_argument = |index: usize| match call_expr.arguments.get(index) {
+        Some(Argument::Expression(Expression::ObjectExpression(obj))) => Some(&**obj),
+        _ => None,
+    };
+    if is_method_call(
+        call_expr,
+        Some(&["Object", "Reflect"]),
+        Some(&["defineProperty"]),
+        Some(3),
+        None,
+    ) {
+        return object_argument(2).into_iter().collect();
+    }
+    if is_method_call(
+        call_expr,
+        Some(&["Object"]),
+        Some(&["create", "defineProperties"]),
+        Some(2),
+        None,
+    ) {
+        return object_argument(1)
+            .into_iter()
+            .flat_map(|descriptors| &descriptors.properties)
+            .filter_map(|prop| match prop {
+                ObjectPropertyKind::ObjectProperty(prop) => match &prop.value {
+                    Expression::ObjectExpression(obj) => Some(&**obj),
+                    _ => None,
+                },
+                ObjectPropertyKind::SpreadProperty(_) => None,
+            })
+            .collect();
+    }
+    vec![]
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let get_without_set = || Some(json!([{ "getWithoutSet": true }]));
+
+    let pass = vec![
+        ("var o = { a: 1 };", None),
+        ("var o = { a() {} };", None),
+        ("var o = { get a() {} };", None),
+        ("var o = { set a(foo) {}, get a() {} };", None),
+        ("var o = { get a() {}, set a(foo) {} };", None),
+        ("var o = { get a() {}, b: 1, set a(foo) {} };", None),
+        ("var o = { set 'a'(foo) {}, get ['a']() {} };", None),
+        ("var o = { set [a](foo) {}, get [a]() {} };", None),
+        ("var o = { set a(foo) {} };", Some(json!([{ "setWithoutGet": false }]))),
+        ("var o = { get a() {}, set a(foo) {} };", get_without_set()),
+        ("var o = { set(foo) {} };", None),
+        ("var o = { set: foo };", None),
+        ("class A { get a() {} set a(foo) {} }", None),
+        ("class A { static get a() {} static set a(foo) {} }", None),
+        ("class A { get #a() {} set #a(foo) {} }", None),
+        ("class A { set a(foo) {} }", Some(json!([{ "enforceForClassMembers": false }]))),
+        ("class A { get a() {} }", None),
+        ("Object.defineProperty(o, 'a', { get() {}, set(foo) {} });", None),
+        ("Object.defineProperty(o, 'a', { get: foo, set: bar });", None),
+        ("Object.defineProperty(o, 'a', { get() {} });", None),
+        ("Object.defineProperty(o, 'a', { value: 1 });", None),
+        ("Object.defineProperties(o, { a: { get() {}, set(foo) {} } });", None),
+        ("foo.defineProperty(o, 'a', { set(foo) {} });", None),
+        (
+            "Object.defineProperty(o, 'a', { set(foo) {} });",
+            Some(json!([{ "setWithoutGet": false }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("var o = { set a(foo) {} };", None),
+        ("var o = { set 'a'(foo) {} };", None),
+        ("var o = { set [a](foo) {} };", None),
+        ("var o = { set a(foo) {}, get b() {} };", None),
+        ("var o = { set a(foo) {}, a: 1 };", None),
+        ("var o = { set [a](foo) {}, get [b]() {} };", None),
+        ("var o = { get a() {} };", get_without_set()),
+        ("var o = { get a() {}, set b(foo) {} };", get_without_set()),
+        ("class A { set a(foo) {} }", None),
+        ("class A { static set a(foo) {} }", None),
+        ("class A { set #a(foo) {} }", None),
+        ("class A { static get a() {} set a(foo) {} }", None),
+        ("class A { get a() {} }", get_without_set()),
+        ("(class { set a(foo) {} });", None),
+        ("Object.defineProperty(o, 'a', { set(foo) {} });", None),
+        ("Object.defineProperty(o, 'a', { set: foo });", None),
+        ("Reflect.defineProperty(o, 'a', { set(foo) {} });", None),
+        ("Object.defineProperties(o, { a: { set(foo) {} } });", None),
+        ("Object.create(null, { a: { set(foo) {} } });", None),
+        ("Object.defineProperty(o, 'a', { get() {} });", get_without_set()),
+    ];
+
+    Tester::new(AccessorPairs::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,390 @@
+use oxc_ast::{
+    ast::{
+        AssignmentTarget, BindingPattern, BindingPatternKind, ImportDeclarationSpecifier,
+        MemberExpression, ModuleDeclaration, PropertyKey, SimpleAssignmentTarget,
+    },
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+use regex::Regex;
+
+use crate::{
+    context::LintContext,
+    rule::{compile_option_regex, Rule},
+    utils::get_string_array_option,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(camelcase): Identifier '{0}' is not in camel case.")]
+#[diagnostic(severity(warning))]
+struct CamelcaseDiagnostic(Atom, #[label] pub Span);
+
+#[derive(Debug, Clone)]
+pub struct Camelcase {
+    /// Whether to check property names, `properties: "always" | "never"`.
+    properties: bool,
+    /// Whether to ignore shorthand destructured names, e.g. `const { foo_bar } = obj`.
+    ignore_destructuring: bool,
+    /// Whether to ignore named imports which keep their name, e.g. `import { foo_bar } from "mod"`.
+    ignore_imports: bool,
+    /// Names or regular expressions of names which are allowed.
+    allow: Vec<String>,
+    allow_patterns: Vec<Regex>,
+}
+
+impl Default for Camelcase {
+    fn default() -> Self {
+        Self {
+            properties: true,
+            ignore_destructuring: false,
+            ignore_imports: false,
+            allow: vec![],
+            allow_patterns: vec![],
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforce camelcase naming convention
+    ///
+    /// ### Why is this bad?
+    /// Mixing naming conventions makes code harder to read. This rule reports names
+    /// containing underscores, other than leading or trailing ones and `UPPER_CASE` constants.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var my_favorite_color = "#112C85";
+    /// function do_something() {}
+    /// obj.do_something = function() {};
+    /// ```
+    Camelcase,
+    style
+);
+
+impl Rule for Camelcase {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self::try_from_configuration(value).unwrap_or_default()
+    }
+
+    fn try_from_configuration(value: serde_json::Value) -> Result<Self, oxc_diagnostics::Error> {
+        let obj = value.get(0);
+        let get_bool = |key: &str| {
+            obj.and_then(|v| v.get(key)).and_then(serde_json::Value::as_bool).unwrap_or_default()
+        };
+        let allow = obj.map(|v| get_string_array_option(v, "allow")).unwrap_or_default();
+        let allow_patterns = allow
+            .iter()
+            .map(|pattern| compile_option_regex("allow", pattern))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            properties: obj.and_then(|v| v.get("properties")).and_then(serde_json::Value::as_str)
+                != Some("never"),
+            ignore_destructuring: get_bool("ignoreDestructuring"),
+            ignore_imports: get_bool("ignoreImports"),
+            allow,
+            allow_patterns,
+        })
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::VariableDeclarator(decl) => self.check_binding_pattern(&decl.id, ctx),
+            AstKind::FormalParameter(param) => self.check_binding_pattern(&param.pattern, ctx),
+            AstKind::CatchClause(clause) => {
+                if let Some(param) = &clause.param {
+                    self.check_binding_pattern(param, ctx);
+                }
+            }
+            AstKind::Function(func) => {
+                if let Some(id) = &func.id {
+                    self.check(&id.name, id.span, ctx);
+                }
+            }
+            AstKind::Class(class) => {
+                if let Some(id) = &class.id {
+                    self.check(&id.name, id.span, ctx);
+                }
+            }
+            AstKind::LabeledStatement(stmt) => self.check(&stmt.label.name, stmt.label.span, ctx),
+            AstKind::ModuleDeclaration(ModuleDeclaration::ImportDeclaration(decl)) => {
+                for specifier in decl.specifiers.iter().flatten() {
+                    let local = match specifier {
+                        ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                            if self.ignore_imports
+                                && specifier.imported.name() == &specifier.local.name
+                            {
+                                continue;
+                            }
+                            &specifier.local
+                        }
+                        ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                            &specifier.local
+                        }
+                        ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                            &specifier.local
+                        }
+                    };
+                    self.check(&local.name, local.span, ctx);
+                }
+            }
+            AstKind::ObjectProperty(prop) if self.properties && !prop.shorthand => {
+                self.check_property_key(&prop.key, ctx);
+            }
+            AstKind::PropertyDefinition(def) if self.properties => {
+                self.check_property_key(&def.key, ctx);
+            }
+            AstKind::MethodDefinition(def) if self.properties => {
+                self.check_property_key(&def.key, ctx);
+            }
+            AstKind::AssignmentExpression(expr) if self.properties => {
+                if let AssignmentTarget::SimpleAssignmentTarget(
+                    SimpleAssignmentTarget::MemberAssignmentTarget(member),
+                ) = &expr.left
+                {
+                    if let MemberExpression::StaticMemberExpression(member) = &**member {
+                        self.check(&member.property.name, member.property.span, ctx);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Camelcase {
+    fn check(&self, name: &Atom, span: Span, ctx: &LintContext<'_>) {
+        if is_underscored(name) && !self.is_allowed(name) {
+            ctx.diagnostic(CamelcaseDiagnostic(name.clone(), span));
+        }
+    }
+
+    fn is_allowed(&self, name: &str) -> bool {
+        self.allow.iter().any(|allowed| allowed == name)
+            || self.allow_patterns.iter().any(|pattern| pattern.is_match(name))
+    }
+
+    fn check_property_key(&self, key: &PropertyKey, ctx: &LintContext<'_>) {
+        match key {
+            PropertyKey::Identifier(ident) => self.check(&ident.name, ident.span, ctx),
+            PropertyKey::PrivateIdentifier(ident) => self.check(&ident.name, ident.span, ctx),
+            PropertyKey::Expression(_) => {}
+        }
+    }
+
+    fn check_binding_pattern(&self, pattern: &BindingPattern, ctx: &LintContext<'_>) {
+        match &pattern.kind {
+            BindingPatternKind::BindingIdentifier(ident) => {
+                self.check(&ident.name, ident.span, ctx);
+            }
+            BindingPatternKind::ObjectPattern(pat) => {
+                for prop in &pat.properties {
+                    if self.ignore_destructuring && !prop.computed {
+                        let value = match &prop.value.kind {
+                            BindingPatternKind::AssignmentPattern(pat) => &pat.left,
+                            _ => &prop.value,
+                        };
+                        // `{ foo_bar }`, `{ foo_bar = 1 }` or `{ foo_bar: foo_bar }`
+                        if let BindingPatternKind::BindingIdentifier(ident) = &value.kind {
+                            if prop.key.static_name().is_some_and(|name| name == ident.name) {
+                                continue;
+                            }
+                        }
+                    }
+                    self.check_binding_pattern(&prop.value, ctx);
+                }
+                if let Some(rest) = &pat.rest {
+                    self.check_binding_pattern(&rest.argument, ctx);
+                }
+            }
+            BindingPatternKind::ArrayPattern(pat) => {
+                for element in pat.elements.iter().flatten() {
+                    self.check_binding_pattern(element, ctx);
+                }
+                if let Some(rest) = &pat.rest {
+                    self.check_binding_pattern(&rest.argument, ctx);
+                }
+            }
+            BindingPatternKind::AssignmentPattern(pat) => {
+                self.check_binding_pattern(&pat.left, ctx);
+            }
+        }
+    }
+}
+
+/// Whether the name contains an underscore which is not leading or trailing,
+/// and is not an `UPPER_CASE` constant.
+fn is_underscored(name: &str) -> bool {
+    let name = name.trim_matches('_');
+    name.contains('_') && name != name.to_uppercase()
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("firstName = \"Nicholas\"", None),
+        ("FIRST_NAME = \"Nicholas\"", None),
+        ("__myPrivateVariable = \"Patrick\"", None),
+        ("myPrivateVariable_ = \"Patrick\"", None),
+        ("function doSomething(){}", None),
+        ("do_something()", None),
+        ("new do_something", None),
+        ("new do_something()", None),
+        ("foo.do_something()", None),
+        ("var foo = bar.baz_boom;", None),
+        ("var foo = bar.baz_boom.something;", None),
+        ("foo.boom_pow.qux = bar.baz_boom.something;", None),
+        ("if (bar.baz_boom) {}", None),
+        ("var obj = { key: foo.bar_baz };", None),
+        ("var arr = [foo.bar_baz];", None),
+        ("[foo.bar_baz]", None),
+        ("var arr = [foo.bar_baz.qux];", None),
+        ("[foo.bar_baz.nesting]", None),
+        ("if (foo.bar_baz === boom.bam_pow) { [foo.baz_boom] }", None),
+        ("var o = {key: 1}", Some(json!([{ "properties": "always" }]))),
+        ("var o = {_leading: 1}", Some(json!([{ "properties": "always" }]))),
+        ("var o = {trailing_: 1}", Some(json!([{ "properties": "always" }]))),
+        ("var o = {bar_baz: 1}", Some(json!([{ "properties": "never" }]))),
+        ("var o = {_leading: 1}", Some(json!([{ "properties": "never" }]))),
+        ("var o = {trailing_: 1}", Some(json!([{ "properties": "never" }]))),
+        ("obj.a_b = 2;", Some(json!([{ "properties": "never" }]))),
+        ("obj._a = 2;", Some(json!([{ "properties": "always" }]))),
+        ("obj.a_ = 2;", Some(json!([{ "properties": "always" }]))),
+        ("obj._a = 2;", Some(json!([{ "properties": "never" }]))),
+        ("obj.a_ = 2;", Some(json!([{ "properties": "never" }]))),
+        ("var obj = {\n a_a: 1 \n};\n obj.a_b = 2;", Some(json!([{ "properties": "never" }]))),
+        ("obj.foo_bar = function(){};", Some(json!([{ "properties": "never" }]))),
+        ("const { ['foo']: _foo } = obj;", None),
+        ("const { [_foo_]: foo } = obj;", None),
+        ("var { category_id } = query;", Some(json!([{ "ignoreDestructuring": true }]))),
+        (
+            "var { category_id: category_id } = query;",
+            Some(json!([{ "ignoreDestructuring": true }])),
+        ),
+        ("var { category_id = 1 } = query;", Some(json!([{ "ignoreDestructuring": true }]))),
+        ("var { category_id: category } = query;", None),
+        ("var { _leading } = query;", None),
+        ("var { trailing_ } = query;", None),
+        ("import { camelCased } from \"external module\";", None),
+        ("import { _leading } from \"external module\";", None),
+        ("import { trailing_ } from \"external module\";", None),
+        ("import { no_camelcased as camelCased } from \"external-module\";", None),
+        ("import { no_camelcased as _leading } from \"external-module\";", None),
+        ("import { no_camelcased as trailing_ } from \"external-module\";", None),
+        (
+            "import { no_camelcased } from \"external-module\";",
+            Some(json!([{ "ignoreImports": true }])),
+        ),
+        (
+            "import { no_camelcased as camelCased, anotherCamelCased } from \"external-module\";",
+            None,
+        ),
+        ("import { snake_cased } from 'mod'", Some(json!([{ "ignoreImports": true }]))),
+        ("function foo({ no_camelcased: camelCased }) {};", None),
+        ("function foo({ no_camelcased: _leading }) {};", None),
+        ("function foo({ no_camelcased: trailing_ }) {};", None),
+        ("function foo({ camelCased = 'default value' }) {};", None),
+        ("function foo({ _leading = 'default value' }) {};", None),
+        ("function foo({ trailing_ = 'default value' }) {};", None),
+        ("function foo({ camelCased }) {};", None),
+        ("function foo({ _leading }) {}", None),
+        ("function foo({ trailing_ }) {}", None),
+        ("var ignored_foo = 0;", Some(json!([{ "allow": ["ignored_foo"] }]))),
+        (
+            "var ignored_foo = 0, ignored_bar = 1;",
+            Some(json!([{ "allow": ["ignored_foo", "ignored_bar"] }])),
+        ),
+        ("var user_id = 0;", Some(json!([{ "allow": ["_id$"] }]))),
+        ("var __option_foo__ = 0;", Some(json!([{ "allow": ["__option_foo__"] }]))),
+        ("function UNSAFE_componentWillMount() {}", Some(json!([{ "allow": ["^UNSAFE_"] }]))),
+        ("var foo_bar = 1;", Some(json!([{ "allow": ["^foo_"] }]))),
+        ("foo = { [computed_bar]: 0 };", None),
+        ("({ a: obj.fo_o } = bar);", None),
+        ("({ a: obj.foo } = bar);", None),
+        ("({ a: obj.fo_o.b_ar } = baz);", None),
+        ("({ a: { b: { c: obj.fo_o } } } = bar);", None),
+        ("({ a: { b: { c: obj.fo_o.b_ar } } } = baz);", None),
+        ("([obj.fo_o] = bar);", None),
+        ("([obj.foo = obj.fo_o] = bar);", None),
+        ("({ c: [ob.fo_o]} = bar);", None),
+        ("({ c: [ob.fo_o.b_ar]} = baz);", None),
+        ("({ a: obj.fo_o } = bar);", None),
+        ("const FOO_BAR = 1", None),
+        ("class C { camelCase; #camelCase; #camelCase2() {} }", None),
+        (
+            "class C { snake_case; #snake_case; #snake_case2() {} }",
+            Some(json!([{ "properties": "never" }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("var first_name = \"Nicholas\"", None),
+        ("var __private_first_name = \"Patrick\"", None),
+        ("function foo_bar(){}", None),
+        ("obj.foo_bar = function(){};", None),
+        ("foo.bar_baz = boom.bam_pow", None),
+        ("var foo = { bar_baz: boom.bam_pow }", None),
+        ("var foo = { bar_baz: boom.bam_pow }", Some(json!([{ "ignoreDestructuring": true }]))),
+        ("foo.qux.boom_pow = { bar: boom.bam_pow }", None),
+        ("var o = {bar_baz: 1}", Some(json!([{ "properties": "always" }]))),
+        ("obj.a_b = 2;", Some(json!([{ "properties": "always" }]))),
+        ("var { category_id: category_alias } = query;", None),
+        ("var { category_id: category_alias } = query;", Some(json!([{ "ignoreDestructuring": true }]))),
+        ("var { category_id: categoryId, ...other_props } = query;", Some(json!([{ "ignoreDestructuring": true }]))),
+        ("var { category_id } = query;", None),
+        ("var { category_id = 1 } = query;", None),
+        ("var foo_bar = 1", None),
+        ("let [foo_bar] = arr;", Some(json!([{ "ignoreDestructuring": true }]))),
+        ("import no_camelcased from \"external-module\";", None),
+        ("import * as no_camelcased from \"external-module\";", None),
+        ("import { no_camelcased } from \"external-module\";", None),
+        ("import { no_camelcased as no_camel_cased } from \"external module\";", None),
+        ("import { camelCased as no_camel_cased } from \"external module\";", None),
+        ("import { camelCased, no_camelcased } from \"external-module\";", None),
+        ("import { no_camelcased as camelCased, another_no_camelcased } from \"external-module\";", None),
+        ("import camelCased, { no_camelcased } from \"external-module\";", None),
+        ("import no_camelcased, { another_no_camelcased as camelCased } from \"external-module\";", None),
+        ("import snake_cased from 'mod'", Some(json!([{ "ignoreImports": true }]))),
+        ("import * as snake_cased from 'mod'", Some(json!([{ "ignoreImports": true }]))),
+        ("import { camelCased as no_camel_cased } from \"external module\";", Some(json!([{ "ignoreImports": true }]))),
+        ("function foo({ no_camelcased }) {};", None),
+        ("function foo({ no_camelcased = 'default value' }) {};", None),
+        ("const no_camelcased = 0; function foo({ camelcased_value = no_camelcased}) {}", None),
+        ("const { bar: no_camelcased } = foo;", None),
+        ("function foo({ value_1: my_default }) {}", None),
+        ("function foo({ isCamelcased: no_camelcased }) {};", None),
+        ("var { foo: bar_baz = 1 } = quz;", None),
+        ("const { no_camelcased = false } = bar;", None),
+        ("var not_ignored_foo = 0;", Some(json!([{ "allow": ["ignored_bar"] }]))),
+        ("var not_ignored_foo = 0;", Some(json!([{ "allow": ["_id$"] }]))),
+        ("foo = { [computed_bar]: 0, bar_baz: 1 };", None),
+        ("class C { snake_case; }", None),
+        ("class C { #snake_case; foo() { this.#snake_case; } }", None),
+        ("class C { #snake_case() {} }", None),
+        ("try {} catch (error_object) {}", None),
+        ("my_label: for (;;) { break my_label; }", None),
+        ("var x = class foo_bar {};", None),
+    ];
+
+    Tester::new(Camelcase::NAME, pass, fail).test_and_snapshot();
+}
+
+#[test]
+fn test_invalid_allow_pattern() {
+    use serde_json::json;
+
+    let err =
+        Camelcase::try_from_configuration(json!([{ "allow": ["^UNSAFE_", "(foo"] }])).unwrap_err();
+    assert!(err.to_string().starts_with("Invalid regular expression `(foo` in the `allow` option"));
+}
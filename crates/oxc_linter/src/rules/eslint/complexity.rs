@@ -0,0 +1,197 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::AstNodeId;
+use oxc_span::Span;
+use oxc_syntax::operator::AssignmentOperator;
+use rustc_hash::FxHashMap;
+
+use crate::{context::LintContext, rule::Rule, utils::get_max_option};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(complexity): {0} has a complexity of {1}. Maximum allowed is {2}.")]
+#[diagnostic(severity(warning), help("Consider splitting the function into smaller functions."))]
+struct ComplexityDiagnostic(String, usize, usize, #[label] pub Span);
+
+#[derive(Debug, Clone)]
+pub struct Complexity {
+    max: usize,
+}
+
+impl Default for Complexity {
+    fn default() -> Self {
+        Self { max: 20 }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforce a maximum cyclomatic complexity allowed in a program
+    ///
+    /// ### Why is this bad?
+    /// Cyclomatic complexity measures the number of linearly independent paths through
+    /// a function. Functions with a high complexity are hard to understand and to test.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// function a(x) {
+    ///     if (true) {
+    ///         return x; // 1st path
+    ///     } else if (false) {
+    ///         return x+1; // 2nd path
+    ///     } else {
+    ///         return 4; // 3rd path
+    ///     }
+    /// }
+    /// ```
+    Complexity,
+    pedantic
+);
+
+impl Rule for Complexity {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        // `maximum` is the deprecated name of `max`.
+        let maximum = value
+            .get(0)
+            .and_then(|v| v.get("maximum"))
+            .and_then(serde_json::Value::as_u64)
+            .map_or(20, |v| usize::try_from(v).unwrap_or(20));
+
+        Self { max: get_max_option(&value, maximum) }
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let nodes = ctx.nodes();
+
+        // Count the decision points of each function, excluding nested functions.
+        let mut decision_points: FxHashMap<AstNodeId, usize> = FxHashMap::default();
+        for node in nodes.iter() {
+            if !is_decision_point(node.kind()) {
+                continue;
+            }
+            let function_id = nodes.ancestors(node.id()).find(|id| {
+                matches!(nodes.kind(*id), AstKind::Function(_) | AstKind::ArrowExpression(_))
+            });
+            if let Some(function_id) = function_id {
+                *decision_points.entry(function_id).or_default() += 1;
+            }
+        }
+
+        for node in nodes.iter() {
+            let (name, span) = match node.kind() {
+                AstKind::Function(func) => (
+                    func.id.as_ref().map_or_else(
+                        || "Function".to_string(),
+                        |id| format!("Function '{}'", id.name),
+                    ),
+                    Span::new(func.span.start, func.params.span.end),
+                ),
+                AstKind::ArrowExpression(arrow) => (
+                    "Arrow function".to_string(),
+                    Span::new(arrow.span.start, arrow.params.span.end),
+                ),
+                _ => continue,
+            };
+            let complexity = decision_points.get(&node.id()).copied().unwrap_or_default() + 1;
+            if complexity <= self.max {
+                continue;
+            }
+            ctx.diagnostic(ComplexityDiagnostic(name, complexity, self.max, span));
+        }
+    }
+}
+
+fn is_decision_point(kind: AstKind) -> bool {
+    match kind {
+        AstKind::IfStatement(_)
+        | AstKind::ForStatement(_)
+        | AstKind::ForInStatement(_)
+        | AstKind::ForOfStatement(_)
+        | AstKind::WhileStatement(_)
+        | AstKind::DoWhileStatement(_)
+        | AstKind::CatchClause(_)
+        | AstKind::ConditionalExpression(_)
+        | AstKind::LogicalExpression(_) => true,
+        AstKind::SwitchCase(case) => case.test.is_some(),
+        AstKind::AssignmentExpression(expr) => matches!(
+            expr.operator,
+            AssignmentOperator::LogicalAnd
+                | AssignmentOperator::LogicalOr
+                | AssignmentOperator::LogicalNullish
+        ),
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function a(x) {}", None),
+        ("function b(x) {}", Some(json!([1]))),
+        ("function a(x) {if (true) {return x;}}", Some(json!([2]))),
+        ("function a(x) {if (true) {return x;} else {return x+1;}}", Some(json!([2]))),
+        (
+            "function a(x) {if (true) {return x;} else if (false) {return x+1;} else {return 4;}}",
+            Some(json!([3])),
+        ),
+        ("function a(x) {for(var i = 0; i < 5; i ++) {x ++;} return x;}", Some(json!([2]))),
+        ("function a(obj) {for(var i in obj) {obj[i] = 3;}}", Some(json!([2]))),
+        (
+            "function a(x) {for(var i = 0; i < 5; i ++) {if(i % 2 === 0) {x ++;}} return x;}",
+            Some(json!([3])),
+        ),
+        ("function a(x) {var y = x ? x : 1;}", Some(json!([2]))),
+        ("function a(x) {var y = x && 1;}", Some(json!([2]))),
+        ("function a(x) {var y = x || 1;}", Some(json!([{ "max": 2 }]))),
+        (
+            "function a(x) {switch(x){case 1: 1; break; case 2: 2; break; default: 3;}}",
+            Some(json!([3])),
+        ),
+        ("function a(x) {while(true) {'foo';}}", Some(json!([2]))),
+        ("function a(x) {do {'foo';} while (true)}", Some(json!([2]))),
+        ("if (foo) { bar(); }", Some(json!([1]))),
+        ("var a = (x) => {do {'foo';} while (true)}", Some(json!([2]))),
+        ("function a(x) { (function() { if (x) {} })(); }", Some(json!([2]))),
+        ("function a(x) {try {} catch (e) {}}", Some(json!([2]))),
+        ("function a(x) {x ??= 1;}", Some(json!([2]))),
+    ];
+
+    let fail = vec![
+        ("function a(x) {}", Some(json!([0]))),
+        ("var func = function () {}", Some(json!([0]))),
+        ("var obj = { a(x) {} }", Some(json!([0]))),
+        ("class Test { a(x) {} }", Some(json!([0]))),
+        ("var a = (x) => {if (true) {return x;}}", Some(json!([1]))),
+        ("function a(x) {if (true) {return x;}}", Some(json!([1]))),
+        ("function a(x) {if (true) {return x;} else {return x+1;}}", Some(json!([1]))),
+        ("function a(x) {if (true) {return x;} else if (false) {return x+1;} else {return 4;}}", Some(json!([2]))),
+        ("function a(x) {for(var i = 0; i < 5; i ++) {x ++;} return x;}", Some(json!([1]))),
+        ("function a(obj) {for(var i in obj) {obj[i] = 3;}}", Some(json!([1]))),
+        ("function a(obj) {for(var i of obj) {obj[i] = 3;}}", Some(json!([1]))),
+        ("function a(x) {var y = x ? x : 1;}", Some(json!([1]))),
+        ("function a(x) {var y = x && 1;}", Some(json!([1]))),
+        ("function a(x) {var y = x || 1;}", Some(json!([1]))),
+        ("function a(x) {var y = x ?? 1;}", Some(json!([1]))),
+        ("function a(x) {var y = x && y || z;}", Some(json!([2]))),
+        ("function a(x) {switch(x){case 1: 1; break; case 2: 2; break; default: 3;}}", Some(json!([2]))),
+        ("function a(x) {while(true) {'foo';}}", Some(json!([1]))),
+        ("function a(x) {do {'foo';} while (true)}", Some(json!([1]))),
+        ("function a(x) {try {} catch (e) {}}", Some(json!([1]))),
+        ("function a(x) {x ||= 1;}", Some(json!([1]))),
+        ("function a(x) {(function() {while(true){'foo';}})(); (function() {while(true){'bar';}})();}", Some(json!([1]))),
+        ("function a(x) {(function() {while(true){'foo';}})(); (function() {'bar';})();}", Some(json!([1]))),
+        (
+            "function a(x) { if (a && b || c && d || e) { while (f) { g ? h() : i(); } } }",
+            Some(json!([{ "max": 5 }])),
+        ),
+    ];
+
+    Tester::new(Complexity::NAME, pass, fail).test_and_snapshot();
+}
@@ -1,5 +1,5 @@
 use oxc_ast::{
-    ast::{Expression, MethodDefinitionKind, Statement},
+    ast::{Expression, MethodDefinitionKind},
     AstKind,
 };
 use oxc_diagnostics::{
@@ -9,13 +9,23 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, utils::SuperCallAnalysis, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(constructor-super): Expected to call 'super()'.")]
 #[diagnostic(severity(warning), help("Ensure 'super()' is called from constructor"))]
 struct ConstructorSuperDiagnostic(#[label] pub Span);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(constructor-super): Lacked a call of 'super()' in some code paths.")]
+#[diagnostic(severity(warning), help("Ensure 'super()' is called on every code path"))]
+struct MissingSomeSuperDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(constructor-super): Unexpected duplicate 'super()'.")]
+#[diagnostic(severity(warning), help("'super()' can only be called once"))]
+struct DuplicateSuperDiagnostic(#[label] pub Span);
+
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(constructor-super): Unexpected 'super()' because 'super' is not a constructor.")]
 #[diagnostic(severity(warning), help("Do not call 'super()' from constructor."))]
@@ -33,16 +43,34 @@ declare_oxc_lint!(
     ///
     /// ### Why is this bad?
     ///
+    /// The constructor of a derived class must call `super()` exactly once before it returns,
+    /// otherwise a `ReferenceError` is thrown when the class is instantiated.
+    /// The code paths of the constructor are checked, so a call which is missing in a branch,
+    /// or which may be made twice, is reported as well.
+    /// Calling `super()` is also an error when the class extends something which cannot be a
+    /// constructor, such as `null` or a literal.
     ///
     /// ### Example
     /// ```javascript
+    /// // Bad
     /// class A extends B {
     ///   constructor() {}
     /// }
+    /// class C extends B {
+    ///   constructor() {
+    ///     if (foo) super();
+    ///   }
+    /// }
+    ///
+    /// // Good
+    /// class A extends B {
+    ///   constructor() {
+    ///     super();
+    ///   }
+    /// }
     /// ```
     ConstructorSuper,
-    nursery // This rule should be implemented with CFG, the current implementation has a lot of
-            // false positives.
+    correctness
 );
 
 impl Rule for ConstructorSuper {
@@ -55,41 +83,34 @@ impl Rule for ConstructorSuper {
 
         // In cases where there's no super-class, calling 'super()' inside the constructor
         // is handled by the parser.
-        if let Some(super_class) = &class.super_class {
-            ctor.value.body.as_ref().map_or_else(
-                || {
-                    ctx.diagnostic(ConstructorSuperDiagnostic(ctor.span));
-                },
-                |function_body| {
-                    function_body
-                        .statements
-                        .iter()
-                        .find_map(|stmt| {
-                            let Statement::ExpressionStatement(expr) = stmt else { return None };
-                            let Expression::CallExpression(call_expr) = &expr.expression else {
-                                return None;
-                            };
-                            if matches!(call_expr.callee, Expression::Super(_)) {
-                                Some(call_expr.span)
-                            } else {
-                                None
-                            }
-                        })
-                        .map_or_else(
-                            || {
-                                ctx.diagnostic(ConstructorSuperDiagnostic(ctor.span));
-                            },
-                            |span| {
-                                if let Some(super_class_span) = super_class.span() {
-                                    ctx.diagnostic(SuperNotConstructorDiagnostic(
-                                        span,
-                                        super_class_span,
-                                    ));
-                                }
-                            },
-                        );
-                },
-            );
+        let Some(super_class) = &class.super_class else { return };
+        let analysis = SuperCallAnalysis::new(&ctor.value);
+
+        if let Some(super_class_span) = super_class.span() {
+            let mut reachable_calls =
+                analysis.super_calls.iter().filter(|(_, before)| before.is_reachable()).peekable();
+            // `extends null` can't be instantiated without calling 'super()' either.
+            if reachable_calls.peek().is_none() && matches!(super_class, Expression::NullLiteral(_))
+            {
+                ctx.diagnostic(ConstructorSuperDiagnostic(ctor.span));
+            }
+            for (span, _) in reachable_calls {
+                ctx.diagnostic(SuperNotConstructorDiagnostic(*span, super_class_span));
+            }
+            return;
+        }
+
+        for (span, before) in &analysis.super_calls {
+            if before.may_be_called() {
+                ctx.diagnostic(DuplicateSuperDiagnostic(*span));
+            }
+        }
+        if analysis.returns.may_be_missing() {
+            if analysis.returns.may_be_called() {
+                ctx.diagnostic(MissingSomeSuperDiagnostic(ctor.span));
+            } else {
+                ctx.diagnostic(ConstructorSuperDiagnostic(ctor.span));
+            }
         }
     }
 }
@@ -98,7 +119,7 @@ trait NonConstructor {
     fn span(&self) -> Option<Span>;
 }
 
-impl<'a> NonConstructor for Expression<'a> {
+impl NonConstructor for Expression<'_> {
     fn span(&self) -> Option<Span> {
         match self {
             Self::NullLiteral(lit) => Some(lit.span),
@@ -129,6 +150,21 @@ fn test() {
         ("class A extends (B ??= 5) { constructor() { super(); } }", None),
         ("class A extends (B || C) { constructor() { super(); } }", None),
         ("class A extends (5 && B) { constructor() { super(); } }", None),
+        ("class A extends B { constructor() { if (true) { super(); } else { super(); } } }", None),
+        ("class A extends B { constructor() { a ? super() : super(); } }", None),
+        ("class A extends B { constructor() { switch (a) { case 0: super(); break; default: super(); } } }", None),
+        ("class A extends B { constructor() { try {} finally { super(); } } }", None),
+        ("class A extends B { constructor() { if (a) throw Error(); super(); } }", None),
+        ("class A extends B { constructor() { if (a) { super(); return; } super(); } }", None),
+        ("class A extends B { constructor() { while (!a) { if (b) throw Error(); a = 1; } super(); } }", None),
+        ("class A extends B { constructor() { for (const a of b) { foo(a); } super(); } }", None),
+        ("class A extends B { constructor() { label: { if (a) break label; } super(); } }", None),
+        ("class A extends B { constructor() { super(); this.a = () => super(); } }", None),
+        ("class A extends B { constructor() { super(); function a() {} } }", None),
+        ("class A extends B { constructor() { super(); class C extends D { constructor() { super(); } } } }", None),
+        ("class A extends B { constructor() { throw new Error(); } }", None),
+        ("class A extends B { constructor(a = super()) {} }", None),
+        ("class A { constructor() { return; } }", None),
     ];
 
     let fail = vec![
@@ -137,6 +173,22 @@ fn test() {
         ("class A extends null { constructor() { } }", None),
         ("class A extends 100 { constructor() { super(); } }", None),
         ("class A extends 'test' { constructor() { super(); } }", None),
+        ("class A extends B { constructor() { if (a) super(); } }", None),
+        ("class A extends B { constructor() { a && super(); } }", None),
+        ("class A extends B { constructor() { switch (a) { case 0: super(); } } }", None),
+        ("class A extends B { constructor() { for (a in b) super(); } }", None),
+        ("class A extends B { constructor() { while (a) super(); } }", None),
+        ("class A extends B { constructor() { try { super(); } catch (e) {} } }", None),
+        ("class A extends B { constructor() { if (a) return; super(); } }", None),
+        ("class A extends B { constructor() { return; super(); } }", None),
+        ("class A extends B { constructor() { label: { break label; super(); } } }", None),
+        ("class A extends B { constructor() { function a() { super(); } } }", None),
+        ("class A extends B { constructor() { super(); super(); } }", None),
+        ("class A extends B { constructor() { super() || super(); } }", None),
+        ("class A extends B { constructor() { if (a) super(); super(); } }", None),
+        ("class A extends B { constructor() { for (;;) super(); } }", None),
+        ("class A extends B { constructor() { do { super(); } while (a); } }", None),
+        ("class A extends B { constructor(a = super()) { super(); } }", None),
     ];
 
     Tester::new(ConstructorSuper::NAME, pass, fail).test_and_snapshot();
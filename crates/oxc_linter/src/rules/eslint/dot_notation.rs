@@ -0,0 +1,319 @@
+use oxc_ast::{
+    ast::{Expression, MemberExpression},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use regex::Regex;
+
+use crate::{
+    context::LintContext,
+    rule::{compile_option_regex, Rule},
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum DotNotationDiagnostic {
+    /// The key as written in brackets, e.g. `"foo"` or `null`, and the key.
+    #[error("eslint(dot-notation): [{0}] is better written in dot notation.")]
+    #[diagnostic(severity(warning), help("Use `.{1}` instead."))]
+    UseDot(String, String, #[label] Span),
+
+    #[error("eslint(dot-notation): .{0} is a syntax error.")]
+    #[diagnostic(severity(warning), help("Use `[{0:?}]` instead."))]
+    UseBrackets(String, #[label] Span),
+}
+
+#[derive(Debug, Clone)]
+pub struct DotNotation {
+    /// Whether reserved words may be used as properties in dot notation.
+    allow_keywords: bool,
+    /// Properties which may stay in bracket notation.
+    allow_pattern: Option<Regex>,
+}
+
+impl Default for DotNotation {
+    fn default() -> Self {
+        Self { allow_keywords: true, allow_pattern: None }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce dot notation whenever possible.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Accessing a property with a string literal in brackets, e.g. `foo["bar"]`,
+    /// is harder to read than dot notation and hides the access from searches for `.bar`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// var x = foo["bar"];
+    ///
+    /// // Good
+    /// var x = foo.bar;
+    /// var x = foo[bar];
+    /// var x = foo["bar-baz"];
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// - `allowKeywords`: Set to `false` to require bracket notation for reserved words, e.g. `foo["class"]`.
+    /// - `allowPattern`: A regular expression of property names which may use bracket notation.
+    DotNotation,
+    style
+);
+
+impl Rule for DotNotation {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self::try_from_configuration(value).unwrap_or_default()
+    }
+
+    fn try_from_configuration(value: serde_json::Value) -> Result<Self, oxc_diagnostics::Error> {
+        let Some(config) = value.get(0) else { return Ok(Self::default()) };
+        let allow_pattern = config
+            .get("allowPattern")
+            .and_then(serde_json::Value::as_str)
+            .filter(|pattern| !pattern.is_empty())
+            .map(|pattern| compile_option_regex("allowPattern", pattern))
+            .transpose()?;
+        Ok(Self {
+            allow_keywords: config
+                .get("allowKeywords")
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(true),
+            allow_pattern,
+        })
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::MemberExpression(member_expr) = node.kind() else { return };
+        match member_expr {
+            MemberExpression::ComputedMemberExpression(expr) => {
+                let key = match &expr.expression {
+                    Expression::StringLiteral(lit) => lit.value.as_str(),
+                    Expression::NullLiteral(_) => "null",
+                    Expression::BooleanLiteral(lit) => lit.as_str(),
+                    Expression::TemplateLiteral(template) if template.expressions.is_empty() => {
+                        let Some(quasi) = template.quasis.first() else { return };
+                        let Some(cooked) = &quasi.value.cooked else { return };
+                        cooked.as_str()
+                    }
+                    _ => return,
+                };
+                if !is_valid_identifier(key)
+                    || (!self.allow_keywords && KEYWORDS.contains(&key))
+                    || self.allow_pattern.as_ref().is_some_and(|pattern| pattern.is_match(key))
+                {
+                    return;
+                }
+
+                let formatted = if matches!(
+                    expr.expression,
+                    Expression::NullLiteral(_) | Expression::BooleanLiteral(_)
+                ) {
+                    key.to_string()
+                } else {
+                    format!("{key:?}")
+                };
+                let diagnostic = DotNotationDiagnostic::UseDot(
+                    formatted,
+                    key.to_string(),
+                    expr.expression.span(),
+                );
+                // Only fix `obj["key"]` and `obj?.["key"]` without comments in between,
+                // and leave `1["toString"]` which would need a space before the dot.
+                let brackets = Span::new(expr.object.span().end, expr.span.end);
+                let brackets_text = brackets.source_text(ctx.source_text());
+                let opening = if expr.optional { "?.[" } else { "[" };
+                let before_key = Span::new(brackets.start, expr.expression.span().start)
+                    .source_text(ctx.source_text());
+                let after_key = Span::new(expr.expression.span().end, expr.span.end)
+                    .source_text(ctx.source_text());
+                if before_key.trim() != opening
+                    || after_key.trim() != "]"
+                    || matches!(expr.object, Expression::NumberLiteral(_))
+                    || brackets_text.contains("/*")
+                {
+                    ctx.diagnostic(diagnostic);
+                    return;
+                }
+                let replacement = format!("{}.{key}", if expr.optional { "?" } else { "" });
+                ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(brackets, replacement));
+            }
+            MemberExpression::StaticMemberExpression(expr) => {
+                if self.allow_keywords {
+                    return;
+                }
+                let key = expr.property.name.as_str();
+                if !KEYWORDS.contains(&key) {
+                    return;
+                }
+                let property = Span::new(expr.object.span().end, expr.span.end);
+                let diagnostic =
+                    DotNotationDiagnostic::UseBrackets(key.to_string(), expr.property.span);
+                if property.source_text(ctx.source_text()).contains("/*") {
+                    ctx.diagnostic(diagnostic);
+                    return;
+                }
+                let replacement = format!("{}[\"{key}\"]", if expr.optional { "?." } else { "" });
+                ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(property, replacement));
+            }
+            MemberExpression::PrivateFieldExpression(_) => {}
+        }
+    }
+}
+
+/// Whether `name` is an ASCII identifier, e.g. `foo` but not `foo-bar`.
+fn is_valid_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Reserved words which were not allowed as properties in dot notation before ES5.
+const KEYWORDS: [&str; 59] = [
+    "abstract",
+    "boolean",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "final",
+    "finally",
+    "float",
+    "for",
+    "function",
+    "goto",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "int",
+    "interface",
+    "long",
+    "native",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "short",
+    "static",
+    "super",
+    "switch",
+    "synchronized",
+    "this",
+    "throw",
+    "throws",
+    "transient",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "volatile",
+    "while",
+    "with",
+];
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("a.b;", None),
+        ("a.b.c;", None),
+        ("a['12'];", None),
+        ("a[b];", None),
+        ("a[0];", None),
+        ("a['foo-bar'];", None),
+        ("a['1foo'];", None),
+        ("a['foo bar'];", None),
+        ("a[`time${range}`];", None),
+        ("a[''];", None),
+        ("a.b.c['d-e'];", None),
+        ("a.class;", None),
+        ("a['class'];", Some(json!([{ "allowKeywords": false }]))),
+        ("a['while'];", Some(json!([{ "allowKeywords": false }]))),
+        ("a.foo;", Some(json!([{ "allowKeywords": false }]))),
+        ("a['snake_case'];", Some(json!([{ "allowPattern": "^[a-z]+(_[a-z]+)+$" }]))),
+        ("a['lots_of_snake_case'];", Some(json!([{ "allowPattern": "^[a-z]+(_[a-z]+)+$" }]))),
+        ("this.#priv;", None),
+        ("a[null];", Some(json!([{ "allowKeywords": false }]))),
+        ("a[true];", Some(json!([{ "allowKeywords": false }]))),
+    ];
+
+    let fail = vec![
+        ("a['foo'];", None),
+        ("a[\"foo\"];", None),
+        ("a[`foo`];", None),
+        ("a['true'];", None),
+        ("a['class'];", None),
+        ("a.class;", Some(json!([{ "allowKeywords": false }]))),
+        ("a?.class;", Some(json!([{ "allowKeywords": false }]))),
+        ("a['SnakeCase'];", Some(json!([{ "allowPattern": "^[a-z]+(_[a-z]+)+$" }]))),
+        ("a?.['foo'];", None),
+        ("a['b']['c'];", None),
+        ("foo.bar['baz'].qux;", None),
+        ("1['toString'];", None),
+        ("a /* comment */ ['foo'];", None),
+        ("a[null];", None),
+        ("a[true];", None),
+        ("a[false];", None),
+    ];
+
+    let fix = vec![
+        ("a['foo'];", "a.foo;", None),
+        ("a[\"foo\"];", "a.foo;", None),
+        ("a[`foo`];", "a.foo;", None),
+        ("a?.['foo'];", "a?.foo;", None),
+        ("a [ 'foo' ];", "a.foo;", None),
+        ("a['b']['c'];", "a.b['c'];", None),
+        ("a.class;", "a[\"class\"];", Some(json!([{ "allowKeywords": false }]))),
+        ("a?.class;", "a?.[\"class\"];", Some(json!([{ "allowKeywords": false }]))),
+        ("1['toString'];", "1['toString'];", None),
+        ("a /* comment */ ['foo'];", "a /* comment */ ['foo'];", None),
+        ("a[null];", "a.null;", None),
+        ("a[true];", "a.true;", None),
+        ("a?.[false];", "a?.false;", None),
+    ];
+
+    Tester::new(DotNotation::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
+
+#[test]
+fn test_invalid_allow_pattern() {
+    use serde_json::json;
+
+    let err = DotNotation::try_from_configuration(json!([{ "allowPattern": "(foo" }])).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("Invalid regular expression `(foo` in the `allowPattern` option"));
+}
@@ -105,7 +105,7 @@ impl Rule for Eqeqeq {
         if is_type_of_binary_bool || are_literals_and_same_type_bool {
             ctx.diagnostic_with_fix(
                 EqeqeqDiagnostic(operator, preferred_operator, binary_expr.span),
-                || {
+                |_| {
                     let start = binary_expr.left.span().end;
                     let end = binary_expr.right.span().start;
                     Fix::new(preferred_operator_with_padding, Span { start, end })
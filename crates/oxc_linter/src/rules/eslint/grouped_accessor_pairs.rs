@@ -0,0 +1,194 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{class_accessors, object_accessors, Accessor, AccessorKey},
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum GroupedAccessorPairsDiagnostic {
+    #[error("eslint(grouped-accessor-pairs): Accessor pair {0} and {1} should be grouped.")]
+    #[diagnostic(severity(warning))]
+    NotGrouped(String, String, #[label("{0} is defined here")] Span, #[label] Span),
+
+    #[error("eslint(grouped-accessor-pairs): Expected {1} to be before {0}.")]
+    #[diagnostic(severity(warning))]
+    InvalidOrder(String, String, #[label("{0} is defined here")] Span, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Order {
+    #[default]
+    Any,
+    GetBeforeSet,
+    SetBeforeGet,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct GroupedAccessorPairs {
+    order: Order,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require grouped accessor pairs in object literals and classes.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A getter and a setter for the same property can be defined anywhere in an object literal
+    /// or class, but they are easier to read when they are defined next to each other.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// const foo = {
+    ///     get a() { return this.val; },
+    ///     b: 1,
+    ///     set a(value) { this.val = value; }
+    /// };
+    ///
+    /// // Good
+    /// const foo = {
+    ///     get a() { return this.val; },
+    ///     set a(value) { this.val = value; },
+    ///     b: 1
+    /// };
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `"anyOrder"` (default), `"getBeforeSet"` or `"setBeforeGet"` to also enforce the order of each pair.
+    GroupedAccessorPairs,
+    style
+);
+
+impl Rule for GroupedAccessorPairs {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            order: match value.get(0).and_then(serde_json::Value::as_str) {
+                Some("getBeforeSet") => Order::GetBeforeSet,
+                Some("setBeforeGet") => Order::SetBeforeGet,
+                _ => Order::Any,
+            },
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let accessors = match node.kind() {
+            AstKind::ObjectExpression(obj) => object_accessors(obj, ctx),
+            AstKind::Class(class) => class_accessors(class, ctx),
+            _ => return,
+        };
+
+        // (getters, setters) of each key, in the order in which the keys first appear.
+        let mut pairs: Vec<(Vec<&Accessor>, Vec<&Accessor>)> = vec![];
+        let mut pair_indices: FxHashMap<(bool, &AccessorKey), usize> = FxHashMap::default();
+        for accessor in &accessors {
+            let pair_index =
+                *pair_indices.entry((accessor.is_static, &accessor.key)).or_insert_with(|| {
+                    pairs.push((vec![], vec![]));
+                    pairs.len() - 1
+                });
+            let (getters, setters) = &mut pairs[pair_index];
+            if accessor.is_getter { getters } else { setters }.push(accessor);
+        }
+
+        for (getters, setters) in pairs {
+            // Duplicate accessors are reported by `no-dupe-keys` and `no-dupe-class-members`.
+            let ([getter], [setter]) = (getters.as_slice(), setters.as_slice()) else { continue };
+            let (former, latter) =
+                if getter.index < setter.index { (getter, setter) } else { (setter, getter) };
+            if latter.index - former.index > 1 {
+                ctx.diagnostic(GroupedAccessorPairsDiagnostic::NotGrouped(
+                    former.name(),
+                    latter.name(),
+                    former.span,
+                    latter.span,
+                ));
+            } else if (self.order == Order::GetBeforeSet && former.is_setter())
+                || (self.order == Order::SetBeforeGet && former.is_getter)
+            {
+                ctx.diagnostic(GroupedAccessorPairsDiagnostic::InvalidOrder(
+                    former.name(),
+                    latter.name(),
+                    former.span,
+                    latter.span,
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let get_before_set = || Some(json!(["getBeforeSet"]));
+    let set_before_get = || Some(json!(["setBeforeGet"]));
+
+    let pass = vec![
+        ("({})", None),
+        ("({ a })", None),
+        ("({ a(){}, b(){}, a(){} })", None),
+        ("({ a: 1, b: 2 })", None),
+        ("({ a, ...b, c: 1 })", None),
+        ("({ get a(){} })", None),
+        ("({ set a(foo){} })", None),
+        ("({ get a(){}, set a(foo){} })", None),
+        ("({ set a(foo){}, get a(){} })", None),
+        ("({ get a(){}, set a(foo){}, b: 1 })", None),
+        ("({ b: 1, get a(){}, set a(foo){} })", None),
+        ("({ get a(){}, set a(foo){}, get b(){}, set b(bar){} })", None),
+        ("({ get a(){}, set b(foo){}, b: 1, c: 2 })", None),
+        ("({ get 'a'(){}, set ['a'](foo){} })", None),
+        ("({ get [a](){}, set [a](foo){} })", None),
+        ("({ get a(){}, b: 1, set b(foo){}, c: 2, get a(){}, set a(foo){} })", None),
+        ("class A { get a(){} set a(foo){} }", None),
+        ("class A { set a(foo){} get a(){} }", None),
+        ("class A { get a(){} set a(foo){} b(){} }", None),
+        ("class A { static get a(){} static set a(foo){} }", None),
+        ("class A { get a(){} b(){} static set a(foo){} }", None),
+        ("class A { static get a(){} b(){} set a(foo){} }", None),
+        ("class A { get #a(){} set #a(foo){} }", None),
+        ("class A { get #a(){} b(){} set a(foo){} }", None),
+        ("({ get a(){}, set a(foo){} })", get_before_set()),
+        ("({ set a(foo){}, get a(){} })", set_before_get()),
+        ("class A { get a(){} set a(foo){} }", get_before_set()),
+        ("class A { static set a(foo){} static get a(){} }", set_before_get()),
+        ("({ get a(){}, b: 1, get a(){} })", None),
+    ];
+
+    let fail = vec![
+        ("({ get a(){}, b: 1, set a(foo){} })", None),
+        ("({ set a(foo){}, b: 1, get a(){} })", None),
+        ("({ get a(){}, b(){}, c: 1, set a(foo){} })", None),
+        ("({ get 'a'(){}, b: 1, set ['a'](foo){} })", None),
+        ("({ get [a](){}, b: 1, set [a](foo){} })", None),
+        ("({ get a(){}, ...b, set a(foo){} })", None),
+        ("({ get a(){}, get b(){}, set a(foo){}, set b(bar){} })", None),
+        ("class A { get a(){} b(){} set a(foo){} }", None),
+        ("class A { static set a(foo){} b(){} static get a(){} }", None),
+        ("class A { get a(){} static b(){} set a(foo){} }", None),
+        ("class A { get #a(){} b(){} set #a(foo){} }", None),
+        ("class A { get a(){} c = 1; set a(foo){} }", None),
+        ("({ set a(foo){}, get a(){} })", get_before_set()),
+        ("({ get a(){}, set a(foo){} })", set_before_get()),
+        ("class A { set a(foo){} get a(){} }", get_before_set()),
+        ("class A { static get a(){} static set a(foo){} }", set_before_get()),
+        ("({ get a(){}, b: 1, set a(foo){} })", set_before_get()),
+    ];
+
+    Tester::new(GroupedAccessorPairs::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,271 @@
+use oxc_ast::{
+    ast::{AssignmentTarget, MemberExpression, PropertyKey, SimpleAssignmentTarget},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+use regex::Regex;
+
+use crate::{
+    context::LintContext,
+    rule::{compile_option_regex, Rule},
+    utils::get_string_array_option,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum IdLengthDiagnostic {
+    #[error("eslint(id-length): Identifier name '{0}' is too short (< {1}).")]
+    #[diagnostic(severity(warning))]
+    TooShort(Atom, usize, #[label] Span),
+    #[error("eslint(id-length): Identifier name '{0}' is too long (> {1}).")]
+    #[diagnostic(severity(warning))]
+    TooLong(Atom, usize, #[label] Span),
+}
+
+#[derive(Debug, Clone)]
+pub struct IdLength {
+    min: usize,
+    max: usize,
+    /// Whether to check property names, `properties: "always" | "never"`.
+    properties: bool,
+    /// Names which are always allowed, e.g. `i` or `_`.
+    exceptions: Vec<String>,
+    /// Regular expressions of names which are always allowed.
+    exception_patterns: Vec<Regex>,
+}
+
+impl Default for IdLength {
+    fn default() -> Self {
+        Self {
+            min: 2,
+            max: usize::MAX,
+            properties: true,
+            exceptions: vec![],
+            exception_patterns: vec![],
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforce minimum and maximum identifier lengths
+    ///
+    /// ### Why is this bad?
+    /// Very short identifiers like `e`, `x` or `_t`, and very long ones, make code
+    /// harder to read and maintain.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var x = 5;
+    /// obj.e = document.body;
+    /// function foo(a) {}
+    /// ```
+    IdLength,
+    style
+);
+
+impl Rule for IdLength {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self::try_from_configuration(value).unwrap_or_default()
+    }
+
+    fn try_from_configuration(value: serde_json::Value) -> Result<Self, oxc_diagnostics::Error> {
+        let obj = value.get(0);
+        let get_usize = |key: &str, default: usize| {
+            obj.and_then(|v| v.get(key))
+                .and_then(serde_json::Value::as_u64)
+                .map_or(default, |v| usize::try_from(v).unwrap_or(default))
+        };
+        let get_strings =
+            |key: &str| obj.map(|v| get_string_array_option(v, key)).unwrap_or_default();
+
+        Ok(Self {
+            min: get_usize("min", 2),
+            max: get_usize("max", usize::MAX),
+            properties: obj.and_then(|v| v.get("properties")).and_then(serde_json::Value::as_str)
+                != Some("never"),
+            exceptions: get_strings("exceptions"),
+            exception_patterns: get_strings("exceptionPatterns")
+                .iter()
+                .map(|pattern| compile_option_regex("exceptionPatterns", pattern))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::BindingIdentifier(ident) => self.check(&ident.name, ident.span, ctx),
+            AstKind::LabeledStatement(stmt) => self.check(&stmt.label.name, stmt.label.span, ctx),
+            AstKind::ObjectProperty(prop) if self.properties && !prop.shorthand => {
+                self.check_property_key(&prop.key, ctx);
+            }
+            AstKind::PropertyDefinition(def) if self.properties => {
+                self.check_property_key(&def.key, ctx);
+            }
+            AstKind::MethodDefinition(def) if self.properties => {
+                self.check_property_key(&def.key, ctx);
+            }
+            AstKind::AssignmentExpression(expr) if self.properties => {
+                if let AssignmentTarget::SimpleAssignmentTarget(
+                    SimpleAssignmentTarget::MemberAssignmentTarget(member),
+                ) = &expr.left
+                {
+                    if let MemberExpression::StaticMemberExpression(member) = &**member {
+                        self.check(&member.property.name, member.property.span, ctx);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl IdLength {
+    fn check(&self, name: &Atom, span: Span, ctx: &LintContext<'_>) {
+        let len = name.chars().count();
+        if (self.min..=self.max).contains(&len) || self.is_exception(name) {
+            return;
+        }
+        if len < self.min {
+            ctx.diagnostic(IdLengthDiagnostic::TooShort(name.clone(), self.min, span));
+        } else {
+            ctx.diagnostic(IdLengthDiagnostic::TooLong(name.clone(), self.max, span));
+        }
+    }
+
+    fn is_exception(&self, name: &str) -> bool {
+        self.exceptions.iter().any(|exception| exception == name)
+            || self.exception_patterns.iter().any(|pattern| pattern.is_match(name))
+    }
+
+    fn check_property_key(&self, key: &PropertyKey, ctx: &LintContext<'_>) {
+        match key {
+            PropertyKey::Identifier(ident) => self.check(&ident.name, ident.span, ctx),
+            PropertyKey::PrivateIdentifier(ident) => self.check(&ident.name, ident.span, ctx),
+            PropertyKey::Expression(_) => {}
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var xyz;", None),
+        ("var xy = 1;", None),
+        ("const ab = 1", None),
+        ("function xyz() {};", None),
+        ("function xyz(abc, de) {};", None),
+        ("var obj = { abc: 1, de: 1 };", None),
+        ("var obj = { 'a': 1, bc: 2 };", None),
+        ("var obj = {}; obj['a'] = 2;", None),
+        ("abc = d;", None),
+        ("try { blah(); } catch (err) { /* pass */ }", None),
+        ("var handler = function ($e) {};", None),
+        ("var _a = 2", None),
+        ("var _ad$$ = new $;", None),
+        ("var xyz = new ΣΣ();", None),
+        ("unrelatedExpressionThatNeedsToBeIgnored();", None),
+        ("var obj = { 'a': 1, bc: 2 }; obj.tk = obj.a;", None),
+        ("var query = location.query.q || '';", None),
+        ("var query = location.query.q ? location.query.q : ''", None),
+        ("let {a: foo} = bar;", None),
+        ("let foo = { [a]: 1 };", None),
+        ("let foo = { [a + b]: 1 };", None),
+        ("var x = Foo(42)", Some(json!([{ "min": 1 }]))),
+        ("var x = Foo(42)", Some(json!([{ "min": 0 }]))),
+        ("foo.$x = Foo(42)", Some(json!([{ "min": 1 }]))),
+        ("var lalala = Foo(42)", Some(json!([{ "max": 6 }]))),
+        (
+            "for (var q, h=0; h < 10; h++) { console.log(h); q++; }",
+            Some(json!([{ "exceptions": ["h", "q"] }])),
+        ),
+        ("(num) => { num * num };", None),
+        ("function foo(num = 0) { }", None),
+        ("class MyClass { }", None),
+        ("class Foo { method() {} }", None),
+        ("function foo(...args) { }", None),
+        ("var { prop } = {};", None),
+        ("var { [(a)]: prop } = {};", None),
+        ("var { a: foo } = {};", Some(json!([{ "min": 3 }]))),
+        ("var { prop: [foo] } = {};", None),
+        ("import something from 'y';", None),
+        ("export var num = 0;", None),
+        ("({ prop: obj.x.y.something } = {});", None),
+        ("({ prop: obj.longName } = {});", None),
+        ("var obj = { a: 1, bc: 2 };", Some(json!([{ "properties": "never" }]))),
+        ("var obj = {}; obj.a = 1; obj.bc = 2;", Some(json!([{ "properties": "never" }]))),
+        ("({ prop: obj.x } = {});", Some(json!([{ "properties": "never" }]))),
+        ("var obj = { aaaaa: 1 };", Some(json!([{ "max": 4, "properties": "never" }]))),
+        ("var obj = {}; obj.aaaaa = 1;", Some(json!([{ "max": 4, "properties": "never" }]))),
+        ("class Foo { a = 1; #b = 2; c() {} }", Some(json!([{ "properties": "never" }]))),
+        ("var i = 0;", Some(json!([{ "exceptions": ["i"] }]))),
+        ("var _ = require('lodash');", Some(json!([{ "exceptions": ["_"] }]))),
+        ("function e() {}", Some(json!([{ "exceptionPatterns": ["^[a-z]$"] }]))),
+        ("var x = 1, y = 2;", Some(json!([{ "exceptionPatterns": ["^[xy]$"] }]))),
+        ("class Foo { #abc = 1 }", None),
+        ("class Foo { abc = 1 }", None),
+    ];
+
+    let fail = vec![
+        ("var x = 1;", None),
+        ("const a = 1", None),
+        ("var x;", None),
+        ("obj.e = document.body;", None),
+        ("function x() {};", None),
+        ("function xyz(a) {};", None),
+        ("var obj = { a: 1, bc: 2 };", None),
+        ("try { blah(); } catch (e) { /* pass */ }", None),
+        ("var handler = function (e) {};", None),
+        ("for (var i=0; i < 10; i++) { console.log(i); }", None),
+        ("var j=0; while (j > -10) { console.log(--j); }", None),
+        ("var _$xt_$ = Foo(42)", Some(json!([{ "min": 2, "max": 4 }]))),
+        ("var _$x$_t$ = Foo(42)", Some(json!([{ "min": 2, "max": 4 }]))),
+        ("(a) => { a * a };", None),
+        ("function foo(x = 0) { }", None),
+        ("class x { }", None),
+        ("class Foo { x() {} }", None),
+        ("function foo(...x) { }", None),
+        ("var { x} = {};", None),
+        ("var { x: a} = {};", None),
+        ("var { a: [x]} = {};", None),
+        ("import x from 'y';", None),
+        ("import * as x from 'y';", None),
+        ("import { x } from 'y';", None),
+        ("import { foo as x } from 'y';", None),
+        ("export var x = 0;", None),
+        ("var i = 0;", Some(json!([{ "exceptions": ["j"] }]))),
+        ("function e() {}", Some(json!([{ "exceptionPatterns": ["^[x-z]$"] }]))),
+        ("var x = 1;", Some(json!([{ "properties": "never" }]))),
+        ("class Foo { #x() {} }", None),
+        ("class Foo { x = 1 }", None),
+        ("class Foo { #x = 1 }", None),
+        ("class Foo { #abcdefg = 1 }", Some(json!([{ "max": 3 }]))),
+        ("x: for (;;) { break x; }", None),
+    ];
+
+    Tester::new(IdLength::NAME, pass, fail).test_and_snapshot();
+}
+
+#[test]
+fn test_invalid_exception_pattern() {
+    use serde_json::json;
+
+    let err = IdLength::try_from_configuration(json!([{ "exceptionPatterns": ["^x", "[a-"] }]))
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("Invalid regular expression `[a-` in the `exceptionPatterns` option"));
+    // The lenient constructor falls back to the default configuration.
+    assert!(IdLength::from_configuration(json!([{ "exceptionPatterns": ["[a-"] }]))
+        .exception_patterns
+        .is_empty());
+}
@@ -0,0 +1,179 @@
+use oxc_ast::{
+    ast::{BindingPatternKind, ModifierKind},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum InitDeclarationsDiagnostic {
+    #[error("eslint(init-declarations): Variable '{0}' should be initialized on declaration.")]
+    #[diagnostic(severity(warning))]
+    Initialized(Atom, #[label] Span),
+
+    #[error("eslint(init-declarations): Variable '{0}' should not be initialized on declaration.")]
+    #[diagnostic(severity(warning))]
+    NotInitialized(Atom, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    #[default]
+    Always,
+    Never,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct InitDeclarations {
+    mode: Mode,
+    /// In `never` mode, allow initializing the bindings of `for (let i = 0; ; )`.
+    ignore_for_loop_init: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require or disallow initialization in variable declarations.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Some teams prefer every variable to be initialized where it is declared,
+    /// so that it never holds `undefined` by accident. Others prefer to declare
+    /// variables up front and assign them later.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad, with "always" (the default)
+    /// let foo;
+    ///
+    /// // Bad, with "never"
+    /// let foo = 1;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `"always"` (default) or `"never"`. In `never` mode, `const` declarations are exempt,
+    /// and `{ "ignoreForLoopInit": true }` also allows `for (let i = 0; ; )`.
+    /// The bindings of `for-in` and `for-of` loops are exempt in both modes.
+    InitDeclarations,
+    style
+);
+
+impl Rule for InitDeclarations {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            mode: match value.get(0).and_then(serde_json::Value::as_str) {
+                Some("never") => Mode::Never,
+                _ => Mode::Always,
+            },
+            ignore_for_loop_init: value
+                .get(1)
+                .and_then(|config| config.get("ignoreForLoopInit"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::VariableDeclaration(decl) = node.kind() else { return };
+        if decl.modifiers.contains(ModifierKind::Declare) {
+            return;
+        }
+        let parent_kind = ctx.nodes().parent_kind(node.id());
+        let is_for_in_of_left = match parent_kind {
+            Some(AstKind::ForInStatement(stmt)) => stmt.left.span() == decl.span,
+            Some(AstKind::ForOfStatement(stmt)) => stmt.left.span() == decl.span,
+            _ => false,
+        };
+        if is_for_in_of_left {
+            return;
+        }
+        let is_for_loop_init = matches!(parent_kind, Some(AstKind::ForStatementInit(_)));
+
+        for declarator in &decl.declarations {
+            let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+                continue;
+            };
+            match self.mode {
+                Mode::Always if declarator.init.is_none() => {
+                    ctx.diagnostic(InitDeclarationsDiagnostic::Initialized(
+                        ident.name.clone(),
+                        declarator.span,
+                    ));
+                }
+                Mode::Never
+                    if declarator.init.is_some()
+                        && !decl.kind.is_const()
+                        && !(is_for_loop_init && self.ignore_for_loop_init) =>
+                {
+                    ctx.diagnostic(InitDeclarationsDiagnostic::NotInitialized(
+                        ident.name.clone(),
+                        declarator.span,
+                    ));
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let always = || Some(json!(["always"]));
+    let never = || Some(json!(["never"]));
+
+    let pass = vec![
+        ("var foo = null;", None),
+        ("foo = true;", None),
+        ("var foo = 1, bar = false, baz = {};", None),
+        ("function foo() { var foo = 0; var bar = []; }", None),
+        ("var fn = function() {};", None),
+        ("var foo = bar = 2;", None),
+        ("for (var i = 0; i < 1; i++) {}", None),
+        ("for (var foo in []) {}", None),
+        ("for (var foo of []) {}", None),
+        ("let a = true;", always()),
+        ("const a = {};", always()),
+        ("function foo() { let a = 1, b = false; if (a) { let c = 3, d = null; } }", always()),
+        ("declare let foo: number;", always()),
+        ("var foo;", never()),
+        ("var foo, bar, baz;", never()),
+        ("function foo() { var foo; var bar; }", never()),
+        ("let a;", never()),
+        ("const a = 1;", never()),
+        ("function foo() { let a, b; if (a) { let c, d; } }", never()),
+        ("for (var i in []) {}", never()),
+        ("for (let i of []) {}", never()),
+        ("for (const i of []) {}", never()),
+        ("for (let i = 0; i < 1; i++) {}", Some(json!(["never", { "ignoreForLoopInit": true }]))),
+        ("for (var [a, b] of []) {}", always()),
+    ];
+
+    let fail = vec![
+        ("var foo;", None),
+        ("for (var a in []) var foo;", None),
+        ("var foo, bar = false, baz;", None),
+        ("function foo() { var foo = 0; var bar; }", None),
+        ("let a;", always()),
+        ("function foo() { let a = 1, b; if (a) { let c = 3, d = null; } }", always()),
+        ("var foo = (bar = 2);", never()),
+        ("var foo = true;", never()),
+        ("var foo, bar = 5, baz = 3;", never()),
+        ("let a = 1;", never()),
+        ("let a = 'foo', b;", never()),
+        ("for (var i = 0; i < 1; i++) {}", never()),
+        ("for (let i = 0; i < 1; i++) {}", Some(json!(["never", { "ignoreForLoopInit": false }]))),
+    ];
+
+    Tester::new(InitDeclarations::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,140 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::AstNodeId;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, utils::get_max_option, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(max-depth): Blocks are nested too deeply ({0}). Maximum allowed is {1}.")]
+#[diagnostic(severity(warning))]
+struct MaxDepthDiagnostic(usize, usize, #[label] pub Span);
+
+#[derive(Debug, Clone)]
+pub struct MaxDepth {
+    max: usize,
+}
+
+impl Default for MaxDepth {
+    fn default() -> Self {
+        Self { max: 4 }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforce a maximum depth that blocks can be nested
+    ///
+    /// ### Why is this bad?
+    /// Deeply nested blocks are hard to read and usually a sign that the
+    /// function should be split up or use early returns.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// function foo() {
+    ///     for (;;) { // Nested 1 deep
+    ///         while (true) { // Nested 2 deep
+    ///             if (true) { // Nested 3 deep
+    ///                 if (true) { // Nested 4 deep
+    ///                     if (true) { // Nested 5 deep
+    ///                     }
+    ///                 }
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    MaxDepth,
+    pedantic
+);
+
+impl Rule for MaxDepth {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self { max: get_max_option(&value, 4) }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if !is_nesting_block(node.id(), ctx) {
+            return;
+        }
+
+        let depth = ctx
+            .nodes()
+            .ancestors(node.id())
+            .take_while(|id| !is_function_boundary(ctx.nodes().kind(*id)))
+            .filter(|id| is_nesting_block(*id, ctx))
+            .count();
+
+        if depth > self.max {
+            ctx.diagnostic(MaxDepthDiagnostic(depth, self.max, node.kind().span()));
+        }
+    }
+}
+
+/// Whether the node is a statement which increases the nesting depth.
+/// An `else if` continues its parent `if` rather than nesting in it.
+fn is_nesting_block(node_id: AstNodeId, ctx: &LintContext<'_>) -> bool {
+    match ctx.nodes().kind(node_id) {
+        AstKind::IfStatement(stmt) => !matches!(
+            ctx.nodes().parent_kind(node_id),
+            Some(AstKind::IfStatement(parent))
+                if parent.alternate.as_ref().is_some_and(|alternate| alternate.span() == stmt.span)
+        ),
+        AstKind::SwitchStatement(_)
+        | AstKind::TryStatement(_)
+        | AstKind::DoWhileStatement(_)
+        | AstKind::WhileStatement(_)
+        | AstKind::WithStatement(_)
+        | AstKind::ForStatement(_)
+        | AstKind::ForInStatement(_)
+        | AstKind::ForOfStatement(_) => true,
+        _ => false,
+    }
+}
+
+fn is_function_boundary(kind: AstKind) -> bool {
+    matches!(kind, AstKind::Function(_) | AstKind::ArrowExpression(_) | AstKind::StaticBlock(_))
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function foo() { if (true) { if (false) { if (true) { } } } }", Some(json!([3]))),
+        ("function foo() { if (true) { } else if (false) { } else if (true) { } else if (false) {} }", Some(json!([3]))),
+        ("var foo = () => { if (true) { if (false) { if (true) { } } } }", Some(json!([3]))),
+        ("function foo() { if (true) { if (false) { if (true) { } } } }", None),
+        ("function foo() { if (true) { if (false) { if (true) { } } } }", Some(json!([{ "max": 3 }]))),
+        ("class C { static { if (1) { if (2) {} } } }", Some(json!([2]))),
+        ("class C { static { if (1) { if (2) {} } if (1) { if (2) {} } } }", Some(json!([2]))),
+        ("if (1) { class C { static { if (1) { if (2) {} } } } }", Some(json!([2]))),
+        ("function foo() { if (1) { function bar() { if (2) { if (3) {} } } } }", Some(json!([2]))),
+        ("function foo() { return; }", None),
+    ];
+
+    let fail = vec![
+        ("function foo() { if (true) { if (false) { if (true) { } } } }", Some(json!([2]))),
+        ("var foo = () => { if (true) { if (false) { if (true) { } } } }", Some(json!([2]))),
+        ("function foo() { if (true) {} else { for(;;) {} } }", Some(json!([1]))),
+        ("function foo() { while (true) { if (true) {} } }", Some(json!([1]))),
+        ("function foo() { for (let x of foo) { if (true) {} } }", Some(json!([1]))),
+        ("function foo() { while (true) { if (true) { if (false) { } } } }", Some(json!([1]))),
+        ("function foo() { if (true) { if (false) { if (true) { if (false) { if (true) { } } } } } }", None),
+        ("function foo() { if (true) { if (false) { if (true) { } } } }", Some(json!([{ "max": 2 }]))),
+        ("function foo() { if (a) { if (b) { if (c) { if (d) { if (e) {} } } } } }", Some(json!([{}]))),
+        ("function foo() { if (true) {} }", Some(json!([{ "max": 0 }]))),
+        ("class C { static { if (1) { if (2) { if (3) {} } } } }", Some(json!([2]))),
+        ("if (1) { class C { static { if (1) { if (2) { if (3) {} } } } } }", Some(json!([2]))),
+        ("function foo() { if (1) { class C { static { if (1) { if (2) { if (3) {} } } } } } }", Some(json!([2]))),
+        ("if (a) { if (b) { if (c) { if (d) { if (e) {} } } } }", None),
+    ];
+
+    Tester::new(MaxDepth::NAME, pass, fail).test_and_snapshot();
+}
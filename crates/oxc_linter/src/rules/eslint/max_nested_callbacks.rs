@@ -0,0 +1,118 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::AstNodeId;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, utils::get_max_option, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(max-nested-callbacks): Too many nested callbacks ({0}). Maximum allowed is {1}.")]
+#[diagnostic(
+    severity(warning),
+    help("Consider using promises, async functions or named functions.")
+)]
+struct MaxNestedCallbacksDiagnostic(usize, usize, #[label] pub Span);
+
+#[derive(Debug, Clone)]
+pub struct MaxNestedCallbacks {
+    max: usize,
+}
+
+impl Default for MaxNestedCallbacks {
+    fn default() -> Self {
+        Self { max: 10 }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforce a maximum depth that callbacks can be nested
+    ///
+    /// ### Why is this bad?
+    /// Deeply nested callbacks, also known as "callback hell", are hard to read
+    /// and to reason about.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// foo1(function() {
+    ///     foo2(function() {
+    ///         foo3(function() {
+    ///             foo4(function() {
+    ///                 // Do something
+    ///             });
+    ///         });
+    ///     });
+    /// });
+    /// ```
+    MaxNestedCallbacks,
+    pedantic
+);
+
+impl Rule for MaxNestedCallbacks {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self { max: get_max_option(&value, 10) }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if !is_callback(node.id(), ctx) {
+            return;
+        }
+
+        let depth = ctx.nodes().ancestors(node.id()).filter(|id| is_callback(*id, ctx)).count();
+        if depth > self.max {
+            ctx.diagnostic(MaxNestedCallbacksDiagnostic(depth, self.max, node.kind().span()));
+        }
+    }
+}
+
+/// Whether the node is a function passed directly as an argument of a call.
+fn is_callback(node_id: AstNodeId, ctx: &LintContext<'_>) -> bool {
+    let nodes = ctx.nodes();
+    match nodes.kind(node_id) {
+        AstKind::Function(func) if func.is_expression() => {}
+        AstKind::ArrowExpression(_) => {}
+        _ => return false,
+    }
+    let Some(argument) = nodes.parent_node(node_id) else { return false };
+    matches!(argument.kind(), AstKind::Argument(_))
+        && matches!(nodes.parent_kind(argument.id()), Some(AstKind::CallExpression(_)))
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("foo(function() { bar(thing, function(data) {}); });", Some(json!([3]))),
+        ("var foo = function() {}; bar(function(){ baz(function() { qux(foo); }) });", Some(json!([2]))),
+        ("fn(function(){}, function(){}, function(){});", Some(json!([2]))),
+        ("fn(() => {}, function(){}, function(){});", Some(json!([2]))),
+        ("foo(function() { bar(thing, (data) => {}); });", Some(json!([{ "max": 3 }]))),
+        (
+            "foo(function() { var a = function() { var b = function() { bar(function() {}); }; }; });",
+            Some(json!([2])),
+        ),
+        ("foo(function() { function a() { bar(function() {}); } });", Some(json!([2]))),
+        ("foo(function() { foo(function() { foo(function() {}); }); });", None),
+    ];
+
+    let fail = vec![
+        ("foo(function() { bar(thing, function(data) { baz(function() {}); }); });", Some(json!([2]))),
+        ("foo(function() { bar(thing, (data) => { baz(() => {}); }); });", Some(json!([2]))),
+        ("foo(() => { bar(thing, (data) => { baz( () => {}); }); });", Some(json!([{ "max": 2 }]))),
+        ("foo(function() { if (isTrue) { bar(function(data) { baz(function() {}); }); } });", Some(json!([2]))),
+        ("foo(function() { var a = function() { bar(function() { baz(function() {}); }); }; });", Some(json!([2]))),
+        (
+            "a(() => { b(() => { c(() => { d(() => { e(() => { f(() => { g(() => { h(() => { i(() => { j(() => { k(() => {}); }); }); }); }); }); }); }); }); }); });",
+            None,
+        ),
+    ];
+
+    Tester::new(MaxNestedCallbacks::NAME, pass, fail).test_and_snapshot();
+}
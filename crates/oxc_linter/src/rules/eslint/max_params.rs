@@ -0,0 +1,94 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, utils::get_max_option, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(max-params): Function has too many parameters ({0}). Maximum allowed is {1}.")]
+#[diagnostic(severity(warning), help("Consider passing an options object instead."))]
+struct MaxParamsDiagnostic(usize, usize, #[label] pub Span);
+
+#[derive(Debug, Clone)]
+pub struct MaxParams {
+    max: usize,
+}
+
+impl Default for MaxParams {
+    fn default() -> Self {
+        Self { max: 3 }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforce a maximum number of parameters in function definitions
+    ///
+    /// ### Why is this bad?
+    /// Functions that take many parameters are hard to call correctly, since
+    /// the order of the arguments must be remembered.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// function foo(bar, baz, qux, qxx) {
+    ///     doSomething();
+    /// }
+    /// ```
+    MaxParams,
+    style
+);
+
+impl Rule for MaxParams {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self { max: get_max_option(&value, 3) }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let params = match node.kind() {
+            AstKind::Function(func) => &func.params,
+            AstKind::ArrowExpression(arrow) => &arrow.params,
+            _ => return,
+        };
+        let count = params.parameters_count();
+        if count > self.max {
+            ctx.diagnostic(MaxParamsDiagnostic(count, self.max, params.span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function test(d, e, f) {}", None),
+        ("var test = function(a, b, c) {};", Some(json!([3]))),
+        ("var test = (a, b, c) => {};", Some(json!([3]))),
+        ("var test = function test(a, b, c) {};", Some(json!([3]))),
+        ("var test = function(a, b, c) {};", Some(json!([{ "max": 3 }]))),
+        ("function test(a, b, ...c) {}", None),
+        ("class A { method(a, b, c) {} }", None),
+        ("function test() {}", Some(json!([0]))),
+    ];
+
+    let fail = vec![
+        ("function test(a, b, c, d) {}", None),
+        ("function test(a, b, c) {}", Some(json!([2]))),
+        ("function test(a, b, c, d) {}", Some(json!([{ "max": 3 }]))),
+        ("var test = function(a, b, c, d) {};", Some(json!([3]))),
+        ("var test = (a, b, c, d) => {};", Some(json!([3]))),
+        ("(function(a, b, c, d) {});", Some(json!([3]))),
+        ("var test = function test(a, b, c) {};", Some(json!([1]))),
+        ("function test(a, b, c, ...d) {}", None),
+        ("class A { method(a, b, c, d) {} }", None),
+        ("function test(a) {}", Some(json!([{ "max": 0 }]))),
+    ];
+
+    Tester::new(MaxParams::NAME, pass, fail).test_and_snapshot();
+}
@@ -1,12 +1,12 @@
-use oxc_ast::AstKind;
+use oxc_ast::{ast::Expression, AstKind};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 
-use crate::{context::LintContext, rule::Rule, AstNode};
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-array-constructor): Disallow `Array` constructors")]
@@ -53,12 +53,22 @@ impl Rule for NoArrayConstructor {
             _ => return,
         };
 
-        if callee.is_specific_id("Array")
+        let Expression::Identifier(ident) = callee else { return };
+        if ident.name == "Array"
             && arguments.len() != 1
             && type_parameters.is_none()
             && !optional
+            && ctx.semantic().is_reference_to_global_variable(ident)
         {
-            ctx.diagnostic(NoArrayConstructorDiagnostic(span));
+            let elements = match (arguments.first(), arguments.last()) {
+                (Some(first), Some(last)) => {
+                    Span::new(first.span().start, last.span().end).source_text(ctx.source_text())
+                }
+                _ => "",
+            };
+            ctx.diagnostic_with_fix(NoArrayConstructorDiagnostic(span), |_| {
+                Fix::new(format!("[{elements}]"), span)
+            });
         }
     }
 }
@@ -92,6 +102,8 @@ fn test() {
         ("Array?.<Foo>();", None),
         ("Array?.(0, 1, 2);", None),
         ("Array?.(x, y);", None),
+        ("var Array = function() {}; new Array(1, 2, 3);", None),
+        ("function f(Array) { return Array(a, b); }", None),
     ];
 
     let fail = vec![
@@ -102,7 +114,17 @@ fn test() {
         ("new Array(0, 1, 2)", None),
         ("Array(x, y)", None),
         ("Array(0, 1, 2)", None),
+        ("new Array(1, 2, 3)", None),
+        ("Array(a, b)", None),
     ];
 
-    Tester::new(NoArrayConstructor::NAME, pass, fail).test_and_snapshot();
+    let fix = vec![
+        ("new Array(1, 2, 3)", "[1, 2, 3]", None),
+        ("Array(a, b)", "[a, b]", None),
+        ("new Array()", "[]", None),
+        ("new Array", "[]", None),
+        ("var a = new Array(...b, c);", "var a = [...b, c];", None),
+    ];
+
+    Tester::new(NoArrayConstructor::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
 }
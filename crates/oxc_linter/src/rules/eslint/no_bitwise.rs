@@ -137,6 +137,9 @@ fn test() {
         ("~1<<2 === -8", Some(json!([ { "allow": ["~", "<<"] }]))),
         ("a|0", Some(json!([ { "int32Hint": true}]))),
         ("a|0", Some(json!([ { "int32Hint": false, "allow": ["|"] }]))),
+        ("x | 0", Some(json!([ { "int32Hint": true }]))),
+        ("a &= b", Some(json!([ { "allow": ["&="] }]))),
+        ("a & b", Some(json!([ { "allow": ["&"] }]))),
     ];
 
     let fail = vec![
@@ -153,6 +156,11 @@ fn test() {
         ("a <<= b", None),
         ("a >>= b", None),
         ("a >>>= b", None),
+        ("a | 0", None),
+        ("a | 1", Some(json!([ { "int32Hint": true }]))),
+        ("a |= 0", Some(json!([ { "int32Hint": true }]))),
+        ("a & b", Some(json!([ { "allow": ["&="] }]))),
+        ("a &= b", Some(json!([ { "allow": ["&"] }]))),
     ];
 
     Tester::new(NoBitwise::NAME, pass, fail).test_and_snapshot();
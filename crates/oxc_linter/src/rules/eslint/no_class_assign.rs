@@ -61,6 +61,7 @@ fn test() {
 
     let pass = vec![
         ("class A { } foo(A);", None),
+        ("class A { } new A();", None),
         ("let A = class A { }; foo(A);", None),
         ("class A { b(A) { A = 0; } }", None),
         ("class A { b() { let A; A = 0; } }", None),
@@ -87,6 +88,8 @@ fn test() {
         ("let A = class A { b() { A = 0; } }", None),
         ("class A { } A = 0; A = 1;", None),
         ("if (foo) { class A {} A = 1; }", None),
+        ("class A { } A++;", None),
+        ("let B = class A { static b() { A = 0; } }", None),
     ];
 
     Tester::new(NoClassAssign::NAME, pass, fail).test_and_snapshot();
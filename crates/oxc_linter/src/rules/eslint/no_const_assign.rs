@@ -59,6 +59,7 @@ fn test() {
     use crate::tester::Tester;
 
     let pass = vec![
+        ("const x = 1; console.log(x);", None),
         ("const x = 0; { let x; x = 1; }", None),
         ("const x = 0; function a(x) { x = 1; }", None),
         ("const x = 0; foo(x);", None),
@@ -81,6 +82,13 @@ fn test() {
         ("const x = 0; ({a: x = 1} = {});", None),
         ("const x = 0; x += 1;", None),
         ("const x = 0; ++x;", None),
+        ("const x = 1; x++;", None),
+        ("const x = 1; x--;", None),
+        ("for (const x of [1, 2, 3]) { x = 1; }", None),
+        ("for (const x in {a: 1}) { x = 'b'; }", None),
+        ("const x = 0; for (x of [1, 2, 3]);", None),
+        ("const x = 0; for (x in {a: 1});", None),
+        ("const x = 0; x ??= 1;", None),
         ("for (const i = 0; i < 10; ++i) { foo(i); }", None),
         ("const x = 0; x = 1; x = 2;", None),
         ("const x = 0; function foo() { x = x + 1; }", None),
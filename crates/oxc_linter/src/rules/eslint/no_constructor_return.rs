@@ -0,0 +1,111 @@
+use oxc_ast::{ast::MethodDefinitionKind, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-constructor-return): Unexpected return statement in constructor.")]
+#[diagnostic(severity(warning), help("Use a bare `return;` to exit the constructor early."))]
+struct NoConstructorReturnDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoConstructorReturn;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow returning a value from a constructor.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Returning an object from a class constructor replaces the instance being created,
+    /// and any other value is either ignored or throws a `TypeError` in a derived class.
+    /// Either way, it is almost always a mistake. A bare `return;` is allowed for flow control.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// class A {
+    ///     constructor(a) {
+    ///         this.a = a;
+    ///         return a;
+    ///     }
+    /// }
+    ///
+    /// // Good
+    /// class A {
+    ///     constructor(a) {
+    ///         if (!a) {
+    ///             return;
+    ///         }
+    ///         this.a = a;
+    ///     }
+    /// }
+    /// ```
+    NoConstructorReturn,
+    pedantic
+);
+
+impl Rule for NoConstructorReturn {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ReturnStatement(stmt) = node.kind() else { return };
+        if stmt.argument.is_none() {
+            return;
+        }
+        let nodes = ctx.nodes();
+        let Some(function) = nodes.iter_parents(node.id()).find(|parent| {
+            matches!(parent.kind(), AstKind::Function(_) | AstKind::ArrowExpression(_))
+        }) else {
+            return;
+        };
+        if matches!(
+            nodes.parent_kind(function.id()),
+            Some(AstKind::MethodDefinition(method)) if method.kind == MethodDefinitionKind::Constructor
+        ) {
+            ctx.diagnostic(NoConstructorReturnDiagnostic(stmt.span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "function fn() { return }",
+        "function fn(kumiko) { if (kumiko) { return kumiko } }",
+        "const fn = function () { return }",
+        "const fn = function () { if (kumiko) { return kumiko } }",
+        "const fn = () => { return }",
+        "const fn = () => { if (kumiko) { return kumiko } }",
+        "class C {  }",
+        "class C { constructor() {} }",
+        "class C { constructor() { let v } }",
+        "class C { method() { return '' } }",
+        "class C { get value() { return '' } }",
+        "class C { constructor(a) { if (!a) { return } else { a() } } }",
+        "class C { constructor() { function fn() { return true } } }",
+        "class C { constructor() { this.fn = function () { return true } } }",
+        "class C { constructor() { this.fn = () => { return true } } }",
+        "class C { constructor() { this.fn = () => true } }",
+        "class C { constructor() { class D { method() { return 1 } } } }",
+        "class C { constructor() { return } }",
+        "function C() { return {} }",
+    ];
+
+    let fail = vec![
+        "class C { constructor() { return '' } }",
+        "class C { constructor() { return {} } }",
+        "class C { constructor(a) { if (!a) { return '' } else { a() } } }",
+        "class C { constructor() { return this } }",
+        "class C extends D { constructor() { super(); return {} } }",
+        "(class { constructor() { try { return 1 } finally {} } })",
+    ];
+
+    Tester::new_without_config(NoConstructorReturn::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,70 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-continue): Unexpected use of continue statement.")]
+#[diagnostic(severity(warning))]
+struct NoContinueDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoContinue;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow `continue` statements
+    ///
+    /// ### Why is this bad?
+    /// The `continue` statement skips the rest of the current loop iteration.
+    /// Used carelessly it makes the control flow harder to follow and the loop
+    /// harder to test; a condition is usually clearer.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var sum = 0, i;
+    ///
+    /// for (i = 0; i < 10; i++) {
+    ///     if (i >= 5) {
+    ///         continue;
+    ///     }
+    ///
+    ///     sum += i;
+    /// }
+    /// ```
+    NoContinue,
+    style
+);
+
+impl Rule for NoContinue {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if let AstKind::ContinueStatement(stmt) = node.kind() {
+            ctx.diagnostic(NoContinueDiagnostic(stmt.span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var sum = 0, i; for(i = 0; i < 10; i++){ if(i > 5) { sum += i; } }",
+        "var sum = 0, i = 0; while(i < 10) { if(i > 5) { sum += i; } i++; }",
+        "for (const x of xs) { if (x) { break; } }",
+    ];
+
+    let fail = vec![
+        "var sum = 0, i; for(i = 0; i < 10; i++){ if(i <= 5) { continue; } sum += i; }",
+        "var sum = 0, i; myLabel: for(i = 0; i < 10; i++){ if(i <= 5) { continue myLabel; } sum += i; }",
+        "var sum = 0, i = 0; while(i < 10) { if(i <= 5) { i++; continue; } sum += i; i++; }",
+        "var sum = 0, i = 0; myLabel: while(i < 10) { if(i <= 5) { i++; continue myLabel; } sum += i; i++; }",
+    ];
+
+    Tester::new_without_config(NoContinue::NAME, pass, fail).test_and_snapshot();
+}
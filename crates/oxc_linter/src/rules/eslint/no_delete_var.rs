@@ -38,7 +38,9 @@ declare_oxc_lint!(
 impl Rule for NoDeleteVar {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::UnaryExpression(expr) = node.kind() else { return };
-        if expr.operator == UnaryOperator::Delete && expr.argument.is_identifier_reference() {
+        if expr.operator == UnaryOperator::Delete
+            && expr.argument.without_parenthesized().is_identifier_reference()
+        {
             ctx.diagnostic(NoDeleteVarDiagnostic(expr.span));
         }
     }
@@ -48,9 +50,15 @@ impl Rule for NoDeleteVar {
 fn test() {
     use crate::tester::Tester;
 
-    let pass = vec![("delete x.prop;", None)];
+    let pass = vec![
+        ("delete x.prop;", None),
+        ("delete obj.x;", None),
+        ("delete obj[\"x\"];", None),
+        ("delete obj?.x;", None),
+        ("delete foo();", None),
+    ];
 
-    let fail = vec![("delete x", None)];
+    let fail = vec![("delete x", None), ("var x; delete x;", None), ("delete (x);", None)];
 
     Tester::new(NoDeleteVar::NAME, pass, fail).test_and_snapshot();
 }
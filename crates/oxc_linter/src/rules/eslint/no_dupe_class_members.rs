@@ -177,6 +177,8 @@ fn test() {
         "class A { get foo() {} get bar() {} get baz() {} }",
         "class A { 1() {} 2() {} }",
         "class Foo { foo(a: string): string; foo(a: number): number; foo(a: any): any {} }",
+        "class A { static get foo() {} set foo(value) {} }",
+        "class A { get foo() {} static foo() {} set foo(value) {} }",
     ];
 
     let fail = vec![
@@ -208,6 +210,9 @@ fn test() {
         "class A { foo() {} get foo() {} }",
         "class A { set foo(value) {} foo() {} }",
         "class A { foo; foo; }",
+        "class A { get foo() {} get foo() {} }",
+        "class A { get foo() {} set foo(value) {} foo() {} }",
+        "class A { static set foo(value) {} static set ['foo'](value) {} }",
         // typescript-eslint
         "class A { foo() {}  foo() {}}",
         "!class A { foo() {}  foo() {}};",
@@ -130,5 +130,12 @@ fn test() {
         ("var x = ({ '/(?<zero>0)/': 1, [/(?<zero>0)/]: 2 })", None),
     ];
 
-    Tester::new(NoDupeKeys::NAME, pass, fail).test_and_snapshot();
+    let fail_with_count = vec![
+        ("var x = { a: 1, a: 2, a: 3 };", None, 2),
+        ("var x = { a: 1, b: 2, a: 3, b: 4 };", None, 2),
+    ];
+
+    Tester::new(NoDupeKeys::NAME, pass, fail)
+        .expect_fail_with_count(fail_with_count)
+        .test_and_snapshot();
 }
@@ -77,6 +77,8 @@ fn test() {
         ("var foo = /[\\]]/s;", None),
         ("var foo = /[\\]]/d;", None),
         ("var foo = /\\[]/", None),
+        ("var foo = /abc[a]/", None),
+        ("var foo = /abc\\[\\]/", None),
     ];
 
     let fail = vec![
@@ -88,6 +90,7 @@ fn test() {
         ("var foo = /\\[[]/;", None),
         ("var foo = /\\[\\[\\]a-z[]/;", None),
         ("var foo = /[]]/d;", None),
+        ("var foo = /abc[]/", None),
     ];
 
     Tester::new(NoEmptyCharacterClass::NAME, pass, fail).test_and_snapshot();
@@ -0,0 +1,270 @@
+use oxc_ast::{
+    ast::{FunctionBody, MethodDefinitionKind, PropertyKind},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, utils::get_string_array_option, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-empty-function): Unexpected empty {0}.")]
+#[diagnostic(
+    severity(warning),
+    help("Implement the {0}, or add a comment explaining why it is empty.")
+)]
+struct NoEmptyFunctionDiagnostic(&'static str, #[label] pub Span);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FunctionKind {
+    Function,
+    ArrowFunction,
+    GeneratorFunction,
+    AsyncFunction,
+    Method,
+    GeneratorMethod,
+    AsyncMethod,
+    Getter,
+    Setter,
+    Constructor,
+}
+
+impl FunctionKind {
+    fn from_option(option: &str) -> Option<Self> {
+        Some(match option {
+            "functions" => Self::Function,
+            "arrowFunctions" => Self::ArrowFunction,
+            "generatorFunctions" => Self::GeneratorFunction,
+            "asyncFunctions" => Self::AsyncFunction,
+            "methods" => Self::Method,
+            "generatorMethods" => Self::GeneratorMethod,
+            "asyncMethods" => Self::AsyncMethod,
+            "getters" => Self::Getter,
+            "setters" => Self::Setter,
+            "constructors" => Self::Constructor,
+            _ => return None,
+        })
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Self::Function => "function",
+            Self::ArrowFunction => "arrow function",
+            Self::GeneratorFunction => "generator function",
+            Self::AsyncFunction => "async function",
+            Self::Method => "method",
+            Self::GeneratorMethod => "generator method",
+            Self::AsyncMethod => "async method",
+            Self::Getter => "getter",
+            Self::Setter => "setter",
+            Self::Constructor => "constructor",
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoEmptyFunction {
+    allow: Vec<FunctionKind>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow empty functions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Empty functions can reduce readability because readers need to guess whether it’s
+    /// intentional or not. A function whose body only contains a comment is not considered
+    /// empty, so writing a comment explaining why it is empty is encouraged.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// function foo() {}
+    /// const bar = () => {};
+    ///
+    /// // Good
+    /// function foo() {
+    ///     // do nothing.
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `allow` is a list of kinds of functions which are allowed to be empty:
+    /// `"functions"`, `"arrowFunctions"`, `"generatorFunctions"`, `"asyncFunctions"`,
+    /// `"methods"`, `"generatorMethods"`, `"asyncMethods"`, `"getters"`, `"setters"` and
+    /// `"constructors"`.
+    ///
+    /// ```json
+    /// "no-empty-function": ["error", { "allow": ["constructors", "arrowFunctions"] }]
+    /// ```
+    NoEmptyFunction,
+    restriction
+);
+
+impl Rule for NoEmptyFunction {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let allow = value
+            .get(0)
+            .map(|config| get_string_array_option(config, "allow"))
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|option| FunctionKind::from_option(option))
+            .collect();
+        Self { allow }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (kind, body) = match node.kind() {
+            AstKind::Function(function) => {
+                let Some(body) = &function.body else { return };
+                let kind = match ctx.nodes().parent_kind(node.id()) {
+                    Some(AstKind::MethodDefinition(method)) => match method.kind {
+                        MethodDefinitionKind::Constructor => {
+                            // `constructor(private a) {}` declares a property.
+                            if function
+                                .params
+                                .items
+                                .iter()
+                                .any(|param| param.accessibility.is_some() || param.readonly)
+                            {
+                                return;
+                            }
+                            FunctionKind::Constructor
+                        }
+                        MethodDefinitionKind::Get => FunctionKind::Getter,
+                        MethodDefinitionKind::Set => FunctionKind::Setter,
+                        MethodDefinitionKind::Method => {
+                            method_kind(function.generator, function.r#async)
+                        }
+                    },
+                    Some(AstKind::ObjectProperty(prop)) => match prop.kind {
+                        PropertyKind::Get => FunctionKind::Getter,
+                        PropertyKind::Set => FunctionKind::Setter,
+                        PropertyKind::Init if prop.method => {
+                            method_kind(function.generator, function.r#async)
+                        }
+                        PropertyKind::Init => function_kind(function.generator, function.r#async),
+                    },
+                    _ => function_kind(function.generator, function.r#async),
+                };
+                (kind, &**body)
+            }
+            AstKind::ArrowExpression(arrow) if !arrow.expression => {
+                (FunctionKind::ArrowFunction, &*arrow.body)
+            }
+            _ => return,
+        };
+        if self.allow.contains(&kind) || !is_empty(body, ctx) {
+            return;
+        }
+        ctx.diagnostic(NoEmptyFunctionDiagnostic(kind.description(), body.span));
+    }
+}
+
+fn function_kind(generator: bool, is_async: bool) -> FunctionKind {
+    if generator {
+        FunctionKind::GeneratorFunction
+    } else if is_async {
+        FunctionKind::AsyncFunction
+    } else {
+        FunctionKind::Function
+    }
+}
+
+fn method_kind(generator: bool, is_async: bool) -> FunctionKind {
+    if generator {
+        FunctionKind::GeneratorMethod
+    } else if is_async {
+        FunctionKind::AsyncMethod
+    } else {
+        FunctionKind::Method
+    }
+}
+
+fn is_empty(body: &FunctionBody, ctx: &LintContext) -> bool {
+    body.statements.is_empty()
+        && body.directives.is_empty()
+        && !ctx.semantic().trivias().has_comments_between(body.span)
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let allow = |kinds: &[&str]| Some(json!([{ "allow": kinds }]));
+
+    let pass = vec![
+        ("function foo() { bar(); }", None),
+        ("function foo() {\n    // empty\n}", None),
+        ("function foo() { /* empty */ }", None),
+        ("function foo() { 'use strict'; }", None),
+        ("var foo = function() { bar(); };", None),
+        ("var foo = () => { bar(); };", None),
+        ("var foo = () => {\n    // empty\n};", None),
+        ("var foo = () => bar;", None),
+        ("var foo = () => ({});", None),
+        ("var obj = { foo() { bar(); } };", None),
+        ("var obj = { foo() { /* empty */ } };", None),
+        ("class A { constructor() { /* empty */ } }", None),
+        ("class A { foo() { bar(); } }", None),
+        ("class A { get foo() { /* empty */ } }", None),
+        ("function foo(): void;", None),
+        ("declare function foo(): void;", None),
+        ("abstract class A { abstract foo(): void; }", None),
+        ("class A { constructor(private a) {} }", None),
+        ("class A { constructor(readonly a) {} }", None),
+        ("function foo() {}", allow(&["functions"])),
+        ("var foo = function() {};", allow(&["functions"])),
+        ("var obj = { foo: function() {} };", allow(&["functions"])),
+        ("var foo = () => {};", allow(&["arrowFunctions"])),
+        ("function* foo() {}", allow(&["generatorFunctions"])),
+        ("async function foo() {}", allow(&["asyncFunctions"])),
+        ("var obj = { foo() {} };", allow(&["methods"])),
+        ("class A { foo() {} }", allow(&["methods"])),
+        ("class A { static foo() {} }", allow(&["methods"])),
+        ("var obj = { *foo() {} };", allow(&["generatorMethods"])),
+        ("class A { *foo() {} }", allow(&["generatorMethods"])),
+        ("var obj = { async foo() {} };", allow(&["asyncMethods"])),
+        ("class A { async foo() {} }", allow(&["asyncMethods"])),
+        ("var obj = { get foo() {} };", allow(&["getters"])),
+        ("class A { get foo() {} }", allow(&["getters"])),
+        ("var obj = { set foo(value) {} };", allow(&["setters"])),
+        ("class A { set foo(value) {} }", allow(&["setters"])),
+        ("class A { constructor() {} }", allow(&["constructors"])),
+        ("class A { constructor() {} foo() {} }", allow(&["constructors", "methods"])),
+    ];
+
+    let fail = vec![
+        ("function foo() {}", None),
+        ("var foo = function() {};", None),
+        ("var obj = { foo: function() {} };", None),
+        ("var foo = () => {};", None),
+        ("function* foo() {}", None),
+        ("async function foo() {}", None),
+        ("var obj = { foo() {} };", None),
+        ("class A { foo() {} }", None),
+        ("class A { static foo() {} }", None),
+        ("var obj = { *foo() {} };", None),
+        ("var obj = { async foo() {} };", None),
+        ("var obj = { get foo() {} };", None),
+        ("class A { set foo(value) {} }", None),
+        ("class A { constructor() {} }", None),
+        ("class A { constructor() {} }", allow(&["methods"])),
+        ("function foo() {}", allow(&["arrowFunctions"])),
+        ("var foo = () => {};", allow(&["functions"])),
+        ("async function foo() {}", allow(&["functions"])),
+        ("class A { get foo() {} }", allow(&["setters"])),
+        ("function foo() { function bar() {} }", None),
+    ];
+
+    Tester::new(NoEmptyFunction::NAME, pass, fail).test_and_snapshot();
+}
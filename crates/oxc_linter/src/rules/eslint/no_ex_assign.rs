@@ -29,11 +29,11 @@ declare_oxc_lint!(
     ///
     /// ### Example
     /// ```javascript
-    // try {
-    //     // code
-    // } catch (e) {
-    //     e = 10;
-    // }
+    /// try {
+    ///     // code
+    /// } catch (e) {
+    ///     e = 10;
+    /// }
     /// ```
     NoExAssign,
     correctness
@@ -60,6 +60,10 @@ fn test() {
         ("try { } catch (e) { three = 2 + 1; }", None),
         ("try { } catch ({e}) { this.something = 2; }", None),
         ("function foo() { try { } catch (e) { return false; } }", None),
+        ("try { } catch (e) { let x = e; }", None),
+        ("try { } catch ({message}) { console.log(message); }", None),
+        ("try { } catch ([first]) { const e = first; }", None),
+        ("try { } catch (e) { function f(e) { e = 1; } }", None),
     ];
 
     let fail = vec![
@@ -68,6 +72,8 @@ fn test() {
         ("try { } catch (ex) { [ex] = []; }", None),
         ("try { } catch (ex) { ({x: ex = 0} = {}); }", None),
         ("try { } catch ({message}) { message = 10; }", None),
+        ("try { } catch ({message: msg}) { msg += ''; }", None),
+        ("try { } catch ([first]) { first++; }", None),
     ];
 
     Tester::new(NoExAssign::NAME, pass, fail).test_and_snapshot();
@@ -56,12 +56,15 @@ fn test() {
     use crate::tester::Tester;
 
     let pass = vec![
+        ("function foo() {} foo();", None),
+        ("function foo() {} const bar = foo;", None),
         ("function foo() { var foo = bar; }", None),
         ("function foo(foo) { foo = bar; }", None),
         ("function foo() { var foo; foo = bar; }", None),
         ("var foo = () => {}; foo = bar;", None),
         ("var foo = function() {}; foo = bar;", None),
         ("var foo = function() { foo = bar; };", None),
+        ("let foo = function bar() {}; foo = baz;", None),
         ("import bar from 'bar'; function foo() { var foo = bar; }", None),
     ];
 
@@ -74,6 +77,8 @@ fn test() {
         ("function foo() { [foo] = bar; }", None),
         ("(function() { ({x: foo = 0} = bar); function foo() { }; })();", None),
         ("var a = function foo() { foo = 123; };", None),
+        ("function foo() {} foo++;", None),
+        ("function foo() {} foo += 1;", None),
     ];
 
     Tester::new(NoFuncAssign::NAME, pass, fail).test_and_snapshot();
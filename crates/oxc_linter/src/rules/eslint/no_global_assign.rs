@@ -5,7 +5,7 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{Atom, Span};
 
-use crate::{context::LintContext, globals::BUILTINS, rule::Rule};
+use crate::{context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-global-assign): Read-only global '{0}' should not be modified.")]
@@ -60,7 +60,7 @@ impl Rule for NoGlobalAssign {
                 if reference.is_write() && symbol_table.is_global_reference(reference_id) {
                     let name = reference.name();
 
-                    if !self.excludes.contains(name) && BUILTINS.contains_key(name) {
+                    if !self.excludes.contains(name) && ctx.globals().get(name) == Some(false) {
                         ctx.diagnostic(NoGlobalAssignDiagnostic(name.clone(), reference.span()));
                     }
                 }
@@ -78,23 +78,29 @@ fn test() {
         ("var string;", None),
         ("Object = 0;", Some(serde_json::json!([{ "exceptions": ["Object"] }]))),
         ("top = 0;", None),
-        // ("onload = 0;", None), // env: { browser: true }
+        ("/*eslint-env browser*/ onload = 0;", None),
         ("require = 0;", None),
-        // ("a = 1", None), // globals: { a: true } },
-        // ("/*global a:true*/ a = 1", None),
+        ("/*global a:true*/ a = 1", None),
+        ("/*global a:writable*/ a = 1", None),
+        ("var Object; Object = {};", None),
+        ("function f(undefined) { undefined = 1; }", None),
+        ("let window; window = {};", None),
     ];
 
     let fail = vec![
         ("String = 'hello world';", None),
         ("String++;", None),
         ("({Object = 0, String = 0} = {});", None),
-        // ("top = 0;", None), // env: { browser: true },
-        // ("require = 0;", None), // env: { node: true },
+        ("/*eslint-env browser*/ top = 0;", None),
+        ("/*eslint-env node*/ require = 0;", None),
         ("function f() { Object = 1; }", None),
-        // ("/*global b:false*/ function f() { b = 1; }", None),
-        // ("/*global b:false*/ function f() { b++; }", None),
-        // ("/*global b*/ b = 1;", None),
+        ("/*global b:false*/ function f() { b = 1; }", None),
+        ("/*global b:false*/ function f() { b++; }", None),
+        ("/*global b*/ b = 1;", None),
         ("Array = 1;", None),
+        ("undefined = 1;", None),
+        ("Object = {};", None),
+        ("/*eslint-env browser*/ window = {};", None),
     ];
 
     Tester::new(NoGlobalAssign::NAME, pass, fail).test_and_snapshot();
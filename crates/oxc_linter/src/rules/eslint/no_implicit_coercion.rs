@@ -0,0 +1,333 @@
+use oxc_ast::{
+    ast::{Argument, CallExpression, Expression, TemplateLiteral},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::{AssignmentOperator, BinaryOperator, UnaryOperator};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-implicit-coercion): Unexpected implicit coercion encountered.")]
+#[diagnostic(severity(warning), help("Use `{0}` instead."))]
+struct NoImplicitCoercionDiagnostic(String, #[label] pub Span);
+
+#[derive(Debug, Clone)]
+pub struct NoImplicitCoercion {
+    /// Disallow `!!foo` and `~foo.indexOf(bar)`.
+    boolean: bool,
+    /// Disallow `+foo` and `1 * foo`.
+    number: bool,
+    /// Disallow `"" + foo` and `foo += ""`.
+    string: bool,
+    /// Disallow `` `${foo}` ``.
+    disallow_template_shorthand: bool,
+}
+
+impl Default for NoImplicitCoercion {
+    fn default() -> Self {
+        Self { boolean: true, number: true, string: true, disallow_template_shorthand: false }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow shorthand type conversions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Shorthand conversions such as `!!foo` or `+foo` are terse, but harder to read
+    /// and to search for than the explicit `Boolean(foo)` or `Number(foo)`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// var b = !!foo;
+    /// var n = +foo;
+    /// var s = "" + foo;
+    ///
+    /// // Good
+    /// var b = Boolean(foo);
+    /// var n = Number(foo);
+    /// var s = String(foo);
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `boolean`, `number` and `string` (all `true` by default) check the respective conversions,
+    /// `disallowTemplateShorthand` (`false` by default) also checks `` `${foo}` ``.
+    NoImplicitCoercion,
+    style
+);
+
+impl Rule for NoImplicitCoercion {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let default = Self::default();
+        let Some(config) = value.get(0) else { return default };
+        let flag = |key: &str, default: bool| {
+            config.get(key).and_then(serde_json::Value::as_bool).unwrap_or(default)
+        };
+        Self {
+            boolean: flag("boolean", default.boolean),
+            number: flag("number", default.number),
+            string: flag("string", default.string),
+            disallow_template_shorthand: flag(
+                "disallowTemplateShorthand",
+                default.disallow_template_shorthand,
+            ),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::UnaryExpression(unary_expr) => match unary_expr.operator {
+                // !!foo
+                UnaryOperator::LogicalNot if self.boolean => {
+                    let Expression::UnaryExpression(inner) = &unary_expr.argument else { return };
+                    if inner.operator == UnaryOperator::LogicalNot {
+                        report_with_fix(ctx, "Boolean", &inner.argument, unary_expr.span);
+                    }
+                }
+                // ~foo.indexOf(bar)
+                UnaryOperator::BitwiseNot
+                    if self.boolean && is_index_of_call(&unary_expr.argument) =>
+                {
+                    let argument = unary_expr.argument.span().source_text(ctx.source_text());
+                    ctx.diagnostic(NoImplicitCoercionDiagnostic(
+                        format!("{argument} !== -1"),
+                        unary_expr.span,
+                    ));
+                }
+                // +foo
+                UnaryOperator::UnaryPlus if self.number && !is_numeric(&unary_expr.argument) => {
+                    report_with_fix(ctx, "Number", &unary_expr.argument, unary_expr.span);
+                }
+                _ => {}
+            },
+            AstKind::BinaryExpression(binary_expr) => match binary_expr.operator {
+                // 1 * foo, foo * 1
+                BinaryOperator::Multiplication if self.number => {
+                    let operand = if is_number_1(&binary_expr.left) {
+                        &binary_expr.right
+                    } else if is_number_1(&binary_expr.right) {
+                        &binary_expr.left
+                    } else {
+                        return;
+                    };
+                    if !is_numeric(operand) {
+                        report_with_fix(ctx, "Number", operand, binary_expr.span);
+                    }
+                }
+                // "" + foo, foo + ""
+                BinaryOperator::Addition if self.string => {
+                    let operand = if is_empty_string(&binary_expr.left) {
+                        &binary_expr.right
+                    } else if is_empty_string(&binary_expr.right) {
+                        &binary_expr.left
+                    } else {
+                        return;
+                    };
+                    if !is_string_type(operand) {
+                        report_with_fix(ctx, "String", operand, binary_expr.span);
+                    }
+                }
+                _ => {}
+            },
+            // foo += ""
+            AstKind::AssignmentExpression(assign_expr)
+                if self.string
+                    && assign_expr.operator == AssignmentOperator::Addition
+                    && is_empty_string(&assign_expr.right) =>
+            {
+                let target = assign_expr.left.span().source_text(ctx.source_text());
+                ctx.diagnostic(NoImplicitCoercionDiagnostic(
+                    format!("{target} = String({target})"),
+                    assign_expr.span,
+                ));
+            }
+            // `${foo}`
+            AstKind::TemplateLiteral(template) if self.disallow_template_shorthand => {
+                if matches!(
+                    ctx.nodes().parent_kind(node.id()),
+                    Some(AstKind::TaggedTemplateExpression(_))
+                ) {
+                    return;
+                }
+                let Some(expr) = template_shorthand_expression(template) else { return };
+                if !is_string_type(expr) {
+                    report_with_fix(ctx, "String", expr, template.span);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Report `span` and fix it to `{function}({operand})`.
+fn report_with_fix<'a>(
+    ctx: &LintContext<'a>,
+    function: &str,
+    operand: &Expression<'a>,
+    span: Span,
+) {
+    // Keep the parentheses of `(a, b)`, which would otherwise become two arguments.
+    let inner = operand.without_parenthesized();
+    let operand = if matches!(inner, Expression::SequenceExpression(_)) { operand } else { inner };
+    let recommendation = format!("{function}({})", operand.span().source_text(ctx.source_text()));
+    ctx.diagnostic_with_fix(NoImplicitCoercionDiagnostic(recommendation.clone(), span), |fixer| {
+        fixer.replace(span, recommendation)
+    });
+}
+
+fn is_index_of_call(expr: &Expression) -> bool {
+    let Expression::CallExpression(call_expr) = expr.without_parenthesized() else {
+        return false;
+    };
+    let Expression::MemberExpression(member_expr) = &call_expr.callee else { return false };
+    matches!(member_expr.static_property_name(), Some("indexOf" | "lastIndexOf"))
+}
+
+/// A number literal, or a call which always returns a number.
+fn is_numeric(expr: &Expression) -> bool {
+    match expr.without_parenthesized() {
+        Expression::NumberLiteral(_) => true,
+        Expression::CallExpression(call_expr) => {
+            is_call_to(call_expr, &["Number", "parseInt", "parseFloat"])
+        }
+        _ => false,
+    }
+}
+
+fn is_number_1(expr: &Expression) -> bool {
+    matches!(
+        expr.without_parenthesized(),
+        Expression::NumberLiteral(lit) if (lit.value - 1.0).abs() < f64::EPSILON
+    )
+}
+
+fn is_empty_string(expr: &Expression) -> bool {
+    match expr.without_parenthesized() {
+        Expression::StringLiteral(lit) => lit.value.is_empty(),
+        Expression::TemplateLiteral(template) => {
+            template.expressions.is_empty()
+                && template.quasis.iter().all(|quasi| quasi.value.raw.is_empty())
+        }
+        _ => false,
+    }
+}
+
+/// A string literal, a template literal, or a call to `String`.
+fn is_string_type(expr: &Expression) -> bool {
+    match expr.without_parenthesized() {
+        Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => true,
+        Expression::CallExpression(call_expr) => is_call_to(call_expr, &["String"]),
+        _ => false,
+    }
+}
+
+fn is_call_to(call_expr: &CallExpression, names: &[&str]) -> bool {
+    let Expression::Identifier(ident) = &call_expr.callee else { return false };
+    names.contains(&ident.name.as_str())
+        && !matches!(call_expr.arguments.first(), Some(Argument::SpreadElement(_)))
+}
+
+/// The expression of a template literal which consists of only that expression, e.g. `` `${foo}` ``.
+fn template_shorthand_expression<'a, 'b>(
+    template: &'b TemplateLiteral<'a>,
+) -> Option<&'b Expression<'a>> {
+    if template.expressions.len() != 1 || template.quasis.iter().any(|q| !q.value.raw.is_empty()) {
+        return None;
+    }
+    template.expressions.first()
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("Boolean(foo)", None),
+        ("foo.indexOf(1) !== -1", None),
+        ("Number(foo)", None),
+        ("parseInt(foo)", None),
+        ("parseFloat(foo)", None),
+        ("String(foo)", None),
+        ("!foo", None),
+        ("~foo", None),
+        ("-foo", None),
+        ("+1234", None),
+        ("-1234", None),
+        ("+Number(lol)", None),
+        ("-parseFloat(lol)", None),
+        ("2 * foo", None),
+        ("1 * 1234", None),
+        ("1 * Number(foo)", None),
+        ("1 * parseInt(foo)", None),
+        ("Number(foo) * 1", None),
+        ("foo * bar", None),
+        ("\"\" + String(foo)", None),
+        ("\"\" + `${foo}`", None),
+        ("String(foo) + \"\"", None),
+        ("`${foo}` + \"\"", None),
+        ("foo += 1", None),
+        ("foo += \"a\"", None),
+        ("foo + \"a\"", None),
+        ("`${foo}`", None),
+        ("`a${foo}`", None),
+        ("`${foo}`", Some(json!([{ "disallowTemplateShorthand": false }]))),
+        ("tag`${foo}`", Some(json!([{ "disallowTemplateShorthand": true }]))),
+        ("`${String(foo)}`", Some(json!([{ "disallowTemplateShorthand": true }]))),
+        ("`${foo}${bar}`", Some(json!([{ "disallowTemplateShorthand": true }]))),
+        ("!!foo", Some(json!([{ "boolean": false }]))),
+        ("~foo.indexOf(1)", Some(json!([{ "boolean": false }]))),
+        ("+foo", Some(json!([{ "number": false }]))),
+        ("1 * foo", Some(json!([{ "number": false }]))),
+        ("\"\" + foo", Some(json!([{ "string": false }]))),
+        ("foo += \"\"", Some(json!([{ "string": false }]))),
+    ];
+
+    let fail = vec![
+        ("!!foo", None),
+        ("!!(foo + bar)", None),
+        ("~foo.indexOf(1)", None),
+        ("~foo.bar.indexOf(2)", None),
+        ("~foo.lastIndexOf(1)", None),
+        ("+foo", None),
+        ("+foo.bar", None),
+        ("1 * foo", None),
+        ("foo * 1", None),
+        ("1 * foo.bar", None),
+        ("\"\" + foo", None),
+        ("foo + \"\"", None),
+        ("``+foo", None),
+        ("foo+``", None),
+        ("\"\" + foo.bar", None),
+        ("foo += \"\"", None),
+        ("`${foo}`", Some(json!([{ "disallowTemplateShorthand": true }]))),
+        ("`${foo.bar}`", Some(json!([{ "disallowTemplateShorthand": true }]))),
+    ];
+
+    let fix = vec![
+        ("!!foo", "Boolean(foo)", None),
+        ("!!(foo + bar)", "Boolean(foo + bar)", None),
+        ("!!(foo, bar)", "Boolean((foo, bar))", None),
+        ("+foo", "Number(foo)", None),
+        ("+foo.bar", "Number(foo.bar)", None),
+        ("1 * foo", "Number(foo)", None),
+        ("foo * 1", "Number(foo)", None),
+        ("\"\" + foo", "String(foo)", None),
+        ("foo + \"\"", "String(foo)", None),
+        ("``+foo", "String(foo)", None),
+        ("`${foo}`", "String(foo)", Some(json!([{ "disallowTemplateShorthand": true }]))),
+    ];
+
+    Tester::new(NoImplicitCoercion::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
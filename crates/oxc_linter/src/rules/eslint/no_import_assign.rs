@@ -1,10 +1,19 @@
+use oxc_ast::{
+    ast::{
+        Argument, ChainElement, Expression, ImportDeclarationSpecifier, MemberExpression,
+        ModuleDeclaration,
+    },
+    AstKind,
+};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
-    thiserror::Error,
+    thiserror::{self, Error},
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_semantic::SymbolId;
-use oxc_span::Span;
+use oxc_semantic::{AstNodeId, SymbolId};
+use oxc_span::{Atom, GetSpan, Span};
+use oxc_syntax::operator::UnaryOperator;
+use phf::{phf_set, Set};
 
 use crate::{context::LintContext, rule::Rule};
 
@@ -13,6 +22,11 @@ use crate::{context::LintContext, rule::Rule};
 #[diagnostic(severity(warning), help("imported bindings are readonly"))]
 struct NoImportAssignDiagnostic(#[label] pub Span);
 
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-import-assign): do not assign to the members of namespace '{0}'")]
+#[diagnostic(severity(warning), help("the members of a namespace import are readonly"))]
+struct NoImportAssignNamespaceMemberDiagnostic(Atom, #[label] pub Span);
+
 #[derive(Debug, Default, Clone)]
 pub struct NoImportAssign;
 
@@ -42,16 +56,114 @@ declare_oxc_lint!(
     nursery
 );
 
+/// Methods of `Object` which modify their first argument.
+const OBJECT_MUTATION_METHODS: Set<&'static str> =
+    phf_set!("assign", "defineProperty", "defineProperties", "freeze", "setPrototypeOf");
+
+/// Methods of `Reflect` which modify their first argument.
+const REFLECT_MUTATION_METHODS: Set<&'static str> =
+    phf_set!("defineProperty", "deleteProperty", "set", "setPrototypeOf");
+
 impl Rule for NoImportAssign {
     fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
         let symbol_table = ctx.semantic().symbols();
-        if symbol_table.get_flag(symbol_id).is_import_binding() {
-            for reference in symbol_table.get_resolved_references(symbol_id) {
-                if reference.is_write() {
-                    ctx.diagnostic(NoImportAssignDiagnostic(reference.span()));
+        if !symbol_table.get_flag(symbol_id).is_import_binding() {
+            return;
+        }
+        let is_namespace = is_namespace_import(symbol_id, ctx);
+        for reference in symbol_table.get_resolved_references(symbol_id) {
+            if reference.is_write() {
+                ctx.diagnostic(NoImportAssignDiagnostic(reference.span()));
+            } else if is_namespace {
+                if let Some(span) = get_namespace_member_modification(reference.node_id(), ctx) {
+                    ctx.diagnostic(NoImportAssignNamespaceMemberDiagnostic(
+                        symbol_table.get_name(symbol_id).clone(),
+                        span,
+                    ));
+                }
+            }
+        }
+    }
+}
+
+/// Whether the symbol is bound by `import * as ns from "mod"`.
+fn is_namespace_import(symbol_id: SymbolId, ctx: &LintContext<'_>) -> bool {
+    let symbol_span = ctx.symbols().get_span(symbol_id);
+    let declaration = ctx.symbols().get_declaration(symbol_id);
+    let AstKind::ModuleDeclaration(ModuleDeclaration::ImportDeclaration(decl)) =
+        ctx.nodes().kind(declaration)
+    else {
+        return false;
+    };
+    decl.specifiers.iter().flatten().any(|specifier| {
+        matches!(specifier, ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier)
+            if specifier.local.span == symbol_span)
+    })
+}
+
+/// Returns the span of the expression which modifies a member of the namespace
+/// referenced by `node_id`, e.g. `ns.prop = 0`, `delete ns.prop` or `Object.assign(ns, obj)`.
+fn get_namespace_member_modification(node_id: AstNodeId, ctx: &LintContext<'_>) -> Option<Span> {
+    let nodes = ctx.nodes();
+    let parent = nodes.parent_node(node_id)?;
+    match parent.kind() {
+        AstKind::MemberExpression(member) => {
+            let is_object = matches!(member.object(), Expression::Identifier(ident)
+                if ident.span == nodes.kind(node_id).span());
+            if !is_object {
+                return None;
+            }
+            let mut target = nodes.parent_node(parent.id())?;
+            if let AstKind::ChainExpression(_) = target.kind() {
+                target = nodes.parent_node(target.id())?;
+            }
+            match target.kind() {
+                AstKind::SimpleAssignmentTarget(_) | AstKind::UpdateExpression(_) => {
+                    Some(member.span())
+                }
+                AstKind::UnaryExpression(expr) if expr.operator == UnaryOperator::Delete => {
+                    Some(expr.span)
                 }
+                _ => None,
             }
         }
+        AstKind::Argument(arg) => {
+            let AstKind::CallExpression(call) = nodes.parent_kind(parent.id())? else {
+                return None;
+            };
+            let is_first_argument =
+                call.arguments.first().is_some_and(|first| first.span() == arg.span());
+            let is_identifier = matches!(arg, Argument::Expression(Expression::Identifier(_)));
+            (is_first_argument && is_identifier && is_mutation_method(&call.callee, ctx))
+                .then_some(call.span)
+        }
+        _ => None,
+    }
+}
+
+/// Whether the callee is one of the methods of the global `Object` or `Reflect`
+/// which modify their first argument.
+fn is_mutation_method(callee: &Expression<'_>, ctx: &LintContext<'_>) -> bool {
+    let member = match callee.get_inner_expression() {
+        Expression::MemberExpression(member) => member,
+        Expression::ChainExpression(chain) => match &chain.expression {
+            ChainElement::MemberExpression(member) => member,
+            ChainElement::CallExpression(_) => return false,
+        },
+        _ => return false,
+    };
+    let (Expression::Identifier(object), Some(property)) =
+        (member.object(), MemberExpression::static_property_name(member))
+    else {
+        return false;
+    };
+    if !ctx.semantic().is_reference_to_global_variable(object) {
+        return false;
+    }
+    match object.name.as_str() {
+        "Object" => OBJECT_MUTATION_METHODS.contains(property),
+        "Reflect" => REFLECT_MUTATION_METHODS.contains(property),
+        _ => false,
     }
 }
 
@@ -60,6 +172,8 @@ fn test() {
     use crate::tester::Tester;
 
     let pass = vec![
+        ("import mod from 'mod'; console.log(mod);", None),
+        ("import * as mod from 'mod'; console.log(mod.named);", None),
         ("import mod from 'mod'; mod.prop = 0", None),
         ("import mod from 'mod'; mod.prop += 0", None),
         ("import mod from 'mod'; mod.prop++", None),
@@ -154,32 +268,31 @@ fn test() {
         ("import * as mod9 from 'mod'; ({ bar: mod9 } = foo)", None),
         ("import * as mod10 from 'mod'; ({ bar: mod10 = 0 } = foo)", None),
         ("import * as mod11 from 'mod'; ({ ...mod11 } = foo)", None),
-        // TODO
-        // ("import * as mod1 from 'mod'; mod1.named = 0", None),
-        // ("import * as mod2 from 'mod'; mod2.named += 0", None),
-        // ("import * as mod3 from 'mod'; mod3.named++", None),
-        // ("import * as mod4 from 'mod'; for (mod4.named in foo);", None),
-        // ("import * as mod5 from 'mod'; for (mod5.named of foo);", None),
-        // ("import * as mod6 from 'mod'; [mod6.named] = foo", None),
-        // ("import * as mod7 from 'mod'; [mod7.named = 0] = foo", None),
-        // ("import * as mod8 from 'mod'; [...mod8.named] = foo", None),
-        // ("import * as mod9 from 'mod'; ({ bar: mod9.named } = foo)", None),
-        // ("import * as mod10 from 'mod'; ({ bar: mod10.named = 0 } = foo)", None),
-        // ("import * as mod11 from 'mod'; ({ ...mod11.named } = foo)", None),
-        // ("import * as mod12 from 'mod'; delete mod12.named", None),
-        // ("import * as mod from 'mod'; Object.assign(mod, obj)", None),
-        // ("import * as mod from 'mod'; Object.defineProperty(mod, key, d)", None),
-        // ("import * as mod from 'mod'; Object.defineProperties(mod, d)", None),
-        // ("import * as mod from 'mod'; Object.setPrototypeOf(mod, proto)", None),
-        // ("import * as mod from 'mod'; Object.freeze(mod)", None),
-        // ("import * as mod from 'mod'; Reflect.defineProperty(mod, key, d)", None),
-        // ("import * as mod from 'mod'; Reflect.deleteProperty(mod, key)", None),
-        // ("import * as mod from 'mod'; Reflect.set(mod, key, value)", None),
-        // ("import * as mod from 'mod'; Reflect.setPrototypeOf(mod, proto)", None),
-        // ("import mod, * as mod_ns from 'mod'; mod.prop = 0; mod_ns.prop = 0", None),
-        // ("import * as mod from 'mod'; Object?.defineProperty(mod, key, d)", None),
-        // ("import * as mod from 'mod'; (Object?.defineProperty)(mod, key, d)", None),
-        // ("import * as mod from 'mod'; delete mod?.prop", None),
+        ("import * as mod1 from 'mod'; mod1.named = 0", None),
+        ("import * as mod2 from 'mod'; mod2.named += 0", None),
+        ("import * as mod3 from 'mod'; mod3.named++", None),
+        ("import * as mod4 from 'mod'; for (mod4.named in foo);", None),
+        ("import * as mod5 from 'mod'; for (mod5.named of foo);", None),
+        ("import * as mod6 from 'mod'; [mod6.named] = foo", None),
+        ("import * as mod7 from 'mod'; [mod7.named = 0] = foo", None),
+        ("import * as mod8 from 'mod'; [...mod8.named] = foo", None),
+        ("import * as mod9 from 'mod'; ({ bar: mod9.named } = foo)", None),
+        ("import * as mod10 from 'mod'; ({ bar: mod10.named = 0 } = foo)", None),
+        ("import * as mod11 from 'mod'; ({ ...mod11.named } = foo)", None),
+        ("import * as mod12 from 'mod'; delete mod12.named", None),
+        ("import * as mod from 'mod'; Object.assign(mod, obj)", None),
+        ("import * as mod from 'mod'; Object.defineProperty(mod, key, d)", None),
+        ("import * as mod from 'mod'; Object.defineProperties(mod, d)", None),
+        ("import * as mod from 'mod'; Object.setPrototypeOf(mod, proto)", None),
+        ("import * as mod from 'mod'; Object.freeze(mod)", None),
+        ("import * as mod from 'mod'; Reflect.defineProperty(mod, key, d)", None),
+        ("import * as mod from 'mod'; Reflect.deleteProperty(mod, key)", None),
+        ("import * as mod from 'mod'; Reflect.set(mod, key, value)", None),
+        ("import * as mod from 'mod'; Reflect.setPrototypeOf(mod, proto)", None),
+        ("import mod, * as mod_ns from 'mod'; mod.prop = 0; mod_ns.prop = 0", None),
+        ("import * as mod from 'mod'; Object?.defineProperty(mod, key, d)", None),
+        ("import * as mod from 'mod'; (Object?.defineProperty)(mod, key, d)", None),
+        ("import * as mod from 'mod'; delete mod?.prop", None),
     ];
 
     Tester::new(NoImportAssign::NAME, pass, fail).test_and_snapshot();
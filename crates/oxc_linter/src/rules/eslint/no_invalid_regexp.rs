@@ -0,0 +1,510 @@
+// Ported from https://github.com/eslint/eslint/blob/main/lib/rules/no-invalid-regexp.js
+use oxc_ast::{
+    ast::{Argument, Expression, StringLiteral},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+
+use crate::{context::LintContext, rule::Rule, utils::get_string_array_option, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoInvalidRegexpDiagnostic {
+    #[error("eslint(no-invalid-regexp): Invalid regular expression: /{0}/: {1}")]
+    #[diagnostic(severity(warning))]
+    InvalidPattern(Atom, &'static str, #[label] Span),
+
+    #[error("eslint(no-invalid-regexp): Invalid flags supplied to RegExp constructor '{0}'")]
+    #[diagnostic(severity(warning))]
+    InvalidFlags(Atom, #[label] Span),
+
+    #[error("eslint(no-invalid-regexp): Duplicate flags ('{0}') supplied to RegExp constructor")]
+    #[diagnostic(severity(warning))]
+    DuplicateFlags(Atom, #[label] Span),
+
+    #[error("eslint(no-invalid-regexp): Regex 'u' and 'v' flags cannot be used together")]
+    #[diagnostic(severity(warning))]
+    UnicodeAndUnicodeSetsFlags(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoInvalidRegexp {
+    /// Additional flags which are accepted in the RegExp constructor,
+    /// e.g. non-standard flags supported by a specific engine.
+    allow_constructor_flags: Vec<char>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow invalid regular expression strings in `RegExp` constructors
+    ///
+    /// ### Why is this bad?
+    /// An invalid pattern in a regular expression literal is a SyntaxError when the code is parsed,
+    /// but an invalid string in `RegExp` constructors throws a SyntaxError only when the code is executed.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// RegExp('[')
+    /// RegExp('.', 'z')
+    /// new RegExp('\\')
+    /// ```
+    NoInvalidRegexp,
+    correctness
+);
+
+const VALID_FLAGS: &str = "dgimsuyv";
+
+impl Rule for NoInvalidRegexp {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            allow_constructor_flags: value
+                .get(0)
+                .map(|v| get_string_array_option(v, "allowConstructorFlags"))
+                .unwrap_or_default()
+                .iter()
+                .flat_map(|flags| flags.chars())
+                .filter(|c| !VALID_FLAGS.contains(*c))
+                .collect(),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (callee, arguments) = match node.kind() {
+            AstKind::NewExpression(expr) => (&expr.callee, &expr.arguments),
+            AstKind::CallExpression(expr) => (&expr.callee, &expr.arguments),
+            _ => return,
+        };
+        if !callee.is_specific_id("RegExp") {
+            return;
+        }
+
+        let pattern = string_argument(arguments, 0);
+        let flags = string_argument(arguments, 1);
+
+        if let Some(diagnostic) = flags.and_then(|flags| self.check_flags(flags)) {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        let unicode = flags.is_some_and(|flags| flags.value.contains(['u', 'v']));
+
+        if let Some(pattern) = pattern {
+            if let Err(message) = validate_pattern(&pattern.value, unicode) {
+                ctx.diagnostic(NoInvalidRegexpDiagnostic::InvalidPattern(
+                    pattern.value.clone(),
+                    message,
+                    pattern.span,
+                ));
+            }
+        }
+    }
+}
+
+impl NoInvalidRegexp {
+    fn check_flags(&self, flags: &StringLiteral) -> Option<NoInvalidRegexpDiagnostic> {
+        let value = flags.value.as_str();
+
+        let invalid: String = value
+            .chars()
+            .filter(|c| !VALID_FLAGS.contains(*c) && !self.allow_constructor_flags.contains(c))
+            .collect();
+        if !invalid.is_empty() {
+            return Some(NoInvalidRegexpDiagnostic::InvalidFlags(invalid.into(), flags.span));
+        }
+
+        let duplicates: String = value
+            .char_indices()
+            .filter(|(i, c)| value[..*i].contains(*c))
+            .map(|(_, c)| c)
+            .collect();
+        if !duplicates.is_empty() {
+            return Some(NoInvalidRegexpDiagnostic::DuplicateFlags(duplicates.into(), flags.span));
+        }
+
+        if value.contains('u') && value.contains('v') {
+            return Some(NoInvalidRegexpDiagnostic::UnicodeAndUnicodeSetsFlags(flags.span));
+        }
+
+        None
+    }
+}
+
+fn string_argument<'a, 'b>(
+    arguments: &'b oxc_allocator::Vec<'a, Argument<'a>>,
+    index: usize,
+) -> Option<&'b StringLiteral> {
+    match arguments.get(index) {
+        Some(Argument::Expression(Expression::StringLiteral(lit))) => Some(lit),
+        _ => None,
+    }
+}
+
+/// A lightweight validator for the syntax of regular expression patterns.
+///
+/// This catches the errors an engine reports when compiling a pattern
+/// (unterminated groups and classes, misplaced quantifiers, bad escapes),
+/// it does not build a full AST of the pattern.
+fn validate_pattern(pattern: &str, unicode: bool) -> Result<(), &'static str> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut i = 0;
+    let mut groups = 0usize;
+    // Whether the previous term can be followed by a quantifier.
+    let mut can_quantify = false;
+
+    while i < chars.len() {
+        let c = chars[i];
+        i += 1;
+        match c {
+            '\\' => {
+                let Some(&next) = chars.get(i) else {
+                    return Err("\\ at end of pattern");
+                };
+                i += 1;
+                can_quantify = !matches!(next, 'b' | 'B');
+                i = validate_escape(&chars, i, next, unicode, false)?;
+            }
+            '[' => {
+                i = validate_character_class(&chars, i, unicode)?;
+                can_quantify = true;
+            }
+            '(' => {
+                groups += 1;
+                can_quantify = false;
+                if chars.get(i) == Some(&'?') {
+                    i = validate_group_specifier(&chars, i + 1)?;
+                }
+            }
+            ')' => {
+                if groups == 0 {
+                    return Err("Unmatched ')'");
+                }
+                groups -= 1;
+                can_quantify = true;
+            }
+            '|' | '^' | '$' => can_quantify = false,
+            '*' | '+' | '?' => {
+                if !can_quantify {
+                    return Err("Nothing to repeat");
+                }
+                i = skip_lazy(&chars, i);
+                can_quantify = false;
+            }
+            '{' => match read_quantifier_bounds(&chars, i) {
+                Some((min, max, end)) => {
+                    if !can_quantify {
+                        return Err("Nothing to repeat");
+                    }
+                    if max.is_some_and(|max| max < min) {
+                        return Err("numbers out of order in {} quantifier");
+                    }
+                    i = skip_lazy(&chars, end);
+                    can_quantify = false;
+                }
+                None if unicode => return Err("Lone quantifier brackets"),
+                None => can_quantify = true,
+            },
+            '}' | ']' if unicode => return Err("Lone quantifier brackets"),
+            _ => can_quantify = true,
+        }
+    }
+
+    if groups > 0 {
+        return Err("Unterminated group");
+    }
+    Ok(())
+}
+
+fn skip_lazy(chars: &[char], i: usize) -> usize {
+    if chars.get(i) == Some(&'?') {
+        i + 1
+    } else {
+        i
+    }
+}
+
+/// Reads `{min}`, `{min,}` or `{min,max}` starting right after the `{`.
+fn read_quantifier_bounds(chars: &[char], start: usize) -> Option<(u64, Option<u64>, usize)> {
+    let read_number = |i: &mut usize| {
+        let begin = *i;
+        while chars.get(*i).is_some_and(char::is_ascii_digit) {
+            *i += 1;
+        }
+        (begin < *i).then(|| {
+            chars[begin..*i]
+                .iter()
+                .fold(0u64, |n, c| n.saturating_mul(10).saturating_add(u64::from(*c as u8 - b'0')))
+        })
+    };
+
+    let mut i = start;
+    let min = read_number(&mut i)?;
+    let max = if chars.get(i) == Some(&',') {
+        i += 1;
+        read_number(&mut i)
+    } else {
+        Some(min)
+    };
+    (chars.get(i) == Some(&'}')).then_some((min, max, i + 1))
+}
+
+/// Validates the `(?...` of a group, `start` points right after the `?`.
+fn validate_group_specifier(chars: &[char], start: usize) -> Result<usize, &'static str> {
+    match chars.get(start) {
+        Some(':' | '=' | '!') => Ok(start + 1),
+        Some('<') => {
+            if let Some('=' | '!') = chars.get(start + 1) {
+                return Ok(start + 2);
+            }
+            let mut i = start + 1;
+            while chars.get(i).is_some_and(|c| c.is_alphanumeric() || matches!(c, '_' | '$')) {
+                i += 1;
+            }
+            if i == start + 1 || chars.get(i) != Some(&'>') || chars[start + 1].is_ascii_digit() {
+                return Err("Invalid capture group name");
+            }
+            Ok(i + 1)
+        }
+        _ => Err("Invalid group"),
+    }
+}
+
+/// Validates the character class starting right after the `[`,
+/// returning the position after the closing `]`.
+fn validate_character_class(
+    chars: &[char],
+    start: usize,
+    unicode: bool,
+) -> Result<usize, &'static str> {
+    let mut i = start;
+    if chars.get(i) == Some(&'^') {
+        i += 1;
+    }
+    // The previous class atom, and whether a `-` follows it.
+    let mut prev: Option<Option<char>> = None;
+    let mut range_start: Option<Option<char>> = None;
+
+    while let Some(&c) = chars.get(i) {
+        i += 1;
+        let atom = match c {
+            ']' => return Ok(i),
+            '\\' => {
+                let Some(&next) = chars.get(i) else {
+                    return Err("\\ at end of pattern");
+                };
+                i += 1;
+                let end = validate_escape(chars, i, next, unicode, true)?;
+                let atom = class_escape_value(chars, i, end, next);
+                i = end;
+                atom
+            }
+            '-' if prev.is_some() && range_start.is_none() && chars.get(i) != Some(&']') => {
+                range_start = prev.take();
+                continue;
+            }
+            c => Some(c),
+        };
+
+        if let Some(from) = range_start.take() {
+            match (from, atom) {
+                (Some(from), Some(to)) if from > to => {
+                    return Err("Range out of order in character class");
+                }
+                (None, _) | (_, None) if unicode => {
+                    return Err("Invalid character class");
+                }
+                _ => {}
+            }
+            prev = None;
+        } else {
+            prev = Some(atom);
+        }
+    }
+
+    Err("Unterminated character class")
+}
+
+/// The value of the hexadecimal `digits`, or `None` if it does not fit in a `u32`.
+/// Leading zeros are allowed in any number, as in `\u{0000000041}`.
+fn hex_value(digits: &[char]) -> Option<u32> {
+    digits
+        .iter()
+        .try_fold(0u32, |value, digit| value.checked_mul(16)?.checked_add(digit.to_digit(16)?))
+}
+
+/// Returns the character denoted by the escape `\<escape>` inside a character class,
+/// `start` and `end` delimit the characters following `escape`.
+fn class_escape_value(chars: &[char], start: usize, end: usize, escape: char) -> Option<char> {
+    let hex = |digits: &[char]| {
+        let digits = digits.iter().copied().filter(char::is_ascii_hexdigit).collect::<Vec<_>>();
+        hex_value(&digits).and_then(char::from_u32)
+    };
+    match escape {
+        'd' | 'D' | 'w' | 'W' | 's' | 'S' | 'p' | 'P' => None,
+        'x' | 'u' if end > start => hex(&chars[start..end]),
+        'b' => Some('\u{8}'),
+        'n' => Some('\n'),
+        'r' => Some('\r'),
+        't' => Some('\t'),
+        'v' => Some('\u{b}'),
+        'f' => Some('\u{c}'),
+        'c' if end > start => char::from_u32(chars[start] as u32 % 32),
+        '0' => Some('\0'),
+        c => Some(c),
+    }
+}
+
+/// Validates the escape `\<escape>`, `start` points right after `escape`.
+/// Returns the position after the whole escape sequence.
+fn validate_escape(
+    chars: &[char],
+    start: usize,
+    escape: char,
+    unicode: bool,
+    in_class: bool,
+) -> Result<usize, &'static str> {
+    let count_hex = |from: usize, max: usize| {
+        chars[from.min(chars.len())..]
+            .iter()
+            .take(max)
+            .take_while(|c| c.is_ascii_hexdigit())
+            .count()
+    };
+    match escape {
+        'd' | 'D' | 'w' | 'W' | 's' | 'S' | 'b' | 'B' | 'f' | 'n' | 'r' | 't' | 'v' | '0' => {
+            if unicode && escape == '0' && chars.get(start).is_some_and(char::is_ascii_digit) {
+                return Err("Invalid decimal escape");
+            }
+            Ok(start)
+        }
+        'x' => match count_hex(start, 2) {
+            2 => Ok(start + 2),
+            _ if unicode => Err("Invalid escape"),
+            _ => Ok(start),
+        },
+        'u' => {
+            if unicode && chars.get(start) == Some(&'{') {
+                let digits = count_hex(start + 1, usize::MAX);
+                if digits == 0 || chars.get(start + 1 + digits) != Some(&'}') {
+                    return Err("Invalid Unicode escape");
+                }
+                let value = hex_value(&chars[start + 1..start + 1 + digits]);
+                if value.map_or(true, |value| value > 0x0010_FFFF) {
+                    return Err("Invalid Unicode escape");
+                }
+                return Ok(start + digits + 2);
+            }
+            match count_hex(start, 4) {
+                4 => Ok(start + 4),
+                _ if unicode => Err("Invalid Unicode escape"),
+                _ => Ok(start),
+            }
+        }
+        'c' => match chars.get(start) {
+            Some(c) if c.is_ascii_alphabetic() => Ok(start + 1),
+            _ if unicode => Err("Invalid unicode escape"),
+            _ => Ok(start),
+        },
+        'p' | 'P' if unicode => {
+            let Some(end) = (chars.get(start) == Some(&'{'))
+                .then(|| chars[start..].iter().position(|c| *c == '}'))
+                .flatten()
+            else {
+                return Err("Invalid property name");
+            };
+            if end <= 1 {
+                return Err("Invalid property name");
+            }
+            Ok(start + end + 1)
+        }
+        'k' if unicode => {
+            let Some(end) = (chars.get(start) == Some(&'<'))
+                .then(|| chars[start..].iter().position(|c| *c == '>'))
+                .flatten()
+            else {
+                return Err("Invalid named reference");
+            };
+            if end <= 1 {
+                return Err("Invalid named reference");
+            }
+            Ok(start + end + 1)
+        }
+        '1'..='9' => {
+            if unicode && in_class {
+                return Err("Invalid class escape");
+            }
+            Ok(start)
+        }
+        '^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|'
+        | '/' => Ok(start),
+        '-' if in_class => Ok(start),
+        _ if unicode => Err("Invalid escape"),
+        _ => Ok(start),
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("RegExp('')", None),
+        ("RegExp()", None),
+        ("RegExp('.', 'g')", None),
+        ("new RegExp('.')", None),
+        ("new RegExp", None),
+        ("new RegExp('.', 'im')", None),
+        ("global.RegExp('\\\\')", None),
+        ("new RegExp('.', y)", None),
+        ("new RegExp('\\\\d')", None),
+        ("new RegExp('[a-z]+', 'gi')", None),
+        ("new RegExp('(?<year>\\\\d{4})-(?<month>\\\\d{2})', 'u')", None),
+        ("new RegExp('(?<=a)b(?!c)')", None),
+        ("new RegExp('\\\\u{0000000041}', 'u')", None),
+        ("new RegExp('[\\\\u{0000000041}-\\\\u{5A}]', 'u')", None),
+        ("new RegExp('a{2,3}?')", None),
+        ("new RegExp('a{')", None),
+        ("new RegExp('}')", None),
+        ("new RegExp(']')", None),
+        ("new RegExp('\\\\p{Letter}', 'u')", None),
+        ("new RegExp('[\\\\d-z]')", None),
+        ("new RegExp('[-a]')", None),
+        ("new RegExp('.', 'd')", None),
+        ("new RegExp('.', 'v')", None),
+        ("new RegExp('.', 'a')", Some(json!([{ "allowConstructorFlags": ["a"] }]))),
+        ("new RegExp('.', 'ag')", Some(json!([{ "allowConstructorFlags": ["a"] }]))),
+        ("new RegExp('.', 'z')", Some(json!([{ "allowConstructorFlags": ["a", "z"] }]))),
+        ("new RegExp('.', 'az')", Some(json!([{ "allowConstructorFlags": ["az"] }]))),
+    ];
+
+    let fail = vec![
+        ("RegExp('[');", None),
+        ("new RegExp('[')", None),
+        ("RegExp('.', 'z');", None),
+        ("RegExp('.', 'a');", Some(json!([{}]))),
+        ("new RegExp('.', 'a');", Some(json!([{ "allowConstructorFlags": [] }]))),
+        ("new RegExp('.', 'z');", Some(json!([{ "allowConstructorFlags": ["a"] }]))),
+        ("new RegExp('.', 'gg')", None),
+        ("new RegExp('.', 'uv')", None),
+        ("new RegExp('\\\\')", None),
+        ("new RegExp('(')", None),
+        ("new RegExp(')')", None),
+        ("new RegExp('*')", None),
+        ("new RegExp('a**')", None),
+        ("new RegExp('a{2,1}')", None),
+        ("new RegExp('[z-a]')", None),
+        ("new RegExp('(?x)')", None),
+        ("new RegExp('(?<>a)')", None),
+        ("new RegExp('{', 'u')", None),
+        ("new RegExp('\\\\a', 'u')", None),
+        ("new RegExp('\\\\u{110000}', 'u')", None),
+        ("new RegExp('\\\\p', 'u')", None),
+        ("new RegExp('[\\\\d-z]', 'u')", None),
+    ];
+
+    Tester::new(NoInvalidRegexp::NAME, pass, fail).test_and_snapshot();
+}
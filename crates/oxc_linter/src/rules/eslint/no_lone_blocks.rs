@@ -0,0 +1,121 @@
+use oxc_ast::{
+    ast::{BlockStatement, Declaration, Statement},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-lone-blocks): {0} is redundant.")]
+#[diagnostic(severity(warning), help("Remove the braces around the block."))]
+struct NoLoneBlocksDiagnostic(&'static str, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoLoneBlocks;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow unnecessary nested blocks
+    ///
+    /// ### Why is this bad?
+    /// Before ES2015, blocks do not create a new scope, so standalone blocks are
+    /// redundant. Since ES2015, a block only creates a scope for `let`, `const`,
+    /// `class` and function declarations, so blocks without them are still redundant.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// {}
+    ///
+    /// if (foo) {
+    ///     bar();
+    ///     {
+    ///         baz();
+    ///     }
+    /// }
+    /// ```
+    NoLoneBlocks,
+    style
+);
+
+impl Rule for NoLoneBlocks {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::BlockStatement(block) = node.kind() else { return };
+        if has_block_level_bindings(block) {
+            return;
+        }
+
+        let message = match ctx.nodes().parent_kind(node.id()) {
+            Some(AstKind::BlockStatement(_) | AstKind::StaticBlock(_)) => "Nested block",
+            Some(AstKind::Program(_) | AstKind::FunctionBody(_)) => "Block",
+            // A block which is the only statement of a case clause is allowed,
+            // e.g. `case 1: { ... }`.
+            Some(AstKind::SwitchCase(case))
+                if !matches!(case.consequent.as_slice(), [Statement::BlockStatement(_)]) =>
+            {
+                "Block"
+            }
+            _ => return,
+        };
+        ctx.diagnostic(NoLoneBlocksDiagnostic(message, block.span));
+    }
+}
+
+fn has_block_level_bindings(block: &BlockStatement) -> bool {
+    block.body.iter().any(|stmt| match stmt {
+        Statement::Declaration(Declaration::VariableDeclaration(decl)) => decl.kind.is_lexical(),
+        Statement::Declaration(
+            Declaration::FunctionDeclaration(_)
+            | Declaration::ClassDeclaration(_)
+            | Declaration::UsingDeclaration(_),
+        ) => true,
+        _ => false,
+    })
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("if (foo) { if (bar) { baz(); } }", None),
+        ("do { bar(); } while (foo)", None),
+        ("function foo() { while (bar) { baz() } }", None),
+        ("{ let x = 1; }", None),
+        ("{ const y = 1; }", None),
+        ("{ class Bar {} }", None),
+        ("{ function bar() {} }", None),
+        ("{ {let y = 1;} let x = 1; }", None),
+        ("switch (foo) { case bar: { baz; } }", None),
+        ("switch (foo) { case bar: { baz; } case qux: { boop; } }", None),
+        ("switch (foo) { case bar: { let x = 1; baz; } break; }", None),
+        ("foo: { bar(); }", None),
+        ("function foo() { return 1; }", None),
+        ("try { foo(); } catch (e) { bar(); } finally { baz(); }", None),
+        ("class C { static { let x = 1; } }", None),
+        ("class C { static { lbl: { if (something) { break lbl; } foo(); } } }", None),
+    ];
+
+    let fail = vec![
+        ("{}", None),
+        ("{var x = 1;}", None),
+        ("foo(); {} bar();", None),
+        ("if (foo) { bar(); {} baz(); }", None),
+        ("{ { } }", None),
+        ("function foo() { bar(); {} baz(); }", None),
+        ("while (foo) { {} }", None),
+        ("{ foo(); }", None),
+        ("function foo() { { bar(); } }", None),
+        ("switch (foo) { case 1: foo(); { bar; } }", None),
+        ("switch (foo) { case 1: { bar; } foo(); }", None),
+        ("class C { static { if (foo) { { bar(); } } } }", None),
+        ("class C { static { { bar(); } } }", None),
+    ];
+
+    Tester::new(NoLoneBlocks::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,290 @@
+// Ported from https://github.com/eslint/eslint/blob/main/lib/rules/no-misleading-character-class.js
+use lazy_static::lazy_static;
+use oxc_ast::{ast::RegExpFlags, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use regex::Regex;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoMisleadingCharacterClassDiagnostic {
+    #[error(
+        "eslint(no-misleading-character-class): Unexpected surrogate pair in character class."
+    )]
+    #[diagnostic(severity(warning), help("Use the 'u' flag."))]
+    SurrogatePairWithoutUFlag(#[label] Span),
+
+    #[error(
+        "eslint(no-misleading-character-class): Unexpected combined character in character class."
+    )]
+    #[diagnostic(
+        severity(warning),
+        help("Combining marks are matched separately from the character they modify.")
+    )]
+    CombiningClass(#[label] Span),
+
+    #[error(
+        "eslint(no-misleading-character-class): Unexpected modified Emoji in character class."
+    )]
+    #[diagnostic(
+        severity(warning),
+        help("Emoji modifiers are matched separately from the Emoji they modify.")
+    )]
+    EmojiModifier(#[label] Span),
+
+    #[error("eslint(no-misleading-character-class): Unexpected national flag in character class.")]
+    #[diagnostic(severity(warning), help("Regional indicator symbols are matched one at a time."))]
+    RegionalIndicatorSymbol(#[label] Span),
+
+    #[error("eslint(no-misleading-character-class): Unexpected joined character sequence in character class.")]
+    #[diagnostic(
+        severity(warning),
+        help("Characters joined by a zero width joiner are matched separately.")
+    )]
+    ZeroWidthJoiner(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoMisleadingCharacterClass;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow characters which are made with multiple code points in character class syntax
+    ///
+    /// ### Why is this bad?
+    /// Unicode includes characters which are made by multiple code points, e.g. `Á` (`A` and a
+    /// combining acute accent) or `👍🏻` (an emoji and a skin tone modifier). A character class
+    /// matches each of those code points on its own, so `/^[Á]$/` does not match `"Á"`.
+    /// Without the `u` flag, astral characters such as `👍` are split into two surrogate code units.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// /^[Á]$/u;
+    /// /^[👍]$/;
+    /// /^[🇯🇵]$/u;
+    /// ```
+    NoMisleadingCharacterClass,
+    correctness
+);
+
+impl Rule for NoMisleadingCharacterClass {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::RegExpLiteral(lit) = node.kind() else { return };
+        let unicode = lit.regex.flags.intersects(RegExpFlags::U | RegExpFlags::V);
+        // The pattern starts right after the opening `/`.
+        let pattern_start = lit.span.start + 1;
+
+        for (start, end, elements) in character_classes(&lit.regex.pattern, unicode) {
+            let span = Span::new(pattern_start + start, pattern_start + end);
+            if let Some(diagnostic) = check_character_class(&elements, unicode, span) {
+                ctx.diagnostic(diagnostic);
+            }
+        }
+    }
+}
+
+/// Collects the character classes of a regular expression pattern as
+/// `(start, end, elements)`, with byte offsets relative to the pattern.
+///
+/// Each element is the code point (or code unit, without the `u` flag) of a
+/// character in the class. Escapes that don't denote a single character,
+/// such as `\d`, are recorded as `None`.
+#[allow(clippy::cast_possible_truncation)]
+fn character_classes(pattern: &str, unicode: bool) -> Vec<(u32, u32, Vec<Option<u32>>)> {
+    let mut classes = vec![];
+    let mut chars = pattern.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '[' => {
+                let mut elements = vec![];
+                while let Some((i, c)) = chars.next() {
+                    match c {
+                        ']' => {
+                            classes.push((start as u32, i as u32 + 1, elements));
+                            break;
+                        }
+                        '\\' => elements.push(read_escape(&mut chars, unicode)),
+                        c if !unicode && c as u32 > 0xFFFF => {
+                            let mut units = [0; 2];
+                            c.encode_utf16(&mut units);
+                            elements.extend(units.iter().map(|unit| Some(u32::from(*unit))));
+                        }
+                        c => elements.push(Some(c as u32)),
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    classes
+}
+
+/// Reads the escape sequence following a `\` inside a character class.
+fn read_escape(
+    chars: &mut std::iter::Peekable<std::str::CharIndices<'_>>,
+    unicode: bool,
+) -> Option<u32> {
+    let (_, c) = chars.next()?;
+    match c {
+        'u' if unicode && chars.peek().is_some_and(|(_, c)| *c == '{') => {
+            chars.next();
+            let mut value = String::new();
+            for (_, c) in chars.by_ref() {
+                if c == '}' {
+                    break;
+                }
+                value.push(c);
+            }
+            u32::from_str_radix(&value, 16).ok()
+        }
+        'u' => read_hex(chars, 4),
+        'x' => read_hex(chars, 2),
+        // `\cJ` is the control character of the letter, e.g. a line feed.
+        'c' if chars.peek().is_some_and(|(_, c)| c.is_ascii_alphabetic()) => {
+            chars.next().map(|(_, c)| c as u32 % 32)
+        }
+        'd' | 'D' | 'w' | 'W' | 's' | 'S' | 'b' | 'B' | 'p' | 'P' | 'c' | 'k' | '0'..='9' => None,
+        'n' => Some(0x0A),
+        'r' => Some(0x0D),
+        't' => Some(0x09),
+        'v' => Some(0x0B),
+        'f' => Some(0x0C),
+        c => Some(c as u32),
+    }
+}
+
+fn read_hex(chars: &mut std::iter::Peekable<std::str::CharIndices<'_>>, len: usize) -> Option<u32> {
+    let mut value = 0;
+    for _ in 0..len {
+        let digit = chars.peek().and_then(|(_, c)| c.to_digit(16))?;
+        chars.next();
+        value = value * 16 + digit;
+    }
+    Some(value)
+}
+
+fn is_high_surrogate(c: u32) -> bool {
+    (0xD800..=0xDBFF).contains(&c)
+}
+
+fn is_low_surrogate(c: u32) -> bool {
+    (0xDC00..=0xDFFF).contains(&c)
+}
+
+fn is_combining_mark(c: u32) -> bool {
+    lazy_static! {
+        static ref COMBINING_MARK: Regex = Regex::new(r"^\p{M}$").unwrap();
+    }
+    char::from_u32(c).is_some_and(|c| COMBINING_MARK.is_match(c.encode_utf8(&mut [0; 4])))
+}
+
+fn is_emoji_modifier(c: u32) -> bool {
+    (0x1F3FB..=0x1F3FF).contains(&c)
+}
+
+fn is_regional_indicator_symbol(c: u32) -> bool {
+    (0x1F1E6..=0x1F1FF).contains(&c)
+}
+
+const ZERO_WIDTH_JOINER: u32 = 0x200D;
+
+fn check_character_class(
+    elements: &[Option<u32>],
+    unicode: bool,
+    span: Span,
+) -> Option<NoMisleadingCharacterClassDiagnostic> {
+    // With the `u` flag, escaped surrogate pairs such as `\uD83D\uDC4D` denote a single code point.
+    let mut chars: Vec<Option<u32>> = Vec::with_capacity(elements.len());
+    for element in elements {
+        match (chars.last().copied().flatten(), element) {
+            (Some(high), Some(low))
+                if unicode && is_high_surrogate(high) && is_low_surrogate(*low) =>
+            {
+                chars.pop();
+                chars.push(Some(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)));
+            }
+            _ => chars.push(*element),
+        }
+    }
+
+    for (i, pair) in chars.windows(2).enumerate() {
+        let (Some(prev), Some(c)) = (pair[0], pair[1]) else { continue };
+        if !unicode && is_high_surrogate(prev) && is_low_surrogate(c) {
+            return Some(NoMisleadingCharacterClassDiagnostic::SurrogatePairWithoutUFlag(span));
+        }
+        if is_combining_mark(c) && !is_combining_mark(prev) {
+            return Some(NoMisleadingCharacterClassDiagnostic::CombiningClass(span));
+        }
+        if is_emoji_modifier(c) && !is_emoji_modifier(prev) {
+            return Some(NoMisleadingCharacterClassDiagnostic::EmojiModifier(span));
+        }
+        if is_regional_indicator_symbol(prev) && is_regional_indicator_symbol(c) {
+            return Some(NoMisleadingCharacterClassDiagnostic::RegionalIndicatorSymbol(span));
+        }
+        if c == ZERO_WIDTH_JOINER
+            && prev != ZERO_WIDTH_JOINER
+            && chars.get(i + 2).copied().flatten().is_some_and(|next| next != ZERO_WIDTH_JOINER)
+        {
+            return Some(NoMisleadingCharacterClassDiagnostic::ZeroWidthJoiner(span));
+        }
+    }
+
+    None
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var r = /[a-z]/", None),
+        ("var r = /[abc]/", None),
+        ("var r = /[👍]/u", None),
+        ("var r = /[\\uD83D\\uDC4D]/u", None),
+        ("var r = /[\\u{1F44D}]/u", None),
+        ("var r = /❇️/", None),
+        ("var r = /A\u{301}/", None),
+        ("var r = /[❇]/", None),
+        ("var r = /👶🏻/u", None),
+        ("var r = /🇯🇵/u", None),
+        ("var r = /👨‍👩‍👦/u", None),
+        ("var r = /[\\u0301]/", None),
+        ("var r = /[\\]👍]/u", None),
+        ("var r = /\\[👍\\]/", None),
+        ("var r = new RegExp('[👍]')", None),
+    ];
+
+    let fail = vec![
+        ("var r = /[👍]/", None),
+        ("var r = /[\\uD83D\\uDC4D]/", None),
+        ("var r = /[A\u{301}]/", None),
+        ("var r = /[A\u{301}]/u", None),
+        ("var r = /[\\u0041\\u0301]/", None),
+        ("var r = /[❇️]/", None),
+        ("var r = /[👶🏻]/u", None),
+        ("var r = /[🇯🇵]/u", None),
+        ("var r = /[👨‍👩‍👦]/u", None),
+        ("var r = /^[a-z][A\u{301}]$/u", None),
+        ("var r = /[\\cJA\u{301}]/", None),
+    ];
+
+    Tester::new(NoMisleadingCharacterClass::NAME, pass, fail).test_and_snapshot();
+}
+
+#[test]
+fn test_control_escape() {
+    assert_eq!(
+        character_classes(r"[\cJa]", false),
+        vec![(0, 6, vec![Some(0x0A), Some(u32::from('a'))])]
+    );
+}
@@ -81,12 +81,12 @@ impl Rule for NoMixedOperators {
     }
 
     fn from_configuration(value: serde_json::Value) -> Self {
-        Self::try_from_configuration(&value).unwrap_or_default()
+        Self::parse_options(&value).unwrap_or_default()
     }
 }
 
 impl NoMixedOperators {
-    pub fn try_from_configuration(value: &serde_json::Value) -> Option<Self> {
+    pub fn parse_options(value: &serde_json::Value) -> Option<Self> {
         let config = value.get(0)?;
 
         let mut groups = vec![];
@@ -268,6 +268,9 @@ fn test() {
         ("a || b || c || d", None),
         ("(a || b) && c && d", None),
         ("a || (b && c && d)", None),
+        ("a || (b && c)", None),
+        ("a + (b * c)", None),
+        ("(a + b) * c", None),
         ("(a || b || c) && d", None),
         ("a || b || (c && d)", None),
         ("a + b + c + d", None),
@@ -296,6 +299,10 @@ fn test() {
 
     let fail = vec![
         ("a && b || c", None),
+        ("a || b && c", None),
+        ("a + b * c", None),
+        ("a * b - c", None),
+        ("a % b ** c", None),
         ("a && b > 0 || c", Some(json!([{ "groups": [["&&", "||", ">"]] }]))),
         ("a && b > 0 || c", Some(json!([{ "groups": [["&&", "||"]] }]))),
         (
@@ -345,7 +352,7 @@ mod internal_tests {
             ],
             "allowSamePrecedence": true
         }]);
-        let rule = NoMixedOperators::try_from_configuration(&config);
+        let rule = NoMixedOperators::parse_options(&config);
         assert_eq!(Some(NoMixedOperators::default()), rule);
     }
 
@@ -354,7 +361,7 @@ mod internal_tests {
         let config = json!([
           { "allowSamePrecedence": false }
         ]);
-        let rule = NoMixedOperators::try_from_configuration(&config);
+        let rule = NoMixedOperators::parse_options(&config);
         // missing groups should fall back to default
         let expected = NoMixedOperators { groups: default_groups(), allow_same_precedence: false };
         assert_eq!(Some(expected), rule);
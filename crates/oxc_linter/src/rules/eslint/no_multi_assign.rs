@@ -0,0 +1,124 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-multi-assign): Unexpected chained assignment.")]
+#[diagnostic(severity(warning), help("Split the chain into separate assignments."))]
+struct NoMultiAssignDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoMultiAssign {
+    /// Only report chains which initialize a declaration, e.g. `let a = b = c`.
+    ignore_non_declaration: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow use of chained assignment expressions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Chained assignments are hard to read, and can create implicit globals:
+    /// in `let a = b = 1`, only `a` is declared and `b` is assigned in the enclosing scope.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// var a = b = c = 5;
+    /// foo = bar = "baz";
+    ///
+    /// // Good
+    /// var a = 5;
+    /// var b = 5;
+    /// var c = 5;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `ignoreNonDeclaration` (`false` by default) allows chains which do not initialize a declaration,
+    /// e.g. `a = b = c`.
+    NoMultiAssign,
+    style
+);
+
+impl Rule for NoMultiAssign {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            ignore_non_declaration: value
+                .get(0)
+                .and_then(|config| config.get("ignoreNonDeclaration"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (value, span) = match node.kind() {
+            AstKind::VariableDeclarator(decl) => {
+                let Some(init) = &decl.init else { return };
+                (init, decl.span)
+            }
+            AstKind::PropertyDefinition(prop) => {
+                let Some(value) = &prop.value else { return };
+                (value, prop.span)
+            }
+            AstKind::AssignmentExpression(assign_expr) if !self.ignore_non_declaration => {
+                (&assign_expr.right, assign_expr.span)
+            }
+            _ => return,
+        };
+        if matches!(value.without_parenthesized(), Expression::AssignmentExpression(_)) {
+            ctx.diagnostic(NoMultiAssignDiagnostic(span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let ignore_non_declaration = || Some(json!([{ "ignoreNonDeclaration": true }]));
+
+    let pass = vec![
+        ("var a, b, c,\nd = 0;", None),
+        ("var a = 1; var b = 2; var c = 3;\nvar d = 0;", None),
+        ("var a = 1 + (b === 10 ? 5 : 4);", None),
+        ("const a = 1, b = 2, c = 3;", None),
+        ("let a = 1;\nlet b = 2;", None),
+        ("a = 1;", None),
+        ("a = b + 1;", None),
+        ("for (var a = 0, b = 0; a < 10; a++) {}", None),
+        ("a = b === c;", None),
+        ("a += b;", None),
+        ("class C { x = 1; y = z; }", None),
+        ("a = b = c;", ignore_non_declaration()),
+        ("a.x = b.y = 1;", ignore_non_declaration()),
+    ];
+
+    let fail = vec![
+        ("a = b = 1;", None),
+        ("a = b = c = 1;", None),
+        ("a = (b = 1);", None),
+        ("a += b = 1;", None),
+        ("a.x = b.y = 1;", None),
+        ("let a = b = 1;", None),
+        ("var a = b = c = 5;", None),
+        ("const foo = bar = 'baz';", None),
+        ("for (var a = b = 0; ; ) {}", None),
+        ("class C { x = y = 1; }", None),
+        ("let a = b = 1;", ignore_non_declaration()),
+        ("class C { x = y = 1; }", ignore_non_declaration()),
+    ];
+
+    Tester::new(NoMultiAssign::NAME, pass, fail).test_and_snapshot();
+}
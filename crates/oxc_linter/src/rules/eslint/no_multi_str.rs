@@ -0,0 +1,83 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-multi-str): Unexpected multiline string.")]
+#[diagnostic(severity(warning), help("Use string concatenation or a template literal instead."))]
+struct NoMultiStrDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoMultiStr;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow multiline strings
+    ///
+    /// ### Why is this bad?
+    /// A backslash at the end of a line continues a string literal on the next line.
+    /// This is an undocumented feature of early JavaScript versions and is easy to break
+    /// with trailing whitespace.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var x = "Line 1 \
+    ///          Line 2";
+    /// ```
+    NoMultiStr,
+    style
+);
+
+impl Rule for NoMultiStr {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::StringLiteral(lit) = node.kind() else { return };
+        if matches!(ctx.nodes().parent_kind(node.id()), Some(AstKind::JSXAttributeItem(_))) {
+            return;
+        }
+        if has_line_continuation(lit.span.source_text(ctx.source_text())) {
+            ctx.diagnostic(NoMultiStrDiagnostic(lit.span));
+        }
+    }
+}
+
+/// Whether the raw text of a string literal contains a backslash followed by a line terminator.
+fn has_line_continuation(raw: &str) -> bool {
+    let mut chars = raw.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' && matches!(chars.next(), Some('\n' | '\r' | '\u{2028}' | '\u{2029}')) {
+            return true;
+        }
+    }
+    false
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var a = 'Line 1 Line 2';", None),
+        ("var a = 'Line 1\\nLine 2';", None),
+        ("var a = 'Line 1 \\\\';", None),
+        ("var a = `Line 1\nLine 2`;", None),
+        ("var a = `Line 1 \\\nLine 2`;", None),
+        ("var a = <div>\n<h1>Wat</h1>\n</div>;", None),
+        ("var a = <div className=\"a \\\nb\" />;", None),
+    ];
+
+    let fail = vec![
+        ("var x = 'Line 1 \\\n Line 2'", None),
+        ("test('Line 1 \\\n Line 2');", None),
+        ("'foo\\\rbar';", None),
+        ("'foo\\\u{2028}bar';", None),
+        ("'foo\\\u{2029}ar';", None),
+    ];
+
+    Tester::new(NoMultiStr::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,64 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-new): Do not use 'new' for side effects.")]
+#[diagnostic(
+    severity(warning),
+    help("Assign the created object to a variable or call a function instead.")
+)]
+struct NoNewDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoNew;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow `new` operators outside of assignments or comparisons
+    ///
+    /// ### Why is this bad?
+    /// Calling a constructor without using the created object suggests that the
+    /// constructor is only used for its side effects, which should be done in a
+    /// function instead.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// new Thing();
+    /// ```
+    NoNew,
+    suspicious
+);
+
+impl Rule for NoNew {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ExpressionStatement(stmt) = node.kind() else { return };
+        if let Expression::NewExpression(expr) = stmt.expression.without_parenthesized() {
+            ctx.diagnostic(NoNewDiagnostic(expr.span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var a = new Date()", None),
+        ("var a; if (a === new Date()) { a = false; }", None),
+        ("const t = new Thing();", None),
+        ("new Thing().start();", None),
+        ("foo(new Thing());", None),
+        ("function f() { return new Thing(); }", None),
+    ];
+
+    let fail = vec![("new Date()", None), ("new Thing();", None), ("(new Thing());", None)];
+
+    Tester::new(NoNew::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,112 @@
+use oxc_ast::{
+    ast::{Argument, Expression},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-new-object): The object literal notation {{}} is preferable.")]
+#[diagnostic(severity(warning), help("Use an object literal instead."))]
+struct NoNewObjectDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoNewObject;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow `Object` constructors
+    ///
+    /// ### Why is this bad?
+    /// `new Object()` is equivalent to the shorter and more readable object literal `{}`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var myObject = new Object();
+    /// ```
+    NoNewObject,
+    style
+);
+
+impl Rule for NoNewObject {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::NewExpression(expr) = node.kind() else { return };
+        let Expression::Identifier(ident) = &expr.callee else { return };
+        if ident.name != "Object" || !ctx.semantic().is_reference_to_global_variable(ident) {
+            return;
+        }
+
+        let replacement = match expr.arguments.as_slice() {
+            [] => "{}",
+            [Argument::Expression(arg @ Expression::ObjectExpression(_))] => {
+                arg.span().source_text(ctx.source_text())
+            }
+            // `new Object(value)` converts the value to an object.
+            _ => return,
+        };
+        ctx.diagnostic_with_fix(NoNewObjectDiagnostic(expr.span), |_| {
+            if starts_statement(node, ctx) {
+                Fix::new(format!("({replacement})"), expr.span)
+            } else {
+                Fix::new(replacement, expr.span)
+            }
+        });
+    }
+}
+
+/// Whether `node` starts an expression statement or the body of an arrow function, where `{`
+/// would start a block, as in `new Object();` or `() => new Object()`.
+fn starts_statement(node: &AstNode, ctx: &LintContext) -> bool {
+    let start = node.kind().span().start;
+    for parent in ctx.nodes().iter_parents(node.id()).skip(1) {
+        if parent.kind().span().start != start {
+            return false;
+        }
+        // The expression body of an arrow function is an expression statement as well.
+        if matches!(parent.kind(), AstKind::ExpressionStatement(_)) {
+            return true;
+        }
+    }
+    false
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var myObject = {};", None),
+        ("var myObject = new CustomObject();", None),
+        ("var foo = new foo.Object()", None),
+        ("var myObject = new Object(foo);", None),
+        ("var Object = function Object() {}; new Object();", None),
+        ("function bar(Object) { var baz = new Object(); }", None),
+        ("import { Object } from './'; new Object();", None),
+    ];
+
+    let fail = vec![
+        ("var foo = new Object()", None),
+        ("new Object();", None),
+        ("const a = new Object()", None),
+        ("var foo = new Object({ a: 1 })", None),
+    ];
+
+    let fix = vec![
+        ("var foo = new Object()", "var foo = {}", None),
+        ("var foo = new Object({ a: 1 })", "var foo = { a: 1 }", None),
+        ("new Object();", "({});", None),
+        ("new Object().foo;", "({}).foo;", None),
+        ("() => new Object()", "() => ({})", None),
+        ("() => new Object({ a: 1 })", "() => ({ a: 1 })", None),
+        ("() => foo(new Object())", "() => foo({})", None),
+        ("() => (new Object())", "() => ({})", None),
+    ];
+
+    Tester::new(NoNewObject::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
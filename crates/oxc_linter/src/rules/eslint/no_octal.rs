@@ -0,0 +1,71 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-octal): Octal literals should not be used.")]
+#[diagnostic(severity(warning), help("Use the `0o` prefix for octal numbers."))]
+struct NoOctalDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoOctal;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow octal literals
+    ///
+    /// ### Why is this bad?
+    /// Numbers with a leading zero are legacy octal literals, which is confusing
+    /// since `071` is `57`. They are a syntax error in strict mode.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var num = 071;
+    /// ```
+    NoOctal,
+    correctness
+);
+
+impl Rule for NoOctal {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::NumberLiteral(lit) = node.kind() else { return };
+        let mut chars = lit.raw.chars();
+        if chars.next() == Some('0') && chars.next().is_some_and(|c| c.is_ascii_digit()) {
+            ctx.diagnostic(NoOctalDiagnostic(lit.span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var a = 'hello world';", None),
+        ("0x1234", None),
+        ("0X5;", None),
+        ("a = 0;", None),
+        ("0.1", None),
+        ("0.5e1", None),
+        ("0o71", None),
+        ("0b101", None),
+        ("1_000", None),
+    ];
+
+    let fail = vec![
+        ("var a = 01234;", None),
+        ("a = 1 + 01234;", None),
+        ("00", None),
+        ("071", None),
+        ("08", None),
+        ("09.1", None),
+    ];
+
+    Tester::new(NoOctal::NAME, pass, fail).test_and_snapshot();
+}
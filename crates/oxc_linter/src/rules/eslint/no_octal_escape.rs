@@ -0,0 +1,115 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-octal-escape): Don't use octal: '\\{0}'. Use '\\u....' instead.")]
+#[diagnostic(severity(warning))]
+struct NoOctalEscapeDiagnostic(String, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoOctalEscape;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow octal escape sequences in string literals
+    ///
+    /// ### Why is this bad?
+    /// Octal escape sequences such as `"\251"` are deprecated in favor of Unicode escape
+    /// sequences such as `"\u00A9"`, and are a syntax error in strict mode.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var foo = "Copyright \251";
+    /// ```
+    NoOctalEscape,
+    correctness
+);
+
+impl Rule for NoOctalEscape {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::StringLiteral(lit) = node.kind() else { return };
+        if let Some(escape) = find_octal_escape(lit.span.source_text(ctx.source_text())) {
+            ctx.diagnostic(NoOctalEscapeDiagnostic(escape.to_string(), lit.span));
+        }
+    }
+}
+
+/// Finds the first octal escape sequence in the raw text of a string literal,
+/// returning its digits. `\0` not followed by a digit is the null character and is allowed.
+fn find_octal_escape(raw: &str) -> Option<&str> {
+    let bytes = raw.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'\\' {
+            i += 1;
+            continue;
+        }
+        let start = i + 1;
+        let is_octal_digit = |j: usize| bytes.get(j).is_some_and(|b| (b'0'..=b'7').contains(b));
+        let end = match bytes.get(start) {
+            Some(b'0') if bytes.get(start + 1).is_some_and(|b| matches!(b, b'8' | b'9')) => {
+                start + 1
+            }
+            Some(b'0'..=b'3') if is_octal_digit(start + 1) => {
+                if is_octal_digit(start + 2) {
+                    start + 3
+                } else {
+                    start + 2
+                }
+            }
+            Some(b'4'..=b'7') if is_octal_digit(start + 1) => start + 2,
+            Some(b'1'..=b'7') => start + 1,
+            _ => {
+                // Skip the escaped character.
+                i = start + 1;
+                continue;
+            }
+        };
+        return Some(&raw[start..end]);
+    }
+    None
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var foo = \"\\x51\";", None),
+        ("var foo = \"foo \\\\251 bar\";", None),
+        ("var foo = /([abc]) \\1/g;", None),
+        ("var foo = '\\0';", None),
+        ("'\\0'", None),
+        ("'\\8'", None),
+        ("'\\9'", None),
+        ("'foo \\\\251'", None),
+        ("var foo = \"\\u00a9\";", None),
+        ("'\\\\a'", None),
+    ];
+
+    let fail = vec![
+        ("var foo = \"foo \\01 bar\";", None),
+        ("var foo = \"foo \\000 bar\";", None),
+        ("var foo = \"foo \\377 bar\";", None),
+        ("var foo = \"foo \\378 bar\";", None),
+        ("var foo = \"foo \\37a bar\";", None),
+        ("var foo = \"foo \\381 bar\";", None),
+        ("var foo = \"foo \\3a1 bar\";", None),
+        ("var foo = \"foo \\251 bar\";", None),
+        ("var foo = \"foo \\400 bar\";", None),
+        ("var foo = \"\\t\\1\";", None),
+        ("var foo = \"\\\\\\751\";", None),
+        ("'\\08'", None),
+        ("'\\09'", None),
+        ("'\\0\\1'", None),
+    ];
+
+    Tester::new(NoOctalEscape::NAME, pass, fail).test_and_snapshot();
+}
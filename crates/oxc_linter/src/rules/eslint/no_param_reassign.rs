@@ -0,0 +1,244 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{AstNodeId, SymbolId};
+use oxc_span::{Atom, GetSpan, Span};
+use oxc_syntax::operator::UnaryOperator;
+use regex::Regex;
+
+use crate::{
+    context::LintContext,
+    rule::{compile_option_regex, Rule},
+    utils::get_string_array_option,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoParamReassignDiagnostic {
+    #[error("eslint(no-param-reassign): Assignment to function parameter '{0}'.")]
+    #[diagnostic(severity(warning), help("Assign to a new local variable instead."))]
+    Reassign(Atom, #[label] Span),
+
+    #[error("eslint(no-param-reassign): Assignment to property of function parameter '{0}'.")]
+    #[diagnostic(severity(warning), help("Copy the parameter before modifying its properties."))]
+    ModifyProperty(Atom, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoParamReassign {
+    /// Also report modifications of the properties of parameters, e.g. `arg.x = 1`.
+    props: bool,
+    /// Parameters whose properties may be modified.
+    ignore_property_modifications_for: Vec<String>,
+    ignore_property_modifications_for_regex: Vec<Regex>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow reassigning function parameters.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Assigning to a parameter hides the value which was passed in, and in sloppy mode
+    /// also changes the `arguments` object. With `props: true`, modifying the properties
+    /// of a parameter is reported as well, as it changes the object of the caller.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// function foo(bar) {
+    ///     bar = 13;
+    /// }
+    ///
+    /// // Good
+    /// function foo(bar) {
+    ///     var baz = bar;
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// - `props`: Also report modifying the properties of parameters (`false` by default).
+    /// - `ignorePropertyModificationsFor`: Names of parameters whose properties may be modified.
+    /// - `ignorePropertyModificationsForRegex`: Regular expressions of such names.
+    NoParamReassign,
+    restriction
+);
+
+impl Rule for NoParamReassign {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self::try_from_configuration(value).unwrap_or_default()
+    }
+
+    fn try_from_configuration(value: serde_json::Value) -> Result<Self, oxc_diagnostics::Error> {
+        let Some(config) = value.get(0) else { return Ok(Self::default()) };
+        Ok(Self {
+            props: config.get("props").and_then(serde_json::Value::as_bool).unwrap_or_default(),
+            ignore_property_modifications_for: get_string_array_option(
+                config,
+                "ignorePropertyModificationsFor",
+            ),
+            ignore_property_modifications_for_regex: get_string_array_option(
+                config,
+                "ignorePropertyModificationsForRegex",
+            )
+            .iter()
+            .map(|pattern| compile_option_regex("ignorePropertyModificationsForRegex", pattern))
+            .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
+        let symbol_table = ctx.semantic().symbols();
+        let declaration = symbol_table.get_declaration(symbol_id);
+        if !matches!(ctx.nodes().kind(declaration), AstKind::FormalParameters(_)) {
+            return;
+        }
+
+        let name = symbol_table.get_name(symbol_id);
+        let check_props = self.props && !self.is_ignored(name);
+        for reference in symbol_table.get_resolved_references(symbol_id) {
+            if reference.is_write() {
+                ctx.diagnostic(NoParamReassignDiagnostic::Reassign(name.clone(), reference.span()));
+            } else if check_props && is_modifying_property(reference.node_id(), ctx) {
+                ctx.diagnostic(NoParamReassignDiagnostic::ModifyProperty(
+                    name.clone(),
+                    reference.span(),
+                ));
+            }
+        }
+    }
+}
+
+impl NoParamReassign {
+    fn is_ignored(&self, name: &str) -> bool {
+        self.ignore_property_modifications_for.iter().any(|ignored| ignored == name)
+            || self.ignore_property_modifications_for_regex.iter().any(|re| re.is_match(name))
+    }
+}
+
+/// Whether the reference at `node_id` is the object of a member expression which is modified,
+/// e.g. `arg.x = 1`, `arg.x++`, `delete arg.x` or `[arg.x] = []`.
+fn is_modifying_property(node_id: AstNodeId, ctx: &LintContext<'_>) -> bool {
+    let reference_span = ctx.nodes().kind(node_id).span();
+    let mut current = reference_span;
+    for parent in ctx.nodes().iter_parents(node_id).skip(1) {
+        match parent.kind() {
+            // The reference itself being assigned is a write, not a property modification.
+            AstKind::SimpleAssignmentTarget(_) | AstKind::AssignmentTarget(_) => {
+                return current != reference_span
+            }
+            AstKind::UpdateExpression(_) => return current != reference_span,
+            AstKind::UnaryExpression(expr) if expr.operator == UnaryOperator::Delete => {
+                return true
+            }
+            AstKind::AssignmentExpression(_) => return false,
+            AstKind::MemberExpression(expr) if expr.object().span() != current => return false,
+            AstKind::CallExpression(expr) if expr.callee.span() != current => return false,
+            AstKind::ConditionalExpression(expr) if expr.test.span() == current => return false,
+            AstKind::ObjectProperty(prop) if prop.key.span() == current => return false,
+            kind if kind.is_statement()
+                || kind.is_declaration()
+                || matches!(
+                    kind,
+                    AstKind::Function(_) | AstKind::ArrowExpression(_) | AstKind::Program(_)
+                ) =>
+            {
+                return false
+            }
+            kind => current = kind.span(),
+        }
+    }
+    false
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let props = || Some(json!([{ "props": true }]));
+
+    let pass = vec![
+        ("function foo(a) { var b = a; }", None),
+        ("function foo(a) { for (b in a); }", None),
+        ("function foo(a) { for (b of a); }", None),
+        ("function foo(a) { a.prop = 'value'; }", None),
+        ("function foo(a) { for (a.prop in obj); }", None),
+        ("function foo(a) { (function() { var a = 12; a++; })(); }", None),
+        ("function foo() { someGlobal = 13; }", None),
+        ("function foo() { someGlobal = 13; } function bar(someGlobal) {}", None),
+        ("function foo(a) { a.b = 0; }", Some(json!([{ "props": false }]))),
+        ("function foo(a) { bar(a.b).c = 0; }", props()),
+        ("function foo(a) { data[a] = 0; }", props()),
+        ("function foo(a) { +a.b; }", props()),
+        ("function foo(a) { (a ? [] : [])[0] = 1; }", props()),
+        ("function foo(a) { b = a.x; }", props()),
+        ("function foo(a) { b(a.x); }", props()),
+        ("function foo(a) { a.x(); }", props()),
+        (
+            "function foo(a) { a.b = 0; }",
+            Some(json!([{ "props": true, "ignorePropertyModificationsFor": ["a"] }])),
+        ),
+        (
+            "function foo(aFoo) { aFoo.b = 0; }",
+            Some(json!([{ "props": true, "ignorePropertyModificationsForRegex": ["^a.*$"] }])),
+        ),
+        ("const f = (a) => a + 1;", None),
+        ("function foo(a) { var a; }", None),
+        ("function foo(a) { var a = 1; }", None),
+    ];
+
+    let fail = vec![
+        ("function foo(bar) { bar = 13; }", None),
+        ("function foo(bar) { bar += 13; }", None),
+        ("function foo(bar) { (function() { bar = 13; })(); }", None),
+        ("function foo(bar) { ++bar; }", None),
+        ("function foo(bar) { bar++; }", None),
+        ("function foo(bar) { --bar; }", None),
+        ("function foo({bar}) { bar = 13; }", None),
+        ("function foo([, {bar}]) { bar = 13; }", None),
+        ("function foo(bar) { ({bar} = {}); }", None),
+        ("function foo(bar) { [bar] = []; }", None),
+        ("function foo(bar) { for (bar in baz); }", None),
+        ("function foo(bar) { for (bar of baz); }", None),
+        ("const foo = (bar) => { bar = 1; };", None),
+        ("function foo(bar) { bar.a = 0; }", props()),
+        ("function foo(bar) { bar.get(0).a = 0; }", props()),
+        ("function foo(bar) { delete bar.a; }", props()),
+        ("function foo(bar) { ++bar.a; }", props()),
+        ("function foo(bar) { bar.a++; }", props()),
+        ("function foo(bar) { bar[0] = 1; }", props()),
+        ("function foo(bar) { [bar.a] = []; }", props()),
+        ("function foo(bar) { ({ x: bar.a } = {}); }", props()),
+        ("function foo(bar) { for (bar.a in baz); }", props()),
+        ("function foo(bar) { (bar.a) = 0; }", props()),
+        (
+            "function foo(bar) { bar.a = 0; }",
+            Some(json!([{ "props": true, "ignorePropertyModificationsFor": ["a"] }])),
+        ),
+        (
+            "function foo(bar) { bar.a = 0; }",
+            Some(json!([{ "props": true, "ignorePropertyModificationsForRegex": ["^a.*$"] }])),
+        ),
+    ];
+
+    Tester::new(NoParamReassign::NAME, pass, fail).test_and_snapshot();
+}
+
+#[test]
+fn test_invalid_ignore_pattern() {
+    use serde_json::json;
+
+    let err = NoParamReassign::try_from_configuration(
+        json!([{ "props": true, "ignorePropertyModificationsForRegex": ["(foo"] }]),
+    )
+    .unwrap_err();
+    assert!(err.to_string().starts_with(
+        "Invalid regular expression `(foo` in the `ignorePropertyModificationsForRegex` option"
+    ));
+}
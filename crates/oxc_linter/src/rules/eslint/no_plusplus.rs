@@ -0,0 +1,153 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::AstNodeId;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::UpdateOperator;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-plusplus): Unary operator '{0}' used.")]
+#[diagnostic(severity(warning), help("Use `{1}` instead."))]
+struct NoPlusplusDiagnostic(&'static str, &'static str, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoPlusplus {
+    /// Whether to allow `++` and `--` in the afterthought of a `for` loop.
+    allow_for_loop_afterthoughts: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow the unary operators `++` and `--`
+    ///
+    /// ### Why is this bad?
+    /// The unary `++` and `--` operators are subject to automatic semicolon insertion,
+    /// so differences in whitespace can change the semantics of the code.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var i = 10;
+    /// var j = 20;
+    ///
+    /// i ++
+    /// j
+    /// // i = 11, j = 20
+    ///
+    /// i
+    /// ++
+    /// j
+    /// // i = 10, j = 21
+    /// ```
+    NoPlusplus,
+    restriction
+);
+
+impl Rule for NoPlusplus {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            allow_for_loop_afterthoughts: value
+                .get(0)
+                .and_then(|v| v.get("allowForLoopAfterthoughts"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::UpdateExpression(expr) = node.kind() else { return };
+
+        if self.allow_for_loop_afterthoughts && is_for_loop_afterthought(node.id(), ctx) {
+            return;
+        }
+
+        let replacement = match expr.operator {
+            UpdateOperator::Increment => "+= 1",
+            UpdateOperator::Decrement => "-= 1",
+        };
+        ctx.diagnostic(NoPlusplusDiagnostic(expr.operator.as_str(), replacement, expr.span));
+    }
+}
+
+/// Whether the node is the update of a `for` loop, possibly as part of a sequence,
+/// e.g. `for (;; i++, j--)`.
+fn is_for_loop_afterthought(node_id: AstNodeId, ctx: &LintContext<'_>) -> bool {
+    let nodes = ctx.nodes();
+    let mut current = node_id;
+    loop {
+        let Some(parent) = nodes.parent_node(current) else { return false };
+        match parent.kind() {
+            AstKind::SequenceExpression(_) | AstKind::ParenthesizedExpression(_) => {
+                current = parent.id();
+            }
+            AstKind::ForStatement(stmt) => {
+                let span = nodes.kind(current).span();
+                return stmt.update.as_ref().is_some_and(|update| update.span() == span);
+            }
+            _ => return false,
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var foo = 0; foo=+1;", None),
+        ("var foo = 0; foo+=1;", None),
+        ("var foo = 0; foo-=1;", None),
+        ("i += 1", None),
+        ("var foo = 0; foo=+1;", Some(json!([{ "allowForLoopAfterthoughts": true }]))),
+        (
+            "for (i = 0; i < l; i++) { console.log(i); }",
+            Some(json!([{ "allowForLoopAfterthoughts": true }])),
+        ),
+        ("for (;;i++) {}", Some(json!([{ "allowForLoopAfterthoughts": true }]))),
+        (
+            "for (var i = 0, j = i + 1; j < example.length; i++, j++) {}",
+            Some(json!([{ "allowForLoopAfterthoughts": true }])),
+        ),
+        ("for (;; i--, foo());", Some(json!([{ "allowForLoopAfterthoughts": true }]))),
+        ("for (;; foo(), --i);", Some(json!([{ "allowForLoopAfterthoughts": true }]))),
+        ("for (;; foo(), ++i, bar);", Some(json!([{ "allowForLoopAfterthoughts": true }]))),
+        ("for (;; i++, (++j, k--));", Some(json!([{ "allowForLoopAfterthoughts": true }]))),
+        (
+            "for (;; foo(), (bar(), i++), baz());",
+            Some(json!([{ "allowForLoopAfterthoughts": true }])),
+        ),
+        (
+            "for (;; (--i, j += 2), bar = j + 1);",
+            Some(json!([{ "allowForLoopAfterthoughts": true }])),
+        ),
+        (
+            "for (;; a, (i--, (b, ++j, c)), d);",
+            Some(json!([{ "allowForLoopAfterthoughts": true }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("var foo = 0; foo++;", None),
+        ("var foo = 0; foo--;", None),
+        ("var foo = 0; --foo;", None),
+        ("i++;", None),
+        ("for (i = 0; i < l; i++) { console.log(i); }", None),
+        ("for (i = 0; i < l; foo, i++) { console.log(i); }", None),
+        ("var foo = 0; foo++;", Some(json!([{ "allowForLoopAfterthoughts": true }]))),
+        ("for (i = 0; i < l; i++) { v++; }", Some(json!([{ "allowForLoopAfterthoughts": true }]))),
+        ("for (i++;;);", Some(json!([{ "allowForLoopAfterthoughts": true }]))),
+        ("for (;--i;);", Some(json!([{ "allowForLoopAfterthoughts": true }]))),
+        ("for (;;) ++i;", Some(json!([{ "allowForLoopAfterthoughts": true }]))),
+        ("for (;; i = j++);", Some(json!([{ "allowForLoopAfterthoughts": true }]))),
+        ("for (;; i++, f(--j));", Some(json!([{ "allowForLoopAfterthoughts": true }]))),
+        ("for (;; foo + (i++, bar));", Some(json!([{ "allowForLoopAfterthoughts": true }]))),
+    ];
+
+    Tester::new(NoPlusplus::NAME, pass, fail).test_and_snapshot();
+}
@@ -10,7 +10,7 @@ use oxc_macros::declare_oxc_lint;
 use oxc_semantic::VariableInfo;
 use oxc_span::{Atom, Span};
 
-use crate::{context::LintContext, globals::BUILTINS, rule::Rule};
+use crate::{context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-redeclare): '{0}' is already defined.")]
@@ -76,18 +76,30 @@ impl Rule for NoRedeclare {
         let redeclare_variables = ctx.semantic().redeclare_variables();
         let symbol_table = ctx.semantic().symbols();
 
+        if self.built_in_globals && is_script(ctx) {
+            let root_scope_id = ctx.scopes().root_scope_id();
+            for (name, symbol_id) in ctx.scopes().get_bindings(root_scope_id) {
+                if ctx.globals().contains(name.as_str()) {
+                    ctx.diagnostic(NoRedeclareAsBuiltiInDiagnostic(
+                        name.clone(),
+                        symbol_table.get_span(*symbol_id),
+                    ));
+                }
+            }
+        }
+
         for variable in redeclare_variables {
             let decl = symbol_table.get_declaration(variable.symbol_id);
             match ctx.nodes().kind(decl) {
                 AstKind::VariableDeclarator(var) => {
                     if let BindingPatternKind::BindingIdentifier(ident) = &var.id.kind {
-                        self.report_diagnostic(ctx, variable, ident);
+                        Self::report_diagnostic(ctx, variable, ident);
                     }
                 }
                 AstKind::FormalParameters(params) => {
                     for item in &params.items {
                         if let BindingPatternKind::BindingIdentifier(ident) = &item.pattern.kind {
-                            self.report_diagnostic(ctx, variable, ident);
+                            Self::report_diagnostic(ctx, variable, ident);
                         }
                     }
                 }
@@ -97,16 +109,17 @@ impl Rule for NoRedeclare {
     }
 }
 
+/// Top-level declarations of a module are scoped to the module, so they only redeclare
+/// built-in globals in scripts. Every file is parsed as a module, so a file without `import`
+/// or `export` declarations is treated as a script.
+fn is_script(ctx: &LintContext) -> bool {
+    ctx.source_type().is_script()
+        || !ctx.nodes().iter().any(|node| matches!(node.kind(), AstKind::ModuleDeclaration(_)))
+}
+
 impl NoRedeclare {
-    fn report_diagnostic(
-        &self,
-        ctx: &LintContext,
-        variable: &VariableInfo,
-        ident: &BindingIdentifier,
-    ) {
-        if self.built_in_globals && BUILTINS.get(&ident.name).is_some() {
-            ctx.diagnostic(NoRedeclareAsBuiltiInDiagnostic(ident.name.clone(), ident.span));
-        } else if variable.name == ident.name && variable.span != ident.span {
+    fn report_diagnostic(ctx: &LintContext, variable: &VariableInfo, ident: &BindingIdentifier) {
+        if variable.name == ident.name && variable.span != ident.span {
             ctx.diagnostic(NoRedeclareDiagnostic(ident.name.clone(), ident.span, variable.span));
         }
     }
@@ -140,6 +153,14 @@ fn test() {
         ("var self = 1", Some(serde_json::json!([{ "builtinGlobals": false }]))),
         ("var globalThis = foo", Some(serde_json::json!([{ "builtinGlobals": false }]))),
         ("var globalThis = foo", Some(serde_json::json!([{ "builtinGlobals": false }]))),
+        ("function f() { var Object = 0; }", Some(serde_json::json!([{ "builtinGlobals": true }]))),
+        ("export var Object = 0;", Some(serde_json::json!([{ "builtinGlobals": true }]))),
+        (
+            "import foo from 'foo'; var top = 0;",
+            Some(serde_json::json!([{ "builtinGlobals": true }])),
+        ),
+        ("var a; { let a; }", None),
+        ("function f() { var a; } function g() { var a; }", None),
     ];
 
     let fail = vec![
@@ -155,24 +176,33 @@ fn test() {
         ("export var a; var a;", None),
         // `var` redeclaration in class static blocks. Redeclaration of functions is not allowed in class static blocks.
         ("class C { static { var a; var a; } }", None),
-        // Todo: Fix me
-        // ("class C { static { var a; { var a; } } }", None),
-        // ("class C { static { { var a; } var a; } }", None),
-        // ("class C { static { { var a; } { var a; } } }", None),
-        // ("var Object = 0;", Some(serde_json::json!([{ "builtinGlobals": true }]))),
+        ("class C { static { var a; { var a; } } }", None),
+        ("class C { static { { var a; } var a; } }", None),
+        ("class C { static { { var a; } { var a; } } }", None),
+        ("var Object = 0;", Some(serde_json::json!([{ "builtinGlobals": true }]))),
+        (
+            "/*eslint-env browser*/ var top = 0;",
+            Some(serde_json::json!([{ "builtinGlobals": true }])),
+        ),
+        ("/*global a*/ var a = 0;", Some(serde_json::json!([{ "builtinGlobals": true }]))),
+        ("function Object() {}", Some(serde_json::json!([{ "builtinGlobals": true }]))),
         (
             "var a; var {a = 0, b: Object = 0} = {};",
             Some(serde_json::json!([{ "builtinGlobals": true }])),
         ),
-        // ("var globalThis = 0;", Some(serde_json::json!([{ "builtinGlobals": true }]))),
+        ("var globalThis = 0;", Some(serde_json::json!([{ "builtinGlobals": true }]))),
         (
             "var a; var {a = 0, b: globalThis = 0} = {};",
             Some(serde_json::json!([{ "builtinGlobals": true }])),
         ),
         ("function f() { var a; var a; }", None),
         ("function f(a) { var a; }", None),
-        // ("function f() { var a; if (test) { var a; } }", None),
+        ("function f() { var a; if (test) { var a; } }", None),
         ("for (var a, a;;);", None),
+        // A `let` colliding with another declaration is a syntax error, which is reported by
+        // the semantic analysis before the rule runs.
+        ("let a; var a;", None),
+        ("var a; let a;", None),
     ];
 
     Tester::new(NoRedeclare::NAME, pass, fail).test_and_snapshot();
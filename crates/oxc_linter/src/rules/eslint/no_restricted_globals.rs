@@ -0,0 +1,141 @@
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+
+use crate::{context::LintContext, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-restricted-globals): Unexpected use of '{0}'.{1}")]
+#[diagnostic(severity(warning))]
+struct NoRestrictedGlobalsDiagnostic(Atom, String, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedGlobals {
+    /// Restricted global names with their optional custom messages.
+    restricted: Vec<(String, Option<String>)>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow specified global variables
+    ///
+    /// ### Why is this bad?
+    /// Some globals are easy to use by mistake. For example, a misspelled `event`
+    /// parameter silently refers to the deprecated `window.event` in browsers, and a
+    /// leftover `fdescribe` disables the rest of a test suite. Only references which
+    /// are not declared in the file are reported, including references to globals of the
+    /// enabled environments and of `/* global */` comments.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// /* eslint no-restricted-globals: ["error", "event", {
+    ///     "name": "fdescribe",
+    ///     "message": "Do not commit fdescribe. Use describe instead."
+    /// }] */
+    /// function onClick() {
+    ///     console.log(event);
+    /// }
+    /// fdescribe("foo", function() {});
+    /// ```
+    NoRestrictedGlobals,
+    restriction
+);
+
+impl Rule for NoRestrictedGlobals {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let restricted = value
+            .as_array()
+            .map(|options| {
+                options
+                    .iter()
+                    .filter_map(|option| match option {
+                        serde_json::Value::String(name) => Some((name.clone(), None)),
+                        serde_json::Value::Object(obj) => Some((
+                            obj.get("name")?.as_str()?.to_string(),
+                            obj.get("message")
+                                .and_then(serde_json::Value::as_str)
+                                .map(ToString::to_string),
+                        )),
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self { restricted }
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        let symbol_table = ctx.symbols();
+        let unresolved_references = ctx.scopes().root_unresolved_references();
+
+        for (name, message) in &self.restricted {
+            // Globals from `ctx.globals()` have no declaration either, so references to them
+            // are unresolved like those to undeclared names.
+            let Some(reference_ids) = unresolved_references.get(name.as_str()) else { continue };
+            let message =
+                message.as_ref().map_or_else(String::new, |message| format!(" {message}"));
+            for &reference_id in reference_ids {
+                let reference = symbol_table.get_reference(reference_id);
+                ctx.diagnostic(NoRestrictedGlobalsDiagnostic(
+                    reference.name().clone(),
+                    message.clone(),
+                    reference.span(),
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("foo", None),
+        ("foo", Some(json!(["bar"]))),
+        ("var foo = 1;", Some(json!(["foo"]))),
+        ("event", Some(json!(["bar"]))),
+        ("import foo from 'bar';", Some(json!(["foo"]))),
+        ("function foo() {}", Some(json!(["foo"]))),
+        ("function fn() { var foo; }", Some(json!(["foo"]))),
+        ("foo.bar", Some(json!(["bar"]))),
+        ("foo", Some(json!([{ "name": "bar", "message": "Use baz instead." }]))),
+        ("function onClick(event) { console.log(event); }", Some(json!(["event"]))),
+        ("let event; event = 1;", Some(json!(["event"]))),
+        ("try {} catch (event) { event; }", Some(json!(["event"]))),
+        ("({ event: 1 })", Some(json!(["event"]))),
+        ("/* global foo */ function fn(foo) { foo; }", Some(json!(["foo"]))),
+    ];
+
+    let fail = vec![
+        ("foo", Some(json!(["foo"]))),
+        ("function fn() { foo; }", Some(json!(["foo"]))),
+        ("event", Some(json!(["foo", "event"]))),
+        ("foo = 1; foo;", Some(json!(["foo"]))),
+        ("typeof event", Some(json!(["event"]))),
+        ("function onClick() { console.log(event); }", Some(json!(["event"]))),
+        ("foo", Some(json!([{ "name": "foo" }]))),
+        ("foo", Some(json!([{ "name": "foo", "message": "Use bar instead." }]))),
+        (
+            "fdescribe('foo', function() {});",
+            Some(
+                json!(["event", { "name": "fdescribe", "message": "Do not commit fdescribe. Use describe instead." }]),
+            ),
+        ),
+        (
+            "window; event;",
+            Some(json!([{ "name": "event", "message": "Use local event parameter." }, "window"])),
+        ),
+        ("/* global foo */ foo", Some(json!(["foo"]))),
+        ("/* global foo:writable */ foo = bar;", Some(json!(["foo"]))),
+        ("/* eslint-env browser */ window", Some(json!(["window"]))),
+    ];
+
+    Tester::new(NoRestrictedGlobals::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,465 @@
+use oxc_ast::{
+    ast::{ImportDeclaration, ImportDeclarationSpecifier, ModuleDeclaration},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use regex::Regex;
+
+use crate::{
+    context::LintContext,
+    rule::{compile_option_regex, InvalidRegexOption, Rule},
+    utils::get_string_array_option,
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoRestrictedImportsDiagnostic {
+    #[error("eslint(no-restricted-imports): '{0}' import is restricted from being used.{1}")]
+    #[diagnostic(severity(warning))]
+    Path(String, String, #[label] Span),
+    #[error("eslint(no-restricted-imports): '{0}' import from '{1}' is restricted.{2}")]
+    #[diagnostic(severity(warning))]
+    ImportName(String, String, String, #[label] Span),
+    #[error(
+        "eslint(no-restricted-imports): * import is invalid because '{0}' from '{1}' is restricted.{2}"
+    )]
+    #[diagnostic(severity(warning))]
+    Everything(String, String, String, #[label] Span),
+    #[error(
+        "eslint(no-restricted-imports): '{0}' import is restricted from being used by a pattern.{1}"
+    )]
+    #[diagnostic(severity(warning))]
+    Pattern(String, String, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedImports {
+    paths: Vec<RestrictedPath>,
+    patterns: Vec<RestrictedPatterns>,
+}
+
+#[derive(Debug, Clone)]
+struct RestrictedPath {
+    name: String,
+    /// When set, only these imported names are restricted, `default` included.
+    import_names: Option<Vec<String>>,
+    message: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+struct RestrictedPatterns {
+    group: Vec<GlobPattern>,
+    message: Option<String>,
+}
+
+/// A gitignore-style glob, e.g. `lodash/*`, `**/internal` or `!lodash/pick`.
+#[derive(Debug, Clone)]
+struct GlobPattern {
+    negated: bool,
+    regex: Regex,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow specified modules when loaded by `import`
+    ///
+    /// ### Why is this bad?
+    /// Some modules should not be used in a project, e.g. because they are
+    /// deprecated, too large for the browser, or have a preferred replacement.
+    ///
+    /// Restricted modules are given as `paths`, which match module names exactly and
+    /// can be limited to some `importNames`, and as `patterns`, which are gitignore-style
+    /// globs like `lodash/*` or `!lodash/pick`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// /* eslint no-restricted-imports: ["error", {
+    ///     "paths": [{ "name": "import-foo", "importNames": ["Bar"], "message": "Use Baz instead." }],
+    ///     "patterns": ["lodash/*"]
+    /// }] */
+    /// import { Bar } from "import-foo";
+    /// import pick from "lodash/pick";
+    /// ```
+    NoRestrictedImports,
+    restriction
+);
+
+impl Rule for NoRestrictedImports {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self::try_from_configuration(value).unwrap_or_default()
+    }
+
+    fn try_from_configuration(value: serde_json::Value) -> Result<Self, oxc_diagnostics::Error> {
+        let Some(options) = value.as_array() else { return Ok(Self::default()) };
+
+        // Either `["foo", { "name": "bar" }]` or `[{ "paths": [...], "patterns": [...] }]`.
+        let rule = match options.first() {
+            Some(obj @ serde_json::Value::Object(_))
+                if obj.get("paths").is_some() || obj.get("patterns").is_some() =>
+            {
+                Self {
+                    paths: obj
+                        .get("paths")
+                        .and_then(serde_json::Value::as_array)
+                        .map(|paths| paths.iter().filter_map(RestrictedPath::parse).collect())
+                        .unwrap_or_default(),
+                    patterns: obj
+                        .get("patterns")
+                        .and_then(serde_json::Value::as_array)
+                        .map(|patterns| RestrictedPatterns::parse_list(patterns))
+                        .transpose()?
+                        .unwrap_or_default(),
+                }
+            }
+            _ => Self {
+                paths: options.iter().filter_map(RestrictedPath::parse).collect(),
+                patterns: vec![],
+            },
+        };
+        Ok(rule)
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ModuleDeclaration(ModuleDeclaration::ImportDeclaration(decl)) = node.kind()
+        else {
+            return;
+        };
+        let source = decl.source.value.as_str();
+
+        for path in self.paths.iter().filter(|path| path.name == source) {
+            path.check(decl, ctx);
+        }
+
+        for patterns in &self.patterns {
+            if patterns.is_match(source) {
+                ctx.diagnostic(NoRestrictedImportsDiagnostic::Pattern(
+                    source.to_string(),
+                    message_suffix(patterns.message.as_ref()),
+                    decl.source.span,
+                ));
+            }
+        }
+    }
+}
+
+impl RestrictedPath {
+    fn parse(value: &serde_json::Value) -> Option<Self> {
+        match value {
+            serde_json::Value::String(name) => {
+                Some(Self { name: name.clone(), import_names: None, message: None })
+            }
+            serde_json::Value::Object(obj) => Some(Self {
+                name: obj.get("name")?.as_str()?.to_string(),
+                import_names: obj
+                    .get("importNames")
+                    .is_some_and(serde_json::Value::is_array)
+                    .then(|| get_string_array_option(value, "importNames")),
+                message: obj
+                    .get("message")
+                    .and_then(serde_json::Value::as_str)
+                    .map(ToString::to_string),
+            }),
+            _ => None,
+        }
+    }
+
+    fn check(&self, decl: &ImportDeclaration, ctx: &LintContext) {
+        let source = decl.source.value.to_string();
+        let message = message_suffix(self.message.as_ref());
+        let Some(import_names) = &self.import_names else {
+            ctx.diagnostic(NoRestrictedImportsDiagnostic::Path(source, message, decl.span));
+            return;
+        };
+
+        for specifier in decl.specifiers.iter().flatten() {
+            match specifier {
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                    ctx.diagnostic(NoRestrictedImportsDiagnostic::Everything(
+                        import_names.join(", "),
+                        source.clone(),
+                        message.clone(),
+                        specifier.span,
+                    ));
+                }
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                    if import_names.iter().any(|name| name == "default") {
+                        ctx.diagnostic(NoRestrictedImportsDiagnostic::ImportName(
+                            "default".to_string(),
+                            source.clone(),
+                            message.clone(),
+                            specifier.span,
+                        ));
+                    }
+                }
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                    let name = specifier.imported.name();
+                    if import_names.iter().any(|import_name| import_name == name.as_str()) {
+                        ctx.diagnostic(NoRestrictedImportsDiagnostic::ImportName(
+                            name.to_string(),
+                            source.clone(),
+                            message.clone(),
+                            specifier.imported.span(),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl RestrictedPatterns {
+    /// Either a list of globs forming a single group, or a list of `{ group, message }` objects.
+    fn parse_list(values: &[serde_json::Value]) -> Result<Vec<Self>, InvalidRegexOption> {
+        let globs = values.iter().filter_map(serde_json::Value::as_str).collect::<Vec<_>>();
+        let mut list = vec![];
+        if !globs.is_empty() {
+            list.push(Self { group: GlobPattern::parse_all(globs)?, message: None });
+        }
+        for obj in values.iter().filter(|value| value.is_object()) {
+            let Some(group) = obj.get("group").and_then(serde_json::Value::as_array) else {
+                continue;
+            };
+            list.push(Self {
+                group: GlobPattern::parse_all(group.iter().filter_map(serde_json::Value::as_str))?,
+                message: obj
+                    .get("message")
+                    .and_then(serde_json::Value::as_str)
+                    .map(ToString::to_string),
+            });
+        }
+        Ok(list)
+    }
+
+    /// Like gitignore, the last matching glob decides whether the source is restricted.
+    fn is_match(&self, source: &str) -> bool {
+        self.group
+            .iter()
+            .rev()
+            .find(|pattern| pattern.regex.is_match(source))
+            .map_or(false, |pattern| !pattern.negated)
+    }
+}
+
+impl GlobPattern {
+    fn parse_all<'a, I>(globs: I) -> Result<Vec<Self>, InvalidRegexOption>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        globs.into_iter().filter_map(|glob| Self::parse(glob).transpose()).collect()
+    }
+
+    /// Parses a glob into a regular expression, `None` for an empty glob.
+    fn parse(raw: &str) -> Result<Option<Self>, InvalidRegexOption> {
+        let negated = raw.starts_with('!');
+        let glob = raw.trim_start_matches('!').trim_end_matches('/');
+        if glob.is_empty() {
+            return Ok(None);
+        }
+
+        // A glob without a slash matches at any depth, otherwise it is anchored at the start.
+        let mut pattern = String::from(if glob.contains('/') { "^" } else { "^(?:.*/)?" });
+        let glob = glob.trim_start_matches('/');
+        let mut chars = glob.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '*' if chars.peek() == Some(&'*') => {
+                    chars.next();
+                    if chars.next_if_eq(&'/').is_some() {
+                        pattern.push_str("(?:.*/)?");
+                    } else {
+                        pattern.push_str(".*");
+                    }
+                }
+                '*' => pattern.push_str("[^/]*"),
+                '?' => pattern.push_str("[^/]"),
+                '[' => {
+                    pattern.push('[');
+                    for c in chars.by_ref() {
+                        if c == '\\' {
+                            pattern.push('\\');
+                        }
+                        pattern.push(c);
+                        if c == ']' {
+                            break;
+                        }
+                    }
+                }
+                '\\' => {
+                    if let Some(c) = chars.next() {
+                        pattern.push_str(&regex::escape(&c.to_string()));
+                    }
+                }
+                c => pattern.push_str(&regex::escape(&c.to_string())),
+            }
+        }
+        // Matching a directory also matches everything inside it.
+        pattern.push_str("(?:/.*)?$");
+
+        // Report the glob rather than the regular expression it is translated to.
+        let regex = compile_option_regex("patterns", &pattern)
+            .map_err(|error| InvalidRegexOption { pattern: raw.to_string(), ..error })?;
+        Ok(Some(Self { negated, regex }))
+    }
+}
+
+fn message_suffix(message: Option<&String>) -> String {
+    message.map_or_else(String::new, |message| format!(" {message}"))
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("import os from \"os\";", None),
+        ("import os from \"os\";", Some(json!(["osx"]))),
+        ("import fs from \"fs\";", Some(json!(["crypto"]))),
+        ("import path from \"path\";", Some(json!(["crypto", "stream", "os"]))),
+        ("import async from \"async\";", None),
+        ("import \"foo\"", Some(json!(["crypto"]))),
+        ("import \"foo/bar\";", Some(json!(["foo"]))),
+        ("import withPaths from \"foo/bar\";", Some(json!([{ "paths": ["foo", "bar"] }]))),
+        ("import withPatterns from \"foo/bar\";", Some(json!([{ "patterns": ["foo/c*"] }]))),
+        ("import foo from 'foo';", Some(json!(["../foo"]))),
+        ("import foo from 'foo';", Some(json!([{ "paths": ["../foo"] }]))),
+        ("import foo from 'foo';", Some(json!([{ "patterns": ["../foo"] }]))),
+        ("import foo from 'foo';", Some(json!(["/foo"]))),
+        ("import foo from 'foo';", Some(json!([{ "paths": ["/foo"] }]))),
+        ("import relative from '../foo';", None),
+        ("import relative from '../foo';", Some(json!(["../notFoo"]))),
+        ("import relativeWithPaths from '../foo';", Some(json!([{ "paths": ["../notFoo"] }]))),
+        ("import relativeWithPatterns from '../foo';", Some(json!([{ "patterns": ["notFoo"] }]))),
+        ("import absolute from '/foo';", Some(json!(["/notFoo"]))),
+        (
+            "import withPatternsAndPaths from \"foo/bar\";",
+            Some(json!([{ "paths": ["foo"], "patterns": ["foo/c*"] }])),
+        ),
+        (
+            "import withGitignores from \"foo/bar\";",
+            Some(json!([{ "patterns": ["foo/*", "!foo/bar"] }])),
+        ),
+        (
+            "import withPatterns from \"foo/bar\";",
+            Some(
+                json!([{ "patterns": [{ "group": ["foo/*", "!foo/bar"], "message": "foo is forbidden, use bar instead" }] }]),
+            ),
+        ),
+        (
+            "import AllowedObject from \"foo\";",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        (
+            "import DisallowedObject from \"foo\";",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        (
+            "import { AllowedObject } from \"foo\";",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        (
+            "import { 'AllowedObject' as bar } from \"foo\";",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        (
+            "import { DisallowedObject as AllowedObject } from \"bar\";",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        (
+            "import \"foo\";",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        ("import { pick } from \"lodash\";", Some(json!([{ "patterns": ["lodash/*"] }]))),
+        ("import internal from \"./internal/foo\";", Some(json!([{ "patterns": ["/internal"] }]))),
+    ];
+
+    let fail = vec![
+        ("import \"fs\"", Some(json!(["fs"]))),
+        ("import os from \"os\";", Some(json!(["fs", "crypto ", "stream", "os"]))),
+        ("import \"foo/bar\";", Some(json!(["foo/bar"]))),
+        ("import withPaths from \"foo/bar\";", Some(json!([{ "paths": ["foo/bar"] }]))),
+        ("import withPatterns from \"foo/bar\";", Some(json!([{ "patterns": ["foo"] }]))),
+        ("import withPatterns from \"foo/bar\";", Some(json!([{ "patterns": ["bar"] }]))),
+        (
+            "import withPatterns from \"foo/baz\";",
+            Some(
+                json!([{ "patterns": [{ "group": ["foo/*", "!foo/bar"], "message": "foo is forbidden, use foo/bar instead" }] }]),
+            ),
+        ),
+        (
+            "import withPatterns from \"foo/baz\";",
+            Some(
+                json!([{ "patterns": [{ "group": ["foo/bar", "foo/baz"], "message": "some foo subimports are restricted" }] }]),
+            ),
+        ),
+        (
+            "import withPatterns from \"foo/bar\";",
+            Some(json!([{ "patterns": [{ "group": ["foo/bar"] }] }])),
+        ),
+        (
+            "import withGitignores from \"foo/bar\";",
+            Some(json!([{ "patterns": ["foo/*", "!foo/baz"] }])),
+        ),
+        (
+            "import withPatternsAndPaths from \"foo/bar\";",
+            Some(json!([{ "paths": ["foo/bar"], "patterns": ["foo/*"] }])),
+        ),
+        (
+            "import DisallowedObject from \"foo\";",
+            Some(
+                json!([{ "paths": [{ "name": "foo", "message": "Please import from 'bar' instead." }] }]),
+            ),
+        ),
+        (
+            "import { DisallowedObject } from \"foo\";",
+            Some(
+                json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"], "message": "Please import 'DisallowedObject' from /bar/ instead." }] }]),
+            ),
+        ),
+        (
+            "import { DisallowedObject as AllowedObject } from \"foo\";",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        (
+            "import { 'DisallowedObject' as AllowedObject } from \"foo\";",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        (
+            "import { AllowedObject, DisallowedObject } from \"foo\";",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject"] }] }])),
+        ),
+        (
+            "import DisallowedObject, { AllowedObject } from \"foo\";",
+            Some(json!([{ "paths": [{ "name": "foo", "importNames": ["default"] }] }])),
+        ),
+        (
+            "import * as All from \"foo\";",
+            Some(
+                json!([{ "paths": [{ "name": "foo", "importNames": ["DisallowedObject", "DisallowedObjectTwo"] }] }]),
+            ),
+        ),
+        ("import absolute from '/foo';", Some(json!(["/foo"]))),
+        ("import relative from '../foo';", Some(json!([{ "patterns": ["../foo"] }]))),
+        ("import pick from \"lodash/pick\";", Some(json!([{ "patterns": ["lodash/*"] }]))),
+        ("import deep from \"a/b/internal/c\";", Some(json!([{ "patterns": ["**/internal/**"] }]))),
+    ];
+
+    Tester::new(NoRestrictedImports::NAME, pass, fail).test_and_snapshot();
+}
+
+#[test]
+fn test_invalid_pattern() {
+    use serde_json::json;
+
+    let err = NoRestrictedImports::try_from_configuration(json!([{ "patterns": ["lodash/[a"] }]))
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("Invalid regular expression `lodash/[a` in the `patterns` option"));
+}
@@ -0,0 +1,581 @@
+use oxc_ast::{
+    ast::{BindingPatternKind, Expression, JSXAttributeItem, MemberExpression, ModuleDeclaration},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use regex::Regex;
+
+use crate::{
+    context::LintContext,
+    rule::{compile_option_regex, InvalidRegexOption, Rule},
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-restricted-syntax): {0}")]
+#[diagnostic(severity(warning))]
+struct NoRestrictedSyntaxDiagnostic(String, #[label] pub Span);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Unsupported selector `{0}` in the `selector` option")]
+#[diagnostic(
+    severity(error),
+    help("Only a node type or `*` followed by attribute selectors like `[name=\"foo\"]` is supported.")
+)]
+struct UnsupportedSelector(String);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("Unknown node type `{0}` in the `selector` option")]
+#[diagnostic(severity(error), help("Use an ESTree node type like `WithStatement`."))]
+struct UnknownNodeType(String);
+
+/// The attributes which can be used in attribute selectors, see [`attribute_value`].
+const SUPPORTED_ATTRIBUTES: [&str; 10] = [
+    "name",
+    "id.name",
+    "operator",
+    "kind",
+    "callee.name",
+    "object.name",
+    "property.name",
+    "value",
+    "async",
+    "generator",
+];
+
+/// The node types which can be selected, see [`node_type`].
+const NODE_TYPES: [&str; 85] = [
+    "Program",
+    "BlockStatement",
+    "BreakStatement",
+    "ContinueStatement",
+    "DebuggerStatement",
+    "DoWhileStatement",
+    "EmptyStatement",
+    "ExpressionStatement",
+    "ForInStatement",
+    "ForOfStatement",
+    "ForStatement",
+    "IfStatement",
+    "LabeledStatement",
+    "ReturnStatement",
+    "SwitchStatement",
+    "ThrowStatement",
+    "TryStatement",
+    "WhileStatement",
+    "WithStatement",
+    "SwitchCase",
+    "CatchClause",
+    "VariableDeclaration",
+    "VariableDeclarator",
+    "Identifier",
+    "PrivateIdentifier",
+    "Literal",
+    "TemplateLiteral",
+    "MetaProperty",
+    "Super",
+    "ArrayExpression",
+    "ArrowFunctionExpression",
+    "AssignmentExpression",
+    "AwaitExpression",
+    "BinaryExpression",
+    "CallExpression",
+    "ChainExpression",
+    "ConditionalExpression",
+    "LogicalExpression",
+    "MemberExpression",
+    "NewExpression",
+    "ObjectExpression",
+    "SequenceExpression",
+    "TaggedTemplateExpression",
+    "ThisExpression",
+    "UnaryExpression",
+    "UpdateExpression",
+    "YieldExpression",
+    "ImportExpression",
+    "Property",
+    "SpreadElement",
+    "RestElement",
+    "FunctionDeclaration",
+    "FunctionExpression",
+    "ClassDeclaration",
+    "ClassExpression",
+    "ClassBody",
+    "StaticBlock",
+    "PropertyDefinition",
+    "MethodDefinition",
+    "ArrayPattern",
+    "ObjectPattern",
+    "AssignmentPattern",
+    "Decorator",
+    "ImportDeclaration",
+    "ExportAllDeclaration",
+    "ExportDefaultDeclaration",
+    "ExportNamedDeclaration",
+    "TSExportAssignment",
+    "TSNamespaceExportDeclaration",
+    "JSXElement",
+    "JSXFragment",
+    "JSXOpeningElement",
+    "JSXExpressionContainer",
+    "JSXAttribute",
+    "JSXSpreadAttribute",
+    "JSXText",
+    "TSAnyKeyword",
+    "TSAsExpression",
+    "TSSatisfiesExpression",
+    "TSNonNullExpression",
+    "TSEnumDeclaration",
+    "TSInterfaceDeclaration",
+    "TSModuleDeclaration",
+    "TSTypeAliasDeclaration",
+    "TSTypeAssertion",
+];
+
+#[derive(Debug, Default, Clone)]
+pub struct NoRestrictedSyntax {
+    restrictions: Vec<Restriction>,
+}
+
+#[derive(Debug, Clone)]
+struct Restriction {
+    selector: Selector,
+    message: String,
+}
+
+/// A subset of the [esquery](https://github.com/estools/esquery) selector syntax:
+/// a node type (or `*`) followed by any number of attribute selectors.
+#[derive(Debug, Clone)]
+struct Selector {
+    /// `None` for the wildcard `*`.
+    node_type: Option<String>,
+    attributes: Vec<AttributeSelector>,
+}
+
+#[derive(Debug, Clone)]
+struct AttributeSelector {
+    path: String,
+    /// `None` for `[attr]`, which only checks that the attribute exists.
+    condition: Option<AttributeCondition>,
+}
+
+#[derive(Debug, Clone)]
+struct AttributeCondition {
+    negated: bool,
+    value: AttributeValue,
+}
+
+#[derive(Debug, Clone)]
+enum AttributeValue {
+    Literal(String),
+    Regex(Regex),
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow specified syntax
+    ///
+    /// ### Why is this bad?
+    /// Some teams restrict language features, e.g. `with` statements or `for...in` loops.
+    /// This rule reports the nodes matching the configured selectors, optionally
+    /// with a custom message.
+    ///
+    /// Only a subset of the selector syntax is supported: a node type or `*`, followed
+    /// by attribute selectors like `[operator="=="]`, `[name=/^foo/]`, `[async]` or
+    /// `[kind!="const"]`. Supported attributes are `name`, `id.name`, `operator`, `kind`,
+    /// `callee.name`, `object.name`, `property.name`, `value`, `async` and `generator`.
+    /// Selectors using any other syntax are reported as configuration errors.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// /* eslint no-restricted-syntax: ["error", "WithStatement", {
+    ///     "selector": "BinaryExpression[operator='in']",
+    ///     "message": "Use Object.hasOwn instead."
+    /// }] */
+    /// with (me) {
+    ///     dontMess();
+    /// }
+    /// const hasKey = "key" in obj;
+    /// ```
+    NoRestrictedSyntax,
+    restriction
+);
+
+impl Rule for NoRestrictedSyntax {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self::try_from_configuration(value).unwrap_or_default()
+    }
+
+    fn try_from_configuration(value: serde_json::Value) -> Result<Self, oxc_diagnostics::Error> {
+        let mut restrictions = vec![];
+        for option in value.as_array().into_iter().flatten() {
+            let (raw, message) = match option {
+                serde_json::Value::String(raw) => (raw.as_str(), None),
+                serde_json::Value::Object(obj) => {
+                    let Some(raw) = obj.get("selector").and_then(serde_json::Value::as_str) else {
+                        continue;
+                    };
+                    (raw, obj.get("message").and_then(serde_json::Value::as_str))
+                }
+                _ => continue,
+            };
+            restrictions.push(Restriction {
+                selector: Selector::parse(raw)?,
+                message: message
+                    .map_or_else(|| format!("Using '{raw}' is not allowed."), ToString::to_string),
+            });
+        }
+
+        Ok(Self { restrictions })
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let kind = node.kind();
+        let Some(node_type) = node_type(kind) else { return };
+        for restriction in &self.restrictions {
+            if restriction.selector.matches(node_type, kind) {
+                ctx.diagnostic(NoRestrictedSyntaxDiagnostic(
+                    restriction.message.clone(),
+                    kind.span(),
+                ));
+            }
+        }
+    }
+}
+
+impl Selector {
+    fn parse(raw: &str) -> Result<Self, oxc_diagnostics::Error> {
+        let unsupported = || UnsupportedSelector(raw.to_string());
+        let selector = raw.trim();
+        let type_end = selector.find('[').unwrap_or(selector.len());
+        let node_type = match &selector[..type_end] {
+            "*" => None,
+            name if NODE_TYPES.contains(&name) => Some(name.to_string()),
+            name if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric()) => {
+                return Err(UnknownNodeType(name.to_string()).into());
+            }
+            // `[attr]` on its own matches any node type.
+            "" if type_end < selector.len() => None,
+            _ => return Err(unsupported().into()),
+        };
+
+        let mut attributes = vec![];
+        let mut rest = &selector[type_end..];
+        while !rest.is_empty() {
+            let Some(end) = rest.find(']').filter(|_| rest.starts_with('[')) else {
+                return Err(unsupported().into());
+            };
+            let attribute = AttributeSelector::parse(&rest[1..end])?;
+            if !SUPPORTED_ATTRIBUTES.contains(&attribute.path.as_str()) {
+                return Err(unsupported().into());
+            }
+            attributes.push(attribute);
+            rest = &rest[end + 1..];
+        }
+
+        Ok(Self { node_type, attributes })
+    }
+
+    fn matches(&self, node_type: &str, kind: AstKind) -> bool {
+        self.node_type.as_ref().map_or(true, |expected| expected == node_type)
+            && self.attributes.iter().all(|attribute| attribute.matches(kind))
+    }
+}
+
+impl AttributeSelector {
+    fn parse(attribute: &str) -> Result<Self, InvalidRegexOption> {
+        let Some(eq) = attribute.find('=') else {
+            return Ok(Self { path: attribute.trim().to_string(), condition: None });
+        };
+        let path = &attribute[..eq];
+        let negated = path.ends_with('!');
+        let path = path.trim_end_matches('!');
+        let raw_value = attribute[eq + 1..].trim();
+        let value = if let Some(pattern) =
+            raw_value.strip_prefix('/').and_then(|value| value.strip_suffix('/'))
+        {
+            AttributeValue::Regex(compile_option_regex("selector", pattern)?)
+        } else {
+            let unquoted = raw_value
+                .strip_prefix('"')
+                .and_then(|value| value.strip_suffix('"'))
+                .or_else(|| raw_value.strip_prefix('\'').and_then(|value| value.strip_suffix('\'')))
+                .unwrap_or(raw_value);
+            AttributeValue::Literal(unquoted.to_string())
+        };
+
+        Ok(Self {
+            path: path.trim().to_string(),
+            condition: Some(AttributeCondition { negated, value }),
+        })
+    }
+
+    fn matches(&self, kind: AstKind) -> bool {
+        let value = attribute_value(kind, &self.path);
+        match (&self.condition, value) {
+            (None, value) => value.is_some(),
+            (Some(condition), None) => condition.negated,
+            (Some(condition), Some(value)) => {
+                let is_match = match &condition.value {
+                    AttributeValue::Literal(expected) => *expected == value,
+                    AttributeValue::Regex(regex) => regex.is_match(&value),
+                };
+                is_match != condition.negated
+            }
+        }
+    }
+}
+
+/// The ESTree node type of the node, for the node kinds which can be selected.
+fn node_type(kind: AstKind) -> Option<&'static str> {
+    let node_type = match kind {
+        AstKind::Program(_) => "Program",
+        AstKind::BlockStatement(_) => "BlockStatement",
+        AstKind::BreakStatement(_) => "BreakStatement",
+        AstKind::ContinueStatement(_) => "ContinueStatement",
+        AstKind::DebuggerStatement(_) => "DebuggerStatement",
+        AstKind::DoWhileStatement(_) => "DoWhileStatement",
+        AstKind::EmptyStatement(_) => "EmptyStatement",
+        AstKind::ExpressionStatement(_) => "ExpressionStatement",
+        AstKind::ForInStatement(_) => "ForInStatement",
+        AstKind::ForOfStatement(_) => "ForOfStatement",
+        AstKind::ForStatement(_) => "ForStatement",
+        AstKind::IfStatement(_) => "IfStatement",
+        AstKind::LabeledStatement(_) => "LabeledStatement",
+        AstKind::ReturnStatement(_) => "ReturnStatement",
+        AstKind::SwitchStatement(_) => "SwitchStatement",
+        AstKind::ThrowStatement(_) => "ThrowStatement",
+        AstKind::TryStatement(_) => "TryStatement",
+        AstKind::WhileStatement(_) => "WhileStatement",
+        AstKind::WithStatement(_) => "WithStatement",
+        AstKind::SwitchCase(_) => "SwitchCase",
+        AstKind::CatchClause(_) => "CatchClause",
+        AstKind::VariableDeclaration(_) => "VariableDeclaration",
+        AstKind::VariableDeclarator(_) => "VariableDeclarator",
+        AstKind::IdentifierName(_)
+        | AstKind::IdentifierReference(_)
+        | AstKind::BindingIdentifier(_)
+        | AstKind::LabelIdentifier(_) => "Identifier",
+        AstKind::PrivateIdentifier(_) => "PrivateIdentifier",
+        AstKind::NumberLiteral(_)
+        | AstKind::StringLiteral(_)
+        | AstKind::BooleanLiteral(_)
+        | AstKind::NullLiteral(_)
+        | AstKind::BigintLiteral(_)
+        | AstKind::RegExpLiteral(_) => "Literal",
+        AstKind::TemplateLiteral(_) => "TemplateLiteral",
+        AstKind::MetaProperty(_) => "MetaProperty",
+        AstKind::Super(_) => "Super",
+        AstKind::ArrayExpression(_) => "ArrayExpression",
+        AstKind::ArrowExpression(_) => "ArrowFunctionExpression",
+        AstKind::AssignmentExpression(_) => "AssignmentExpression",
+        AstKind::AwaitExpression(_) => "AwaitExpression",
+        AstKind::BinaryExpression(_) | AstKind::PrivateInExpression(_) => "BinaryExpression",
+        AstKind::CallExpression(_) => "CallExpression",
+        AstKind::ChainExpression(_) => "ChainExpression",
+        AstKind::ConditionalExpression(_) => "ConditionalExpression",
+        AstKind::LogicalExpression(_) => "LogicalExpression",
+        AstKind::MemberExpression(_) => "MemberExpression",
+        AstKind::NewExpression(_) => "NewExpression",
+        AstKind::ObjectExpression(_) => "ObjectExpression",
+        AstKind::SequenceExpression(_) => "SequenceExpression",
+        AstKind::TaggedTemplateExpression(_) => "TaggedTemplateExpression",
+        AstKind::ThisExpression(_) => "ThisExpression",
+        AstKind::UnaryExpression(_) => "UnaryExpression",
+        AstKind::UpdateExpression(_) => "UpdateExpression",
+        AstKind::YieldExpression(_) => "YieldExpression",
+        AstKind::ImportExpression(_) => "ImportExpression",
+        AstKind::ObjectProperty(_) => "Property",
+        AstKind::SpreadElement(_) => "SpreadElement",
+        AstKind::RestElement(_) => "RestElement",
+        AstKind::Function(func) if func.is_declaration() => "FunctionDeclaration",
+        AstKind::Function(_) => "FunctionExpression",
+        AstKind::Class(class) if class.is_declaration() => "ClassDeclaration",
+        AstKind::Class(_) => "ClassExpression",
+        AstKind::ClassBody(_) => "ClassBody",
+        AstKind::StaticBlock(_) => "StaticBlock",
+        AstKind::PropertyDefinition(_) => "PropertyDefinition",
+        AstKind::MethodDefinition(_) => "MethodDefinition",
+        AstKind::ArrayPattern(_) => "ArrayPattern",
+        AstKind::ObjectPattern(_) => "ObjectPattern",
+        AstKind::AssignmentPattern(_) => "AssignmentPattern",
+        AstKind::Decorator(_) => "Decorator",
+        AstKind::ModuleDeclaration(decl) => match decl {
+            ModuleDeclaration::ImportDeclaration(_) => "ImportDeclaration",
+            ModuleDeclaration::ExportAllDeclaration(_) => "ExportAllDeclaration",
+            ModuleDeclaration::ExportDefaultDeclaration(_) => "ExportDefaultDeclaration",
+            ModuleDeclaration::ExportNamedDeclaration(_) => "ExportNamedDeclaration",
+            ModuleDeclaration::TSExportAssignment(_) => "TSExportAssignment",
+            ModuleDeclaration::TSNamespaceExportDeclaration(_) => "TSNamespaceExportDeclaration",
+        },
+        AstKind::JSXElement(_) => "JSXElement",
+        AstKind::JSXFragment(_) => "JSXFragment",
+        AstKind::JSXOpeningElement(_) => "JSXOpeningElement",
+        AstKind::JSXExpressionContainer(_) => "JSXExpressionContainer",
+        AstKind::JSXAttributeItem(JSXAttributeItem::Attribute(_)) => "JSXAttribute",
+        AstKind::JSXSpreadAttribute(_) => "JSXSpreadAttribute",
+        AstKind::JSXText(_) => "JSXText",
+        AstKind::TSAnyKeyword(_) => "TSAnyKeyword",
+        AstKind::TSAsExpression(_) => "TSAsExpression",
+        AstKind::TSSatisfiesExpression(_) => "TSSatisfiesExpression",
+        AstKind::TSNonNullExpression(_) => "TSNonNullExpression",
+        AstKind::TSEnumDeclaration(_) => "TSEnumDeclaration",
+        AstKind::TSInterfaceDeclaration(_) => "TSInterfaceDeclaration",
+        AstKind::TSModuleDeclaration(_) => "TSModuleDeclaration",
+        AstKind::TSTypeAliasDeclaration(_) => "TSTypeAliasDeclaration",
+        AstKind::TSTypeAssertion(_) => "TSTypeAssertion",
+        _ => return None,
+    };
+    Some(node_type)
+}
+
+/// The value of a supported attribute of the node, as a string.
+fn attribute_value(kind: AstKind, path: &str) -> Option<String> {
+    let identifier_name = |expr: &Expression| match expr {
+        Expression::Identifier(ident) => Some(ident.name.to_string()),
+        _ => None,
+    };
+
+    match (path, kind) {
+        ("name", AstKind::IdentifierName(ident)) => Some(ident.name.to_string()),
+        ("name", AstKind::IdentifierReference(ident)) => Some(ident.name.to_string()),
+        ("name", AstKind::BindingIdentifier(ident)) => Some(ident.name.to_string()),
+        ("name", AstKind::LabelIdentifier(ident)) => Some(ident.name.to_string()),
+        ("name", AstKind::PrivateIdentifier(ident)) => Some(ident.name.to_string()),
+        ("id.name", AstKind::Function(func)) => func.id.as_ref().map(|id| id.name.to_string()),
+        ("id.name", AstKind::Class(class)) => class.id.as_ref().map(|id| id.name.to_string()),
+        ("id.name", AstKind::VariableDeclarator(decl)) => match &decl.id.kind {
+            BindingPatternKind::BindingIdentifier(ident) => Some(ident.name.to_string()),
+            _ => None,
+        },
+        ("operator", AstKind::BinaryExpression(expr)) => Some(expr.operator.as_str().to_string()),
+        ("operator", AstKind::PrivateInExpression(_)) => Some("in".to_string()),
+        ("operator", AstKind::LogicalExpression(expr)) => Some(expr.operator.as_str().to_string()),
+        ("operator", AstKind::UnaryExpression(expr)) => Some(expr.operator.as_str().to_string()),
+        ("operator", AstKind::UpdateExpression(expr)) => Some(expr.operator.as_str().to_string()),
+        ("operator", AstKind::AssignmentExpression(expr)) => {
+            Some(expr.operator.as_str().to_string())
+        }
+        ("kind", AstKind::VariableDeclaration(decl)) => Some(decl.kind.as_str().to_string()),
+        ("callee.name", AstKind::CallExpression(expr)) => identifier_name(&expr.callee),
+        ("callee.name", AstKind::NewExpression(expr)) => identifier_name(&expr.callee),
+        ("object.name", AstKind::MemberExpression(expr)) => identifier_name(expr.object()),
+        (
+            "property.name",
+            AstKind::MemberExpression(MemberExpression::StaticMemberExpression(expr)),
+        ) => Some(expr.property.name.to_string()),
+        ("value", AstKind::StringLiteral(lit)) => Some(lit.value.to_string()),
+        ("value", AstKind::NumberLiteral(lit)) => Some(lit.value.to_string()),
+        ("value", AstKind::BooleanLiteral(lit)) => Some(lit.value.to_string()),
+        ("value", AstKind::NullLiteral(_)) => Some("null".to_string()),
+        ("async", AstKind::Function(func)) => Some(func.r#async.to_string()),
+        ("async", AstKind::ArrowExpression(arrow)) => Some(arrow.r#async.to_string()),
+        ("generator", AstKind::Function(func)) => Some(func.generator.to_string()),
+        _ => None,
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("doSomething();", None),
+        ("var foo = 42;", Some(json!(["ConditionalExpression"]))),
+        ("foo += 42;", Some(json!(["VariableDeclaration", "FunctionExpression"]))),
+        ("foo;", Some(json!(["Identifier[name=\"bar\"]"]))),
+        ("for (var key in obj) {}", Some(json!(["WithStatement"]))),
+        ("with (obj) {}", Some(json!([]))),
+        ("var foo = 1;", Some(json!(["VariableDeclaration[kind='let']"]))),
+        ("let foo = 1;", Some(json!(["VariableDeclaration[kind!='let']"]))),
+        ("a === b", Some(json!(["BinaryExpression[operator='==']"]))),
+        ("foo()", Some(json!(["CallExpression[callee.name=/^(eval|setTimeout)$/]"]))),
+        ("function foo() {}", Some(json!(["FunctionDeclaration[async=true]"]))),
+        ("foo.bar", Some(json!(["MemberExpression[property.name='baz']"]))),
+        ("foo.bar", Some(json!([{ "selector": "MemberExpression[object.name='baz']" }]))),
+    ];
+
+    let fail = vec![
+        ("var foo = 41;", Some(json!(["VariableDeclaration"]))),
+        (";function lol(a) { return 42; }", Some(json!(["EmptyStatement"]))),
+        (
+            "try { voila(); } catch (e) { oops(); }",
+            Some(json!(["TryStatement", "CallExpression", "CatchClause"])),
+        ),
+        ("bar;", Some(json!(["Identifier[name=\"bar\"]"]))),
+        ("bar;", Some(json!(["Identifier", "Identifier[name=\"bar\"]"]))),
+        ("with (obj) { foo(); }", Some(json!(["WithStatement"]))),
+        (
+            "for (var key in obj) {}",
+            Some(
+                json!([{ "selector": "ForInStatement", "message": "for..in loops iterate over the entire prototype chain. Use Object.keys instead." }]),
+            ),
+        ),
+        ("for (var key in obj) {}", Some(json!([{ "selector": "ForInStatement" }]))),
+        ("let foo = 1;", Some(json!(["VariableDeclaration[kind='let']"]))),
+        ("var foo = 1;", Some(json!(["VariableDeclaration[kind!='let']"]))),
+        ("a == b", Some(json!(["BinaryExpression[operator='==']"]))),
+        (
+            "eval('foo')",
+            Some(
+                json!([{ "selector": "CallExpression[callee.name=/^(eval|setTimeout)$/]", "message": "Avoid dynamic code execution." }]),
+            ),
+        ),
+        ("async function foo() {}", Some(json!(["FunctionDeclaration[async=true]"]))),
+        ("function* foo() {}", Some(json!(["[generator=true]"]))),
+        ("foo.bar", Some(json!(["MemberExpression[object.name='foo'][property.name='bar']"]))),
+        ("'use strict'; foo('bar')", Some(json!(["Literal[value='bar']"]))),
+        ("const x = class {}", Some(json!(["ClassExpression"]))),
+        ("export default 1", Some(json!(["ExportDefaultDeclaration"]))),
+        ("const f = () => {}", Some(json!(["*[async=false]"]))),
+    ];
+
+    Tester::new(NoRestrictedSyntax::NAME, pass, fail).test_and_snapshot();
+}
+
+#[test]
+fn test_invalid_regex() {
+    use serde_json::json;
+
+    let err =
+        NoRestrictedSyntax::try_from_configuration(json!(["Identifier[name=/(foo/]"])).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("Invalid regular expression `(foo` in the `selector` option"));
+}
+
+#[test]
+fn test_unsupported_selector() {
+    use serde_json::json;
+
+    for selector in [
+        "ArrowFunctionExpression > BlockStatement",
+        "Property > Literal.key",
+        "BreakStatement[label]",
+        "FunctionDeclaration[params.length>2]",
+        "Identifier:not([name='foo'])",
+        "Identifier[name='foo'",
+    ] {
+        let err = NoRestrictedSyntax::try_from_configuration(json!([{ "selector": selector }]))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("Unsupported selector `{selector}` in the `selector` option")
+        );
+    }
+}
+
+#[test]
+fn test_unknown_node_type() {
+    use serde_json::json;
+
+    let err = NoRestrictedSyntax::try_from_configuration(json!(["WithStatment"])).unwrap_err();
+    assert_eq!(err.to_string(), "Unknown node type `WithStatment` in the `selector` option");
+}
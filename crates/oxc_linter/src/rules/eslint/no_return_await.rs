@@ -41,7 +41,7 @@ impl Rule for NoReturnAwait {
                 let start = await_expr.span.start;
                 let end = start + 5;
                 let await_keyword_span = Span::new(start, end);
-                ctx.diagnostic_with_fix(NoReturnAwaitDiagnostic(await_keyword_span), || {
+                ctx.diagnostic_with_fix(NoReturnAwaitDiagnostic(await_keyword_span), |_| {
                     Fix::new("", await_keyword_span)
                 });
             }
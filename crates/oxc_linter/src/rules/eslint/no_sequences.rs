@@ -0,0 +1,170 @@
+use oxc_ast::{ast::SequenceExpression, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-sequences): Unexpected use of comma operator.")]
+#[diagnostic(
+    severity(warning),
+    help("Wrap the sequence in parentheses if the comma operator is intended.")
+)]
+struct NoSequencesDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Clone)]
+pub struct NoSequences {
+    /// Whether to allow sequences wrapped in parentheses, e.g. `a = (b, c)`.
+    allow_in_parentheses: bool,
+}
+
+impl Default for NoSequences {
+    fn default() -> Self {
+        Self { allow_in_parentheses: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow comma operators
+    ///
+    /// ### Why is this bad?
+    /// The comma operator evaluates each of its operands and returns the last one,
+    /// which makes it easy to hide side effects or to mistype a comma for another operator.
+    /// Sequences in the initialization or update of a `for` loop, and explicitly
+    /// parenthesized sequences, are allowed.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// foo = doSomething(), val;
+    /// if (doSomething(), !!test);
+    /// ```
+    NoSequences,
+    restriction
+);
+
+impl Rule for NoSequences {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            allow_in_parentheses: value
+                .get(0)
+                .and_then(|v| v.get("allowInParentheses"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(true),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::SequenceExpression(expr) = node.kind() else { return };
+        let nodes = ctx.nodes();
+
+        match nodes.parent_kind(node.id()) {
+            Some(AstKind::ForStatementInit(_)) => return,
+            Some(AstKind::ForStatement(stmt))
+                if stmt.update.as_ref().is_some_and(|update| update.span() == expr.span) =>
+            {
+                return
+            }
+            _ => {}
+        }
+
+        if self.allow_in_parentheses {
+            let mut ancestors = nodes.ancestors(node.id()).skip(1);
+            let mut parens = 0;
+            let outer = loop {
+                match ancestors.next() {
+                    Some(id) if matches!(nodes.kind(id), AstKind::ParenthesizedExpression(_)) => {
+                        parens += 1;
+                    }
+                    id => break id,
+                }
+            };
+
+            // The parentheses of an arrow function body are part of its syntax,
+            // e.g. `() => (a, b)`, so the sequence needs a second pair.
+            let is_arrow_body = outer.is_some_and(|id| {
+                matches!(nodes.kind(id), AstKind::ExpressionStatement(_))
+                    && matches!(
+                        nodes.ancestors(id).nth(2).map(|id| nodes.kind(id)),
+                        Some(AstKind::ArrowExpression(arrow)) if arrow.expression
+                    )
+            });
+            if parens >= if is_arrow_body { 2 } else { 1 } {
+                return;
+            }
+        }
+
+        ctx.diagnostic(NoSequencesDiagnostic(first_comma_span(expr, ctx.source_text())));
+    }
+}
+
+/// The span of the first comma of the sequence, falling back to the whole expression.
+fn first_comma_span(expr: &SequenceExpression, source_text: &str) -> Span {
+    let (Some(first), Some(second)) = (expr.expressions.first(), expr.expressions.get(1)) else {
+        return expr.span;
+    };
+    let start = first.span().end;
+    Span::new(start, second.span().start)
+        .source_text(source_text)
+        .find(',')
+        .and_then(|offset| u32::try_from(offset).ok())
+        .map_or(expr.span, |offset| Span::new(start + offset, start + offset + 1))
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var arr = [1, 2];", None),
+        ("var obj = {a: 1, b: 2};", None),
+        ("var a = 1, b = 2;", None),
+        ("var foo = (1, 2);", None),
+        ("(0,eval)(\"foo()\");", None),
+        ("for (i = 1, j = 2;; i++, j++);", None),
+        ("for (i = 0, j = 1; i < 10; i++, j--) {}", None),
+        ("foo(a, (b, c), d);", None),
+        ("do {} while ((doSomething(), !!test));", None),
+        ("for ((doSomething(), somethingElse()); (doSomething(), !!test); );", None),
+        ("if ((doSomething(), !!test));", None),
+        ("switch ((doSomething(), val)) {}", None),
+        ("while ((doSomething(), !!test));", None),
+        ("with ((doSomething(), val)) {}", None),
+        ("a => ((doSomething(), a))", None),
+        ("a = (b, c)", None),
+        ("var foo = (1, 2);", Some(json!([{}]))),
+        ("var foo = (1, 2);", Some(json!([{ "allowInParentheses": true }]))),
+        ("for (i = 1, j = 2;; i++, j++);", Some(json!([{ "allowInParentheses": false }]))),
+        ("foo(a, b);", Some(json!([{ "allowInParentheses": false }]))),
+        ("var x = [a, b];", Some(json!([{ "allowInParentheses": false }]))),
+    ];
+
+    let fail = vec![
+        ("1, 2;", None),
+        ("a = 1, 2", None),
+        ("do {} while (doSomething(), !!test);", None),
+        ("for (; doSomething(), !!test; );", None),
+        ("if (doSomething(), !!test);", None),
+        ("switch (doSomething(), val) {}", None),
+        ("while (doSomething(), !!test);", None),
+        ("with (doSomething(), val) {}", None),
+        ("a => (doSomething(), a)", None),
+        ("(1), 2", None),
+        ("((1)) , (2)", None),
+        ("while((1) , 2);", None),
+        ("var foo = (1, 2);", Some(json!([{ "allowInParentheses": false }]))),
+        ("(0,eval)(\"foo()\");", Some(json!([{ "allowInParentheses": false }]))),
+        ("foo(a, (b, c), d);", Some(json!([{ "allowInParentheses": false }]))),
+        ("do {} while ((doSomething(), !!test));", Some(json!([{ "allowInParentheses": false }]))),
+        ("if ((doSomething(), !!test));", Some(json!([{ "allowInParentheses": false }]))),
+        ("a => ((doSomething(), a))", Some(json!([{ "allowInParentheses": false }]))),
+    ];
+
+    Tester::new(NoSequences::NAME, pass, fail).test_and_snapshot();
+}
@@ -1,10 +1,14 @@
-use oxc_ast::AstKind;
+use oxc_ast::{
+    ast::{Argument, ChainElement, Expression, MemberExpression, PropertyKind},
+    AstKind,
+};
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
 };
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_semantic::AstNodeId;
+use oxc_span::{GetSpan, Span};
 
 use crate::{context::LintContext, rule::Rule, AstNode};
 
@@ -34,6 +38,12 @@ declare_oxc_lint!(
     ///     return true;
     ///   }
     /// }
+    ///
+    /// Object.defineProperty(foo, "bar", {
+    ///   set(value) {
+    ///     return value;
+    ///   }
+    /// });
     /// ```
     NoSetterReturn,
     correctness
@@ -41,13 +51,120 @@ declare_oxc_lint!(
 
 impl Rule for NoSetterReturn {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
-        let AstKind::ReturnStatement(stmt) = node.kind() else { return };
-        if stmt.argument.is_some() && ctx.scopes().get_flags(node.scope_id()).is_set_accessor() {
-            ctx.diagnostic(NoSetterReturnDiagnostic(stmt.span));
+        match node.kind() {
+            AstKind::ReturnStatement(stmt)
+                if stmt.argument.is_some()
+                    && (ctx.scopes().get_flags(node.scope_id()).is_set_accessor()
+                        || enclosing_function(node.id(), ctx).is_some_and(|function_id| {
+                            is_property_descriptor_setter(function_id, ctx)
+                        })) =>
+            {
+                ctx.diagnostic(NoSetterReturnDiagnostic(stmt.span));
+            }
+            // `Object.defineProperty(foo, "bar", { set: (val) => val })`
+            AstKind::ArrowExpression(arrow)
+                if arrow.expression && is_property_descriptor_setter(node.id(), ctx) =>
+            {
+                let Some(expr) = arrow.get_expression() else { return };
+                ctx.diagnostic(NoSetterReturnDiagnostic(expr.span()));
+            }
+            _ => {}
+        }
+    }
+}
+
+fn enclosing_function(node_id: AstNodeId, ctx: &LintContext) -> Option<AstNodeId> {
+    ctx.nodes()
+        .iter_parents(node_id)
+        .find(|parent| matches!(parent.kind(), AstKind::Function(_) | AstKind::ArrowExpression(_)))
+        .map(AstNode::id)
+}
+
+/// Whether the function at `function_id` is the `set` of a property descriptor,
+/// e.g. `Object.defineProperty(foo, "bar", { set(val) {} })`.
+fn is_property_descriptor_setter(function_id: AstNodeId, ctx: &LintContext) -> bool {
+    let nodes = ctx.nodes();
+    let Some(prop_node) = nodes.parent_node(function_id) else { return false };
+    let AstKind::ObjectProperty(prop) = prop_node.kind() else { return false };
+    if prop.kind != PropertyKind::Init
+        || !prop.key.is_specific_static_name("set")
+        || prop.value.span() != nodes.kind(function_id).span()
+    {
+        return false;
+    }
+    nodes.parent_node(prop_node.id()).is_some_and(|descriptor| {
+        matches!(descriptor.kind(), AstKind::ObjectExpression(_))
+            && is_property_descriptor(descriptor, ctx)
+    })
+}
+
+/// Whether the object literal `node` is the descriptor of `Object.defineProperty(obj, key, descriptor)`,
+/// `Reflect.defineProperty(obj, key, descriptor)`, `Object.defineProperties(obj, { key: descriptor })`
+/// or `Object.create(proto, { key: descriptor })`.
+fn is_property_descriptor(node: &AstNode, ctx: &LintContext) -> bool {
+    let nodes = ctx.nodes();
+    match nodes.parent_kind(node.id()) {
+        Some(AstKind::Argument(_)) => is_argument_of_global_method_call(
+            node,
+            2,
+            &[("Object", "defineProperty"), ("Reflect", "defineProperty")],
+            ctx,
+        ),
+        Some(AstKind::ObjectProperty(prop)) if prop.value.span() == node.kind().span() => {
+            let Some(prop_node) = nodes.parent_node(node.id()) else { return false };
+            let Some(descriptors) = nodes.parent_node(prop_node.id()) else { return false };
+            matches!(descriptors.kind(), AstKind::ObjectExpression(_))
+                && is_argument_of_global_method_call(
+                    descriptors,
+                    1,
+                    &[("Object", "create"), ("Object", "defineProperties")],
+                    ctx,
+                )
         }
+        _ => false,
     }
 }
 
+/// Whether `node` is the argument at `index` of a call to one of `methods` of a global object,
+/// e.g. `Object.defineProperty(foo, "bar", node)`.
+fn is_argument_of_global_method_call(
+    node: &AstNode,
+    index: usize,
+    methods: &[(&str, &str)],
+    ctx: &LintContext,
+) -> bool {
+    let Some(argument_node) = ctx.nodes().parent_node(node.id()) else { return false };
+    let Some(AstKind::CallExpression(call_expr)) = ctx.nodes().parent_kind(argument_node.id())
+    else {
+        return false;
+    };
+    let Some(Argument::Expression(argument)) = call_expr.arguments.get(index) else {
+        return false;
+    };
+    if argument.span() != node.kind().span() {
+        return false;
+    }
+
+    let member_expr = match call_expr.callee.get_inner_expression() {
+        Expression::MemberExpression(member_expr) => member_expr,
+        Expression::ChainExpression(chain) => match &chain.expression {
+            ChainElement::MemberExpression(member_expr) => member_expr,
+            ChainElement::CallExpression(_) => return false,
+        },
+        _ => return false,
+    };
+    let Expression::Identifier(object) = member_expr.object().get_inner_expression() else {
+        return false;
+    };
+    let Some(property) = member_expr.static_property_name() else { return false };
+    !matches!(&**member_expr, MemberExpression::PrivateFieldExpression(_))
+        && methods
+            .iter()
+            .any(|(object_name, method)| object.name == *object_name && property == *method)
+        && ctx.semantic().is_reference_to_global_variable(object)
+        && ctx.globals().contains(object.name.as_str())
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -168,13 +285,16 @@ fn test() {
             None,
         ),
         ("object.create(foo, { bar: { set: function(val) { return 1; } } })", None),
-        ("Reflect.defineProperty(foo, 'bar', { set(val) { if (val) { return 1; } } })", None),
+        (
+            "/* globals Reflect:off */ Reflect.defineProperty(foo, 'bar', { set(val) { if (val) { return 1; } } })",
+            None,
+        ),
         (
             "/* globals Object:off */ Object.defineProperty(foo, 'bar', { set(val) { return 1; } })",
             None,
         ),
         (
-            "Object.defineProperties(foo, { bar: { set(val) { try { return 1; } catch(e){} } } })",
+            "/* global Object:off */ Object.defineProperties(foo, { bar: { set(val) { try { return 1; } catch(e){} } } })",
             None,
         ),
         ("let Object; Object.defineProperty(foo, 'bar', { set(val) { return 1; } })", None),
@@ -243,43 +363,43 @@ fn test() {
         ("x = function f(){}; class A { set a(val) { return 1; } };", None),
         ("x = () => {}; A = class { set a(val) { return 1; } };", None),
         ("return; ({ set a(val) { return 1; } }); return 2;", None),
-        // ("Object.defineProperty(foo, 'bar', { set(val) { return 1; } })", None),
-        // ("Reflect.defineProperty(foo, 'bar', { set(val) { return 1; } })", None),
-        // ("Object.defineProperties(foo, { baz: { set(val) { return 1; } } })", None),
-        // ("Object.create(null, { baz: { set(val) { return 1; } } })", None),
-        // ("Object.defineProperty(foo, 'bar', { set: val => val })", None),
-        // ("Reflect.defineProperty(foo, 'bar', { set: val => f(val) })", None),
-        // ("Object.defineProperties(foo, { baz: { set: val => a + b } })", None),
-        // ("Object.create({}, { baz: { set: val => this._val } })", None),
-        // (
-        // "Object.defineProperty(foo, 'bar', { set(val) { if (val) { return; } return false; }, get(val) { return 1; } })",
-        // None,
-        // ),
-        // (
-        // "Reflect.defineProperty(foo, 'bar', { set(val) { try { return f(val) } catch (e) { return e }; } })",
-        // None,
-        // ),
-        // (
-        // "Object.defineProperties(foo, { bar: { get(){ return null; }, set(val) { return null; } } })",
-        // None,
-        // ),
-        // (
-        // "Object.create(null, { baz: { set(val) { return this._val; return; return undefined; } } })",
-        // None,
-        // ),
-        // (
-        // "Object.defineProperties(foo, { baz: { set(val) { return 1; } }, bar: { set(val) { return 1; } } })",
-        // None,
-        // ),
-        // ("Object.create({}, { baz: { set(val) { return 1; } }, bar: { set: (val) => 1 } })", None),
-        // ("Object['defineProperty'](foo, 'bar', { set: function bar(val) { return 1; } })", None),
-        // ("Reflect.defineProperty(foo, 'bar', { 'set'(val) { return 1; } })", None),
-        // ("Object[`defineProperties`](foo, { baz: { ['set'](val) { return 1; } } })", None),
-        // ("Object.create({}, { baz: { [`set`]: (val) => { return 1; } } })", None),
-        // ("Object.defineProperty(foo, 'bar', { set: function Object(val) { return 1; } })", None),
-        // ("Object.defineProperty(foo, 'bar', { set: function(Object) { return 1; } })", None),
-        // ("Object?.defineProperty(foo, 'bar', { set(val) { return 1; } })", None),
-        // ("(Object?.defineProperty)(foo, 'bar', { set(val) { return 1; } })", None),
+        ("Object.defineProperty(foo, 'bar', { set(val) { return 1; } })", None),
+        ("Reflect.defineProperty(foo, 'bar', { set(val) { return 1; } })", None),
+        ("Object.defineProperties(foo, { baz: { set(val) { return 1; } } })", None),
+        ("Object.create(null, { baz: { set(val) { return 1; } } })", None),
+        ("Object.defineProperty(foo, 'bar', { set: val => val })", None),
+        ("Reflect.defineProperty(foo, 'bar', { set: val => f(val) })", None),
+        ("Object.defineProperties(foo, { baz: { set: val => a + b } })", None),
+        ("Object.create({}, { baz: { set: val => this._val } })", None),
+        (
+            "Object.defineProperty(foo, 'bar', { set(val) { if (val) { return; } return false; }, get(val) { return 1; } })",
+            None,
+        ),
+        (
+            "Reflect.defineProperty(foo, 'bar', { set(val) { try { return f(val) } catch (e) { return e }; } })",
+            None,
+        ),
+        (
+            "Object.defineProperties(foo, { bar: { get(){ return null; }, set(val) { return null; } } })",
+            None,
+        ),
+        (
+            "Object.create(null, { baz: { set(val) { return this._val; return; return undefined; } } })",
+            None,
+        ),
+        (
+            "Object.defineProperties(foo, { baz: { set(val) { return 1; } }, bar: { set(val) { return 1; } } })",
+            None,
+        ),
+        ("Object.create({}, { baz: { set(val) { return 1; } }, bar: { set: (val) => 1 } })", None),
+        ("Object['defineProperty'](foo, 'bar', { set: function bar(val) { return 1; } })", None),
+        ("Reflect.defineProperty(foo, 'bar', { 'set'(val) { return 1; } })", None),
+        ("Object[`defineProperties`](foo, { baz: { ['set'](val) { return 1; } } })", None),
+        ("Object.create({}, { baz: { [`set`]: (val) => { return 1; } } })", None),
+        ("Object.defineProperty(foo, 'bar', { set: function Object(val) { return 1; } })", None),
+        ("Object.defineProperty(foo, 'bar', { set: function(Object) { return 1; } })", None),
+        ("Object?.defineProperty(foo, 'bar', { set(val) { return 1; } })", None),
+        ("(Object?.defineProperty)(foo, 'bar', { set(val) { return 1; } })", None),
     ];
 
     Tester::new(NoSetterReturn::NAME, pass, fail).test_and_snapshot();
@@ -0,0 +1,246 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::SymbolId;
+use oxc_span::{Atom, Span};
+
+use crate::{context::LintContext, rule::Rule, utils::get_string_array_option};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-shadow): '{0}' is already declared in the upper scope.")]
+#[diagnostic(severity(warning))]
+struct NoShadowDiagnostic(
+    Atom,
+    #[label("'{0}' is declared here")] pub Span,
+    #[label("'{0}' is shadowed here")] pub Span,
+);
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-shadow): '{0}' is already a global variable.")]
+#[diagnostic(severity(warning))]
+struct NoShadowBuiltinGlobalDiagnostic(Atom, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoShadow {
+    /// Whether to report shadowing of built-in global variables, e.g. `Object`.
+    builtin_globals: bool,
+    hoist: NoShadowHoist,
+    /// Names which are allowed to be shadowed.
+    allow: Vec<String>,
+}
+
+/// Whether to report shadowing before the outer variable is defined.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+enum NoShadowHoist {
+    /// Report shadowing before the outer functions are defined.
+    #[default]
+    Functions,
+    /// Report shadowing before the outer variables and functions are defined.
+    All,
+    /// Never report shadowing before the outer variables or functions are defined.
+    Never,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow variable declarations from shadowing variables declared in the outer scope
+    ///
+    /// ### Why is this bad?
+    /// Shadowing is the process by which a local variable shares the same name as a variable in
+    /// its containing scope. Shadowing makes it impossible to access the outer variable from the
+    /// inner scope, and may be confusing as to which variable is being read or written.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var a = 3;
+    /// function b() {
+    ///     var a = 10;
+    /// }
+    /// ```
+    NoShadow,
+    restriction
+);
+
+impl Rule for NoShadow {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let obj = value.get(0);
+        Self {
+            builtin_globals: obj
+                .and_then(|v| v.get("builtinGlobals"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+            hoist: obj
+                .and_then(|v| v.get("hoist"))
+                .and_then(serde_json::Value::as_str)
+                .map_or_else(NoShadowHoist::default, |value| match value {
+                    "all" => NoShadowHoist::All,
+                    "never" => NoShadowHoist::Never,
+                    _ => NoShadowHoist::Functions,
+                }),
+            allow: obj.map(|v| get_string_array_option(v, "allow")).unwrap_or_default(),
+        }
+    }
+
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
+        let symbols = ctx.symbols();
+        let name = symbols.get_name(symbol_id);
+        if self.allow.iter().any(|allowed| allowed == name.as_str()) {
+            return;
+        }
+
+        let scope_id = symbols.get_scope_id(symbol_id);
+        let shadowed = ctx
+            .scopes()
+            .ancestors(scope_id)
+            .skip(1)
+            .filter_map(|scope_id| ctx.scopes().get_binding(scope_id, name))
+            .find(|shadowed_id| *shadowed_id != symbol_id);
+
+        let Some(shadowed_id) = shadowed else {
+            let is_global_scope =
+                scope_id == ctx.scopes().root_scope_id() && !ctx.source_type().is_module();
+            if self.builtin_globals && !is_global_scope && ctx.globals().contains(name.as_str()) {
+                ctx.diagnostic(NoShadowBuiltinGlobalDiagnostic(
+                    name.clone(),
+                    symbols.get_span(symbol_id),
+                ));
+            }
+            return;
+        };
+
+        if self.is_in_tdz(symbol_id, shadowed_id, ctx)
+            || is_on_initializer(symbol_id, shadowed_id, ctx)
+        {
+            return;
+        }
+
+        ctx.diagnostic(NoShadowDiagnostic(
+            name.clone(),
+            symbols.get_span(shadowed_id),
+            symbols.get_span(symbol_id),
+        ));
+    }
+}
+
+impl NoShadow {
+    /// Whether the shadowing variable is declared before the shadowed variable,
+    /// and the `hoist` option allows that.
+    fn is_in_tdz(&self, symbol_id: SymbolId, shadowed_id: SymbolId, ctx: &LintContext<'_>) -> bool {
+        let symbols = ctx.symbols();
+        let declared_before = symbols.get_span(symbol_id).end < symbols.get_span(shadowed_id).start;
+        match self.hoist {
+            NoShadowHoist::All => false,
+            NoShadowHoist::Never => declared_before,
+            NoShadowHoist::Functions => {
+                declared_before
+                    && !matches!(
+                        ctx.nodes().kind(symbols.get_declaration(shadowed_id)),
+                        AstKind::Function(func) if func.is_declaration()
+                    )
+            }
+        }
+    }
+}
+
+/// Whether the shadowing variable is the name of a function or class expression
+/// which initializes the shadowed variable, e.g. `var a = function a() {}`.
+fn is_on_initializer(symbol_id: SymbolId, shadowed_id: SymbolId, ctx: &LintContext<'_>) -> bool {
+    let symbols = ctx.symbols();
+    let AstKind::VariableDeclarator(declarator) =
+        ctx.nodes().kind(symbols.get_declaration(shadowed_id))
+    else {
+        return false;
+    };
+    let Some(init) = &declarator.init else {
+        return false;
+    };
+    match (init.without_parenthesized(), ctx.nodes().kind(symbols.get_declaration(symbol_id))) {
+        (Expression::FunctionExpression(init), AstKind::Function(func)) => init.span == func.span,
+        (Expression::ClassExpression(init), AstKind::Class(class)) => init.span == class.span,
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            "var a=3; function b(x) { a++; return x + a; }; setTimeout(function() { b(a); }, 0);",
+            None,
+        ),
+        ("(function() { var doSomething = function doSomething() {}; doSomething() }())", None),
+        ("var arguments;\nfunction bar() { }", None),
+        ("var a=3; var b = (x) => { a++; return x + a; }; setTimeout(() => { b(a); }, 0);", None),
+        ("class A {}", None),
+        ("class A { constructor() { var a; } }", None),
+        ("(function() { var A = class A {}; })()", None),
+        ("{ var a; } var a;", None),
+        ("{ let a; } let a;", Some(json!([{ "hoist": "never" }]))),
+        ("{ let a; } var a;", Some(json!([{ "hoist": "never" }]))),
+        ("{ let a; } function a() {}", Some(json!([{ "hoist": "never" }]))),
+        ("{ const a = 0; } const a = 1;", Some(json!([{ "hoist": "never" }]))),
+        ("{ const a = 0; } var a;", Some(json!([{ "hoist": "never" }]))),
+        ("function foo() { let a; } let a;", Some(json!([{ "hoist": "never" }]))),
+        ("function foo() { var a; } let a;", Some(json!([{ "hoist": "never" }]))),
+        ("function foo(a) { } let a;", Some(json!([{ "hoist": "never" }]))),
+        ("{ let a; } let a;", None),
+        ("{ let a; } var a;", None),
+        ("function foo() { let a; } let a;", None),
+        ("function foo(a) { } let a;", None),
+        ("{ let a; } { let a; }", None),
+        ("function foo() { let a; } function bar() { let a; }", None),
+        ("function foo() { var Object = 0; }", None),
+        ("function foo() { var top = 0; }", None),
+        (
+            "function foo(cb) { (function (cb) { cb(42); })(cb); }",
+            Some(json!([{ "allow": ["cb"] }])),
+        ),
+        ("function bar(a) {} function foo() { var a; }", None),
+    ];
+
+    let fail = vec![
+        ("function a(x) { var b = function c() { var x = 'foo'; }; }", None),
+        ("var a = (x) => { var b = () => { var x = 'foo'; }; }", None),
+        ("function a(x) { var b = function () { var x = 'foo'; }; }", None),
+        ("var x = 1; function a(x) { return ++x; }", None),
+        ("var a=3; function b() { var a=10; }", None),
+        ("var a=3; function b() { var a=10; }; setTimeout(function() { b(); }, 0);", None),
+        ("var a=3; function b() { var a=10; var b=0; }; setTimeout(function() { b(); }, 0);", None),
+        ("var x = 1; { let x = 2; }", None),
+        ("let x = 1; { const x = 2; }", None),
+        ("{ let a; } function a() {}", None),
+        ("{ const a = 0; } function a() {}", None),
+        ("function foo() { let a; } function a() {}", None),
+        ("function foo() { var a; } function a() {}", None),
+        ("function foo(a) { } function a() {}", None),
+        ("{ let a; } let a;", Some(json!([{ "hoist": "all" }]))),
+        ("{ let a; } var a;", Some(json!([{ "hoist": "all" }]))),
+        ("function foo() { let a; } let a;", Some(json!([{ "hoist": "all" }]))),
+        ("function foo(a) { } let a;", Some(json!([{ "hoist": "all" }]))),
+        ("class A { constructor() { var A; } }", None),
+        ("function foo() { var Object = 0; }", Some(json!([{ "builtinGlobals": true }]))),
+        ("var Object = 0;", Some(json!([{ "builtinGlobals": true }]))),
+        (
+            "/* global myGlobal */ function foo() { var myGlobal = 0; }",
+            Some(json!([{ "builtinGlobals": true }])),
+        ),
+        (
+            "/* eslint-env browser */ function foo() { var window = 0; }",
+            Some(json!([{ "builtinGlobals": true }])),
+        ),
+        ("function foo(cb) { (function (cb) { cb(42); })(cb); }", None),
+        (
+            "function foo(cb) { (function (cb) { cb(42); })(cb); }",
+            Some(json!([{ "allow": ["a"] }])),
+        ),
+    ];
+
+    Tester::new(NoShadow::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,61 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-ternary): Ternary operator used.")]
+#[diagnostic(severity(warning))]
+struct NoTernaryDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoTernary;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow ternary operators
+    ///
+    /// ### Why is this bad?
+    /// Some teams prefer `if`/`else` statements over ternary operators because
+    /// they consider them harder to read.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var foo = isBar ? baz : qux;
+    /// ```
+    NoTernary,
+    style
+);
+
+impl Rule for NoTernary {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if let AstKind::ConditionalExpression(expr) = node.kind() {
+            ctx.diagnostic(NoTernaryDiagnostic(expr.span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "\"x ? y\";",
+        "if (true) { thing() } else { stuff() };",
+        "var foo; if (isBar) { foo = baz; } else { foo = qux; }",
+    ];
+
+    let fail = vec![
+        "var foo = true ? thing : stuff;",
+        "true ? thing() : stuff();",
+        "function foo(bar) { return bar ? baz : qux; }",
+        "var foo = a ? b ? c : d : e;",
+    ];
+
+    Tester::new_without_config(NoTernary::NAME, pass, fail).test_and_snapshot();
+}
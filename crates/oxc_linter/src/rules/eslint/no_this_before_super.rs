@@ -0,0 +1,128 @@
+use oxc_ast::{
+    ast::{Expression, MethodDefinitionKind},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, utils::SuperCallAnalysis, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-this-before-super): '{0}' is not allowed before 'super()'.")]
+#[diagnostic(
+    severity(warning),
+    help("Call 'super()' before accessing '{0}' in the constructor of a derived class.")
+)]
+struct NoThisBeforeSuperDiagnostic(&'static str, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoThisBeforeSuper;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow `this`/`super` before calling `super()` in constructors.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// In the constructor of a derived class, `this` is not initialized until `super()` is
+    /// called, so using `this` or `super.foo` before it throws a `ReferenceError`.
+    /// A use is reported when `super()` may not have been called on some code path reaching it.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// class A extends B {
+    ///     constructor() {
+    ///         this.a = 0;
+    ///         super();
+    ///     }
+    /// }
+    ///
+    /// // Good
+    /// class A extends B {
+    ///     constructor() {
+    ///         super();
+    ///         this.a = 0;
+    ///     }
+    /// }
+    /// ```
+    NoThisBeforeSuper,
+    correctness
+);
+
+impl Rule for NoThisBeforeSuper {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::MethodDefinition(ctor) = node.kind() else { return };
+        if ctor.kind != MethodDefinitionKind::Constructor || ctor.value.body.is_none() {
+            return;
+        }
+        let Some(AstKind::Class(class)) = ctx.nodes().parent_kind(node.id()) else { return };
+        // `this` is always initialized without a super-class, and it can never be with
+        // `extends null`.
+        match &class.super_class {
+            None | Some(Expression::NullLiteral(_)) => return,
+            Some(_) => {}
+        }
+
+        let analysis = SuperCallAnalysis::new(&ctor.value);
+        let references = analysis
+            .this_expressions
+            .iter()
+            .map(|(span, before)| ("this", span, before))
+            .chain(analysis.super_members.iter().map(|(span, before)| ("super", span, before)));
+        for (keyword, span, before) in references {
+            if before.may_be_missing() {
+                ctx.diagnostic(NoThisBeforeSuperDiagnostic(keyword, *span));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "class A { constructor() { this.b = 0; } }",
+        "class A extends null { constructor() { this.b = 0; } }",
+        "class A extends B { constructor() { super(); this.c = 0; } }",
+        "class A extends B { constructor() { super(); super.c(); } }",
+        "class A extends B { constructor() { super(a ? 0 : 1); this.c = 0; } }",
+        "class A extends B { constructor() { function c() { this.d(); } super(); } }",
+        "class A extends B { constructor() { var c = () => this.d(); super(); c(); } }",
+        "class A extends B { constructor() { class C extends D { constructor() { super(); this.e(); } } super(); } }",
+        "class A extends B { constructor() { if (a) { super(); } else { super(); } this.c(); } }",
+        "class A extends B { constructor() { a ? super() : super(); this.c(); } }",
+        "class A extends B { constructor() { if (a) { super(); this.c(); } } }",
+        "class A extends B { constructor() { if (a) return; super(); this.c(); } }",
+        "class A extends B { constructor() { try { super(); } finally {} this.c(); } }",
+        "class A extends B { constructor() { super(); for (;;) { this.c(); } } }",
+        "class A extends B { constructor() { return; this.c(); } }",
+        "class A extends B { c = this.d; constructor() { super(); } }",
+        "class A { b() { this.c(); } }",
+    ];
+
+    let fail = vec![
+        "class A extends B { constructor() { this.c = 0; super(); } }",
+        "class A extends B { constructor() { this.c(); super(); } }",
+        "class A extends B { constructor() { super.c(); super(); } }",
+        "class A extends B { constructor() { super(this); } }",
+        "class A extends B { constructor() { super(super.c()); } }",
+        "class A extends B { constructor(a = this.c) { super(); } }",
+        "class A extends B { constructor() { if (a) super(); this.c(); } }",
+        "class A extends B { constructor() { a && super(); this.c(); } }",
+        "class A extends B { constructor() { switch (a) { case 0: super(); } this.c(); } }",
+        "class A extends B { constructor() { while (a) { super(); } this.c(); } }",
+        "class A extends B { constructor() { try { super(); } catch (e) { this.c(); } } }",
+        "class A extends B { constructor() { for (;;) { this.c(); super(); } } }",
+        "class A extends B { constructor() { c(this); } }",
+        "class A extends (class B extends C { constructor() { super(); } }) { constructor() { this.c(); super(); } }",
+    ];
+
+    Tester::new_without_config(NoThisBeforeSuper::NAME, pass, fail).test_and_snapshot();
+}
@@ -7,7 +7,7 @@ use oxc_macros::declare_oxc_lint;
 use oxc_span::{Atom, Span};
 use oxc_syntax::operator::UnaryOperator;
 
-use crate::{context::LintContext, globals::BUILTINS, rule::Rule, AstNode};
+use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-undef): Disallow the use of undeclared variables")]
@@ -53,13 +53,14 @@ impl Rule for NoUndef {
         for reference_id_list in ctx.scopes().root_unresolved_references().values() {
             for &reference_id in reference_id_list {
                 let reference = symbol_table.get_reference(reference_id);
-                if BUILTINS.contains_key(reference.name().as_str()) {
-                    return;
+                let name = reference.name().as_str();
+                if ctx.globals().contains(name) {
+                    continue;
                 }
 
                 let node = ctx.nodes().get_node(reference.node_id());
                 if !self.type_of && has_typeof_operator(node, ctx) {
-                    return;
+                    continue;
                 }
 
                 ctx.diagnostic(NoUndefDiagnostic(reference.name().clone(), reference.span()));
@@ -82,16 +83,18 @@ fn test() {
 
     let pass = vec![
         ("var a = 1, b = 2; a;", None),
-        // ("/*global b*/ function f() { b; }", None),
+        ("/*global b*/ function f() { b; }", None),
         // { code: "function f() { b; }", globals: { b: false } },
-        // ("/*global b a:false*/  a;  function f() { b; a; }", None),
+        ("/*global b a:false*/  a;  function f() { b; a; }", None),
         ("function a(){}  a();", None),
         ("function f(b) { b; }", None),
         ("var a; a = 1; a++;", None),
         ("var a; function f() { a = 1; }", None),
-        // ("/*global b:true*/ b++;", None),
-        // ("/*eslint-env browser*/ window;", None),
-        // ("/*eslint-env node*/ require(\"a\");", None),
+        ("/*global b:true*/ b++;", None),
+        ("/*global b: true, c : writable*/ b++; c = 1;", None),
+        ("/*eslint-env browser*/ window;", None),
+        ("/*eslint-env node*/ require(\"a\");", None),
+        ("/* eslint-env browser, jest */ describe(() => document);", None),
         ("Object; isNaN();", None),
         ("toString()", None),
         ("hasOwnProperty()", None),
@@ -121,11 +124,12 @@ fn test() {
         // ("customElements;", None),
         // ("PromiseRejectionEvent;", None),
         ("(foo, bar) => { foo ||= WeakRef; bar ??= FinalizationRegistry; }", None),
-        // ("/*global b:false*/ function f() { b = 1; }", None),
+        ("/*global b:false*/ function f() { b = 1; }", None),
         // { code: "function f() { b = 1; }", globals: { b: false } },
-        // ("/*global b:false*/ function f() { b++; }", None),
-        // ("/*global b*/ b = 1;", None),
-        // ("/*global b:false*/ var b = 1;", None),
+        ("/*global b:false*/ function f() { b++; }", None),
+        ("/*global b*/ b = 1;", None),
+        ("/*global b:false*/ var b = 1;", None),
+        ("/* globals a, b */ a; b;", None),
         ("Array = 1;", None),
         ("class A { constructor() { new.target; } }", None),
         // {
@@ -155,6 +159,12 @@ fn test() {
 
     let fail = vec![
         ("a = 1;", None),
+        ("Object; a; b;", None),
+        ("typeof a; b;", None),
+        ("// global b\n b;", None),
+        ("/*global b*/ a;", None),
+        ("window;", None),
+        ("/*eslint-env node*/ window;", None),
         (
             "if (typeof anUndefinedVar === 'string') {}",
             Some(serde_json::json!([{ "typeof": true }])),
@@ -0,0 +1,152 @@
+use oxc_ast::{
+    ast::{BindingPatternKind, Expression, VariableDeclarationKind},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-undef-init): It's not necessary to initialize '{0}' to undefined.")]
+#[diagnostic(severity(warning), help("Remove the initializer."))]
+struct NoUndefInitDiagnostic(String, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUndefInit;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow initializing variables to `undefined`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Variables declared with `var` and `let` without an initializer are already `undefined`,
+    /// so initializing them to `undefined` is redundant.
+    ///
+    /// The fix is only offered for `let` declarations outside of `for` loop initializers:
+    /// removing the initializer of a `var` changes the behavior when it is declared again
+    /// or inside of a loop.
+    /// `const` declarations are not reported, as they require an initializer.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// var foo = undefined;
+    /// let bar = undefined;
+    ///
+    /// // Good
+    /// var foo;
+    /// let bar;
+    /// const baz = undefined;
+    /// ```
+    NoUndefInit,
+    style
+);
+
+impl Rule for NoUndefInit {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::VariableDeclarator(declarator) = node.kind() else { return };
+        if declarator.kind == VariableDeclarationKind::Const {
+            return;
+        }
+        let Some(Expression::Identifier(ident)) =
+            declarator.init.as_ref().map(Expression::without_parenthesized)
+        else {
+            return;
+        };
+        if ident.name != "undefined" || !ctx.semantic().is_reference_to_global_variable(ident) {
+            return;
+        }
+
+        let source_text = ctx.source_text();
+        let name = declarator.id.span().source_text(source_text).to_string();
+        let diagnostic = NoUndefInitDiagnostic(name, declarator.span);
+
+        // Keep `let x: T` of `let x: T = undefined`.
+        let id_end = declarator
+            .id
+            .type_annotation
+            .as_ref()
+            .map_or_else(|| declarator.id.span().end, |annotation| annotation.span.end);
+        let initializer = Span::new(id_end, declarator.span.end);
+        let initializer_text = initializer.source_text(source_text);
+        // `for (let i = undefined; ...)` is left alone, like ESLint does.
+        let in_for_init = ctx.nodes().parent_node(node.id()).is_some_and(|decl| {
+            matches!(ctx.nodes().parent_kind(decl.id()), Some(AstKind::ForStatementInit(_)))
+        });
+        if declarator.kind == VariableDeclarationKind::Var
+            || in_for_init
+            || !matches!(declarator.id.kind, BindingPatternKind::BindingIdentifier(_))
+            || initializer_text.contains("/*")
+            || initializer_text.contains("//")
+        {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+        ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.remove(initializer));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var a;",
+        "let a;",
+        "const foo = undefined",
+        "var undefined = 5; var foo = undefined;",
+        "function f(undefined) { let a = undefined; }",
+        "let a = void 0;",
+        "let a = null;",
+        "let a = b.undefined;",
+        "class C { field = undefined; }",
+        "for (let i of undefined) {}",
+        "for (var i in undefined) {}",
+    ];
+
+    let fail = vec![
+        "var a = undefined;",
+        "var a = undefined, b = 1;",
+        "var a = 1, b = undefined, c = 5;",
+        "var [a] = undefined;",
+        "var {a} = undefined;",
+        "for (var i = 0; i < 1; i++) { var a = undefined; }",
+        "let a = undefined;",
+        "let a = undefined, b = 1;",
+        "let a = 1, b = undefined, c = 5;",
+        "let [a] = undefined;",
+        "let {a} = undefined;",
+        "let a = (undefined);",
+        "let a: string = undefined;",
+        "for (let i = undefined; i < 1; i++) {}",
+        "for (var i = 0; i < 1; i++) { let a = undefined; }",
+        "let a = /* comment */ undefined;",
+        "let a // comment\n = undefined;",
+    ];
+
+    let fix = vec![
+        ("let a = undefined;", "let a;", None),
+        ("let a = undefined, b = 1;", "let a, b = 1;", None),
+        ("let a = 1, b = undefined, c = 5;", "let a = 1, b, c = 5;", None),
+        ("let a = (undefined);", "let a;", None),
+        ("let a: string = undefined;", "let a: string;", None),
+        ("for (let i = undefined; i < 1; i++) {}", "for (let i = undefined; i < 1; i++) {}", None),
+        ("var a = undefined;", "var a = undefined;", None),
+        (
+            "for (var i = 0; i < 1; i++) { var a = undefined; }",
+            "for (var i = 0; i < 1; i++) { var a = undefined; }",
+            None,
+        ),
+        ("let [a] = undefined;", "let [a] = undefined;", None),
+        ("let a = /* comment */ undefined;", "let a = /* comment */ undefined;", None),
+    ];
+
+    Tester::new_without_config(NoUndefInit::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,110 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-undefined): Unexpected use of undefined.")]
+#[diagnostic(severity(warning), help("Use `void 0` or leave the value out instead."))]
+struct NoUndefinedDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUndefined;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow the use of `undefined` as an identifier.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `undefined` is not a keyword, it can be shadowed by a local variable,
+    /// and in older environments the global could even be reassigned.
+    /// Checking with `typeof` or comparing against `void 0` does not depend on it.
+    /// Declaring a variable named `undefined` is reported as well.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// var foo = undefined;
+    /// if (foo === undefined) {}
+    /// function baz(undefined) {}
+    ///
+    /// // Good
+    /// var foo = void 0;
+    /// if (typeof foo === "undefined") {}
+    /// var bar = obj.undefined;
+    /// ```
+    NoUndefined,
+    restriction
+);
+
+impl Rule for NoUndefined {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (name, span) = match node.kind() {
+            AstKind::IdentifierReference(ident) => (&ident.name, ident.span),
+            AstKind::BindingIdentifier(ident) => (&ident.name, ident.span),
+            _ => return,
+        };
+        if name.as_str() == "undefined" {
+            ctx.diagnostic(NoUndefinedDiagnostic(span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "void 0",
+        "void!0",
+        "void-0",
+        "void+0",
+        "null",
+        "undefine",
+        "a.undefined",
+        "a?.undefined",
+        "a[\"undefined\"]",
+        "({ undefined: 1 })",
+        "class C { undefined() {} }",
+        "class C { undefined = 1 }",
+        "ndefined",
+        "if (typeof x === 'undefined') {}",
+        "foo: while (true) { break foo; }",
+    ];
+
+    let fail = vec![
+        "undefined",
+        "undefined.a",
+        "a[undefined]",
+        "undefined[0]",
+        "f(undefined)",
+        "function f() { return undefined; }",
+        "var x = undefined;",
+        "if (x === undefined) {}",
+        "({ undefined })",
+        "[undefined] = [1]",
+        "undefined = true",
+        "var undefined = true",
+        "let undefined;",
+        "function undefined() {}",
+        "function f(undefined) {}",
+        "(undefined) => {}",
+        "try {} catch (undefined) {}",
+        "var { undefined } = obj;",
+        "var [undefined] = arr;",
+        "class undefined {}",
+        "import undefined from 'foo';",
+        "import { undefined } from 'foo';",
+        "import { a as undefined } from 'foo';",
+        "function f() { var undefined = 1; return undefined; }",
+    ];
+
+    Tester::new_without_config(NoUndefined::NAME, pass, fail).test_and_snapshot();
+}
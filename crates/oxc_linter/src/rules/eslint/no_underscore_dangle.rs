@@ -0,0 +1,187 @@
+use oxc_ast::{
+    ast::{BindingPatternKind, Expression, MemberExpression, PropertyKey},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+
+use crate::{context::LintContext, rule::Rule, utils::get_string_array_option, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-underscore-dangle): Unexpected dangling '_' in '{0}'.")]
+#[diagnostic(severity(warning))]
+struct NoUnderscoreDangleDiagnostic(Atom, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnderscoreDangle {
+    /// Names which are allowed to have dangling underscores.
+    allow: Vec<String>,
+    /// Whether to allow dangling underscores in members of `this`, e.g. `this._foo`.
+    allow_after_this: bool,
+    /// Whether to allow dangling underscores in members of `super`, e.g. `super._foo`.
+    allow_after_super: bool,
+    /// Whether to check method names, e.g. `class C { _foo() {} }`.
+    enforce_in_method_names: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow dangling underscores in identifiers
+    ///
+    /// ### Why is this bad?
+    /// Leading or trailing underscores are a common convention for marking members
+    /// as private, but JavaScript does not enforce it, so the convention can be
+    /// misleading. Private class members (`#foo`) express the intent directly.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// let foo_;
+    /// const __proto__ = {};
+    /// foo._bar();
+    /// ```
+    NoUnderscoreDangle,
+    restriction
+);
+
+impl Rule for NoUnderscoreDangle {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let obj = value.get(0);
+        let get_bool = |key: &str| {
+            obj.and_then(|v| v.get(key)).and_then(serde_json::Value::as_bool).unwrap_or_default()
+        };
+
+        Self {
+            allow: obj.map(|v| get_string_array_option(v, "allow")).unwrap_or_default(),
+            allow_after_this: get_bool("allowAfterThis"),
+            allow_after_super: get_bool("allowAfterSuper"),
+            enforce_in_method_names: get_bool("enforceInMethodNames"),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        match node.kind() {
+            AstKind::VariableDeclarator(decl) => {
+                let BindingPatternKind::BindingIdentifier(ident) = &decl.id.kind else { return };
+                self.check(&ident.name, ident.span, ctx);
+            }
+            AstKind::Function(func) if func.is_declaration() => {
+                if let Some(id) = &func.id {
+                    self.check(&id.name, id.span, ctx);
+                }
+            }
+            AstKind::MemberExpression(MemberExpression::StaticMemberExpression(expr)) => {
+                let name = &expr.property.name;
+                if name.as_str() == "__proto__" {
+                    return;
+                }
+                match &expr.object {
+                    Expression::ThisExpression(_) if self.allow_after_this => return,
+                    Expression::Super(_) if self.allow_after_super => return,
+                    _ => {}
+                }
+                self.check(name, expr.property.span, ctx);
+            }
+            AstKind::MethodDefinition(def) if self.enforce_in_method_names => {
+                self.check_property_key(&def.key, ctx);
+            }
+            AstKind::ObjectProperty(prop) if self.enforce_in_method_names && prop.method => {
+                self.check_property_key(&prop.key, ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl NoUnderscoreDangle {
+    fn check(&self, name: &Atom, span: Span, ctx: &LintContext<'_>) {
+        if has_dangling_underscore(name)
+            && !self.allow.iter().any(|allowed| allowed.as_str() == name.as_str())
+        {
+            ctx.diagnostic(NoUnderscoreDangleDiagnostic(name.clone(), span));
+        }
+    }
+
+    fn check_property_key(&self, key: &PropertyKey, ctx: &LintContext<'_>) {
+        match key {
+            PropertyKey::Identifier(ident) => self.check(&ident.name, ident.span, ctx),
+            PropertyKey::PrivateIdentifier(ident) => self.check(&ident.name, ident.span, ctx),
+            PropertyKey::Expression(_) => {}
+        }
+    }
+}
+
+/// Whether the name starts or ends with an underscore, ignoring `_` itself,
+/// which is commonly used for libraries such as lodash.
+fn has_dangling_underscore(name: &str) -> bool {
+    name != "_" && (name.starts_with('_') || name.ends_with('_'))
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var foo_bar = 1;", None),
+        ("function foo_bar() {}", None),
+        ("foo.bar.__proto__;", None),
+        ("console.log(__filename); console.log(__dirname);", None),
+        ("var _ = require('underscore');", None),
+        ("var a = b._;", None),
+        ("function foo(_bar) {}", None),
+        ("function foo(bar_) {}", None),
+        ("(function _foo() {})", None),
+        ("function foo(_bar = 0) {}", None),
+        ("function foo(..._bar) {}", None),
+        ("const [_foo] = arr;", None),
+        ("const { _foo } = obj;", None),
+        ("export default function() {}", None),
+        ("var _foo = 1", Some(json!([{ "allow": ["_foo"] }]))),
+        ("var __proto__ = 1;", Some(json!([{ "allow": ["__proto__"] }]))),
+        ("foo._bar;", Some(json!([{ "allow": ["_bar"] }]))),
+        ("function _foo() {}", Some(json!([{ "allow": ["_foo"] }]))),
+        ("this._bar;", Some(json!([{ "allowAfterThis": true }]))),
+        ("this._x = 1;", Some(json!([{ "allowAfterThis": true }]))),
+        ("class foo { constructor() { super._bar; } }", Some(json!([{ "allowAfterSuper": true }]))),
+        ("class foo { _onClick() { } }", None),
+        ("class foo { onClick_() { } }", None),
+        ("const o = { _onClick() { } }", None),
+        ("const o = { onClick() { } }", Some(json!([{ "enforceInMethodNames": true }]))),
+        ("class foo { onClick() { } }", Some(json!([{ "enforceInMethodNames": true }]))),
+        ("class foo { _field; }", None),
+        ("const o = { _foo: 1 }", None),
+        ("foo[_bar];", None),
+        ("foo['_bar'];", None),
+        ("var foo = undefined;", None),
+    ];
+
+    let fail = vec![
+        ("var _foo = 1", None),
+        ("var foo_ = 1", None),
+        ("function _foo() {}", None),
+        ("function foo_() {}", None),
+        ("var __proto__ = 1;", None),
+        ("foo._bar;", None),
+        ("foo._bar();", None),
+        ("this._prop;", None),
+        ("this._x = 1;", None),
+        ("this._x = 1;", Some(json!([{ "allowAfterSuper": true }]))),
+        ("class foo { constructor() { super._prop; } }", None),
+        ("class foo { constructor() { super._prop; } }", Some(json!([{ "allowAfterThis": true }]))),
+        ("class foo { _onClick() { } }", Some(json!([{ "enforceInMethodNames": true }]))),
+        ("class foo { onClick_() { } }", Some(json!([{ "enforceInMethodNames": true }]))),
+        ("const o = { _onClick() { } }", Some(json!([{ "enforceInMethodNames": true }]))),
+        ("const o = { onClick_() { } }", Some(json!([{ "enforceInMethodNames": true }]))),
+        ("class foo { #_bar() {} }", Some(json!([{ "enforceInMethodNames": true }]))),
+        ("class foo { get _bar() { return 1; } }", Some(json!([{ "enforceInMethodNames": true }]))),
+        ("var _foo = 1", Some(json!([{ "allow": ["foo_"] }]))),
+        ("foo._bar;", Some(json!([{ "allowAfterThis": true }]))),
+    ];
+
+    Tester::new(NoUnderscoreDangle::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,332 @@
+use oxc_ast::{
+    ast::{Expression, Statement},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_string_array_option, JumpTargets},
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-unreachable-loop): Invalid loop. Its body allows only one iteration.")]
+#[diagnostic(severity(warning), help("Use an `if` statement instead, or make the loop continue."))]
+struct NoUnreachableLoopDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LoopKind {
+    While,
+    DoWhile,
+    For,
+    ForIn,
+    ForOf,
+}
+
+impl LoopKind {
+    fn from_option(option: &str) -> Option<Self> {
+        Some(match option {
+            "WhileStatement" => Self::While,
+            "DoWhileStatement" => Self::DoWhile,
+            "ForStatement" => Self::For,
+            "ForInStatement" => Self::ForIn,
+            "ForOfStatement" => Self::ForOf,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnreachableLoop {
+    ignore: Vec<LoopKind>,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow loops with a body that allows only one iteration.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A loop whose body always exits with `break`, `return` or `throw` on every code path,
+    /// and never `continue`s, runs at most once. This is usually a mistake, such as a `break`
+    /// which was meant to be inside of an `if` statement.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// for (let i = 0; i < arr.length; i++) {
+    ///     if (arr[i].name === myName) {
+    ///         doSomething(arr[i]);
+    ///     }
+    ///     break;
+    /// }
+    ///
+    /// // Good
+    /// for (let i = 0; i < arr.length; i++) {
+    ///     if (arr[i].name === myName) {
+    ///         doSomething(arr[i]);
+    ///         break;
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `ignore` is a list of loop types which are not checked: `"WhileStatement"`,
+    /// `"DoWhileStatement"`, `"ForStatement"`, `"ForInStatement"` and `"ForOfStatement"`.
+    ///
+    /// ```json
+    /// "no-unreachable-loop": ["error", { "ignore": ["ForInStatement", "ForOfStatement"] }]
+    /// ```
+    NoUnreachableLoop,
+    suspicious
+);
+
+impl Rule for NoUnreachableLoop {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let ignore = value
+            .get(0)
+            .map(|config| get_string_array_option(config, "ignore"))
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|option| LoopKind::from_option(option))
+            .collect();
+        Self { ignore }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (kind, body) = match node.kind() {
+            AstKind::WhileStatement(stmt) => (LoopKind::While, &stmt.body),
+            AstKind::DoWhileStatement(stmt) => (LoopKind::DoWhile, &stmt.body),
+            AstKind::ForStatement(stmt) => (LoopKind::For, &stmt.body),
+            AstKind::ForInStatement(stmt) => (LoopKind::ForIn, &stmt.body),
+            AstKind::ForOfStatement(stmt) => (LoopKind::ForOf, &stmt.body),
+            _ => return,
+        };
+        if self.ignore.contains(&kind) {
+            return;
+        }
+
+        let mut labels = vec![];
+        let mut current = node.id();
+        while let Some(AstKind::LabeledStatement(labeled)) = ctx.nodes().parent_kind(current) {
+            labels.push(&labeled.label.name);
+            current = ctx.nodes().parent_node(current).map_or(current, AstNode::id);
+        }
+
+        let mut analyzer =
+            LoopBodyAnalyzer { labels, loops_back: false, targets: JumpTargets::default() };
+        let completes = analyzer.completes(body);
+        if !completes && !analyzer.loops_back {
+            ctx.diagnostic(NoUnreachableLoopDiagnostic(node.kind().span()));
+        }
+    }
+}
+
+/// Finds out whether the body of a loop can reach the next iteration.
+struct LoopBodyAnalyzer<'s> {
+    /// Labels of the loop being checked.
+    labels: Vec<&'s Atom>,
+    /// Whether a reachable `continue` jumps to the loop being checked.
+    loops_back: bool,
+    /// Statements nested in the loop body, with whether a reachable `break` or `continue`
+    /// jumps to each of them.
+    targets: JumpTargets<&'s Atom, bool>,
+}
+
+impl<'s> LoopBodyAnalyzer<'s> {
+    /// Whether `stmt` can complete normally when it is reached.
+    /// Expressions are not analyzed, so any of them may throw without being noticed.
+    fn completes<'a: 's>(&mut self, stmt: &'s Statement<'a>) -> bool {
+        self.completes_labeled(stmt, vec![])
+    }
+
+    fn completes_all<'a: 's>(&mut self, stmts: &'s [Statement<'a>]) -> bool {
+        // Statements after one which can't complete are unreachable.
+        stmts.iter().all(|stmt| self.completes(stmt))
+    }
+
+    fn completes_labeled<'a: 's>(
+        &mut self,
+        stmt: &'s Statement<'a>,
+        labels: Vec<&'s Atom>,
+    ) -> bool {
+        match stmt {
+            Statement::LabeledStatement(stmt) => {
+                let mut labels = labels;
+                labels.push(&stmt.label.name);
+                self.completes_labeled(&stmt.body, labels)
+            }
+            Statement::WhileStatement(stmt) => self.completes_loop(labels, |this| {
+                this.completes(&stmt.body);
+                !is_true_literal(&stmt.test)
+            }),
+            Statement::DoWhileStatement(stmt) => self.completes_loop(labels, |this| {
+                let body = this.completes(&stmt.body);
+                let is_continued =
+                    this.targets.innermost_mut().is_some_and(|target| target.continues);
+                (body || is_continued) && !is_true_literal(&stmt.test)
+            }),
+            Statement::ForStatement(stmt) => self.completes_loop(labels, |this| {
+                this.completes(&stmt.body);
+                stmt.test.as_ref().is_some_and(|test| !is_true_literal(test))
+            }),
+            Statement::ForInStatement(stmt) => self.completes_loop(labels, |this| {
+                this.completes(&stmt.body);
+                true
+            }),
+            Statement::ForOfStatement(stmt) => self.completes_loop(labels, |this| {
+                this.completes(&stmt.body);
+                true
+            }),
+            Statement::SwitchStatement(stmt) => {
+                self.completes_target(labels, false, true, |this| {
+                    // Each case falls through to the next one.
+                    let mut completes = true;
+                    for case in &stmt.cases {
+                        completes = this.completes_all(&case.consequent);
+                    }
+                    completes || stmt.cases.iter().all(|case| case.test.is_some())
+                })
+            }
+            _ if !labels.is_empty() => {
+                self.completes_target(labels, false, false, |this| this.completes(stmt))
+            }
+            Statement::BlockStatement(block) => self.completes_all(&block.body),
+            Statement::IfStatement(stmt) => {
+                let consequent = self.completes(&stmt.consequent);
+                let alternate = stmt.alternate.as_ref().map_or(true, |alt| self.completes(alt));
+                consequent || alternate
+            }
+            Statement::ReturnStatement(_) | Statement::ThrowStatement(_) => false,
+            Statement::BreakStatement(stmt) => {
+                let label = stmt.label.as_ref().map(|label| &label.name);
+                if let Some(target) = self.targets.find(label, false) {
+                    target.breaks = true;
+                }
+                false
+            }
+            Statement::ContinueStatement(stmt) => {
+                let label = stmt.label.as_ref().map(|label| &label.name);
+                // Without a nested target, it jumps to the loop being checked or out of it.
+                if let Some(target) = self.targets.find(label, true) {
+                    target.continues = true;
+                } else if label.map_or(true, |label| self.labels.contains(&label)) {
+                    self.loops_back = true;
+                }
+                false
+            }
+            Statement::TryStatement(stmt) => {
+                // Any statement of the `try` block may throw, so the `catch` block is reachable.
+                let block = self.completes_all(&stmt.block.body);
+                let handler = stmt
+                    .handler
+                    .as_ref()
+                    .is_some_and(|handler| self.completes_all(&handler.body.body));
+                let finalizer = stmt
+                    .finalizer
+                    .as_ref()
+                    .map_or(true, |finalizer| self.completes_all(&finalizer.body));
+                (block || handler) && finalizer
+            }
+            Statement::WithStatement(stmt) => self.completes(&stmt.body),
+            _ => true,
+        }
+    }
+
+    fn completes_loop(
+        &mut self,
+        labels: Vec<&'s Atom>,
+        visit: impl FnOnce(&mut Self) -> bool,
+    ) -> bool {
+        self.completes_target(labels, true, true, visit)
+    }
+
+    /// Visits a statement which `break` can jump to with `visit`, which returns whether the
+    /// statement can complete normally other than by `break`.
+    fn completes_target(
+        &mut self,
+        labels: Vec<&'s Atom>,
+        is_loop: bool,
+        is_breakable: bool,
+        visit: impl FnOnce(&mut Self) -> bool,
+    ) -> bool {
+        self.targets.push(labels, is_loop, is_breakable);
+        let completes = visit(self);
+        completes || self.targets.pop().breaks
+    }
+}
+
+fn is_true_literal(expr: &Expression) -> bool {
+    matches!(expr.without_parenthesized(), Expression::BooleanLiteral(lit) if lit.value)
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let ignore = |kinds: &[&str]| Some(json!([{ "ignore": kinds }]));
+
+    let pass = vec![
+        ("while (foo) { bar(); }", None),
+        ("while (foo) { if (bar) { break; } }", None),
+        ("while (foo) { if (bar) { continue; } return; }", None),
+        ("while (foo) { if (bar) { return; } baz(); }", None),
+        ("do { foo(); } while (bar);", None),
+        ("for (let i = 0; i < 10; i++) { if (foo) { break; } }", None),
+        ("for (const a of b) { if (a) { return a; } }", None),
+        ("for (const a in b) { try { foo(a); break; } catch (e) {} }", None),
+        ("for (;;) { switch (foo) { case 1: break; default: return; } }", None),
+        ("for (;;) { switch (foo) { case 1: return; } }", None),
+        ("for (;;) { label: { if (foo) break label; return; } }", None),
+        ("for (;;) { for (;;) { if (foo) break; } }", None),
+        ("for (;;) { try { foo(); } finally { bar(); } }", None),
+        ("for (;;) { do { continue; } while (foo); }", None),
+        ("for (;;) { label: if (foo) { break label; } else { return; } }", None),
+        ("outer: for (;;) { for (;;) { if (foo) continue outer; } }", None),
+        ("a: b: for (;;) { for (;;) { if (foo) continue a; } }", None),
+        ("for (;;) { function foo() { return; } }", None),
+        ("for (;;) { const foo = () => { return; }; }", None),
+        ("for (const a of b) return a;", ignore(&["ForOfStatement"])),
+        ("for (const a in b) break;", ignore(&["ForInStatement"])),
+        ("while (foo) break;", ignore(&["WhileStatement"])),
+        ("do { break; } while (foo);", ignore(&["DoWhileStatement"])),
+        ("for (;;) break;", ignore(&["ForStatement"])),
+    ];
+
+    let fail = vec![
+        ("while (foo) { break; }", None),
+        ("while (foo) { bar(); break; }", None),
+        ("function f() { while (foo) { return; } }", None),
+        ("function f() { while (foo) { bar(); return bar; } }", None),
+        ("while (foo) { throw new Error(); }", None),
+        ("do { break; } while (foo);", None),
+        ("for (let i = 0; i < 10; i++) { if (foo) { doSomething(); } break; }", None),
+        ("for (;;) { if (foo) { break; } else { break; } }", None),
+        ("function f() { for (const a of b) { return a; } }", None),
+        ("for (const a in b) break;", None),
+        ("for (;;) { switch (foo) { case 1: bar(); default: break; } break; }", None),
+        ("for (;;) { try { break; } catch (e) { break; } }", None),
+        ("for (;;) { try { foo(); } finally { break; } }", None),
+        ("for (;;) { while (true) { foo(); } }", None),
+        ("for (;;) { for (;;) { continue; } }", None),
+        ("for (;;) { while (foo) { break; } }", None),
+        ("outer: for (;;) { inner: for (;;) { break outer; } }", None),
+        ("for (;;) { label: { break label; } break; }", None),
+        ("for (;;) { do { continue; } while (foo); break; }", None),
+        ("for (;;) { do { continue; } while (true); }", None),
+        ("for (const a of b) return a;", ignore(&["ForInStatement"])),
+    ];
+
+    Tester::new(NoUnreachableLoop::NAME, pass, fail).test_and_snapshot();
+}
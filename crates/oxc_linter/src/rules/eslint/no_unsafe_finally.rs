@@ -164,6 +164,10 @@ fn test() {
             "var foo = function() { try { return 1; } finally { class bar { constructor() {} static ehm() { return 'Hola!'; } } } };",
             None,
         ),
+        ("var foo = function() { try {} finally { a: while (true) { break a; } } }", None),
+        ("var foo = function() { try {} finally { a: for (;;) { continue a; } } }", None),
+        ("var foo = function() { try {} finally { a: { break a; } } }", None),
+        ("var foo = function() { try {} finally { for (const x of y) { if (x) break; } } }", None),
     ];
 
     let fail = vec![
@@ -218,6 +222,8 @@ fn test() {
             "var foo = function() { a: switch (true) { case true: try {} finally { switch (true) { case true: break a; } } } }",
             None,
         ),
+        ("var foo = function() { for (;;) { try {} finally { if (x) continue; } } }", None),
+        ("var foo = function() { a: for (;;) { try {} finally { for (;;) { break a; } } } }", None),
     ];
 
     Tester::new(NoUnsafeFinally::NAME, pass, fail).test_and_snapshot();
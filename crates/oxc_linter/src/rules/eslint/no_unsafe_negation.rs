@@ -86,7 +86,7 @@ impl NoUnsafeNegation {
         // Diagnostic points at the unexpected negation
         let diagnostic = NoUnsafeNegationDiagnostic(expr.operator.as_str(), expr.left.span());
 
-        let fix_producer = || {
+        let fix_producer = |_| {
             // modify `!a instance of B` to `!(a instanceof B)`
             let modified_code = {
                 let mut formatter = ctx.formatter();
@@ -1,5 +1,5 @@
 use oxc_ast::{
-    ast::{Argument, AssignmentTarget, Expression},
+    ast::{Argument, ArrayExpressionElement, AssignmentTarget, Expression},
     AstKind,
 };
 use oxc_diagnostics::{
@@ -114,7 +114,8 @@ impl Rule for NoUnsafeOptionalChaining {
             AstKind::AssignmentPattern(pat) if pat.left.kind.is_destructuring_pattern() => {
                 Self::check_unsafe_usage(&pat.right, ctx);
             }
-            AstKind::Argument(Argument::SpreadElement(elem)) => {
+            AstKind::Argument(Argument::SpreadElement(elem))
+            | AstKind::ArrayExpressionElement(ArrayExpressionElement::SpreadElement(elem)) => {
                 Self::check_unsafe_usage(&elem.argument, ctx);
             }
             AstKind::VariableDeclarator(decl) if decl.id.kind.is_destructuring_pattern() => {
@@ -176,16 +177,14 @@ impl NoUnsafeOptionalChaining {
                     Self::check_undefined_short_circuit(expr, error_type, ctx);
                 }
             }
-            Expression::ChainExpression(expr) => {
-                match error_type {
-                    ErrorType::Usage => {
-                        ctx.diagnostic(NoUnsafeOptionalChainingDiagnostic(expr.span));
-                    }
-                    ErrorType::Arithmetic => {
-                        ctx.diagnostic(NoUnsafeArithmeticDiagnostic(expr.span));
-                    }
-                };
-            }
+            Expression::ChainExpression(expr) => match error_type {
+                ErrorType::Usage => {
+                    ctx.diagnostic(NoUnsafeOptionalChainingDiagnostic(expr.span));
+                }
+                ErrorType::Arithmetic => {
+                    ctx.diagnostic(NoUnsafeArithmeticDiagnostic(expr.span));
+                }
+            },
             _ => {}
         }
     }
@@ -272,6 +271,44 @@ fn test() {
         ("with (obj?.foo) {};", None),
         ("async function foo() { with ( await obj?.foo) {}; }", None),
         ("(foo ? obj?.foo : obj?.bar).bar", None),
+        ("(obj?.foo)();", None),
+        ("(obj.foo ?? bar?.baz)();", None),
+        ("(obj.foo || bar?.baz)();", None),
+        ("(obj?.foo && bar)();", None),
+        ("(bar && obj?.foo)();", None),
+        ("(obj?.foo?.())();", None),
+        ("(obj?.foo).bar;", None),
+        ("(obj?.foo)[1];", None),
+        ("(obj?.foo)`template`", None),
+        ("new (obj?.foo)();", None),
+        ("new (obj?.foo?.())()", None),
+        ("new (obj?.foo?.() || obj?.bar)()", None),
+        ("async function foo() { (await obj?.foo)(); }", None),
+        ("async function foo() { (await obj?.foo).bar; }", None),
+        ("async function foo() { new (await obj?.foo)(); }", None),
+        ("(foo, obj?.foo)();", None),
+        ("(foo ? obj?.foo : bar)();", None),
+        ("[...obj?.foo];", None),
+        ("bar(...obj?.foo);", None),
+        ("new Bar(...obj?.foo);", None),
+        ("const {foo} = obj?.bar;", None),
+        ("const [foo] = obj?.bar;", None),
+        ("({ foo } = obj?.bar);", None),
+        ("[foo] = obj?.bar;", None),
+        ("[{ foo } = obj?.bar] = [];", None),
+        ("function foo({ a } = obj?.b) {}", None),
+        ("1 in obj?.foo;", None),
+        ("bar instanceof obj?.foo;", None),
+        ("for (bar of obj?.foo);", None),
+        ("class A extends obj?.foo {}", None),
+        ("var a = class A extends obj?.foo {}", None),
+        ("obj?.foo + bar;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("+obj?.foo;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        ("bar += obj?.foo;", Some(serde_json::json!([{ "disallowArithmeticOperators": true }]))),
+        (
+            "async function foo() { await obj?.foo * bar; }",
+            Some(serde_json::json!([{ "disallowArithmeticOperators": true }])),
+        ),
     ];
 
     Tester::new(NoUnsafeOptionalChaining::NAME, pass, fail).test_and_snapshot();
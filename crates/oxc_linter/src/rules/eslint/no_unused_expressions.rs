@@ -0,0 +1,198 @@
+use oxc_ast::{
+    ast::{ChainElement, Expression},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use oxc_syntax::operator::UnaryOperator;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-unused-expressions): Expected an assignment or function call and instead saw an expression.")]
+#[diagnostic(severity(warning), help("Remove the expression or use its value."))]
+struct NoUnusedExpressionsDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnusedExpressions {
+    /// Whether to allow short circuit evaluations, e.g. `a && b()`.
+    allow_short_circuit: bool,
+    /// Whether to allow ternary operators, e.g. `a ? b() : c()`.
+    allow_ternary: bool,
+    /// Whether to allow tagged template literals, e.g. `` tag`foo` ``.
+    allow_tagged_templates: bool,
+    /// Whether to report JSX elements, which have no side effects.
+    enforce_for_jsx: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow unused expressions
+    ///
+    /// ### Why is this bad?
+    /// An expression whose value is not used and that has no side effects does
+    /// nothing, which usually indicates a logic error.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// a + b;
+    /// x.y;
+    /// foo && bar;
+    /// ```
+    NoUnusedExpressions,
+    restriction
+);
+
+impl Rule for NoUnusedExpressions {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let obj = value.get(0);
+        let get = |key: &str| {
+            obj.and_then(|v| v.get(key)).and_then(serde_json::Value::as_bool).unwrap_or_default()
+        };
+        Self {
+            allow_short_circuit: get("allowShortCircuit"),
+            allow_ternary: get("allowTernary"),
+            allow_tagged_templates: get("allowTaggedTemplates"),
+            enforce_for_jsx: get("enforceForJSX"),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ExpressionStatement(stmt) = node.kind() else { return };
+        if !self.is_valid_expression(&stmt.expression) {
+            ctx.diagnostic(NoUnusedExpressionsDiagnostic(stmt.span));
+        }
+    }
+}
+
+impl NoUnusedExpressions {
+    /// Whether the expression may have side effects when evaluated as a statement.
+    fn is_valid_expression(&self, expr: &Expression) -> bool {
+        match expr.get_inner_expression() {
+            Expression::AssignmentExpression(_)
+            | Expression::AwaitExpression(_)
+            | Expression::CallExpression(_)
+            | Expression::ImportExpression(_)
+            | Expression::NewExpression(_)
+            | Expression::UpdateExpression(_)
+            | Expression::YieldExpression(_) => true,
+            Expression::UnaryExpression(expr) => {
+                matches!(expr.operator, UnaryOperator::Delete | UnaryOperator::Void)
+            }
+            Expression::ChainExpression(expr) => {
+                matches!(expr.expression, ChainElement::CallExpression(_))
+            }
+            Expression::ConditionalExpression(expr) => {
+                self.allow_ternary
+                    && self.is_valid_expression(&expr.consequent)
+                    && self.is_valid_expression(&expr.alternate)
+            }
+            Expression::LogicalExpression(expr) => {
+                self.allow_short_circuit && self.is_valid_expression(&expr.right)
+            }
+            Expression::TaggedTemplateExpression(_) => self.allow_tagged_templates,
+            Expression::JSXElement(_) | Expression::JSXFragment(_) => !self.enforce_for_jsx,
+            _ => false,
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("function f(){}", None),
+        ("a = b", None),
+        ("new a", None),
+        ("{}", None),
+        ("f(); g()", None),
+        ("foo();", None),
+        ("i++", None),
+        ("a()", None),
+        ("a && a()", Some(json!([{ "allowShortCircuit": true }]))),
+        ("a() || (b = c)", Some(json!([{ "allowShortCircuit": true }]))),
+        ("a ? b() : c()", Some(json!([{ "allowTernary": true }]))),
+        (
+            "a ? b() || (c = d) : e()",
+            Some(json!([{ "allowShortCircuit": true, "allowTernary": true }])),
+        ),
+        ("delete foo.bar", None),
+        ("void new C", None),
+        ("\"use strict\";", None),
+        ("\"directive one\"; \"directive two\"; f();", None),
+        ("function foo() {\"use strict\"; return true; }", None),
+        ("var foo = () => {\"use strict\"; return true; }", None),
+        ("function foo() {\"directive one\"; \"directive two\"; f(); }", None),
+        ("function foo() { var foo = \"use strict\"; return true; }", None),
+        ("function* foo(){ yield 0; }", None),
+        ("async function foo() { await 5; }", None),
+        ("async function foo() { await foo.bar; }", None),
+        (
+            "async function foo() { bar && await baz; }",
+            Some(json!([{ "allowShortCircuit": true }])),
+        ),
+        (
+            "async function foo() { foo ? await bar : await baz; }",
+            Some(json!([{ "allowTernary": true }])),
+        ),
+        ("tag`tagged template literal`", Some(json!([{ "allowTaggedTemplates": true }]))),
+        (
+            "shouldNotBeAffectedByAllowTemplateTagsOption()",
+            Some(json!([{ "allowTaggedTemplates": true }])),
+        ),
+        ("import(\"foo\")", None),
+        ("func?.(\"foo\")", None),
+        ("obj?.foo(\"bar\")", None),
+        ("<div />", None),
+        ("<></>", None),
+        ("var partial = <div />", None),
+        ("var partial = <div />", Some(json!([{ "enforceForJSX": true }]))),
+        ("foo!();", None),
+    ];
+
+    let fail = vec![
+        ("0", None),
+        ("a", None),
+        ("f(), 0", None),
+        ("{0}", None),
+        ("[]", None),
+        ("a && b();", None),
+        ("a() || false", None),
+        ("a || (b = c)", None),
+        ("a ? b() || (c = d) : e", None),
+        ("`untagged template literal`", None),
+        ("tag`tagged template literal`", None),
+        ("a && b()", Some(json!([{ "allowTernary": true }]))),
+        ("a ? b() : c()", Some(json!([{ "allowShortCircuit": true }]))),
+        ("a || b", Some(json!([{ "allowShortCircuit": true }]))),
+        ("a() && b", Some(json!([{ "allowShortCircuit": true }]))),
+        ("a ? b : 0", Some(json!([{ "allowTernary": true }]))),
+        ("a ? b : c()", Some(json!([{ "allowTernary": true }]))),
+        ("foo.bar;", None),
+        ("!a", None),
+        ("+a", None),
+        ("a + b;", None),
+        ("x.y;", None),
+        ("foo && bar;", None),
+        ("\"directive one\"; f(); \"directive two\";", None),
+        ("function foo() {\"directive one\"; f(); \"directive two\"; }", None),
+        ("if (0) { \"not a directive\"; f(); }", None),
+        ("function foo() { var foo = true; \"use strict\"; }", None),
+        ("`untagged template literal`", Some(json!([{ "allowTaggedTemplates": true }]))),
+        ("tag`tagged template literal`", Some(json!([{ "allowTaggedTemplates": false }]))),
+        ("obj?.foo", None),
+        ("obj?.foo.bar", None),
+        ("<div />", Some(json!([{ "enforceForJSX": true }]))),
+        ("<></>", Some(json!([{ "enforceForJSX": true }]))),
+        ("foo as any;", None),
+    ];
+
+    Tester::new(NoUnusedExpressions::NAME, pass, fail).test_and_snapshot();
+}
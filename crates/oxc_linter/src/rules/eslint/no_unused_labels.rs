@@ -6,7 +6,7 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::{Atom, Span};
 
-use crate::{context::LintContext, fixer::Fix, rule::Rule};
+use crate::{context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint(no-unused-labels): Disallow unused labels")]
@@ -49,7 +49,7 @@ impl Rule for NoUnusedLabels {
                 // e.g. A: /* Comment */ function foo(){}
                 ctx.diagnostic_with_fix(
                     NoUnusedLabelsDiagnostic(stmt.label.name.clone(), stmt.label.span),
-                    || Fix::delete(stmt.label.span),
+                    |fixer| fixer.remove(stmt.label.span),
                 );
             }
         }
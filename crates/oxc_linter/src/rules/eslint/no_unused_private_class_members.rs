@@ -0,0 +1,194 @@
+use oxc_ast::{
+    ast::{Class, ClassElement},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::AstNodeId;
+use oxc_span::{Atom, GetSpan, Span};
+use oxc_syntax::operator::AssignmentOperator;
+use rustc_hash::FxHashMap;
+
+use crate::{context::LintContext, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-unused-private-class-members): '#{0}' is defined but never used.")]
+#[diagnostic(severity(warning), help("Remove the unused private member."))]
+struct NoUnusedPrivateClassMembersDiagnostic(Atom, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnusedPrivateClassMembers;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unused private class members.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Private class members can only be accessed from within the class body, so one which is
+    /// never used is dead code, which is usually a leftover of a refactoring or a typo.
+    /// Private fields which are only ever written to are reported as well.
+    /// Private methods and accessors are considered used by any reference, as calling a setter
+    /// can have side effects.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// class A {
+    ///     #unused = 1;
+    ///     #writeOnly;
+    ///     #unusedMethod() {}
+    ///     foo() {
+    ///         this.#writeOnly = 1;
+    ///     }
+    /// }
+    ///
+    /// // Good
+    /// class A {
+    ///     #used = 1;
+    ///     #usedMethod() {
+    ///         return this.#used;
+    ///     }
+    ///     foo() {
+    ///         this.#usedMethod();
+    ///     }
+    /// }
+    /// ```
+    NoUnusedPrivateClassMembers,
+    correctness
+);
+
+impl Rule for NoUnusedPrivateClassMembers {
+    fn run_once(&self, ctx: &LintContext) {
+        // Whether each private name of each class is read, if it is referenced at all.
+        let mut references: FxHashMap<(AstNodeId, Atom), bool> = FxHashMap::default();
+        for node in ctx.nodes().iter() {
+            let AstKind::PrivateIdentifier(ident) = node.kind() else { continue };
+            let Some(parent) = ctx.nodes().parent_node(node.id()) else { continue };
+            let is_read = match parent.kind() {
+                AstKind::MemberExpression(_) => is_read(parent.id(), ctx),
+                // The key of the member itself.
+                AstKind::PropertyKey(_) => continue,
+                // `#a in obj`
+                _ => true,
+            };
+            // The reference resolves to the innermost class which declares the name.
+            let Some(class_node) = ctx.nodes().iter_parents(node.id()).find(|node| {
+                matches!(node.kind(), AstKind::Class(class) if private_members(class).any(|(name, ..)| name == ident.name))
+            }) else {
+                continue;
+            };
+            *references.entry((class_node.id(), ident.name.clone())).or_default() |= is_read;
+        }
+
+        for node in ctx.nodes().iter() {
+            let AstKind::Class(class) = node.kind() else { continue };
+            let mut reported: Vec<Atom> = vec![];
+            for (name, span, is_field) in private_members(class) {
+                let is_used = references
+                    .get(&(node.id(), name.clone()))
+                    .is_some_and(|&is_read| is_read || !is_field);
+                // A getter and a setter share their name.
+                if is_used || reported.contains(&name) {
+                    continue;
+                }
+                reported.push(name.clone());
+                ctx.diagnostic(NoUnusedPrivateClassMembersDiagnostic(name, span));
+            }
+        }
+    }
+}
+
+/// The name, span and whether it is a field of each private member of `class`.
+fn private_members<'a>(class: &'a Class<'a>) -> impl Iterator<Item = (Atom, Span, bool)> + 'a {
+    class.body.body.iter().filter_map(|element| {
+        let is_field = matches!(element, ClassElement::PropertyDefinition(_));
+        let key = element.property_key()?;
+        key.private_name().map(|name| (name, key.span(), is_field))
+    })
+}
+
+/// Whether the value of the private member expression `member_id` is used, rather than only
+/// being assigned to.
+fn is_read(member_id: AstNodeId, ctx: &LintContext) -> bool {
+    let nodes = ctx.nodes();
+    let Some(AstKind::SimpleAssignmentTarget(_)) = nodes.parent_kind(member_id) else {
+        return true;
+    };
+    let Some(target) = nodes.parent_node(member_id).and_then(|node| nodes.parent_node(node.id()))
+    else {
+        return true;
+    };
+    let is_statement =
+        |node_id| matches!(nodes.parent_kind(node_id), Some(AstKind::ExpressionStatement(_)));
+    match target.kind() {
+        // `this.#a++` is only a read if its value is used.
+        AstKind::UpdateExpression(_) => !is_statement(target.id()),
+        // Same for `this.#a += 1`, while `this.#a = 1` is never a read.
+        // Destructuring assignments and `for (this.#a of b)` are not either.
+        AstKind::AssignmentTarget(_) => nodes.parent_node(target.id()).is_some_and(|parent| {
+            matches!(parent.kind(), AstKind::AssignmentExpression(assignment)
+                if assignment.operator != AssignmentOperator::Assign && !is_statement(parent.id()))
+        }),
+        _ => true,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "class Foo {}",
+        "class Foo { publicMember = 42; }",
+        "class Foo { #usedMember = 42; method() { return this.#usedMember; } }",
+        "class Foo { #usedMember = 42; anotherMember = this.#usedMember; }",
+        "class Foo { #usedMember = 42; foo() { anotherMember = this.#usedMember; } }",
+        "class C { #usedMember; foo() { bar(this.#usedMember += 1); } }",
+        "class Foo { #usedMember = 42; method() { return someGlobalMethod(this.#usedMember); } }",
+        "class C { #usedInOuterClass; foo() { return class {}; } bar() { return this.#usedInOuterClass; } }",
+        "class Foo { #usedInForInLoop; method() { for (const bar in this.#usedInForInLoop) {} } }",
+        "class Foo { #usedInForOfLoop; method() { for (const bar of this.#usedInForOfLoop) {} } }",
+        "class Foo { #usedInAssignmentPattern; method() { [bar = 1] = this.#usedInAssignmentPattern; } }",
+        "class Foo { #usedInArrayPattern; method() { [bar] = this.#usedInArrayPattern; } }",
+        "class Foo { #usedInAssignmentPattern; method() { [bar] = this.#usedInAssignmentPattern; } }",
+        "class C { #usedInObjectAssignment; method() { ({ [this.#usedInObjectAssignment]: a } = foo); } }",
+        "class C { set #accessorWithSetterFirst(value) { doSomething(value); } get #accessorWithSetterFirst() { return something(); } method() { this.#accessorWithSetterFirst += 1; } }",
+        "class Foo { set #accessorUsedInMemberAccess(value) {} method(a) { [this.#accessorUsedInMemberAccess] = a; } }",
+        "class C { get #accessorWithGetterFirst() { return something(); } set #accessorWithGetterFirst(value) { doSomething(value); } method() { this.#accessorWithGetterFirst += 1; } }",
+        "class C { #usedInInnerClass; method(a) { return class { foo = a.#usedInInnerClass; } } }",
+        "class Foo { #usedMethod() { return 42; } anotherMethod() { return this.#usedMethod(); } }",
+        "class C { set #x(value) { doSomething(value); } foo() { this.#x = 1; } }",
+        "class C { #a; static isC(obj) { return #a in obj; } }",
+        "class C { #a = 1; foo() { return this.#a++; } }",
+        "class C { accessor #a; foo() { this.#a = 1; } }",
+    ];
+
+    let fail = vec![
+        "class Foo { #unusedMember = 5; }",
+        "class First {} class Second { #unusedMemberInSecondClass = 5; }",
+        "class First { #unusedMemberInFirstClass = 5; } class Second {}",
+        "class First { #firstUnusedMemberInSameClass = 5; #secondUnusedMemberInSameClass = 5; }",
+        "class Foo { #usedOnlyInWrite = 5; method() { this.#usedOnlyInWrite = 42; } }",
+        "class Foo { #usedOnlyInWriteStatement = 5; method() { this.#usedOnlyInWriteStatement += 42; } }",
+        "class C { #usedOnlyInIncrement; foo() { this.#usedOnlyInIncrement++; } }",
+        "class C { #unusedInOuterClass; foo() { return class { #unusedInOuterClass; bar() { return this.#unusedInOuterClass; } }; } }",
+        "class C { #unusedOnlyInSecondNestedClass; foo() { return class { #unusedOnlyInSecondNestedClass; bar() { return this.#unusedOnlyInSecondNestedClass; } }; } baz() { return this.#unusedOnlyInSecondNestedClass; } bar() { return class { #unusedOnlyInSecondNestedClass; } } }",
+        "class Foo { #unusedMethod() {} }",
+        "class Foo { #unusedMethod() {} #usedMethod() { return 42; } publicMethod() { return this.#usedMethod(); } }",
+        "class Foo { set #unusedSetter(value) {} }",
+        "class C { get #unusedAccessor() {} set #unusedAccessor(value) {} }",
+        "class Foo { #usedOnlyInForOfLoop; method() { for (this.#usedOnlyInForOfLoop of bar) {} } }",
+        "class Foo { #usedOnlyInForInLoop; method() { for (this.#usedOnlyInForInLoop in bar) {} } }",
+        "class C { #usedOnlyInDestructuring; method() { [this.#usedOnlyInDestructuring] = foo; } }",
+        "class C { #usedOnlyInDestructuring; method() { ({ a: this.#usedOnlyInDestructuring } = foo); } }",
+        "class C { #usedOnlyInDefault; method() { [this.#usedOnlyInDefault = 1] = foo; } }",
+        "class C { static #unusedStatic = 1; }",
+    ];
+
+    Tester::new_without_config(NoUnusedPrivateClassMembers::NAME, pass, fail).test_and_snapshot();
+}
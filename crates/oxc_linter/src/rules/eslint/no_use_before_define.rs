@@ -0,0 +1,207 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::{Reference, ScopeId, SymbolId};
+use oxc_span::{Atom, GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-use-before-define): '{0}' was used before it was defined.")]
+#[diagnostic(severity(warning))]
+struct NoUseBeforeDefineDiagnostic(
+    Atom,
+    #[label("'{0}' is used here")] pub Span,
+    #[label("'{0}' is defined here")] pub Span,
+);
+
+#[derive(Debug, Clone)]
+pub struct NoUseBeforeDefine {
+    /// Whether to check references to function declarations.
+    functions: bool,
+    /// Whether to check references to class declarations in upper function scopes.
+    classes: bool,
+    /// Whether to check references to variables in upper function scopes.
+    variables: bool,
+}
+
+impl Default for NoUseBeforeDefine {
+    fn default() -> Self {
+        Self { functions: true, classes: true, variables: true }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow the use of variables before they are defined
+    ///
+    /// ### Why is this bad?
+    /// `var` declarations are hoisted to the top of their scope, so using them before the
+    /// declaration reads `undefined`. `let`, `const` and `class` declarations are not initialized
+    /// before their declaration, so using them before it throws a `ReferenceError`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// alert(a);
+    /// var a = 10;
+    ///
+    /// new A();
+    /// class A {}
+    /// ```
+    NoUseBeforeDefine,
+    restriction
+);
+
+impl Rule for NoUseBeforeDefine {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let default = Self::default();
+        match value.get(0) {
+            Some(serde_json::Value::String(option)) if option == "nofunc" => {
+                Self { functions: false, ..default }
+            }
+            Some(obj @ serde_json::Value::Object(_)) => {
+                let get = |key: &str, default: bool| {
+                    obj.get(key).and_then(serde_json::Value::as_bool).unwrap_or(default)
+                };
+                Self {
+                    functions: get("functions", default.functions),
+                    classes: get("classes", default.classes),
+                    variables: get("variables", default.variables),
+                }
+            }
+            _ => default,
+        }
+    }
+
+    fn run_on_symbol(&self, symbol_id: SymbolId, ctx: &LintContext<'_>) {
+        let symbols = ctx.symbols();
+        // Imports are initialized before any code of the module runs.
+        if symbols.get_flag(symbol_id).is_import_binding() {
+            return;
+        }
+        let declaration_span = symbols.get_span(symbol_id);
+
+        for reference in ctx.references_to(symbol_id) {
+            let used_before = reference.span().end < declaration_span.end;
+            if !used_before && !is_in_initializer(symbol_id, reference, ctx) {
+                continue;
+            }
+            if !self.is_forbidden(symbol_id, reference, ctx) {
+                continue;
+            }
+            ctx.diagnostic(NoUseBeforeDefineDiagnostic(
+                symbols.get_name(symbol_id).clone(),
+                reference.span(),
+                declaration_span,
+            ));
+        }
+    }
+}
+
+impl NoUseBeforeDefine {
+    fn is_forbidden(
+        &self,
+        symbol_id: SymbolId,
+        reference: &Reference,
+        ctx: &LintContext<'_>,
+    ) -> bool {
+        let is_outer = || {
+            let reference_scope_id = ctx.scope_of(ctx.nodes().get_node(reference.node_id()));
+            variable_scope(reference_scope_id, ctx)
+                != variable_scope(ctx.symbols().get_scope_id(symbol_id), ctx)
+        };
+        match ctx.nodes().kind(ctx.symbols().get_declaration(symbol_id)) {
+            AstKind::Function(func) if func.is_declaration() => self.functions,
+            AstKind::Class(class) if class.is_declaration() && is_outer() => self.classes,
+            AstKind::VariableDeclarator(_) if is_outer() => self.variables,
+            _ => true,
+        }
+    }
+}
+
+/// Returns the nearest function (or top level) scope containing the given scope.
+fn variable_scope(scope_id: ScopeId, ctx: &LintContext<'_>) -> ScopeId {
+    ctx.scopes()
+        .ancestors(scope_id)
+        .find(|scope_id| ctx.scopes().get_flags(*scope_id).is_var())
+        .unwrap_or_else(|| ctx.scopes().root_scope_id())
+}
+
+/// Whether the reference is evaluated while initializing the variable, e.g. `var a = a;`.
+fn is_in_initializer(symbol_id: SymbolId, reference: &Reference, ctx: &LintContext<'_>) -> bool {
+    let AstKind::VariableDeclarator(declarator) =
+        ctx.nodes().kind(ctx.symbols().get_declaration(symbol_id))
+    else {
+        return false;
+    };
+    let Some(init) = &declarator.init else {
+        return false;
+    };
+    let span = reference.span();
+    let reference_scope_id = ctx.scope_of(ctx.nodes().get_node(reference.node_id()));
+    init.span().start <= span.start
+        && span.end <= init.span().end
+        && variable_scope(reference_scope_id, ctx)
+            == variable_scope(ctx.symbols().get_scope_id(symbol_id), ctx)
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var a=10; alert(a);", None),
+        ("function b(a) { alert(a); }", None),
+        ("Object.hasOwnProperty.call(a);", None),
+        ("function a() { alert(arguments);}", None),
+        ("a(); function a() { alert(arguments); }", Some(json!(["nofunc"]))),
+        ("a(); function a() { alert(arguments); }", Some(json!([{ "functions": false }]))),
+        ("(() => { var a = 42; alert(a); })();", None),
+        ("a(); try { throw new Error() } catch (a) {}", None),
+        ("class A {} new A();", None),
+        ("var a = 0, b = a;", None),
+        ("var {a = 0, b = a} = {};", None),
+        ("var [a = 0, b = a] = {};", None),
+        ("function foo() { foo(); }", None),
+        ("var foo = function() { foo(); };", None),
+        ("var a; for (a in a) {}", None),
+        ("var a; for (a of a) {}", None),
+        ("let a = () => a;", None),
+        ("function foo() { new A(); } class A {};", Some(json!([{ "classes": false }]))),
+        ("function foo() { bar; } var bar;", Some(json!([{ "variables": false }]))),
+        ("var foo = () => bar; var bar;", Some(json!([{ "variables": false }]))),
+        ("foo(); import foo from 'foo';", None),
+    ];
+
+    let fail = vec![
+        ("a++; var a=19;", None),
+        ("a(); var a=function() {};", None),
+        ("alert(a[1]); var a=[1,3];", None),
+        ("a(); function a() { alert(b); var b=10; a(); }", None),
+        ("a(); var a=function() {};", Some(json!(["nofunc"]))),
+        ("(() => { alert(a); var a = 42; })();", None),
+        ("(() => a())(); function a() { }", None),
+        ("a(); try { throw new Error() } catch (foo) {var a;}", None),
+        ("var f = () => a; var a;", None),
+        ("new A(); class A {};", None),
+        ("function foo() { new A(); } class A {};", None),
+        ("new A(); var A = class {};", None),
+        ("function foo() { new A(); } var A = class {};", None),
+        ("a(); function a() {}", None),
+        ("x; let x = 1;", None),
+        ("x; const x = 1;", None),
+        ("function foo() { x; } let x = 1;", None),
+        ("var a = a;", None),
+        ("let a = a + b;", None),
+        ("const a = foo(a);", None),
+        ("new A(); class A {};", Some(json!([{ "classes": false }]))),
+        ("x; let x = 1;", Some(json!([{ "variables": false }]))),
+    ];
+
+    Tester::new(NoUseBeforeDefine::NAME, pass, fail).test_and_snapshot();
+}
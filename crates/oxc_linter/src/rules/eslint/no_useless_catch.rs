@@ -35,6 +35,8 @@ declare_oxc_lint!(
     /// and has no effect on the runtime behavior of the program.
     /// These redundant clauses can be a source of confusion and code bloat,
     /// so it’s better to disallow these unnecessary catch clauses.
+    /// When there is a `finally` block, the `try` statement is still needed,
+    /// so only the catch clause is reported.
     ///
     /// ### Example
     /// ```javascript
@@ -157,6 +159,32 @@ fn test() {
           throw new Error('foo');
         }
       ",
+        "
+        try {
+          foo();
+        } finally {
+          bar();
+        }
+      ",
+        "
+        try {
+          foo();
+        } catch (err) {
+          err = wrap(err);
+          throw err;
+        } finally {
+          bar();
+        }
+      ",
+        "
+        try {
+          foo();
+        } catch (err) {
+          function bar() {
+            throw err;
+          }
+        }
+      ",
     ];
 
     let fail = vec![
@@ -0,0 +1,208 @@
+use oxc_ast::{
+    ast::{
+        Argument, BindingPatternKind, Expression, FormalParameters, FunctionBody,
+        MethodDefinitionKind, Statement, TSAccessibility,
+    },
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-useless-constructor): Useless constructor.")]
+#[diagnostic(severity(warning), help("Remove the constructor, the default one behaves the same."))]
+struct NoUselessConstructorDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUselessConstructor;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow unnecessary constructors.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A class without a constructor gets a default one, which is empty for a base class and
+    /// passes all of its arguments to `super()` for a derived class.
+    /// A constructor which does exactly the same can be removed.
+    /// Constructors with an access modifier, decorators or parameter properties are not reported.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// class A {
+    ///     constructor() {}
+    /// }
+    /// class B extends A {
+    ///     constructor(...args) {
+    ///         super(...args);
+    ///     }
+    /// }
+    ///
+    /// // Good
+    /// class A {
+    ///     constructor() {
+    ///         doSomething();
+    ///     }
+    /// }
+    /// class B extends A {
+    ///     constructor() {
+    ///         super('foo');
+    ///     }
+    /// }
+    /// ```
+    NoUselessConstructor,
+    suspicious
+);
+
+impl Rule for NoUselessConstructor {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::MethodDefinition(ctor) = node.kind() else { return };
+        if ctor.kind != MethodDefinitionKind::Constructor
+            || !ctor.decorators.is_empty()
+            || matches!(
+                ctor.accessibility,
+                Some(TSAccessibility::Private | TSAccessibility::Protected)
+            )
+        {
+            return;
+        }
+        let Some(body) = &ctor.value.body else { return };
+        let params = &ctor.value.params;
+        if params.items.iter().any(|param| {
+            param.accessibility.is_some() || param.readonly || !param.decorators.is_empty()
+        }) {
+            return;
+        }
+        let Some(AstKind::Class(class)) = ctx.nodes().parent_kind(node.id()) else { return };
+
+        let is_useless = if class.super_class.is_some() {
+            is_passing_through(params, body)
+        } else {
+            body.statements.is_empty()
+        };
+        if !is_useless {
+            return;
+        }
+        if ctx.semantic().trivias().has_comments_between(ctor.span) {
+            ctx.diagnostic(NoUselessConstructorDiagnostic(ctor.span));
+        } else {
+            ctx.diagnostic_with_fix(NoUselessConstructorDiagnostic(ctor.span), |fixer| {
+                fixer.remove(ctor.span)
+            });
+        }
+    }
+}
+
+/// Whether `body` only passes the parameters to `super()`, as in `super(a, b, ...c)`
+/// or `super(...arguments)`.
+fn is_passing_through(params: &FormalParameters, body: &FunctionBody) -> bool {
+    let [Statement::ExpressionStatement(stmt)] = body.statements.as_slice() else { return false };
+    let Expression::CallExpression(call) = &stmt.expression else { return false };
+    if !matches!(call.callee, Expression::Super(_)) {
+        return false;
+    }
+
+    if let [Argument::SpreadElement(spread)] = call.arguments.as_slice() {
+        if matches!(&spread.argument, Expression::Identifier(ident) if ident.name == "arguments") {
+            return true;
+        }
+    }
+
+    let rest_count = usize::from(params.rest.is_some());
+    if call.arguments.len() != params.items.len() + rest_count {
+        return false;
+    }
+    let items_match = params.items.iter().zip(&call.arguments).all(|(param, arg)| {
+        let BindingPatternKind::BindingIdentifier(param) = &param.pattern.kind else {
+            return false;
+        };
+        matches!(arg, Argument::Expression(Expression::Identifier(arg)) if arg.name == param.name)
+    });
+    let rest_matches = params.rest.as_ref().map_or(true, |rest| {
+        let BindingPatternKind::BindingIdentifier(param) = &rest.argument.kind else {
+            return false;
+        };
+        let Some(Argument::SpreadElement(spread)) = call.arguments.last() else { return false };
+        matches!(&spread.argument, Expression::Identifier(arg) if arg.name == param.name)
+    });
+    items_match && rest_matches
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "class A { }",
+        "class A { constructor(){ doSomething(); } }",
+        "class A extends B { constructor(){} }",
+        "class A extends B { constructor(){ super('foo'); } }",
+        "class A extends B { constructor(foo, bar){ super(foo, bar, 1); } }",
+        "class A extends B { constructor(){ super(); doSomething(); } }",
+        "class A extends B { constructor(...args){ super(...args); doSomething(); } }",
+        "class A { dummyMethod(){ doSomething(); } }",
+        "class A extends B.C { constructor() { super(foo); } }",
+        "class A extends B.C { constructor([a, b, c]) { super(...arguments); doSomething(); } }",
+        "class A extends B { constructor(a = 1) { super(a); } }",
+        "class A extends B { constructor({ a }) { super({ a }); } }",
+        "class A extends B { constructor(a, b) { super(b, a); } }",
+        "class A extends B { constructor(a) { super(a, ...arguments); } }",
+        "class A extends B { constructor(...args) { super(args); } }",
+        "class A extends B { constructor(a, ...args) { super(...args); } }",
+        "class A { constructor(); }",
+        "class A { private constructor() {} }",
+        "class A { protected constructor() {} }",
+        "class A extends B { protected constructor(a) { super(a); } }",
+        "class A { constructor(private a) {} }",
+        "class A { constructor(public a) {} }",
+        "class A { constructor(readonly a) {} }",
+        "class A extends B { constructor(public a) { super(a); } }",
+        "class A { @Decorator() constructor() {} }",
+        "class A { constructor(@Foo foo) {} }",
+    ];
+
+    let fail = vec![
+        "class A { constructor(){} }",
+        "class A { 'constructor'(){} }",
+        "class A extends B { constructor() { super(); } }",
+        "class A extends B { constructor(foo){ super(foo); } }",
+        "class A extends B { constructor(foo, bar){ super(foo, bar); } }",
+        "class A extends B { constructor(...args){ super(...args); } }",
+        "class A extends B.C { constructor() { super(...arguments); } }",
+        "class A extends B { constructor(a, b, ...c) { super(...arguments); } }",
+        "class A extends B { constructor(a, b, ...c) { super(a, b, ...c); } }",
+        "class A { public constructor() {} }",
+        "class A { constructor() { /* comment */ } }",
+    ];
+
+    let fix = vec![
+        ("class A { constructor(){} }", "class A {  }", None),
+        (
+            "class A extends B { constructor() { super(); } foo() { bar(); } }",
+            "class A extends B {  foo() { bar(); } }",
+            None,
+        ),
+        (
+            "class A extends B { constructor(...args){ super(...args); } }",
+            "class A extends B {  }",
+            None,
+        ),
+        (
+            "class A { constructor() { /* comment */ } }",
+            "class A { constructor() { /* comment */ } }",
+            None,
+        ),
+    ];
+
+    Tester::new_without_config(NoUselessConstructor::NAME, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
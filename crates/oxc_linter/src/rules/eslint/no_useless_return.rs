@@ -0,0 +1,204 @@
+use oxc_ast::{ast::Statement, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::AstNodeId;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-useless-return): Unnecessary return statement.")]
+#[diagnostic(severity(warning), help("Remove the return statement."))]
+struct NoUselessReturnDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUselessReturn;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow redundant return statements.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A `return;` with nothing after it is redundant, as the function returns `undefined`
+    /// at its end anyway. It usually is a leftover of a refactoring.
+    /// A `return;` inside of a `try` or `catch` block with a `finally` block is not reported,
+    /// as it skips the code after the `finally` block, and neither is a `return;` inside of a
+    /// `finally` block, as it overrides the value returned by the `try` or `catch` block.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// function foo() {
+    ///     doSomething();
+    ///     return;
+    /// }
+    /// function bar() {
+    ///     if (condition) {
+    ///         doSomething();
+    ///         return;
+    ///     }
+    /// }
+    ///
+    /// // Good
+    /// function foo() {
+    ///     if (condition) {
+    ///         return;
+    ///     }
+    ///     doSomething();
+    /// }
+    /// ```
+    NoUselessReturn,
+    pedantic
+);
+
+impl Rule for NoUselessReturn {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ReturnStatement(stmt) = node.kind() else { return };
+        if stmt.argument.is_some() || !is_last_executed(node.id(), ctx) {
+            return;
+        }
+        // Removing `return;` from `if (a) return; else b();` would leave `if (a)` without
+        // a body.
+        let is_in_statement_list = matches!(
+            ctx.nodes().parent_kind(node.id()),
+            Some(
+                AstKind::FunctionBody(_)
+                    | AstKind::BlockStatement(_)
+                    | AstKind::SwitchCase(_)
+                    | AstKind::CatchClause(_)
+            )
+        );
+        if is_in_statement_list && !ctx.semantic().trivias().has_comments_between(stmt.span) {
+            ctx.diagnostic_with_fix(NoUselessReturnDiagnostic(stmt.span), |fixer| {
+                fixer.remove(stmt.span)
+            });
+        } else {
+            ctx.diagnostic(NoUselessReturnDiagnostic(stmt.span));
+        }
+    }
+}
+
+/// Whether the statement `node_id` is the last one executed in its function whenever it is
+/// executed, i.e. the function returns right after it completes.
+fn is_last_executed(node_id: AstNodeId, ctx: &LintContext) -> bool {
+    let nodes = ctx.nodes();
+    let is_last = |statements: &[Statement], id: AstNodeId| {
+        statements.last().map(GetSpan::span) == Some(nodes.kind(id).span())
+    };
+
+    let mut current = node_id;
+    while let Some(parent) = nodes.parent_node(current) {
+        match parent.kind() {
+            AstKind::FunctionBody(body) => {
+                return is_last(&body.statements, current)
+                    && matches!(
+                        nodes.parent_kind(parent.id()),
+                        Some(AstKind::Function(_) | AstKind::ArrowExpression(_))
+                    );
+            }
+            AstKind::BlockStatement(block) if !is_last(&block.body, current) => return false,
+            AstKind::CatchClause(clause) if !is_last(&clause.body.body, current) => return false,
+            AstKind::SwitchCase(case) => {
+                if !is_last(&case.consequent, current) {
+                    return false;
+                }
+                // Otherwise it falls through to the next case.
+                let Some(AstKind::SwitchStatement(switch)) = nodes.parent_kind(parent.id()) else {
+                    return false;
+                };
+                if switch.cases.last().map(|case| case.span) != Some(case.span) {
+                    return false;
+                }
+            }
+            // The `finally` block runs after the `try` and `catch` blocks, and a `return;` in
+            // the `finally` block overrides their completion, e.g. the value of `return 1;`.
+            AstKind::TryStatement(stmt) if stmt.finalizer.is_some() => return false,
+            AstKind::BlockStatement(_)
+            | AstKind::CatchClause(_)
+            | AstKind::TryStatement(_)
+            | AstKind::IfStatement(_)
+            | AstKind::LabeledStatement(_)
+            | AstKind::SwitchStatement(_) => {}
+            _ => return false,
+        }
+        current = parent.id();
+    }
+    false
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "function foo() { return 5; }",
+        "function foo() { return null; }",
+        "function foo() { return doSomething(); }",
+        "function foo() { if (bar) { doSomething(); return; } else { doSomethingElse(); } qux(); }",
+        "function foo() { switch (bar) { case 1: doSomething(); return; default: doSomethingElse(); } }",
+        "function foo() { switch (bar) { default: doSomething(); return; case 1: doSomething(); } }",
+        "function foo() { switch (bar) { case 1: if (a) { doSomething(); return; } break; default: doSomethingElse(); } }",
+        "function foo() { for (const foo of bar) return; }",
+        "function foo() { for (const foo of bar) { doSomething(); return; } }",
+        "function foo() { while (bar) { return; } }",
+        "function foo() { try { doSomething(); return; } finally { doSomethingElse(); } }",
+        "function foo() { try { doSomething(); } catch (e) { return; } finally { doSomethingElse(); } }",
+        "function foo() { try { return; } finally { } bar(); }",
+        "function foo() { try { return 1; } finally { return; } }",
+        "function foo() { if (foo) { return; } doSomething(); }",
+        "function foo() { if (foo) return; doSomething(); }",
+        "function foo() { label: { return; } bar(); }",
+        "() => { if (foo) return; bar(); }",
+        "function foo() { return; doSomething(); }",
+        "function foo() { function bar() { return 1; } }",
+        "class A { foo() { return 1; } }",
+    ];
+
+    let fail = vec![
+        "function foo() { return; }",
+        "function foo() { doSomething(); return; }",
+        "function foo() { if (condition) { bar(); return; } else { baz(); } }",
+        "function foo() { if (foo) { return; } }",
+        "function foo() { if (foo) { return; } else { bar(); } }",
+        "function foo() { if (foo) return; }",
+        "function foo() { if (foo) return; else bar(); }",
+        "function foo() { switch (bar) { case 1: doSomething(); default: doSomethingElse(); return; } }",
+        "function foo() { try { return; } catch (e) { } }",
+        "function foo() { try { } catch (e) { return; } }",
+        "function foo() { label: { return; } }",
+        "() => { return; }",
+        "const foo = function () { bar(); return; }",
+        "class A { foo() { return; } }",
+        "function foo() { return /* comment */; }",
+    ];
+
+    let fix = vec![
+        ("function foo() { return; }", "function foo() {  }", None),
+        ("function foo() { doSomething(); return; }", "function foo() { doSomething();  }", None),
+        ("function foo() { if (foo) { return; } }", "function foo() { if (foo) {  } }", None),
+        (
+            "function foo() { if (foo) return; else bar(); }",
+            "function foo() { if (foo) return; else bar(); }",
+            None,
+        ),
+        (
+            "function foo() { try { } catch (e) { return; } }",
+            "function foo() { try { } catch (e) {  } }",
+            None,
+        ),
+        (
+            "function foo() { return /* comment */; }",
+            "function foo() { return /* comment */; }",
+            None,
+        ),
+    ];
+
+    Tester::new_without_config(NoUselessReturn::NAME, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
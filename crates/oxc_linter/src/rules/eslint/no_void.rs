@@ -0,0 +1,95 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+use oxc_syntax::operator::UnaryOperator;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-void): Expected 'undefined' and instead saw 'void'.")]
+#[diagnostic(severity(warning))]
+struct NoVoidDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoVoid {
+    /// Whether to allow `void` as a statement, e.g. `void foo();`.
+    allow_as_statement: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow `void` operators
+    ///
+    /// ### Why is this bad?
+    /// The `void` operator evaluates its operand and returns `undefined`.
+    /// It is rarely needed and `undefined` is easier to read.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// var foo = void bar();
+    /// function baz() { return void 0; }
+    /// ```
+    NoVoid,
+    restriction
+);
+
+impl Rule for NoVoid {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            allow_as_statement: value
+                .get(0)
+                .and_then(|v| v.get("allowAsStatement"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::UnaryExpression(expr) = node.kind() else { return };
+        if expr.operator != UnaryOperator::Void {
+            return;
+        }
+
+        if self.allow_as_statement
+            && matches!(ctx.nodes().parent_kind(node.id()), Some(AstKind::ExpressionStatement(_)))
+        {
+            return;
+        }
+
+        ctx.diagnostic(NoVoidDiagnostic(expr.span));
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var foo = bar()", None),
+        ("foo.void()", None),
+        ("foo.void = bar", None),
+        ("delete foo;", None),
+        ("var foo = undefined;", None),
+        ("void 0", Some(json!([{ "allowAsStatement": true }]))),
+        ("void(0)", Some(json!([{ "allowAsStatement": true }]))),
+        ("void someFn();", Some(json!([{ "allowAsStatement": true }]))),
+    ];
+
+    let fail = vec![
+        ("void 0", None),
+        ("void(0)", None),
+        ("var foo = void 0", None),
+        ("var foo = void 0", Some(json!([{ "allowAsStatement": true }]))),
+        ("function foo() { return void 0; }", Some(json!([{ "allowAsStatement": true }]))),
+        ("void someFn();", None),
+        ("void someFn();", Some(json!([{ "allowAsStatement": false }]))),
+    ];
+
+    Tester::new(NoVoid::NAME, pass, fail).test_and_snapshot();
+}
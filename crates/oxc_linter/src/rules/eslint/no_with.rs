@@ -0,0 +1,54 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(no-with): Unexpected use of `with` statement.")]
+#[diagnostic(severity(warning), help("Use a variable or destructuring instead."))]
+struct NoWithDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoWith;
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Disallow `with` statements
+    ///
+    /// ### Why is this bad?
+    /// The `with` statement makes it unclear which variable an identifier refers to,
+    /// prevents optimizations, and is a syntax error in strict mode.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// with (point) {
+    ///     r = Math.sqrt(x * x + y * y);
+    /// }
+    /// ```
+    NoWith,
+    correctness
+);
+
+impl Rule for NoWith {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        if let AstKind::WithStatement(stmt) = node.kind() {
+            ctx.diagnostic(NoWithDiagnostic(stmt.span));
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![("foo.bar()", None), ("const { x, y } = point;", None)];
+
+    let fail = vec![("with(foo) { bar() }", None), ("with (obj) {}", None)];
+
+    Tester::new(NoWith::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,293 @@
+use oxc_ast::{
+    ast::{VariableDeclaration, VariableDeclarationKind},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::AstNodeId;
+use oxc_span::Span;
+use rustc_hash::FxHashMap;
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum OneVarDiagnostic {
+    #[error("eslint(one-var): Combine this with the previous '{0}' statement.")]
+    #[diagnostic(severity(warning))]
+    Combine(&'static str, #[label] Span),
+
+    #[error("eslint(one-var): Split '{0}' declarations into multiple statements.")]
+    #[diagnostic(severity(warning))]
+    Split(&'static str, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    /// One declaration per scope: the function for `var`, the block for `let` and `const`.
+    #[default]
+    Always,
+    /// One variable per declaration.
+    Never,
+    /// Not checked.
+    Off,
+}
+
+impl Mode {
+    fn from_value(value: Option<&serde_json::Value>) -> Self {
+        match value.and_then(serde_json::Value::as_str) {
+            Some("always") => Self::Always,
+            Some("never") => Self::Never,
+            _ => Self::Off,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct OneVar {
+    var: Mode,
+    r#let: Mode,
+    r#const: Mode,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce variables to be declared either together or separately in functions.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Mixing one declaration per variable with declarations of several variables
+    /// in the same code base makes it harder to read. This rule enforces one of the two styles.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad, with "always" (the default)
+    /// function foo() {
+    ///     var bar;
+    ///     var baz;
+    /// }
+    ///
+    /// // Bad, with "never"
+    /// function foo() {
+    ///     var bar, baz;
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `"always"` (default) or `"never"` for all declarations, or an object such as
+    /// `{ "var": "always", "let": "never" }` to configure `var`, `let` and `const` separately.
+    /// Kinds which are missing from the object are not checked.
+    OneVar,
+    style
+);
+
+impl Rule for OneVar {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let Some(config) = value.get(0) else { return Self::default() };
+        if config.is_string() {
+            let mode = Mode::from_value(Some(config));
+            return Self { var: mode, r#let: mode, r#const: mode };
+        }
+        Self {
+            var: Mode::from_value(config.get("var")),
+            r#let: Mode::from_value(config.get("let")),
+            r#const: Mode::from_value(config.get("const")),
+        }
+    }
+
+    fn run_once(&self, ctx: &LintContext) {
+        // The previous declaration of each kind in each scope.
+        let mut previous: FxHashMap<(AstNodeId, &'static str), &VariableDeclaration> =
+            FxHashMap::default();
+
+        for node in ctx.nodes().iter() {
+            let AstKind::VariableDeclaration(decl) = node.kind() else { continue };
+            match self.mode(decl.kind) {
+                Mode::Always => {
+                    let Some(scope_id) = scope_node_id(node, decl.kind, ctx) else { continue };
+                    if let Some(prev) = previous.insert((scope_id, decl.kind.as_str()), decl) {
+                        report_combine(prev, decl, ctx);
+                    }
+                }
+                Mode::Never => check_split(node, decl, ctx),
+                Mode::Off => {}
+            }
+        }
+    }
+}
+
+impl OneVar {
+    fn mode(&self, kind: VariableDeclarationKind) -> Mode {
+        match kind {
+            VariableDeclarationKind::Var => self.var,
+            VariableDeclarationKind::Let => self.r#let,
+            VariableDeclarationKind::Const => self.r#const,
+        }
+    }
+}
+
+/// The node whose scope `kind` declarations belong to,
+/// the enclosing function for `var` and the enclosing block for `let` and `const`.
+fn scope_node_id(
+    node: &AstNode,
+    kind: VariableDeclarationKind,
+    ctx: &LintContext,
+) -> Option<AstNodeId> {
+    ctx.nodes()
+        .iter_parents(node.id())
+        .skip(1)
+        .find(|parent| match parent.kind() {
+            AstKind::Program(_)
+            | AstKind::Function(_)
+            | AstKind::ArrowExpression(_)
+            | AstKind::StaticBlock(_)
+            | AstKind::TSModuleBlock(_) => true,
+            AstKind::BlockStatement(_)
+            | AstKind::FunctionBody(_)
+            | AstKind::SwitchStatement(_)
+            | AstKind::ForStatement(_)
+            | AstKind::ForInStatement(_)
+            | AstKind::ForOfStatement(_) => kind.is_lexical(),
+            _ => false,
+        })
+        .map(AstNode::id)
+}
+
+fn report_combine(prev: &VariableDeclaration, decl: &VariableDeclaration, ctx: &LintContext) {
+    let kind = decl.kind.as_str();
+    let diagnostic = OneVarDiagnostic::Combine(kind, decl.span);
+    let (Some(prev_last), Some(first)) = (prev.declarations.last(), decl.declarations.first())
+    else {
+        ctx.diagnostic(diagnostic);
+        return;
+    };
+
+    // Only join declarations which directly follow each other, e.g. `let a;\nlet b;`.
+    let between = Span::new(prev_last.span.end, first.span.start);
+    let tokens = between.source_text(ctx.source_text()).split_whitespace().collect::<String>();
+    if tokens != format!(";{kind}") && tokens != kind {
+        ctx.diagnostic(diagnostic);
+        return;
+    }
+    ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(between, ", "));
+}
+
+fn check_split(node: &AstNode, decl: &VariableDeclaration, ctx: &LintContext) {
+    if decl.declarations.len() <= 1 {
+        return;
+    }
+    let parent_kind = ctx.nodes().parent_kind(node.id());
+    // `for (let i = 0, len = arr.length; ; )` can not be split.
+    if matches!(parent_kind, Some(AstKind::ForStatementInit(_))) {
+        return;
+    }
+
+    let kind = decl.kind.as_str();
+    let diagnostic = OneVarDiagnostic::Split(kind, decl.span);
+    // Splitting is only safe in a list of statements, not in `if (x) var a, b;` or an export.
+    if !matches!(
+        parent_kind,
+        Some(
+            AstKind::Program(_)
+                | AstKind::BlockStatement(_)
+                | AstKind::FunctionBody(_)
+                | AstKind::StaticBlock(_)
+                | AstKind::SwitchCase(_)
+                | AstKind::TSModuleBlock(_)
+        )
+    ) {
+        ctx.diagnostic(diagnostic);
+        return;
+    }
+
+    let source_text = ctx.source_text();
+    let mut replacement = String::new();
+    let mut prev_end = None;
+    for declarator in &decl.declarations {
+        if let Some(prev_end) = prev_end {
+            let between = Span::new(prev_end, declarator.span.start);
+            // The separating comma can't be told apart from a comma in a comment without tokens.
+            if ctx.semantic().trivias().has_comments_between(between) {
+                ctx.diagnostic(diagnostic);
+                return;
+            }
+            replacement.push_str(&between.source_text(source_text).replacen(',', ";", 1));
+        }
+        replacement.push_str(kind);
+        replacement.push(' ');
+        replacement.push_str(declarator.span.source_text(source_text));
+        prev_end = Some(declarator.span.end);
+    }
+    let span = Span::new(decl.span.start, prev_end.unwrap_or(decl.span.end));
+    ctx.diagnostic_with_fix(diagnostic, |fixer| fixer.replace(span, replacement));
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let always = || Some(json!(["always"]));
+    let never = || Some(json!(["never"]));
+
+    let pass = vec![
+        ("function foo() { var bar = true; }", always()),
+        ("function foo() { var bar = true, baz = 1; if (qux) { bar = false; } }", always()),
+        ("var foo = function() { var bar = true; baz(); }", always()),
+        ("function foo() { var bar = true, baz = false; }", always()),
+        ("function foo() { var bar = true; }", never()),
+        ("function foo() { var bar = true; var baz = false; }", never()),
+        ("let a;", None),
+        ("let a = 1;", never()),
+        ("let a; const b = 1;", always()),
+        ("let a; { let b; }", always()),
+        ("var a; function foo() { var b; }", always()),
+        ("let a; function foo() { let b; }", always()),
+        ("for (let i = 0; ; ) {} for (let i = 0; ; ) {}", always()),
+        ("switch (a) { case 1: let b; } let c;", always()),
+        ("class C { static { var a; } static { var b; } }", always()),
+        ("for (var i = 0, len = arr.length; i < len; i++) {}", never()),
+        ("var a; var b; let c, d;", Some(json!([{ "let": "always" }]))),
+        ("var a, b; let c; let d;", Some(json!([{ "var": "always", "let": "never" }]))),
+        ("var a; var b;", Some(json!([{ "let": "always", "const": "always" }]))),
+    ];
+
+    let fail = vec![
+        ("function foo() { var bar = true; var baz = false; }", always()),
+        ("function foo() { var bar = true; if (qux) { var baz = false; } }", always()),
+        ("var a = 1; var b = 2;", None),
+        ("let a; let b;", None),
+        ("const a = 1; const b = 2;", always()),
+        ("let a;\nfoo();\nlet b;", always()),
+        ("var a; for (var i = 0; ; ) {}", always()),
+        ("function foo() { var bar = true, baz = false; }", never()),
+        ("let a, b;", never()),
+        ("const a = 1, b = 2;", never()),
+        ("let a = 1, b = 2, c;", never()),
+        ("if (foo) var a, b;", never()),
+        ("export let a, b;", never()),
+        ("let a /* x, y */, b;", never()),
+        ("let a, b; var c; var d;", Some(json!([{ "var": "always", "let": "never" }]))),
+    ];
+
+    let fix = vec![
+        ("let a, b;", "let a; let b;", never()),
+        ("let a = 1, b;", "let a = 1; let b;", never()),
+        ("const a = 1,\n  b = 2;", "const a = 1;\n  const b = 2;", never()),
+        ("var a = [1, 2], b = { c, d };", "var a = [1, 2]; var b = { c, d };", never()),
+        ("if (foo) var a, b;", "if (foo) var a, b;", never()),
+        ("let a /* x, y */, b;", "let a /* x, y */, b;", never()),
+        ("let a = /* x, y */ 1, b;", "let a = /* x, y */ 1; let b;", never()),
+        ("let a; let b;", "let a, b;", always()),
+        ("let a = 1;\nlet b = 2;", "let a = 1, b = 2;", always()),
+        ("var a = 1, b; var c;", "var a = 1, b, c;", always()),
+        ("let a;\nfoo();\nlet b;", "let a;\nfoo();\nlet b;", always()),
+    ];
+
+    Tester::new(OneVar::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,366 @@
+use oxc_ast::{
+    ast::{ImportDeclaration, ImportDeclarationSpecifier, ModuleDeclaration, Statement},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+
+use crate::{
+    context::LintContext, fixer::Fix, rule::Rule, utils::get_string_array_option, AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum SortImportsDiagnostic {
+    #[error("eslint(sort-imports): Expected '{0}' syntax before '{1}' syntax.")]
+    #[diagnostic(severity(warning))]
+    UnexpectedSyntaxOrder(&'static str, &'static str, #[label] Span),
+    #[error("eslint(sort-imports): Imports should be sorted alphabetically.")]
+    #[diagnostic(severity(warning))]
+    SortImportsAlphabetically(#[label] Span),
+    #[error("eslint(sort-imports): Member '{0}' of the import declaration should be sorted alphabetically.")]
+    #[diagnostic(severity(warning))]
+    SortMembersAlphabetically(Atom, #[label] Span),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MemberSyntax {
+    /// `import "foo"`
+    None,
+    /// `import * as foo from "foo"`
+    All,
+    /// `import { a, b } from "foo"`
+    Multiple,
+    /// `import foo from "foo"` or `import { a } from "foo"`
+    Single,
+}
+
+impl MemberSyntax {
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "none" => Some(Self::None),
+            "all" => Some(Self::All),
+            "multiple" => Some(Self::Multiple),
+            "single" => Some(Self::Single),
+            _ => None,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::All => "all",
+            Self::Multiple => "multiple",
+            Self::Single => "single",
+        }
+    }
+
+    fn of(decl: &ImportDeclaration) -> Self {
+        let Some(specifiers) = &decl.specifiers else { return Self::None };
+        if specifiers.is_empty() {
+            Self::None
+        } else if specifiers
+            .iter()
+            .any(|s| matches!(s, ImportDeclarationSpecifier::ImportNamespaceSpecifier(_)))
+        {
+            Self::All
+        } else if specifiers.len() == 1 {
+            Self::Single
+        } else {
+            Self::Multiple
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SortImports {
+    ignore_case: bool,
+    ignore_declaration_sort: bool,
+    ignore_member_sort: bool,
+    /// Whether imports separated by an empty line, a comment or another statement
+    /// are sorted independently.
+    allow_separated_groups: bool,
+    member_syntax_sort_order: Vec<MemberSyntax>,
+}
+
+impl Default for SortImports {
+    fn default() -> Self {
+        Self {
+            ignore_case: false,
+            ignore_declaration_sort: false,
+            ignore_member_sort: false,
+            allow_separated_groups: false,
+            member_syntax_sort_order: vec![
+                MemberSyntax::None,
+                MemberSyntax::All,
+                MemberSyntax::Multiple,
+                MemberSyntax::Single,
+            ],
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Enforce sorted import declarations within modules
+    ///
+    /// ### Why is this bad?
+    /// Sorted imports make it easier to find a dependency and to spot duplicates.
+    /// Import declarations are sorted by their member syntax (`none`, `all`, `multiple`,
+    /// `single` by default) and then by the name of their first local member.
+    /// The named members of each import are sorted alphabetically.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// import b from 'foo.js';
+    /// import a from 'bar.js';
+    ///
+    /// import {b, a, c} from 'foo.js';
+    /// ```
+    SortImports,
+    style
+);
+
+impl Rule for SortImports {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let obj = value.get(0);
+        let get_bool = |key: &str| {
+            obj.and_then(|v| v.get(key)).and_then(serde_json::Value::as_bool).unwrap_or_default()
+        };
+        let member_syntax_sort_order = obj
+            .map(|v| get_string_array_option(v, "memberSyntaxSortOrder"))
+            .map(|v| v.iter().filter_map(|s| MemberSyntax::from_str(s)).collect::<Vec<_>>())
+            .filter(|order| order.len() == 4)
+            .unwrap_or_else(|| Self::default().member_syntax_sort_order);
+
+        Self {
+            ignore_case: get_bool("ignoreCase"),
+            ignore_declaration_sort: get_bool("ignoreDeclarationSort"),
+            ignore_member_sort: get_bool("ignoreMemberSort"),
+            allow_separated_groups: get_bool("allowSeparatedGroups"),
+            member_syntax_sort_order,
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::Program(program) = node.kind() else { return };
+
+        let mut previous: Option<&ImportDeclaration> = None;
+        for stmt in &program.body {
+            let Statement::ModuleDeclaration(decl) = stmt else { continue };
+            let ModuleDeclaration::ImportDeclaration(decl) = &**decl else { continue };
+
+            if !self.ignore_declaration_sort {
+                if let Some(previous) = previous {
+                    if self.is_same_group(previous, decl, ctx) {
+                        self.check_declaration_order(previous, decl, ctx);
+                    }
+                }
+                previous = Some(decl);
+            }
+
+            if !self.ignore_member_sort {
+                self.check_member_order(decl, ctx);
+            }
+        }
+    }
+}
+
+impl SortImports {
+    fn normalize(&self, name: &str) -> String {
+        if self.ignore_case {
+            name.to_lowercase()
+        } else {
+            name.to_string()
+        }
+    }
+
+    fn syntax_index(&self, syntax: MemberSyntax) -> usize {
+        self.member_syntax_sort_order.iter().position(|s| *s == syntax).unwrap_or_default()
+    }
+
+    fn is_same_group(
+        &self,
+        previous: &ImportDeclaration,
+        current: &ImportDeclaration,
+        ctx: &LintContext<'_>,
+    ) -> bool {
+        !self.allow_separated_groups
+            || Span::new(previous.span.end, current.span.start)
+                .source_text(ctx.source_text())
+                .matches('\n')
+                .count()
+                <= 1
+    }
+
+    fn check_declaration_order(
+        &self,
+        previous: &ImportDeclaration,
+        current: &ImportDeclaration,
+        ctx: &LintContext<'_>,
+    ) {
+        let previous_syntax = MemberSyntax::of(previous);
+        let current_syntax = MemberSyntax::of(current);
+        let previous_index = self.syntax_index(previous_syntax);
+        let current_index = self.syntax_index(current_syntax);
+
+        if current_index < previous_index {
+            ctx.diagnostic(SortImportsDiagnostic::UnexpectedSyntaxOrder(
+                current_syntax.as_str(),
+                previous_syntax.as_str(),
+                current.span,
+            ));
+            return;
+        }
+
+        if current_index == previous_index {
+            if let (Some(previous_name), Some(current_name)) =
+                (first_local_member_name(previous), first_local_member_name(current))
+            {
+                if self.normalize(current_name) < self.normalize(previous_name) {
+                    ctx.diagnostic(SortImportsDiagnostic::SortImportsAlphabetically(current.span));
+                }
+            }
+        }
+    }
+
+    fn check_member_order(&self, decl: &ImportDeclaration, ctx: &LintContext<'_>) {
+        let Some(specifiers) = &decl.specifiers else { return };
+        let specifiers: Vec<_> = specifiers
+            .iter()
+            .filter_map(|specifier| match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => Some(specifier),
+                _ => None,
+            })
+            .collect();
+        let names: Vec<_> = specifiers.iter().map(|s| self.normalize(&s.local.name)).collect();
+
+        let Some(unsorted) = (1..names.len()).find(|&i| names[i] < names[i - 1]) else {
+            return;
+        };
+        let diagnostic = SortImportsDiagnostic::SortMembersAlphabetically(
+            specifiers[unsorted].local.name.clone(),
+            specifiers[unsorted].span,
+        );
+
+        let source_text = ctx.source_text();
+        let span = Span::new(specifiers[0].span.start, specifiers[specifiers.len() - 1].span.end);
+        // Comments between the members could end up attached to the wrong member.
+        let text = span.source_text(source_text);
+        if text.contains("//") || text.contains("/*") {
+            ctx.diagnostic(diagnostic);
+            return;
+        }
+
+        ctx.diagnostic_with_fix(diagnostic, |_| {
+            let mut sorted = specifiers.clone();
+            sorted.sort_by_cached_key(|s| self.normalize(&s.local.name));
+            let mut content = String::new();
+            for (i, specifier) in sorted.iter().enumerate() {
+                if i > 0 {
+                    let separator = Span::new(specifiers[i - 1].span.end, specifiers[i].span.start);
+                    content.push_str(separator.source_text(source_text));
+                }
+                content.push_str(specifier.span.source_text(source_text));
+            }
+            Fix::new(content, span)
+        });
+    }
+}
+
+fn first_local_member_name<'a>(decl: &'a ImportDeclaration) -> Option<&'a str> {
+    decl.specifiers.as_ref()?.first().map(|specifier| match specifier {
+        ImportDeclarationSpecifier::ImportSpecifier(s) => s.local.name.as_str(),
+        ImportDeclarationSpecifier::ImportDefaultSpecifier(s) => s.local.name.as_str(),
+        ImportDeclarationSpecifier::ImportNamespaceSpecifier(s) => s.local.name.as_str(),
+    })
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("import a from 'foo.js';\nimport b from 'bar.js';\nimport c from 'baz.js';", None),
+        ("import * as B from 'foo.js';\nimport A from 'bar.js';", None),
+        ("import * as B from 'foo.js';\nimport {a, b} from 'bar.js';", None),
+        ("import {b, c} from 'bar.js';\nimport A from 'foo.js';", None),
+        (
+            "import A from 'bar.js';\nimport {b, c} from 'foo.js';",
+            Some(json!([{ "memberSyntaxSortOrder": ["single", "multiple", "none", "all"] }])),
+        ),
+        ("import {a, b} from 'bar.js';\nimport {c, d} from 'foo.js';", None),
+        ("import A from 'foo.js';\nimport B from 'bar.js';", None),
+        ("import A from 'foo.js';\nimport a from 'bar.js';", None),
+        ("import a, * as b from 'foo.js';\nimport c from 'bar.js';", None),
+        ("import 'foo.js';\nimport a from 'bar.js';", None),
+        ("import B from 'foo.js';\nimport a from 'bar.js';", None),
+        ("import a from 'foo.js';\nimport B from 'bar.js';", Some(json!([{ "ignoreCase": true }]))),
+        ("import {a, b, c, d} from 'foo.js';", None),
+        (
+            "import a from 'foo.js';\nimport B from 'bar.js';",
+            Some(json!([{ "ignoreDeclarationSort": true }])),
+        ),
+        ("import {b, A, C, d} from 'foo.js';", Some(json!([{ "ignoreMemberSort": true }]))),
+        ("import {B, a, C, d} from 'foo.js';", Some(json!([{ "ignoreMemberSort": true }]))),
+        ("import {a, B, c, D} from 'foo.js';", Some(json!([{ "ignoreCase": true }]))),
+        ("import a, * as b from 'foo.js';", None),
+        ("import * as a from 'foo.js';\n\nimport b from 'bar.js';", None),
+        ("import * as bar from 'bar.js';\nimport * as foo from 'foo.js';", None),
+        ("import {B, C, a} from 'foo.js';", None),
+        (
+            "import b from 'b';\n\nimport a from 'a';",
+            Some(json!([{ "allowSeparatedGroups": true }])),
+        ),
+        (
+            "import b from 'b';\n// comment\nimport a from 'a';",
+            Some(json!([{ "allowSeparatedGroups": true }])),
+        ),
+        (
+            "import b from 'b';\nfoo();\nimport a from 'a';",
+            Some(json!([{ "allowSeparatedGroups": true }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("import a from 'foo.js';\nimport A from 'bar.js';", None),
+        ("import b from 'foo.js';\nimport a from 'bar.js';", None),
+        ("import {b, c} from 'foo.js';\nimport {a, d} from 'bar.js';", None),
+        ("import * as foo from 'foo.js';\nimport * as bar from 'bar.js';", None),
+        ("import a from 'foo.js';\nimport {b, c} from 'bar.js';", None),
+        ("import a from 'foo.js';\nimport * as b from 'bar.js';", None),
+        ("import a from 'foo.js';\nimport 'bar.js';", None),
+        (
+            "import b from 'bar.js';\nimport * as a from 'foo.js';",
+            Some(json!([{ "memberSyntaxSortOrder": ["all", "single", "multiple", "none"] }])),
+        ),
+        ("import {b, a, d, c} from 'foo.js';", None),
+        (
+            "import {b, a, d, c} from 'foo.js';\nimport {e, f, g, h} from 'bar.js';",
+            Some(json!([{ "ignoreDeclarationSort": true }])),
+        ),
+        ("import {a, B, c, D} from 'foo.js';", None),
+        ("import {zzzzz, /* comment */ aaaaa} from 'foo.js';", None),
+        ("import b from 'b';\nimport a from 'a';", Some(json!([{ "allowSeparatedGroups": true }]))),
+        ("import b from 'b';\n\nimport a from 'a';", None),
+    ];
+
+    let fix = vec![
+        ("import {b, a, d, c} from 'foo.js';", "import {a, b, c, d} from 'foo.js';", None),
+        ("import {c, b as a} from 'foo.js';", "import {b as a, c} from 'foo.js';", None),
+        ("import {\n  b,\n  a,\n} from 'foo.js';", "import {\n  a,\n  b,\n} from 'foo.js';", None),
+        ("import {a, B, c, D} from 'foo.js';", "import {B, D, a, c} from 'foo.js';", None),
+        (
+            "import {b, A, c} from 'foo.js';",
+            "import {A, b, c} from 'foo.js';",
+            Some(json!([{ "ignoreCase": true }])),
+        ),
+    ];
+
+    Tester::new(SortImports::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,248 @@
+use std::cmp::Ordering;
+
+use oxc_ast::{ast::ObjectPropertyKind, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(sort-keys): Expected object keys to be in {0}order. '{1}' should be before '{2}'.")]
+#[diagnostic(severity(warning))]
+struct SortKeysDiagnostic(String, Atom, Atom, #[label] pub Span);
+
+#[derive(Debug, Clone)]
+pub struct SortKeys {
+    /// Whether keys must be in descending order.
+    desc: bool,
+    case_sensitive: bool,
+    /// Whether numbers in keys are compared by value, e.g. `a2` before `a10`.
+    natural: bool,
+    /// The minimum number of keys an object needs to be checked.
+    min_keys: usize,
+}
+
+impl Default for SortKeys {
+    fn default() -> Self {
+        Self { desc: false, case_sensitive: true, natural: false, min_keys: 2 }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Require object keys to be sorted
+    ///
+    /// ### Why is this bad?
+    /// Sorted keys make it easier to find a property in a large object and to spot
+    /// duplicates. Spread elements and computed keys start a new run of keys which
+    /// is sorted on its own.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// let obj = { a: 1, c: 3, b: 2 };
+    /// let obj = { b: 1, ...c, a: 2 }; // ok
+    /// ```
+    SortKeys,
+    style
+);
+
+impl Rule for SortKeys {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let obj = value.get(1);
+        let default = Self::default();
+
+        Self {
+            desc: value.get(0).and_then(serde_json::Value::as_str) == Some("desc"),
+            case_sensitive: obj
+                .and_then(|v| v.get("caseSensitive"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(default.case_sensitive),
+            natural: obj
+                .and_then(|v| v.get("natural"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(default.natural),
+            min_keys: obj
+                .and_then(|v| v.get("minKeys"))
+                .and_then(serde_json::Value::as_u64)
+                .map_or(default.min_keys, |v| usize::try_from(v).unwrap_or(default.min_keys)),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ObjectExpression(expr) = node.kind() else { return };
+        if expr.properties.len() < self.min_keys {
+            return;
+        }
+
+        let mut prev_name: Option<Atom> = None;
+        for prop in &expr.properties {
+            let ObjectPropertyKind::ObjectProperty(prop) = prop else {
+                prev_name = None;
+                continue;
+            };
+            let Some(name) = prop.key.static_name() else {
+                prev_name = None;
+                continue;
+            };
+
+            if let Some(prev) = &prev_name {
+                if !self.is_valid_order(prev, &name) {
+                    ctx.diagnostic(SortKeysDiagnostic(
+                        self.order_description(),
+                        name.clone(),
+                        prev.clone(),
+                        prop.key.span(),
+                    ));
+                }
+            }
+            prev_name = Some(name);
+        }
+    }
+}
+
+impl SortKeys {
+    fn is_valid_order(&self, prev: &str, current: &str) -> bool {
+        let ordering = if self.case_sensitive {
+            self.compare(prev, current)
+        } else {
+            self.compare(&prev.to_lowercase(), &current.to_lowercase())
+        };
+        if self.desc {
+            ordering != Ordering::Less
+        } else {
+            ordering != Ordering::Greater
+        }
+    }
+
+    fn compare(&self, a: &str, b: &str) -> Ordering {
+        if self.natural {
+            natural_compare(a, b)
+        } else {
+            a.cmp(b)
+        }
+    }
+
+    /// e.g. `natural insensitive ascending `
+    fn order_description(&self) -> String {
+        format!(
+            "{}{}{}",
+            if self.natural { "natural " } else { "" },
+            if self.case_sensitive { "" } else { "insensitive " },
+            if self.desc { "descending " } else { "ascending " },
+        )
+    }
+}
+
+/// Compares strings so that runs of digits are ordered by their numeric value.
+fn natural_compare(a: &str, b: &str) -> Ordering {
+    let mut a_chars = a.chars().peekable();
+    let mut b_chars = b.chars().peekable();
+    loop {
+        match (a_chars.peek().copied(), b_chars.peek().copied()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let take_number = |chars: &mut std::iter::Peekable<std::str::Chars>| {
+                    let mut digits = String::new();
+                    while let Some(c) = chars.next_if(char::is_ascii_digit) {
+                        digits.push(c);
+                    }
+                    digits.trim_start_matches('0').to_string()
+                };
+                let x = take_number(&mut a_chars);
+                let y = take_number(&mut b_chars);
+                let ordering = x.len().cmp(&y.len()).then_with(|| x.cmp(&y));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(x), Some(y)) => {
+                if x != y {
+                    return x.cmp(&y);
+                }
+                a_chars.next();
+                b_chars.next();
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("var obj = {_:2, a:1, b:3} // default", None),
+        ("var obj = {a:1, b:3, c:2}", None),
+        ("var obj = {a:2, b:3, b_:1}", None),
+        ("var obj = {C:3, b_:1, c:2}", None),
+        ("var obj = {$:1, A:3, _:2, a:4}", None),
+        ("var obj = {1:1, '11':2, 2:4, A:3}", None),
+        ("var obj = {'#':1, 'Z':2, À:3, è:4}", None),
+        ("var obj = { a: 1, b: 2 }", None),
+        ("var obj = {a:1, b:3, [a + b]: -1, c:2}", None),
+        ("var obj = {'':1, [``]:2}", None),
+        ("var obj = {[``]:1, '':2}", None),
+        ("var obj = {a:1, [b]:2, a:3}", None),
+        ("var obj = {b:1, [a]:2, a:3}", None),
+        ("var obj = {c:1, ...d, b:1}", None),
+        ("var obj = {b:1, ...c, a:2}", None),
+        ("var obj = {a:1, ...z, b:1}", None),
+        ("var obj = {...a, b:1, ...c, d:1}", None),
+        ("var obj = {...a, b:1, ...d, ...c, e:2, z:5}", None),
+        ("var obj = {b:1, ...c, ...d, e:2}", None),
+        ("var obj = {a:1, b:1, c(){return 1}}", None),
+        ("var obj = {a:1, b:3, c:2, ...{}}", None),
+        ("var {b, a} = obj", None),
+        ("var obj = {a:1, b:{x:1, y:1}, c:1}", None),
+        ("var obj = {a:1, b:2}", Some(json!(["asc"]))),
+        ("var obj = {a:1, B:2, c:3}", Some(json!(["asc", { "caseSensitive": false }]))),
+        ("var obj = {a:1, a2:2, a10:3}", Some(json!(["asc", { "natural": true }]))),
+        ("var obj = {b:3, a:1}", Some(json!(["desc"]))),
+        ("var obj = {c:2, B:3, a:1}", Some(json!(["desc", { "caseSensitive": false }]))),
+        ("var obj = {a10:3, a2:2, a:1}", Some(json!(["desc", { "natural": true }]))),
+        ("var obj = {c:2, b:3, a:1}", Some(json!(["asc", { "minKeys": 4 }]))),
+        ("var obj = {b:1, a:2}", Some(json!(["asc", { "minKeys": 3 }]))),
+        ("var obj = { a: 1 }", None),
+    ];
+
+    let fail = vec![
+        ("var obj = {a:1, '':2} // default", None),
+        ("var obj = {a:1, [``]:2} // default", None),
+        ("var obj = {a:1, _:2, b:3} // default", None),
+        ("var obj = {a:1, c:2, b:3}", None),
+        ("var obj = {b_:1, a:2, b:3}", None),
+        ("var obj = {b_:1, c:2, C:3}", None),
+        ("var obj = {$:1, _:2, A:3, a:4}", None),
+        ("var obj = {1:1, 2:4, A:3, '11':2}", None),
+        ("var obj = {'#':1, À:3, 'Z':2, è:4}", None),
+        ("var obj = { b: 1, a: 2 }", None),
+        ("var obj = {...z, c:1, b:1}", None),
+        ("var obj = {...z, ...c, d:4, b:1, ...y, ...f, g:9, a:5}", None),
+        ("var obj = {c:1, b:1, ...a}", None),
+        ("var obj = {...z, ...a, c:1, b:1}", None),
+        ("var obj = {...z, b:1, a:1, ...d, ...c}", None),
+        ("var obj = {a:1, b:{y:1, x:1}, c:1}", None),
+        ("var obj = {b:1, a(){}}", None),
+        ("var obj = {a:1, [b]:2, d:3, c:4}", None),
+        ("var obj = {a:1, a10:3, a2:2}", Some(json!(["asc", { "natural": true }]))),
+        ("var obj = {a:1, C:3, b:2}", Some(json!(["asc", { "caseSensitive": false }]))),
+        ("var obj = {a:1, b:2}", Some(json!(["desc"]))),
+        ("var obj = {a:1, B:3, c:2}", Some(json!(["desc", { "caseSensitive": false }]))),
+        ("var obj = {a:1, a2:2, a10:3}", Some(json!(["desc", { "natural": true }]))),
+        (
+            "var obj = {a:1, B:2, c:3}",
+            Some(json!(["desc", { "caseSensitive": false, "natural": true }])),
+        ),
+        ("var obj = {b:1, a:2, c:3}", Some(json!(["asc", { "minKeys": 3 }]))),
+    ];
+
+    Tester::new(SortKeys::NAME, pass, fail).test_and_snapshot();
+}
@@ -103,7 +103,7 @@ impl Rule for ValidTypeof {
                             sibling.span(),
                         )
                     },
-                    || Fix::new("\"undefined\"", sibling.span()),
+                    |_| Fix::new("\"undefined\"", sibling.span()),
                 );
                 return;
             }
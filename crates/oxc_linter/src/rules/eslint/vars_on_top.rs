@@ -0,0 +1,161 @@
+use oxc_ast::{
+    ast::{Declaration, ModuleDeclaration, Statement},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(vars-on-top): All 'var' declarations must be at the top of the function scope.")]
+#[diagnostic(severity(warning), help("Move this declaration to the top of its scope."))]
+struct VarsOnTopDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct VarsOnTop;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require `var` declarations be placed at the top of their containing scope.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// `var` declarations are hoisted to the top of the function or program.
+    /// Declaring them where they are used, e.g. inside of a block, hides that they are
+    /// visible in the whole function, while declaring them at the top makes it explicit.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// function doSomething() {
+    ///     if (true) {
+    ///         var first = true;
+    ///     }
+    ///     var second;
+    /// }
+    ///
+    /// // Good
+    /// function doSomething() {
+    ///     var first;
+    ///     var second;
+    ///     if (true) {
+    ///         first = true;
+    ///     }
+    /// }
+    /// ```
+    VarsOnTop,
+    style
+);
+
+impl Rule for VarsOnTop {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::VariableDeclaration(decl) = node.kind() else { return };
+        if !decl.kind.is_var() {
+            return;
+        }
+
+        let is_on_top = match ctx.nodes().parent_kind(node.id()) {
+            // `export var a;`
+            Some(AstKind::ModuleDeclaration(module_decl)) => {
+                let Some(parent) = ctx.nodes().parent_node(node.id()) else { return };
+                match ctx.nodes().parent_kind(parent.id()) {
+                    Some(AstKind::Program(program)) => {
+                        is_var_on_top(module_decl.span(), &program.body, true)
+                    }
+                    _ => false,
+                }
+            }
+            Some(AstKind::Program(program)) => is_var_on_top(decl.span, &program.body, true),
+            Some(AstKind::FunctionBody(body)) => is_var_on_top(decl.span, &body.statements, false),
+            Some(AstKind::StaticBlock(block)) => is_var_on_top(decl.span, &block.body, false),
+            _ => false,
+        };
+        if !is_on_top {
+            ctx.diagnostic(VarsOnTopDiagnostic(decl.span));
+        }
+    }
+}
+
+/// Whether the statement at `span` is only preceded by variable declarations,
+/// and imports if `skip_imports` is set. Directives are not part of `statements`.
+fn is_var_on_top(span: Span, statements: &[Statement], skip_imports: bool) -> bool {
+    statements
+        .iter()
+        .skip_while(|stmt| skip_imports && is_import(stmt))
+        .take_while(|stmt| is_variable_declaration(stmt))
+        .any(|stmt| stmt.span() == span)
+}
+
+fn is_import(stmt: &Statement) -> bool {
+    matches!(stmt, Statement::ModuleDeclaration(decl) if matches!(&**decl, ModuleDeclaration::ImportDeclaration(_)))
+}
+
+/// `var a`, `let a`, `const a` and `export var a`.
+fn is_variable_declaration(stmt: &Statement) -> bool {
+    match stmt {
+        Statement::Declaration(Declaration::VariableDeclaration(_)) => true,
+        Statement::ModuleDeclaration(decl) => matches!(
+            &**decl,
+            ModuleDeclaration::ExportNamedDeclaration(export_decl)
+                if matches!(export_decl.declaration, Some(Declaration::VariableDeclaration(_)))
+        ),
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "var first = 0;\nfunction foo() {\n    first = 2;\n}",
+        "function foo() {\n}",
+        "function foo() {\n   var first;\n   if (true) {\n       first = true;\n   } else {\n       first = 1;\n   }\n}",
+        "function foo() {\n   var first;\n   var second = 1;\n   var third;\n   var fourth = 1, fifth, sixth = third;\n   var seventh;\n   if (true) {\n       third = true;\n   }\n   first = second;\n}",
+        "function foo() {\n   var outer;\n   function inner() {\n       var inner = 1;\n       var outer = inner;\n   }\n   outer = 1;\n}",
+        "function foo() {\n   var first;\n   //Hello\n   var second = 1;\n   first = second;\n}",
+        "function foo() {\n   var first;\n   /*\n       Hello Clarice\n   */\n   var second = 1;\n   first = second;\n}",
+        "function foo() {\n   var i;\n   for (i = 0; i < 10; i++) {\n       alert(i);\n   }\n}",
+        "function foo() {\n   'use strict';\n   var x;\n   f();\n}",
+        "'use strict';\nvar x;\nf();",
+        "function foo() {\n   let x = 1;\n   var y;\n   f();\n}",
+        "const f = () => {\n   var x;\n   f();\n};",
+        "import x from 'x';\nvar a;\nf();",
+        "import x from 'x';\nimport y from 'y';\nvar a;\nf();",
+        "export var a;\nvar b;\nf();",
+        "var a;\nexport var b;\nf();",
+        "class C {\n    static {\n        var x;\n    }\n}",
+        "class C {\n    static {\n        var x;\n        foo();\n    }\n}",
+        "class C {\n    static {\n        var x;\n        var y;\n    }\n}",
+    ];
+
+    let fail = vec![
+        "var first = 0;\nfunction foo() {\n    first = 2;\n    second = 2;\n}\nvar second = 0;",
+        "function foo() {\n   var first;\n   first = 1;\n   first = 2;\n   first = 3;\n   first = 4;\n   var second = 1;\n   second = 2;\n   first = second;\n}",
+        "function foo() {\n   var first;\n   if (true) {\n       var second = true;\n   }\n   first = second;\n}",
+        "function foo() {\n   for (var i = 0; i < 10; i++) {\n       var a = 1;\n   }\n}",
+        "function foo() {\n   var first = 10;\n   var i;\n   for (i = 0; i < first; i++) {\n       var second = i;\n   }\n}",
+        "function foo() {\n   var first = 10;\n   var i;\n   switch (first) {\n       case 10:\n           var hello = 1;\n           break;\n   }\n}",
+        "function foo() {\n   var first = 10;\n   var i;\n   try {\n       var hello = 1;\n   } catch (e) {\n       alert('error');\n   }\n}",
+        "function foo() {\n   var first = 10;\n   var i;\n   label:\n   for (i = 0; i < 10; i++) {\n       var hello = 1;\n   }\n}",
+        "function foo() {\n   var first = 10;\n   while (first) {\n       var hello = 1;\n   }\n}",
+        "function foo() {\n   for (var i = 0; i < 10; i++) {}\n}",
+        "function foo() {\n   for (var x of [1, 2]) {}\n}",
+        "function foo() {\n   f();\n   var x;\n}",
+        "'use strict';\nf();\nvar x;",
+        "f();\nvar x;",
+        "import x from 'x';\nf();\nvar a;",
+        "f();\nexport var a;",
+        "if (foo) {\n   var a;\n}",
+        "class C {\n    static {\n        foo();\n        var x;\n    }\n}",
+        "class C {\n    static {\n        if (foo) {\n            var x;\n        }\n    }\n}",
+    ];
+
+    Tester::new_without_config(VarsOnTop::NAME, pass, fail).test_and_snapshot();
+}
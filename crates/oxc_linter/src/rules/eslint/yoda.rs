@@ -0,0 +1,272 @@
+use oxc_ast::{
+    ast::{BinaryExpression, Expression, LogicalExpression},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::{BinaryOperator, LogicalOperator, UnaryOperator};
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint(yoda): Expected literal to be on the {0} side of {1}.")]
+#[diagnostic(severity(warning))]
+struct YodaDiagnostic(&'static str, &'static str, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct Yoda {
+    /// Whether literals must always be on the left side of comparisons.
+    always: bool,
+    /// Whether to allow yoda conditions in range comparisons, e.g. `0 <= x && x < 10`.
+    except_range: bool,
+    /// Whether to only check the equality operators.
+    only_equality: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    /// Require or disallow "Yoda" conditions
+    ///
+    /// ### Why is this bad?
+    /// Yoda conditions put the literal value first, e.g. `if ("red" === color)`.
+    /// This reads backwards compared to the natural `if (color === "red")`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// if ("red" === color) {}
+    /// if (5 < value) {}
+    /// ```
+    Yoda,
+    style
+);
+
+impl Rule for Yoda {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let options = value.get(1);
+        let get = |key: &str| {
+            options
+                .and_then(|v| v.get(key))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default()
+        };
+        Self {
+            always: value.get(0).and_then(serde_json::Value::as_str) == Some("always"),
+            except_range: get("exceptRange"),
+            only_equality: get("onlyEquality"),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::BinaryExpression(expr) = node.kind() else { return };
+        if !(expr.operator.is_equality() || expr.operator.is_compare()) {
+            return;
+        }
+        if self.only_equality && !expr.operator.is_equality() {
+            return;
+        }
+
+        let (expected_literal, expected_non_literal) =
+            if self.always { (&expr.left, &expr.right) } else { (&expr.right, &expr.left) };
+        if !looks_like_literal(expected_non_literal) || looks_like_literal(expected_literal) {
+            return;
+        }
+
+        if self.except_range {
+            if let Some(AstKind::LogicalExpression(parent)) = ctx.nodes().parent_kind(node.id()) {
+                if is_range_test(parent, ctx) {
+                    return;
+                }
+            }
+        }
+
+        ctx.diagnostic_with_fix(
+            YodaDiagnostic(
+                if self.always { "left" } else { "right" },
+                expr.operator.as_str(),
+                expr.span,
+            ),
+            |_| Fix::new(flip(expr, ctx.source_text()), expr.span),
+        );
+    }
+}
+
+/// Swaps the operands of a comparison, flipping relational operators.
+fn flip(expr: &BinaryExpression, source_text: &str) -> String {
+    let operator = expr.operator.as_str();
+    let flipped = expr.operator.compare_inverse_operator().map_or(operator, |op| op.as_str());
+    let between = Span::new(expr.left.span().end, expr.right.span().start).source_text(source_text);
+    let offset = operator_offset(between, operator).unwrap_or(0);
+    format!(
+        "{}{}{}{}{}",
+        expr.right.span().source_text(source_text),
+        &between[..offset],
+        flipped,
+        &between[offset + operator.len()..],
+        expr.left.span().source_text(source_text),
+    )
+}
+
+/// The offset of `operator` in the text between the operands, skipping comments.
+fn operator_offset(between: &str, operator: &str) -> Option<usize> {
+    let mut offset = 0;
+    while offset < between.len() {
+        let rest = &between[offset..];
+        if rest.starts_with(operator) {
+            return Some(offset);
+        }
+        offset += if rest.starts_with("/*") {
+            rest.find("*/").map_or(rest.len(), |end| end + 2)
+        } else if rest.starts_with("//") {
+            rest.find('\n').unwrap_or(rest.len())
+        } else {
+            rest.chars().next().map_or(1, char::len_utf8)
+        };
+    }
+    None
+}
+
+fn looks_like_literal(expr: &Expression) -> bool {
+    match expr {
+        Expression::TemplateLiteral(lit) => lit.expressions.is_empty(),
+        Expression::UnaryExpression(unary) => {
+            unary.operator == UnaryOperator::UnaryNegation
+                && matches!(unary.argument, Expression::NumberLiteral(_))
+        }
+        expr => expr.is_literal(),
+    }
+}
+
+/// The value of a number literal, including negative numbers.
+fn number_value(expr: &Expression) -> Option<f64> {
+    match expr {
+        Expression::NumberLiteral(lit) => Some(lit.value),
+        Expression::UnaryExpression(unary) if unary.operator == UnaryOperator::UnaryNegation => {
+            number_value(&unary.argument).map(|value| -value)
+        }
+        _ => None,
+    }
+}
+
+/// Whether the logical expression checks that a value is inside or outside of a range,
+/// e.g. `(0 <= x && x < 10)` or `(x < 0 || 10 <= x)`, wrapped in parentheses.
+fn is_range_test(expr: &LogicalExpression, ctx: &LintContext<'_>) -> bool {
+    let (Expression::BinaryExpression(left), Expression::BinaryExpression(right)) =
+        (&expr.left, &expr.right)
+    else {
+        return false;
+    };
+    let is_range_operator =
+        |op: BinaryOperator| matches!(op, BinaryOperator::LessThan | BinaryOperator::LessEqualThan);
+    if !is_range_operator(left.operator) || !is_range_operator(right.operator) {
+        return false;
+    }
+
+    let source_text = ctx.source_text();
+    let ordered =
+        |lower: &Expression, upper: &Expression| match (number_value(lower), number_value(upper)) {
+            (None, None) => false,
+            (Some(lower), Some(upper)) => lower <= upper,
+            _ => true,
+        };
+
+    let is_between_test = expr.operator == LogicalOperator::And
+        && is_same_reference(&left.right, &right.left, source_text)
+        && ordered(&left.left, &right.right);
+    let is_outside_test = expr.operator == LogicalOperator::Or
+        && is_same_reference(&left.left, &right.right, source_text)
+        && ordered(&left.right, &right.left);
+
+    (is_between_test || is_outside_test) && is_parenthesized(expr.span, source_text)
+}
+
+/// Whether both expressions refer to the same value, e.g. `a.b` and `a["b"]`.
+fn is_same_reference(left: &Expression, right: &Expression, source_text: &str) -> bool {
+    match (left.without_parenthesized(), right.without_parenthesized()) {
+        (Expression::Identifier(left), Expression::Identifier(right)) => left.name == right.name,
+        (Expression::ThisExpression(_), Expression::ThisExpression(_)) => true,
+        (Expression::MemberExpression(left_member), Expression::MemberExpression(right_member))
+            if left_member.static_property_name().is_some() =>
+        {
+            left_member.static_property_name() == right_member.static_property_name()
+                && is_same_reference(left_member.object(), right_member.object(), source_text)
+        }
+        (left, right) => {
+            left.span().source_text(source_text) == right.span().source_text(source_text)
+        }
+    }
+}
+
+fn is_parenthesized(span: Span, source_text: &str) -> bool {
+    source_text[..span.start as usize].trim_end().ends_with('(')
+        && source_text[span.end as usize..].trim_start().starts_with(')')
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("if (value === \"red\") {}", Some(json!(["never"]))),
+        ("if (value === value) {}", Some(json!(["never"]))),
+        ("if (value != 5) {}", Some(json!(["never"]))),
+        ("if (5 & foo) {}", Some(json!(["never"]))),
+        ("if (5 === 4) {}", Some(json!(["never"]))),
+        ("if (value === `red`) {}", Some(json!(["never"]))),
+        ("if (`red` === `red`) {}", Some(json!(["never"]))),
+        ("if (`${foo}` === `red`) {}", Some(json!(["never"]))),
+        ("if (x <= -1) {}", None),
+        ("y === \"x\"", None),
+        ("if (\"blue\" === value) {}", Some(json!(["always"]))),
+        ("if (-1 < str.indexOf(substr)) {}", Some(json!(["always"]))),
+        ("if (0 < x && x <= 1) {}", Some(json!(["never", { "exceptRange": true }]))),
+        ("if (x < 0 || 1 <= x) {}", Some(json!(["never", { "exceptRange": true }]))),
+        (
+            "if (a < 4 || (b[c[0]].d['e'] < 0 || 1 <= b[c[0]].d['e'])) {}",
+            Some(json!(["never", { "exceptRange": true }])),
+        ),
+        ("if (0 <= a.b && a[\"b\"] <= 100) {}", Some(json!(["never", { "exceptRange": true }]))),
+        ("if (-1 < x && x < 0) {}", Some(json!(["never", { "exceptRange": true }]))),
+        ("if (1 < a && a <= 2) {}", Some(json!(["never", { "exceptRange": true }]))),
+        ("if (value <= MIN || 10 < value) {}", Some(json!(["never", { "exceptRange": true }]))),
+        ("if (x < 10) {}", Some(json!(["never", { "onlyEquality": true }]))),
+        ("if (10 < x) {}", Some(json!(["never", { "onlyEquality": true }]))),
+    ];
+
+    let fail = vec![
+        ("if (\"red\" == value) {}", Some(json!(["never"]))),
+        ("\"x\" === y", None),
+        ("if (true === value) {}", Some(json!(["never"]))),
+        ("if (5 != value) {}", Some(json!(["never"]))),
+        ("if (5n != value) {}", Some(json!(["never"]))),
+        ("if (null !== value) {}", Some(json!(["never"]))),
+        ("if (\"red\" <= value) {}", Some(json!(["never"]))),
+        ("if (`red` <= value) {}", Some(json!(["never"]))),
+        ("if (-1 < str.indexOf(substr)) {}", Some(json!(["never"]))),
+        ("if (value == \"red\") {}", Some(json!(["always"]))),
+        ("if (value === 5) {}", Some(json!(["always"]))),
+        ("if (x <= 'foo' || 'a' < x) {}", Some(json!(["always", { "exceptRange": true }]))),
+        ("if (0 < x && x <= 1) {}", Some(json!(["never"]))),
+        ("if (0 <= x && x < 1 && y) {}", Some(json!(["never", { "exceptRange": true }]))),
+        ("if (10 === x) {}", Some(json!(["never", { "onlyEquality": true }]))),
+    ];
+
+    let fix = vec![
+        ("if (\"red\" == value) {}", "if (value == \"red\") {}", Some(json!(["never"]))),
+        ("\"x\" === y", "y === \"x\"", None),
+        ("if (5 < value) {}", "if (value > 5) {}", None),
+        ("if (5 <= value) {}", "if (value >= 5) {}", None),
+        ("if (value === 5) {}", "if (5 === value) {}", Some(json!(["always"]))),
+        ("if (value > 5) {}", "if (5 < value) {}", Some(json!(["always"]))),
+        ("5 /* < */ < value", "value /* < */ > 5", None),
+        ("5 // <\n < value", "value // <\n > 5", None),
+        ("'a' /* === */ === /* == */ b", "b /* === */ === /* == */ 'a'", None),
+    ];
+
+    Tester::new(Yoda::NAME, pass, fail).expect_fix(fix).test_and_snapshot();
+}
@@ -0,0 +1,138 @@
+use oxc_ast::{
+    ast::{ModuleDeclaration, Statement},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum FirstDiagnostic {
+    #[error("eslint-plugin-import(first): Import in body of module; reorder to top.")]
+    #[diagnostic(severity(warning))]
+    ImportInBody(#[label] Span),
+    #[error("eslint-plugin-import(first): Absolute imports should come before relative imports.")]
+    #[diagnostic(severity(warning))]
+    AbsoluteAfterRelative(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct First {
+    /// Whether absolute imports must come before relative imports, `"absolute-first"`.
+    absolute_first: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Ensure all imports appear before other statements.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Imports are hoisted, so an import in the middle of a module is evaluated
+    /// before the code above it, which is misleading. Directives like `'use strict'`
+    /// may precede the imports.
+    ///
+    /// ### Example
+    ///
+    /// ```javascript
+    /// // fail
+    /// import foo from './foo';
+    /// initWith(foo);
+    /// import bar from './bar';
+    /// // pass
+    /// import foo from './foo';
+    /// import bar from './bar';
+    /// initWith(foo);
+    /// ```
+    First,
+    style
+);
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/first.md>
+impl Rule for First {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            absolute_first: value.get(0).and_then(serde_json::Value::as_str)
+                == Some("absolute-first"),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::Program(program) = node.kind() else { return };
+
+        // Directives are not part of the program body, but the parser also treats string
+        // statements between imports as directives, e.g. `import a from 'a'; 'use foo';`.
+        let body_start = program.body.first().map_or(u32::MAX, |stmt| stmt.span().start);
+        let misplaced_directive = program
+            .directives
+            .iter()
+            .map(|directive| directive.span.start)
+            .find(|&start| start > body_start);
+
+        let mut non_import_seen = false;
+        let mut relative_seen = false;
+        for stmt in &program.body {
+            let Statement::ModuleDeclaration(decl) = stmt else {
+                non_import_seen = true;
+                continue;
+            };
+            let ModuleDeclaration::ImportDeclaration(decl) = &**decl else {
+                non_import_seen = true;
+                continue;
+            };
+
+            if non_import_seen || misplaced_directive.is_some_and(|start| start < decl.span.start) {
+                ctx.diagnostic(FirstDiagnostic::ImportInBody(decl.span));
+                continue;
+            }
+            if self.absolute_first {
+                if decl.source.value.starts_with('.') {
+                    relative_seen = true;
+                } else if relative_seen {
+                    ctx.diagnostic(FirstDiagnostic::AbsoluteAfterRelative(decl.source.span));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("import { x } from './foo'; import { y } from './bar';\nexport { x, y }", None),
+        ("import { x } from 'foo'; import { y } from './bar'", None),
+        ("import { x } from './foo'; import { y } from 'bar'", None),
+        (
+            "import { x } from './foo'; import { y } from 'bar'",
+            Some(json!(["disable-absolute-first"])),
+        ),
+        ("'use directive';\nimport { x } from 'foo';", None),
+        ("'use strict';\n'use client';\nimport { x } from 'foo';\nx();", None),
+        ("import { x } from 'foo'; import { y } from './bar'", Some(json!(["absolute-first"]))),
+        ("function f() { import('./foo'); }", None),
+    ];
+
+    let fail = vec![
+        ("export { x, y } from './foo';\nimport { z } from './bar';\nvar a = 1;\nimport { w } from './baz';", None),
+        ("var a = 1;\nimport { y } from './bar';\nif (true) { x() };\nimport { x } from './foo';\nimport { z } from './baz'", None),
+        ("import { x } from './foo'; import { y } from 'bar'", Some(json!(["absolute-first"]))),
+        ("import { x } from 'foo';\n'use directive';\nimport { y } from 'bar';", None),
+        ("var a = 1;\nimport { y } from './bar';", None),
+        ("if (true) { console.log(1) }import a from 'b'", None),
+    ];
+
+    Tester::new(First::NAME, pass, fail)
+        .change_rule_path("first.js")
+        .with_import_plugin(true)
+        .test_and_snapshot();
+}
@@ -0,0 +1,109 @@
+use oxc_ast::{ast::ModuleDeclaration, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+
+use crate::{context::LintContext, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoDefaultExportDiagnostic {
+    #[error("eslint-plugin-import(no-default-export): Prefer named exports.")]
+    #[diagnostic(severity(warning))]
+    DefaultExport(#[label] Span),
+    #[error("eslint-plugin-import(no-default-export): Do not alias `{0}` as `default`.")]
+    #[diagnostic(severity(warning), help("Just export `{0}` itself instead."))]
+    AliasedDefault(Atom, #[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDefaultExport;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Forbid default exports.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Named exports make it easier to find where a value is used and keep names
+    /// consistent across a codebase, since a default export can be imported under
+    /// any name.
+    ///
+    /// ### Example
+    ///
+    /// ```javascript
+    /// // fail
+    /// export default function foo() {}
+    /// export { foo as default };
+    /// // pass
+    /// export const foo = 'foo';
+    /// export { foo as bar };
+    /// ```
+    NoDefaultExport,
+    restriction
+);
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-default-export.md>
+impl Rule for NoDefaultExport {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::ModuleDeclaration(decl) = node.kind() else { return };
+        match decl {
+            ModuleDeclaration::ExportDefaultDeclaration(decl) => {
+                ctx.diagnostic(NoDefaultExportDiagnostic::DefaultExport(decl.span));
+            }
+            ModuleDeclaration::ExportNamedDeclaration(decl) => {
+                for specifier in &decl.specifiers {
+                    if specifier.exported.name().as_str() == "default" {
+                        ctx.diagnostic(NoDefaultExportDiagnostic::AliasedDefault(
+                            specifier.local.name().clone(),
+                            specifier.span,
+                        ));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "module.exports = function foo() {}",
+        "module.exports = function foo() {}; module.exports.bar = function bar() {};",
+        "export const foo = 'foo';",
+        "export function foo() {};",
+        "const foo = 'foo'; export { foo };",
+        "const foo = 'foo'; export { foo as bar };",
+        "export const foo = 'foo'; export function bar() {};",
+        "export { foo, bar } from './foo';",
+        "export { default as foo } from './foo';",
+        "export * from './foo';",
+        "export class Foo {}",
+        "let foo; export { foo as 'default-name' };",
+        "import foo from './foo';",
+    ];
+
+    let fail = vec![
+        "export default function bar() {};",
+        "export const foo = 'foo';\nexport default bar;",
+        "export default class Bar {};",
+        "export default function() {};",
+        "export default class {};",
+        "export default 'foo';",
+        "let foo; export { foo as default }",
+        "export { default } from './foo';",
+        "export { foo as default } from './foo';",
+        "let foo; export { foo as 'default' }",
+    ];
+
+    Tester::new_without_config(NoDefaultExport::NAME, pass, fail)
+        .change_rule_path("no-default-export.js")
+        .with_import_plugin(true)
+        .test_and_snapshot();
+}
@@ -0,0 +1,233 @@
+use oxc_ast::{
+    ast::{ImportDeclaration, ImportDeclarationSpecifier, ModuleDeclaration, Statement},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+use rustc_hash::FxHashMap;
+
+use crate::{context::LintContext, fixer::Fix, rule::Rule, AstNode};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-import(no-duplicates): '{0}' imported multiple times.")]
+#[diagnostic(severity(warning), help("Merge these imports into a single import statement."))]
+struct NoDuplicatesDiagnostic(Atom, #[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoDuplicates {
+    /// Whether `./foo?a` and `./foo?b` are considered different modules.
+    consider_query_string: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Forbid importing the same module in multiple places.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Several import statements for the same module are harder to read and maintain
+    /// than a single one. Type-only imports and namespace imports are grouped
+    /// separately, since they cannot always be merged with other imports.
+    ///
+    /// ### Example
+    ///
+    /// ```javascript
+    /// // fail
+    /// import { x } from './foo';
+    /// import { y } from './foo';
+    /// // pass
+    /// import { x, y } from './foo';
+    /// import type { Z } from './foo';
+    /// ```
+    NoDuplicates,
+    style
+);
+
+/// <https://github.com/import-js/eslint-plugin-import/blob/main/docs/rules/no-duplicates.md>
+impl Rule for NoDuplicates {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            consider_query_string: value
+                .get(0)
+                .and_then(|v| v.get("considerQueryString"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::Program(program) = node.kind() else { return };
+
+        // Grouped by module, whether the imports are type-only, and whether they are namespace imports.
+        let mut groups: FxHashMap<(&str, bool, bool), Vec<&ImportDeclaration>> =
+            FxHashMap::default();
+        for stmt in &program.body {
+            let Statement::ModuleDeclaration(decl) = stmt else { continue };
+            let ModuleDeclaration::ImportDeclaration(decl) = &**decl else { continue };
+            let source = decl.source.value.as_str();
+            let module = if self.consider_query_string {
+                source
+            } else {
+                source.split_once('?').map_or(source, |(module, _)| module)
+            };
+            let is_namespace = decl.specifiers.iter().flatten().any(|specifier| {
+                matches!(specifier, ImportDeclarationSpecifier::ImportNamespaceSpecifier(_))
+            });
+            groups
+                .entry((module, decl.import_kind.is_type(), is_namespace))
+                .or_default()
+                .push(decl);
+        }
+
+        for imports in groups.values() {
+            let [first, duplicates @ ..] = imports.as_slice() else { continue };
+            // The fix merges all duplicates at once, so it is only attached to the first one.
+            let mut fix = merge_imports(first, duplicates, ctx.source_text());
+            for duplicate in duplicates {
+                let diagnostic =
+                    NoDuplicatesDiagnostic(duplicate.source.value.clone(), duplicate.source.span);
+                match fix.take() {
+                    Some(fix) => ctx.diagnostic_with_fix(diagnostic, |_| fix),
+                    None => ctx.diagnostic(diagnostic),
+                }
+            }
+        }
+    }
+}
+
+/// Rewrites the first import to include the specifiers of the duplicates, and removes the duplicates.
+fn merge_imports<'a>(
+    first: &ImportDeclaration,
+    duplicates: &[&ImportDeclaration],
+    source_text: &'a str,
+) -> Option<Fix<'a>> {
+    let mut default = None;
+    let mut named = vec![];
+    for import in std::iter::once(first).chain(duplicates.iter().copied()) {
+        let clause = &source_text[import.span.start as usize..import.source.span.start as usize];
+        if clause.contains("//") || clause.contains("/*") {
+            return None;
+        }
+        for specifier in import.specifiers.iter().flatten() {
+            match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                    named.push(specifier.span.source_text(source_text));
+                }
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                    // Two different default imports can not be merged.
+                    if default.replace(specifier.span.source_text(source_text)).is_some() {
+                        return None;
+                    }
+                }
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => return None,
+            }
+        }
+    }
+
+    let mut content =
+        String::from(if first.import_kind.is_type() { "import type " } else { "import " });
+    if let Some(default) = default {
+        content.push_str(default);
+        content.push_str(if named.is_empty() { " " } else { ", " });
+    }
+    if !named.is_empty() {
+        content.push_str("{ ");
+        content.push_str(&named.join(", "));
+        content.push_str(" } ");
+    }
+    if default.is_some() || !named.is_empty() {
+        content.push_str("from ");
+    }
+
+    let mut last_end = first.source.span.start as usize;
+    for duplicate in duplicates {
+        content.push_str(&source_text[last_end..duplicate.span.start as usize]);
+        last_end = duplicate.span.end as usize;
+        // Remove the line of the duplicate as well.
+        if source_text[last_end..].starts_with('\n') {
+            last_end += 1;
+        }
+    }
+
+    let end = u32::try_from(last_end).ok()?;
+    Some(Fix::new(content, Span::new(first.span.start, end)))
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("import './malformed.js'", None),
+        ("import { x } from './foo'; import { y } from './bar'", None),
+        ("import foo from \"234artaf\"; import { shoop } from \"234q25ad\"", None),
+        ("import { x } from './foo'; export { x } from './foo'", None),
+        ("import type { x } from './foo'; import y from './foo'", None),
+        ("import type x from './foo'; import type { y } from './bar'", None),
+        ("import * as ns from './foo'; import { y } from './foo'", None),
+        ("import { x } from './foo'; import * as ns from './foo'", None),
+        (
+            "import x from './bar?optionX'; import y from './bar?optionY';",
+            Some(json!([{ "considerQueryString": true }])),
+        ),
+        (
+            "import x from './foo'; import y from './bar?optionY';",
+            Some(json!([{ "considerQueryString": true }])),
+        ),
+        ("function f() { import('./foo'); import('./foo'); }", None),
+    ];
+
+    let fail = vec![
+        ("import { x } from './foo'; import { y } from './foo'", None),
+        ("import { x } from './foo'; import { y } from './foo'; import { z } from './foo'", None),
+        ("import x from './bar?optionX'; import y from './bar?optionY';", None),
+        (
+            "import x from './bar?optionX'; import y from './bar?optionX';",
+            Some(json!([{ "considerQueryString": true }])),
+        ),
+        ("import x from './bar'; import y from './bar';", None),
+        ("import type { x } from './foo'; import type { y } from './foo'", None),
+        ("import * as ns1 from './foo'; import * as ns2 from './foo'", None),
+        ("import def from './foo'; import { x } from './foo'", None),
+        ("import './foo'; import { x } from './foo'", None),
+        ("import { x } from './foo'; import './foo'", None),
+        ("import { x, /* comment */ y } from './foo'; import { z } from './foo'", None),
+    ];
+
+    let fix = vec![
+        (
+            "import { x } from './foo';\nimport { y } from './foo';",
+            "import { x, y } from './foo';\n",
+            None,
+        ),
+        (
+            "import { x } from './foo';\nimport { y } from './foo';\nimport { z } from './foo';\nfoo();",
+            "import { x, y, z } from './foo';\nfoo();",
+            None,
+        ),
+        (
+            "import def from './foo';\nimport { x as y } from './foo';",
+            "import def, { x as y } from './foo';\n",
+            None,
+        ),
+        ("import './foo';\nimport { x } from './foo';", "import { x } from './foo';\n", None),
+        (
+            "import type { x } from './foo';\nconst a = 1;\nimport type { y } from './foo';",
+            "import type { x, y } from './foo';\nconst a = 1;\n",
+            None,
+        ),
+    ];
+
+    Tester::new(NoDuplicates::NAME, pass, fail)
+        .expect_fix(fix)
+        .change_rule_path("no-duplicates.ts")
+        .with_import_plugin(true)
+        .test_and_snapshot();
+}
@@ -1,3 +1,5 @@
+use std::path::{Component, Path, PathBuf};
+
 use oxc_diagnostics::{
     miette::{self, Diagnostic},
     thiserror::Error,
@@ -36,10 +38,16 @@ impl Rule for NoSelfImport {
         let module_record = ctx.semantic().module_record();
         let resolved_absolute_path = &module_record.resolved_absolute_path;
         for (request, spans) in &module_record.requested_modules {
-            let Some(remote_module_record_ref) = module_record.loaded_modules.get(request) else {
-                continue;
-            };
-            if remote_module_record_ref.value().resolved_absolute_path == *resolved_absolute_path {
+            // Fall back to the file path when the module could not be resolved,
+            // e.g. because the file is not on disk.
+            let is_self_import = module_record.loaded_modules.get(request).map_or_else(
+                || is_relative_self_import(ctx.file_path(), request),
+                |remote_module_record_ref| {
+                    remote_module_record_ref.value().resolved_absolute_path
+                        == *resolved_absolute_path
+                },
+            );
+            if is_self_import {
                 for span in spans {
                     ctx.diagnostic(NoSelfImportDiagnostic(*span));
                 }
@@ -48,6 +56,38 @@ impl Rule for NoSelfImport {
     }
 }
 
+/// Resolves a relative import lexically against the file path, e.g. `./foo`, `./foo.js`
+/// or `.` for `foo/index.js`, without looking at the file system.
+fn is_relative_self_import(file_path: &Path, request: &str) -> bool {
+    if !request.starts_with('.') {
+        return false;
+    }
+    let Some(file_path) = normalize(file_path) else { return false };
+    let Some(dir) = file_path.parent() else { return false };
+    let Some(resolved) = normalize(&dir.join(request)) else { return false };
+
+    resolved == file_path
+        || resolved == file_path.with_extension("")
+        || (file_path.file_stem().is_some_and(|stem| stem == "index") && resolved == dir)
+}
+
+/// Removes the `.` and `..` components of `path`, `None` when `..` goes above its root.
+fn normalize(path: &Path) -> Option<PathBuf> {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    return None;
+                }
+            }
+            component => normalized.push(component),
+        }
+    }
+    Some(normalized)
+}
+
 #[test]
 fn test() {
     use crate::tester::Tester;
@@ -104,6 +144,21 @@ fn test() {
         tester.test();
     }
 
+    {
+        // `self.js` is not on disk, so the imports are resolved against the file path only.
+        let pass =
+            vec!["import x from './other'", "import x from '../self'", "import x from 'self'"];
+        let fail = vec![
+            "import x from './self'",
+            "import x from './self.js'",
+            "import x from '../import/self'",
+            "export { x } from './self'",
+        ];
+
+        tester = tester.change_rule_path("self.js").update_expect_pass_fail(pass, fail);
+        tester.test();
+    }
+
     {
         let pass = vec![];
         let fail = vec!["var bar = require('../no-self-import-folder')"];
@@ -114,3 +169,12 @@ fn test() {
         tester.test_and_snapshot();
     }
 }
+
+#[test]
+fn test_relative_file_path() {
+    assert!(is_relative_self_import(Path::new("a.js"), "./a"));
+    assert!(is_relative_self_import(Path::new("src/a.js"), "../src/a.js"));
+    assert!(!is_relative_self_import(Path::new("a.js"), "../a"));
+    assert!(!is_relative_self_import(Path::new("src/a.js"), "../../src/a"));
+    assert!(!is_relative_self_import(Path::new("/a.js"), "../a"));
+}
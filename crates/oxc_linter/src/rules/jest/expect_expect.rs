@@ -13,7 +13,7 @@ use regex::Regex;
 use crate::{
     ast_util::get_declaration_of_variable,
     context::LintContext,
-    rule::Rule,
+    rule::{InvalidRegexOption, Rule},
     utils::{
         collect_possible_jest_call_node, get_node_name, is_type_of_jest_fn_call, JestFnKind,
         JestGeneralFnKind, PossibleJestNode,
@@ -27,14 +27,15 @@ struct ExpectExpectDiagnostic(#[label] pub Span);
 
 #[derive(Debug, Clone)]
 pub struct ExpectExpect {
-    assert_function_names: Vec<String>,
+    /// Compiled from the `assertFunctionNames` option.
+    assert_function_names: Vec<Regex>,
     additional_test_block_functions: Vec<String>,
 }
 
 impl Default for ExpectExpect {
     fn default() -> Self {
         Self {
-            assert_function_names: vec![String::from("expect")],
+            assert_function_names: vec![Regex::new(&convert_pattern("expect")).unwrap()],
             additional_test_block_functions: vec![],
         }
     }
@@ -63,15 +64,29 @@ declare_oxc_lint!(
 
 impl Rule for ExpectExpect {
     fn from_configuration(value: serde_json::Value) -> Self {
-        let default_assert_function_names = vec![String::from("expect")];
+        Self::try_from_configuration(value).unwrap_or_default()
+    }
+
+    fn try_from_configuration(value: serde_json::Value) -> Result<Self, oxc_diagnostics::Error> {
         let config = value.get(0);
 
-        let assert_function_names = config
+        let assert_function_names = match config
             .and_then(|config| config.get("assertFunctionNames"))
             .and_then(serde_json::Value::as_array)
-            .map_or(default_assert_function_names, |v| {
-                v.iter().filter_map(serde_json::Value::as_str).map(convert_pattern).collect()
-            });
+        {
+            Some(names) => names
+                .iter()
+                .filter_map(serde_json::Value::as_str)
+                .map(|name| {
+                    Regex::new(&convert_pattern(name)).map_err(|error| InvalidRegexOption {
+                        option: "assertFunctionNames",
+                        pattern: name.to_string(),
+                        error,
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?,
+            None => Self::default().assert_function_names,
+        };
 
         let additional_test_block_functions = config
             .and_then(|config| config.get("additionalTestBlockFunctions"))
@@ -81,8 +96,9 @@ impl Rule for ExpectExpect {
             })
             .unwrap_or_default();
 
-        Self { assert_function_names, additional_test_block_functions }
+        Ok(Self { assert_function_names, additional_test_block_functions })
     }
+
     fn run_once(&self, ctx: &LintContext) {
         for possible_jest_node in &collect_possible_jest_call_node(ctx) {
             run(self, possible_jest_node, ctx);
@@ -125,7 +141,7 @@ fn run<'a>(
 
 fn check_arguments<'a>(
     call_expr: &'a CallExpression<'a>,
-    assert_function_names: &[String],
+    assert_function_names: &[Regex],
     ctx: &LintContext<'a>,
 ) -> bool {
     call_expr.arguments.iter().any(|argument| {
@@ -138,7 +154,7 @@ fn check_arguments<'a>(
 
 fn check_assert_function_used<'a>(
     expr: &'a Expression<'a>,
-    assert_function_names: &[String],
+    assert_function_names: &[Regex],
     ctx: &LintContext<'a>,
 ) -> bool {
     match expr {
@@ -185,7 +201,7 @@ fn check_assert_function_used<'a>(
 
 fn check_statements<'a>(
     statements: &'a oxc_allocator::Vec<Statement<'a>>,
-    assert_function_names: &[String],
+    assert_function_names: &[Regex],
     ctx: &LintContext<'a>,
 ) -> bool {
     statements.iter().any(|statement| {
@@ -197,8 +213,8 @@ fn check_statements<'a>(
 }
 
 /// Checks if node names returned by getNodeName matches any of the given star patterns
-fn matches_assert_function_name(name: &str, patterns: &[String]) -> bool {
-    patterns.iter().any(|pattern| Regex::new(pattern).unwrap().is_match(name))
+fn matches_assert_function_name(name: &str, patterns: &[Regex]) -> bool {
+    patterns.iter().any(|pattern| pattern.is_match(name))
 }
 
 fn convert_pattern(pattern: &str) -> String {
@@ -464,3 +480,23 @@ fn test() {
 
     Tester::new(ExpectExpect::NAME, pass, fail).with_jest_plugin(true).test_and_snapshot();
 }
+
+#[test]
+fn test_invalid_assert_function_name() {
+    use serde_json::json;
+
+    use crate::rules::RULES;
+
+    let err = ExpectExpect::try_from_configuration(
+        json!([{ "assertFunctionNames": ["expect", "request.(.expect"] }]),
+    )
+    .unwrap_err();
+    assert!(err.to_string().starts_with(
+        "Invalid regular expression `request.(.expect` in the `assertFunctionNames` option"
+    ));
+
+    let rule = RULES.iter().find(|rule| rule.name() == ExpectExpect::NAME).unwrap();
+    let err =
+        rule.read_json(Some(json!([{ "assertFunctionNames": ["request.(.expect"] }]))).unwrap_err();
+    assert_eq!(err.to_string(), "Invalid configuration for rule `expect-expect`");
+}
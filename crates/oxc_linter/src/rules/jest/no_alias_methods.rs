@@ -82,7 +82,7 @@ fn run<'a>(possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<'a>)
 
                 ctx.diagnostic_with_fix(
                     NoAliasMethodsDiagnostic(name, canonical_name, matcher.span),
-                    || Fix::new(canonical_name, Span { start, end }),
+                    |_| Fix::new(canonical_name, Span { start, end }),
                 );
             }
         }
@@ -8,7 +8,7 @@ use oxc_span::{GetSpan, Span};
 use phf::{phf_map, Map};
 use std::borrow::Cow;
 
-use crate::{context::LintContext, fixer::Fix, rule::Rule};
+use crate::{context::LintContext, rule::Rule};
 
 #[derive(Debug, Error, Diagnostic)]
 #[error("eslint-plugin-jest(no-deprecated-functions): Disallow use of deprecated functions")]
@@ -115,7 +115,7 @@ impl Rule for NoDeprecatedFunctions {
             if jest_version_num >= *base_version {
                 ctx.diagnostic_with_fix(
                     DeprecatedFunction(node_name, (*replacement).to_string(), mem_expr.span()),
-                    || Fix::new(*replacement, mem_expr.span()),
+                    |fixer| fixer.replace_text(mem_expr, *replacement),
                 );
             }
         }
@@ -47,6 +47,9 @@ declare_oxc_lint!(
     /// it('bar');
     /// test('bar');
     ///
+    /// it.todo('bar');
+    /// test.todo('bar');
+    ///
     /// it('foo', () => {
     ///   pending();
     /// });
@@ -67,6 +70,7 @@ enum Message {
     DisabledSuiteWithX,
     DisabledTestWithSkip,
     DisabledTestWithX,
+    TodoTest,
 }
 
 impl Message {
@@ -78,6 +82,7 @@ impl Message {
             Self::DisabledSuiteWithX => ("Disabled test suite", "Remove x prefix"),
             Self::DisabledTestWithSkip => ("Disabled test", "Remove the appending `.skip`"),
             Self::DisabledTestWithX => ("Disabled test", "Remove x prefix"),
+            Self::TodoTest => ("Todo test", "Implement the test, or remove it"),
         }
     }
 }
@@ -130,6 +135,15 @@ fn run<'a>(possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<'a>)
                     Message::DisabledTestWithSkip.details()
                 };
                 ctx.diagnostic(NoDisabledTestsDiagnostic(error, help, call_expr.callee.span()));
+                return;
+            }
+
+            // `it.todo('foo')`
+            if matches!(kind, JestGeneralFnKind::Test)
+                && members.iter().any(|member| member.is_name_equal("todo"))
+            {
+                let (error, help) = Message::TodoTest.details();
+                ctx.diagnostic(NoDisabledTestsDiagnostic(error, help, call_expr.callee.span()));
             }
         } else if let Expression::Identifier(ident) = &call_expr.callee {
             if ident.name.as_str() == "pending"
@@ -158,7 +172,6 @@ fn test() {
         ("test.only('foo', function () {})", None),
         ("test.concurrent('foo', function () {})", None),
         ("describe[`${'skip'}`]('foo', function () {})", None),
-        ("it.todo('fill this later')", None),
         ("var appliedSkip = describe.skip; appliedSkip.apply(describe)", None),
         ("var calledSkip = it.skip; calledSkip.call(it)", None),
         ("({ f: function () {} }).f()", None),
@@ -213,6 +226,8 @@ fn test() {
         ("pending()", None),
         ("describe('contains a call to pending', function () { pending() })", None),
         ("import { test } from '@jest/globals';test('something');", None),
+        ("it.todo('fill this later')", None),
+        ("test.todo('fill this later')", None),
     ];
 
     Tester::new(NoDisabledTests::NAME, pass, fail).with_jest_plugin(true).test_and_snapshot();
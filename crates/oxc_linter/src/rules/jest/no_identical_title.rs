@@ -92,8 +92,11 @@ impl Rule for NoIdenticalTitle {
                     Some((*span, *kind, *parent))
                 })
                 .collect::<Vec<(Span, JestFnKind, AstNodeId)>>();
-            // After being sorted by parent_id, the span with the same parent will be placed nearby.
-            kind_and_spans.sort_by(|a, b| a.2.cmp(&b.2));
+            // After being sorted by parent_id and kind, the span with the same parent and kind
+            // will be placed nearby, e.g. both tests in `it('foo'); describe('foo'); it('foo');`.
+            kind_and_spans.sort_by_key(|(_, kind, parent_id)| {
+                (*parent_id, matches!(kind, JestFnKind::General(JestGeneralFnKind::Describe)))
+            });
 
             // Skip the first element, for `describe('foo'); describe('foo');`, we only need to check the second one.
             for i in 1..kind_and_spans.len() {
@@ -138,7 +141,7 @@ fn filter_and_process_jest_result<'a>(
         return None;
     };
 
-    match call_expr.arguments.get(0) {
+    match call_expr.arguments.first() {
         Some(Argument::Expression(Expression::StringLiteral(string_lit))) => {
             Some((string_lit.span, &string_lit.value, kind, parent_id))
         }
@@ -368,6 +371,14 @@ fn test() {
     ];
 
     let fail = vec![
+        (
+            "
+              it('foo', () => {});
+              describe('foo', () => {});
+              it('foo', () => {});
+            ",
+            None,
+        ),
         (
             "
               describe('foo', () => {
@@ -89,7 +89,7 @@ fn diagnostic_assign_expr<'a>(expr: &'a AssignmentExpression<'a>, ctx: &LintCont
             if let Expression::NumberLiteral(number_literal) = &expr.right {
                 ctx.diagnostic_with_fix(
                     NoJasmineGlobalsDiagnostic(COMMON_ERROR_TEXT, COMMON_HELP_TEXT, span),
-                    || {
+                    |_| {
                         let content = format!("jest.setTimeout({})", number_literal.value);
                         Fix::new(content, expr.span)
                     },
@@ -120,7 +120,7 @@ fn diagnostic_call_expr<'a>(expr: &'a CallExpression<'a>, ctx: &LintContext) {
             |jasmine_property| {
                 let (error, help) = jasmine_property.details();
                 if jasmine_property.available_in_jest_expect() {
-                    ctx.diagnostic_with_fix(NoJasmineGlobalsDiagnostic(error, help, span), || {
+                    ctx.diagnostic_with_fix(NoJasmineGlobalsDiagnostic(error, help, span), |_| {
                         Fix::new("expect", member_expr.object().span())
                     });
                 } else {
@@ -86,7 +86,7 @@ fn run<'a>(possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<'a>)
     let preferred_node_name = get_preferred_node_names(&jest_fn_call);
     let preferred_node_name_cloned = preferred_node_name.clone();
 
-    ctx.diagnostic_with_fix(NoTestPrefixesDiagnostic(preferred_node_name, span), || {
+    ctx.diagnostic_with_fix(NoTestPrefixesDiagnostic(preferred_node_name, span), |_| {
         Fix::new(preferred_node_name_cloned.to_string(), span)
     });
 }
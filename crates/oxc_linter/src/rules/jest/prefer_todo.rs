@@ -82,11 +82,11 @@ fn run<'a>(possible_jest_node: &PossibleJestNode<'a, '_>, ctx: &LintContext<'a>)
 
         if counts == 1 && !filter_todo_case(call_expr) {
             let (content, span) = get_fix_content(call_expr);
-            ctx.diagnostic_with_fix(UmImplementedTestDiagnostic(span), || Fix::new(content, span));
+            ctx.diagnostic_with_fix(UmImplementedTestDiagnostic(span), |_| Fix::new(content, span));
         }
 
         if counts > 1 && is_empty_function(call_expr) {
-            ctx.diagnostic_with_fix(EmptyTest(call_expr.span), || {
+            ctx.diagnostic_with_fix(EmptyTest(call_expr.span), |_| {
                 let (content, span) = build_code(call_expr, ctx);
                 Fix::new(content, span)
             });
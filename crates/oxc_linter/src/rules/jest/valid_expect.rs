@@ -131,24 +131,15 @@ impl ValidExpect {
         let Some(Expression::CallExpression(call_expr)) = jest_fn_call.head.parent else { return };
 
         if call_expr.arguments.len() < self.min_args {
-            let error = Atom::from(format!(
-                "Expect takes at most {} argument{} ",
-                self.min_args,
-                if self.min_args > 1 { "s" } else { "" }
-            ));
-            let help = "Remove the extra arguments.";
+            let (error, help) = Message::NotEnoughArgs(self.min_args).details();
             ctx.diagnostic(ValidExpectDiagnostic(error, help, call_expr.span));
-            return;
         }
         if call_expr.arguments.len() > self.max_args {
-            let error = Atom::from(format!(
-                "Expect requires at least {} argument{} ",
-                self.max_args,
-                if self.max_args > 1 { "s" } else { "" }
-            ));
-            let help = "Add the missing arguments.";
-            ctx.diagnostic(ValidExpectDiagnostic(error, help, call_expr.span));
-            return;
+            let (error, help) = Message::TooManyArgs(self.max_args).details();
+            let first_extra_arg = &call_expr.arguments[self.max_args];
+            let last_arg = &call_expr.arguments[call_expr.arguments.len() - 1];
+            let span = Span::new(first_extra_arg.span().start, last_arg.span().end);
+            ctx.diagnostic(ValidExpectDiagnostic(error, help, span));
         }
 
         let Some(matcher) = jest_fn_call.matcher() else {
@@ -346,6 +337,8 @@ enum Message {
     ModifierUnknown,
     AsyncMustBeAwaited,
     PromisesWithAsyncAssertionsMustBeAwaited,
+    NotEnoughArgs(usize),
+    TooManyArgs(usize),
 }
 
 impl Message {
@@ -369,6 +362,20 @@ impl Message {
                 Atom::from("Promises which return async assertions must be awaited."),
                 "Add `await` to your assertion.",
             ),
+            Self::NotEnoughArgs(amount) => (
+                Atom::from(format!(
+                    "Expect requires at least {amount} argument{}.",
+                    if amount == 1 { "" } else { "s" }
+                )),
+                "Add the missing arguments.",
+            ),
+            Self::TooManyArgs(amount) => (
+                Atom::from(format!(
+                    "Expect takes at most {amount} argument{}.",
+                    if amount == 1 { "" } else { "s" }
+                )),
+                "Remove the extra arguments.",
+            ),
         }
     }
 }
@@ -12,7 +12,7 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::utils::{get_literal_prop_value, get_prop_value, has_jsx_prop_lowercase};
+use crate::utils::{get_prop_value, get_string_literal_prop_value, has_jsx_prop_lowercase};
 use crate::{context::LintContext, rule::Rule, AstNode};
 
 #[derive(Debug, Error, Diagnostic)]
@@ -185,7 +185,8 @@ impl Rule for AltText {
         if let Some(custom_tags) = &self.input_type_image {
             let has_input_with_type_image = name.to_lowercase() == "input"
                 && has_jsx_prop_lowercase(jsx_el, "type")
-                    .map_or(false, |v| get_literal_prop_value(v).map_or(false, |v| v == "image"));
+                    .and_then(get_string_literal_prop_value)
+                    .is_some_and(|v| v == "image");
             if has_input_with_type_image || custom_tags.iter().any(|i| i == name) {
                 input_type_image_rule(jsx_el, ctx);
             }
@@ -205,13 +206,16 @@ fn is_valid_alt_prop(item: &JSXAttributeItem<'_>) -> bool {
 }
 
 fn is_presentation_role<'a>(item: &'a JSXAttributeItem<'a>) -> bool {
-    get_literal_prop_value(item).map_or(false, |value| value == "presentation" || value == "none")
+    get_string_literal_prop_value(item)
+        .is_some_and(|value| value == "presentation" || value == "none")
 }
 
 fn aria_label_has_value<'a>(item: &'a JSXAttributeItem<'a>) -> bool {
+    if let Some(value) = get_string_literal_prop_value(item) {
+        return !value.is_empty();
+    }
     match get_prop_value(item) {
         None => false,
-        Some(JSXAttributeValue::StringLiteral(s)) if s.value.is_empty() => false,
         Some(JSXAttributeValue::ExpressionContainer(JSXExpressionContainer {
             expression: JSXExpression::Expression(expr),
             ..
@@ -286,8 +290,8 @@ fn object_rule<'a>(
         has_jsx_prop_lowercase(node, "aria-labelledby").map_or(false, aria_label_has_value);
     let has_label = has_aria_label || has_aria_labeledby;
     let has_title_attr = has_jsx_prop_lowercase(node, "title")
-        .and_then(get_literal_prop_value)
-        .map_or(false, |v| !v.is_empty());
+        .and_then(get_string_literal_prop_value)
+        .is_some_and(|v| !v.is_empty());
 
     if has_label || has_title_attr || object_has_accessible_child(parent) {
         return;
@@ -391,6 +395,7 @@ fn test() {
         (r"<object><p>This is descriptive!</p></object>", None),
         (r"<Object />", None),
         (r#"<object title="An object" />"#, None),
+        (r#"<object title={"An object"} />"#, None),
         (r#"<area aria-label="foo" />"#, None),
         (r#"<area aria-labelledby="id1" />"#, None),
         (r#"<area alt="" />"#, None),
@@ -470,6 +475,7 @@ fn test() {
         (r"<img aria-label={undefined} />", None),
         (r"<img aria-labelledby={undefined} />", None),
         (r#"<img aria-label="" />"#, None),
+        (r"<img aria-label={``} />", None),
         (r#"<img aria-labelledby="" />"#, None),
         // TODO: When polymorphic components are supported
         // (r#"<SomeComponent as="img" aria-label="" />"#, None),
@@ -477,6 +483,7 @@ fn test() {
         (r"<object><div aria-hidden /></object>", None),
         (r"<object title={undefined} />", None),
         (r#"<object aria-label="" />"#, None),
+        (r"<object title={``} />", None),
         (r#"<object aria-labelledby="" />"#, None),
         (r"<object aria-label={undefined} />", None),
         (r"<object aria-labelledby={undefined} />", None),
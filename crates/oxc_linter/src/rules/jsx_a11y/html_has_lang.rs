@@ -11,7 +11,12 @@ use oxc_diagnostics::{
 use oxc_macros::declare_oxc_lint;
 use oxc_span::Span;
 
-use crate::{context::LintContext, rule::Rule, utils::has_jsx_prop_lowercase, AstNode};
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_prop_value, get_string_literal_prop_value, has_jsx_prop_lowercase},
+    AstNode,
+};
 
 #[derive(Debug, Default, Clone)]
 pub struct HtmlHasLang;
@@ -51,21 +56,15 @@ enum HtmlHasLangDiagnostic {
     MissingLangValue(#[label] Span),
 }
 
-fn get_prop_value<'a, 'b>(item: &'b JSXAttributeItem<'a>) -> Option<&'b JSXAttributeValue<'a>> {
-    if let JSXAttributeItem::Attribute(attr) = item {
-        attr.0.value.as_ref()
-    } else {
-        None
-    }
-}
-
 fn is_valid_lang_prop(item: &JSXAttributeItem) -> bool {
+    if let Some(value) = get_string_literal_prop_value(item) {
+        return !value.is_empty();
+    }
     match get_prop_value(item) {
         Some(JSXAttributeValue::ExpressionContainer(JSXExpressionContainer {
             expression: JSXExpression::Expression(expr),
             ..
         })) => !expr.is_undefined(),
-        Some(JSXAttributeValue::StringLiteral(str)) => !str.value.as_str().is_empty(),
         _ => true,
     }
 }
@@ -104,6 +103,7 @@ fn test() {
         (r#"<html lang="en" />"#, None),
         (r#"<html lang="en-US" />"#, None),
         (r"<html lang={foo} />;", None),
+        (r#"<html lang={"en"} />"#, None),
         (r"<html lang />;", None),
         (r"<HTML />;", None),
         // TODO: When polymorphic components are supported
@@ -115,6 +115,8 @@ fn test() {
         (r"<html {...props} />;", None),
         (r"<html lang={undefined} />;", None),
         (r#"<html lang="" />;"#, None),
+        (r#"<html lang={""} />;"#, None),
+        (r"<html lang={``} />;", None),
         // TODO: When polymorphic components are supported
         // (r"<HTMLTop />;", None),
     ];
@@ -0,0 +1,159 @@
+use oxc_ast::{ast::JSXElementName, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        get_string_array_option, get_string_literal_prop_value, has_jsx_prop_lowercase,
+        is_hidden_from_screen_reader,
+    },
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-jsx-a11y(img-redundant-alt): Redundant alt attribute.")]
+#[diagnostic(
+    severity(warning),
+    help("Screen-readers already announce `img` tags as an image. You don't need to use the words `image`, `photo`, or `picture` (or any specified custom words) in the alt prop.")
+)]
+struct ImgRedundantAltDiagnostic(#[label] pub Span);
+
+const REDUNDANT_WORDS: [&str; 3] = ["image", "photo", "picture"];
+
+#[derive(Debug, Clone)]
+pub struct ImgRedundantAlt {
+    /// Custom components to check in addition to `img`.
+    components: Vec<String>,
+    /// Lowercased words which are redundant in an alt text.
+    words: Vec<String>,
+}
+
+impl Default for ImgRedundantAlt {
+    fn default() -> Self {
+        Self { components: vec![], words: REDUNDANT_WORDS.map(String::from).to_vec() }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce `img` alt attributes do not contain the words `image`, `photo`, or `picture`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Screen readers already announce `img` elements as an image,
+    /// so there is no need to use words such as `image`, `photo`, or `picture` in the alt text.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// <img src="foo" alt="Photo of foo being weird." />
+    /// <img src="bar" alt="Image of me at a bar!" />
+    ///
+    /// // Good
+    /// <img src="foo" alt="Foo eating a sandwich." />
+    /// <img src="bar" aria-hidden alt="Picture of me taking a photo of an image" />
+    /// ```
+    ImgRedundantAlt,
+    correctness
+);
+
+impl Rule for ImgRedundantAlt {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let mut rule = Self::default();
+        let Some(config) = value.get(0) else { return rule };
+        rule.components.extend(get_string_array_option(config, "components"));
+        rule.words.extend(
+            get_string_array_option(config, "words").iter().map(|word| word.to_lowercase()),
+        );
+        rule
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_el) = node.kind() else { return };
+        let JSXElementName::Identifier(ident) = &jsx_el.name else { return };
+        let name = ident.name.as_str();
+        if name != "img" && !self.components.iter().any(|component| component == name) {
+            return;
+        }
+        if is_hidden_from_screen_reader(jsx_el) {
+            return;
+        }
+
+        let Some(alt_prop) = has_jsx_prop_lowercase(jsx_el, "alt") else { return };
+        // Dynamic alt values are skipped.
+        let Some(alt) = get_string_literal_prop_value(alt_prop) else { return };
+        let alt = alt.to_lowercase();
+        if self.words.iter().any(|word| contains_word(&alt, word)) {
+            ctx.diagnostic(ImgRedundantAltDiagnostic(alt_prop.span()));
+        }
+    }
+}
+
+/// Whether `word` appears in `text` as a whole word.
+fn contains_word(text: &str, word: &str) -> bool {
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
+    text.match_indices(word).any(|(start, _)| {
+        let end = start + word.len();
+        !text[..start].chars().next_back().is_some_and(is_word_char)
+            && !text[end..].chars().next().is_some_and(is_word_char)
+    })
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r#"<img alt="foo" />;"#, None),
+        (r#"<img alt="A cat" />;"#, None),
+        (r#"<img alt="picture of me taking a photo of an image" aria-hidden />"#, None),
+        (r#"<img aria-hidden="true" alt="photo of image" />"#, None),
+        (r#"<img ALt="foo" />;"#, None),
+        (r#"<img {...this.props} alt="foo" />"#, None),
+        (r#"<img {...this.props} alt="foo" aria-hidden />"#, None),
+        (r"<img alt={`this should pass for ${now}`} />", None),
+        (r"<img alt={`this should pass for ${photo}`} />", None),
+        (r"<img alt={imageAlt} />", None),
+        (r"<img alt={imageAlt.photo} />", None),
+        (r"<img alt={true} />", None),
+        (r#"<img alt="" />"#, None),
+        (r"<img alt />", None),
+        (r#"<img alt="Photography" />"#, None),
+        (r#"<img alt="ImageMagick logo" />"#, None),
+        (r#"<img alt="this is lo-res" />"#, None),
+        (r#"<Image alt="Photo of a friend" />"#, None),
+        (r#"<div alt="Photo of a friend" />"#, None),
+        (r"<UX.Layout>test</UX.Layout>", None),
+        (r#"<img alt="Word12" />"#, Some(json!([{ "words": ["Word1"] }]))),
+    ];
+
+    let fail = vec![
+        (r#"<img alt="Photo of a cat" />;"#, None),
+        (r#"<img alt="Photo of friend." />;"#, None),
+        (r#"<img alt="Picture of friend." />;"#, None),
+        (r#"<img alt="Image of friend." />;"#, None),
+        (r#"<img alt="PhOtO of friend." />;"#, None),
+        (r#"<img alt={"photo"} />;"#, None),
+        (r"<img alt={`picture`} />;", None),
+        (r#"<img alt="piCTUre of friend." />;"#, None),
+        (r#"<img alt="imAGE of friend." />;"#, None),
+        (r#"<img alt="photo of cool person" aria-hidden="false" />"#, None),
+        (r#"<img alt="photo" {...this.props} />"#, None),
+        (r#"<img alt="my-photo" />"#, None),
+        (r#"<img alt="Word1" />;"#, Some(json!([{ "words": ["Word1"] }]))),
+        (r#"<img alt="Word2 of friend" />;"#, Some(json!([{ "words": ["Word1", "Word2"] }]))),
+        (r#"<img alt="Photo of friend" />;"#, Some(json!([{ "words": ["Word1"] }]))),
+        (r#"<Image alt="Photo of a friend" />"#, Some(json!([{ "components": ["Image"] }]))),
+    ];
+
+    Tester::new(ImgRedundantAlt::NAME, pass, fail).with_jsx_a11y_plugin(true).test_and_snapshot();
+}
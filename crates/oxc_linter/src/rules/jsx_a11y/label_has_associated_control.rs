@@ -0,0 +1,296 @@
+use oxc_ast::{
+    ast::{JSXChild, JSXElement, JSXElementName, JSXExpression, JSXOpeningElement},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        get_prop_value, get_string_array_option, get_string_literal_prop_value, has_jsx_prop,
+        has_jsx_prop_lowercase,
+    },
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum LabelHasAssociatedControlDiagnostic {
+    #[error("eslint-plugin-jsx-a11y(label-has-associated-control): A form label must be associated with a control.")]
+    #[diagnostic(
+        severity(warning),
+        help("Either give the label a `htmlFor` attribute with the id of the associated control, or wrap the label around the control.")
+    )]
+    MissingControl(#[label] Span),
+
+    #[error("eslint-plugin-jsx-a11y(label-has-associated-control): A form label must have accessible text.")]
+    #[diagnostic(severity(warning), help("Add text content or an `aria-label` to the label."))]
+    MissingText(#[label] Span),
+}
+
+const DEFAULT_CONTROL_COMPONENTS: [&str; 6] =
+    ["input", "meter", "output", "progress", "select", "textarea"];
+
+/// Maximum value of the `depth` option.
+const MAX_DEPTH: u64 = 25;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Assert {
+    HtmlFor,
+    Nesting,
+    Both,
+    #[default]
+    Either,
+}
+
+#[derive(Debug, Clone)]
+pub struct LabelHasAssociatedControl {
+    assert: Assert,
+    /// How deep to look into the children of a label for a control or accessible text.
+    depth: u64,
+    /// Custom components which are labels, in addition to `label`.
+    label_components: Vec<String>,
+    /// Custom attributes which label a label, in addition to `aria-label` and `aria-labelledby`.
+    label_attributes: Vec<String>,
+    /// Components which are form controls.
+    control_components: Vec<String>,
+}
+
+impl Default for LabelHasAssociatedControl {
+    fn default() -> Self {
+        Self {
+            assert: Assert::default(),
+            depth: 2,
+            label_components: vec![],
+            label_attributes: vec![],
+            control_components: DEFAULT_CONTROL_COMPONENTS.map(String::from).to_vec(),
+        }
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce that a label tag has a text label and an associated control.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A label which is not associated with a control is not announced by screen readers
+    /// when the control is focused, and clicking it does not focus the control.
+    /// A label is associated with a control either by wrapping it around the control,
+    /// or by pointing its `htmlFor` attribute at the id of the control.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// <label>Surname</label>
+    /// <input type="text" />
+    ///
+    /// // Good
+    /// <label>Surname <input type="text" /></label>
+    /// <label htmlFor="surname">Surname</label>
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// - `assert`: `"htmlFor"`, `"nesting"`, `"both"` or `"either"` (default) to require
+    ///   the label to use `htmlFor`, to wrap the control, to do both or to do either.
+    /// - `depth`: How deep to look into the children of a label (default 2, at most 25).
+    /// - `labelComponents`: Custom components which are labels.
+    /// - `labelAttributes`: Custom attributes which give a label accessible text.
+    /// - `controlComponents`: Custom components which are form controls.
+    LabelHasAssociatedControl,
+    correctness
+);
+
+impl Rule for LabelHasAssociatedControl {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let mut rule = Self::default();
+        let Some(config) = value.get(0) else { return rule };
+        rule.label_components.extend(get_string_array_option(config, "labelComponents"));
+        rule.label_attributes.extend(get_string_array_option(config, "labelAttributes"));
+        rule.control_components.extend(get_string_array_option(config, "controlComponents"));
+        rule.assert = match config.get("assert").and_then(serde_json::Value::as_str) {
+            Some("htmlFor") => Assert::HtmlFor,
+            Some("nesting") => Assert::Nesting,
+            Some("both") => Assert::Both,
+            _ => Assert::Either,
+        };
+        if let Some(depth) = config.get("depth").and_then(serde_json::Value::as_u64) {
+            rule.depth = depth.min(MAX_DEPTH);
+        }
+        rule
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXElement(jsx_el) = node.kind() else { return };
+        let Some(name) = element_name(&jsx_el.opening_element) else { return };
+        if name != "label" && !self.label_components.iter().any(|component| component == name) {
+            return;
+        }
+
+        if !self.has_accessible_label(jsx_el) {
+            ctx.diagnostic(LabelHasAssociatedControlDiagnostic::MissingText(
+                jsx_el.opening_element.span,
+            ));
+            return;
+        }
+
+        let has_html_for = has_jsx_prop(&jsx_el.opening_element, "htmlFor").is_some();
+        let has_nested_control = self.has_nested_control(&jsx_el.children, 1);
+        let is_associated = match self.assert {
+            Assert::HtmlFor => has_html_for,
+            Assert::Nesting => has_nested_control,
+            Assert::Both => has_html_for && has_nested_control,
+            Assert::Either => has_html_for || has_nested_control,
+        };
+        if !is_associated {
+            ctx.diagnostic(LabelHasAssociatedControlDiagnostic::MissingControl(
+                jsx_el.opening_element.span,
+            ));
+        }
+    }
+}
+
+impl LabelHasAssociatedControl {
+    /// Whether a control may be rendered within `depth` levels of `children`.
+    /// Expression containers are assumed to render one.
+    fn has_nested_control(&self, children: &[JSXChild], depth: u64) -> bool {
+        if depth > self.depth {
+            return false;
+        }
+        children.iter().any(|child| match child {
+            JSXChild::ExpressionContainer(container) => {
+                matches!(container.expression, JSXExpression::Expression(_))
+            }
+            JSXChild::Element(el) => {
+                element_name(&el.opening_element)
+                    .is_some_and(|name| self.control_components.iter().any(|c| c == name))
+                    || self.has_nested_control(&el.children, depth + 1)
+            }
+            JSXChild::Fragment(fragment) => self.has_nested_control(&fragment.children, depth + 1),
+            JSXChild::Text(_) | JSXChild::Spread(_) => false,
+        })
+    }
+
+    fn has_accessible_label(&self, jsx_el: &JSXElement) -> bool {
+        self.has_label_attribute(&jsx_el.opening_element)
+            || self.has_accessible_child(&jsx_el.children, 1)
+    }
+
+    fn has_label_attribute(&self, jsx_el: &JSXOpeningElement) -> bool {
+        ["aria-label", "aria-labelledby"]
+            .into_iter()
+            .chain(self.label_attributes.iter().map(String::as_str))
+            .filter_map(|attr| has_jsx_prop_lowercase(jsx_el, attr))
+            .any(|attr| {
+                get_prop_value(attr).is_some()
+                    && get_string_literal_prop_value(attr).map_or(true, |value| !value.is_empty())
+            })
+    }
+
+    /// Whether text may be rendered within `depth` levels of `children`.
+    fn has_accessible_child(&self, children: &[JSXChild], depth: u64) -> bool {
+        if depth > self.depth {
+            return false;
+        }
+        children.iter().any(|child| match child {
+            JSXChild::Text(text) => !text.value.trim().is_empty(),
+            JSXChild::ExpressionContainer(container) => {
+                matches!(container.expression, JSXExpression::Expression(_))
+            }
+            JSXChild::Element(el) => {
+                self.has_label_attribute(&el.opening_element)
+                    || self.has_accessible_child(&el.children, depth + 1)
+            }
+            JSXChild::Fragment(fragment) => {
+                self.has_accessible_child(&fragment.children, depth + 1)
+            }
+            JSXChild::Spread(_) => false,
+        })
+    }
+}
+
+fn element_name<'a>(jsx_el: &'a JSXOpeningElement) -> Option<&'a str> {
+    let JSXElementName::Identifier(ident) = &jsx_el.name else { return None };
+    Some(ident.name.as_str())
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let html_for = || Some(json!([{ "assert": "htmlFor" }]));
+    let nesting = || Some(json!([{ "assert": "nesting" }]));
+    let both = || Some(json!([{ "assert": "both" }]));
+
+    let pass = vec![
+        (r"<div />", None),
+        (r#"<label htmlFor="js_id">A label</label>"#, None),
+        (r#"<label htmlFor="js_id" aria-label="A label" />"#, None),
+        (r#"<label htmlFor="js_id" aria-labelledby="A label" />"#, None),
+        (r"<label>A label<input /></label>", None),
+        (r"<label>A label<textarea /></label>", None),
+        (r"<label><img alt='A label' aria-label='A label' /><input /></label>", None),
+        (r"<label>{children}</label>", None),
+        (r#"<label htmlFor="js_id">{label}</label>"#, None),
+        (r"<label><span>A label<input /></span></label>", None),
+        (r#"<label htmlFor="js_id">A label</label>"#, html_for()),
+        (
+            r#"<label htmlFor="js_id"><span><span>A label</span></span></label>"#,
+            Some(json!([{ "assert": "htmlFor", "depth": 3 }])),
+        ),
+        (r"<label>A label<input /></label>", nesting()),
+        (r"<label><span>A label<input /></span></label>", nesting()),
+        (r#"<label htmlFor="js_id">A label<input /></label>"#, both()),
+        (
+            r"<label><span><span><span>A label<input /></span></span></span></label>",
+            Some(json!([{ "assert": "nesting", "depth": 4 }])),
+        ),
+        (
+            r"<label>A label<CustomInput /></label>",
+            Some(json!([{ "controlComponents": ["CustomInput"] }])),
+        ),
+        (
+            r#"<CustomLabel htmlFor="js_id">A label</CustomLabel>"#,
+            Some(json!([{ "labelComponents": ["CustomLabel"] }])),
+        ),
+        (
+            r#"<label htmlFor="js_id" label="A label" />"#,
+            Some(json!([{ "labelAttributes": ["label"] }])),
+        ),
+        (r"<CustomLabel />", None),
+    ];
+
+    let fail = vec![
+        (r"<label />", None),
+        (r"<label></label>", None),
+        (r"<label>   </label>", None),
+        (r"<label>{/* comment */}</label>", None),
+        (r#"<label htmlFor="js_id" />"#, None),
+        (r#"<label htmlFor="js_id" aria-label="" />"#, None),
+        (r"<label>A label</label>", None),
+        (r"<label><span><span>A label</span></span></label>", None),
+        (r"<label>A label<input /></label>", html_for()),
+        (r#"<label htmlFor="js_id">A label</label>"#, nesting()),
+        (r"<label><span><span><input /></span></span>A label</label>", nesting()),
+        (r#"<label htmlFor="js_id">A label</label>"#, both()),
+        (r"<label>A label<input /></label>", both()),
+        (r"<label>A label<CustomInput /></label>", nesting()),
+        (
+            r"<CustomLabel>A label</CustomLabel>",
+            Some(json!([{ "labelComponents": ["CustomLabel"] }])),
+        ),
+    ];
+
+    Tester::new(LabelHasAssociatedControl::NAME, pass, fail)
+        .with_jsx_a11y_plugin(true)
+        .test_and_snapshot();
+}
@@ -0,0 +1,124 @@
+use oxc_ast::{
+    ast::{JSXAttributeValue, JSXExpression, JSXExpressionContainer},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_jsx_attribute_name, get_prop_value},
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-jsx-a11y(no-access-key): No access key attribute allowed.")]
+#[diagnostic(
+    severity(warning),
+    help("Remove the accessKey attribute. Inconsistencies between keyboard shortcuts and keyboard commands used by screenreaders and keyboard-only users create a11y complications.")
+)]
+struct NoAccessKeyDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct NoAccessKey;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce that the `accessKey` prop is not used on any element.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Access keys are HTML attributes that allow web developers to assign keyboard shortcuts to elements.
+    /// Inconsistencies between keyboard shortcuts and keyboard commands used by screen readers
+    /// and keyboard-only users create accessibility complications.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// <div accessKey="h" />
+    ///
+    /// // Good
+    /// <div />
+    /// ```
+    NoAccessKey,
+    correctness
+);
+
+impl Rule for NoAccessKey {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_el) = node.kind() else { return };
+
+        let mut prev_end = jsx_el
+            .type_parameters
+            .as_ref()
+            .map_or_else(|| jsx_el.name.span().end, |params| params.span.end);
+        for attr in &jsx_el.attributes {
+            let span = attr.span();
+            let start = prev_end;
+            prev_end = span.end;
+
+            if !get_jsx_attribute_name(attr)
+                .is_some_and(|name| name.eq_ignore_ascii_case("accesskey"))
+            {
+                continue;
+            }
+            // `accessKey={undefined}` is the same as not setting it.
+            if let Some(JSXAttributeValue::ExpressionContainer(JSXExpressionContainer {
+                expression: JSXExpression::Expression(expr),
+                ..
+            })) = get_prop_value(attr)
+            {
+                if expr.is_undefined() {
+                    continue;
+                }
+            }
+
+            // Remove the attribute together with the whitespace before it.
+            ctx.diagnostic_with_fix(NoAccessKeyDiagnostic(span), |fixer| {
+                fixer.remove(Span::new(start, span.end))
+            });
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r"<div />;", None),
+        (r"<div {...props} />", None),
+        (r"<div accessKey={undefined} />", None),
+        (r#"<div data-accessKey="h" />"#, None),
+    ];
+
+    let fail = vec![
+        (r#"<div accesskey="h" />"#, None),
+        (r#"<div accessKey="h" />"#, None),
+        (r#"<div accessKey="h" {...props} />"#, None),
+        (r#"<div acCesSKeY="y" />"#, None),
+        (r#"<div accessKey={"y"} />"#, None),
+        (r"<div accessKey={`${y}`} />", None),
+        (r"<div accessKey={`${undefined}y${undefined}`} />", None),
+        (r"<div accessKey={`This is ${bad}`} />", None),
+        (r"<div accessKey={accessKey} />", None),
+        (r"<div accessKey />", None),
+    ];
+
+    let fix = vec![
+        (r#"<div accessKey="h" />"#, r"<div />", None),
+        (r#"<div id="a" accessKey={key}>foo</div>"#, r#"<div id="a">foo</div>"#, None),
+        (r#"<Foo<T> accessKey="h" {...props} />"#, r"<Foo<T> {...props} />", None),
+    ];
+
+    Tester::new(NoAccessKey::NAME, pass, fail)
+        .expect_fix(fix)
+        .with_jsx_a11y_plugin(true)
+        .test_and_snapshot();
+}
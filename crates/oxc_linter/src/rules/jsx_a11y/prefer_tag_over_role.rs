@@ -0,0 +1,105 @@
+use oxc_ast::{ast::JSXElementName, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{get_string_literal_prop_value, has_jsx_prop_lowercase, ROLE_TO_ELEMENT},
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-jsx-a11y(prefer-tag-over-role): Prefer `{1}` over the `{2}` role.")]
+#[diagnostic(
+    severity(warning),
+    help("Use `{1}` instead of the `{2}` role to ensure accessibility across all devices.")
+)]
+struct PreferTagOverRoleDiagnostic(#[label] pub Span, &'static str, String);
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferTagOverRole;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforces using semantic HTML elements over the `role` attribute.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Native HTML elements come with built-in semantics, keyboard interaction and focus handling,
+    /// which have to be reimplemented by hand when a generic element is given a `role`.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// <div role="button" />
+    /// <div role="checkbox" />
+    ///
+    /// // Good
+    /// <button />
+    /// <input type="checkbox" />
+    /// ```
+    PreferTagOverRole,
+    pedantic
+);
+
+impl Rule for PreferTagOverRole {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_el) = node.kind() else { return };
+        let JSXElementName::Identifier(ident) = &jsx_el.name else { return };
+        let Some(role_prop) = has_jsx_prop_lowercase(jsx_el, "role") else { return };
+        // Only literal roles are checked.
+        let Some(roles) = get_string_literal_prop_value(role_prop) else { return };
+
+        // Report the first role which has a semantic element, fallback roles included.
+        let Some((role, (element, suggestion))) = roles.split_whitespace().find_map(|role| {
+            let role = role.to_lowercase();
+            ROLE_TO_ELEMENT.get(role.as_str()).map(|entry| (role, entry))
+        }) else {
+            return;
+        };
+        if ident.name == *element {
+            return;
+        }
+        ctx.diagnostic(PreferTagOverRoleDiagnostic(role_prop.span(), suggestion, role));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r"<div />;", None),
+        (r"<div role />;", None),
+        (r"<div role={role} />;", None),
+        (r#"<div role="" />;"#, None),
+        (r#"<div role="grid" />;"#, None),
+        (r#"<div role="presentation" />;"#, None),
+        (r#"<div role="unknown" />;"#, None),
+        (r"<button />;", None),
+        (r#"<button role="button" />;"#, None),
+        (r#"<input type="checkbox" role="checkbox" />;"#, None),
+        (r#"<Foo.Bar role="button" />;"#, None),
+    ];
+
+    let fail = vec![
+        (r#"<div role="button" />;"#, None),
+        (r#"<div role="BUTTON" />;"#, None),
+        (r#"<div role={"button"} />;"#, None),
+        (r#"<div role="checkbox" />;"#, None),
+        (r#"<span role="link" />;"#, None),
+        (r#"<div role="img" />;"#, None),
+        (r#"<div role="heading button" />;"#, None),
+        (r#"<div role="tabpanel  checkbox" />;"#, None),
+        (r#"<div role="navigation" />;"#, None),
+        (r#"<Foo role="button" />;"#, None),
+    ];
+
+    Tester::new(PreferTagOverRole::NAME, pass, fail).with_jsx_a11y_plugin(true).test_and_snapshot();
+}
@@ -0,0 +1,149 @@
+use oxc_ast::{
+    ast::{JSXAttributeItem, JSXAttributeName, JSXElementName},
+    AstKind,
+};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::{self, Error},
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{Atom, Span};
+
+use crate::{
+    context::LintContext,
+    rule::Rule,
+    utils::{
+        get_implicit_role, get_literal_prop_value, has_jsx_prop_lowercase,
+        is_aria_prop_supported_by_role, VALID_ARIA_PROPS,
+    },
+    AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum RoleSupportsAriaPropsDiagnostic {
+    #[error("eslint-plugin-jsx-a11y(role-supports-aria-props): The attribute {1} is not supported by the role {2}.")]
+    #[diagnostic(severity(warning), help("Try to remove invalid attribute {1}."))]
+    Explicit(#[label] Span, Atom, String),
+
+    #[error("eslint-plugin-jsx-a11y(role-supports-aria-props): The {1} element with the implicit role of {2} does not support the attribute {3}.")]
+    #[diagnostic(severity(warning), help("Try to remove invalid attribute {3}."))]
+    Implicit(#[label] Span, Atom, String, Atom),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct RoleSupportsAriaProps;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce that elements with explicit or implicit roles defined contain only `aria-*` properties supported by that `role`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Many ARIA attributes (states and properties) can only be used on elements with particular roles.
+    /// Some elements have implicit roles, such as `<a href="#" />`, which will resolve to `role="link"`.
+    /// Assistive technologies may ignore or misinterpret an unsupported attribute.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// // Bad
+    /// <span role="link" aria-checked="true" />
+    /// <a href="#" aria-checked />
+    ///
+    /// // Good
+    /// <span role="checkbox" aria-checked="true" />
+    /// <a href="#" aria-expanded />
+    /// ```
+    RoleSupportsAriaProps,
+    correctness
+);
+
+impl Rule for RoleSupportsAriaProps {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_el) = node.kind() else { return };
+
+        let (role, is_implicit) = if let Some(role_prop) = has_jsx_prop_lowercase(jsx_el, "role") {
+            // Only literal roles are checked.
+            let Some(role) = get_literal_prop_value(role_prop) else { return };
+            (role.to_lowercase(), false)
+        } else {
+            let Some(role) = get_implicit_role(jsx_el) else { return };
+            (role.to_string(), true)
+        };
+
+        for attr in &jsx_el.attributes {
+            let JSXAttributeItem::Attribute(attr) = attr else { continue };
+            let JSXAttributeName::Identifier(ident) = &attr.name else { continue };
+            let prop = ident.name.to_lowercase();
+            if !VALID_ARIA_PROPS.contains(prop.as_str()) {
+                continue;
+            }
+            // Unknown roles are left to `aria-role`.
+            if is_aria_prop_supported_by_role(&role, &prop) != Some(false) {
+                continue;
+            }
+
+            if is_implicit {
+                let JSXElementName::Identifier(el_name) = &jsx_el.name else { return };
+                ctx.diagnostic(RoleSupportsAriaPropsDiagnostic::Implicit(
+                    attr.span,
+                    el_name.name.clone(),
+                    role.clone(),
+                    ident.name.clone(),
+                ));
+            } else {
+                ctx.diagnostic(RoleSupportsAriaPropsDiagnostic::Explicit(
+                    attr.span,
+                    ident.name.clone(),
+                    role.clone(),
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r"<Foo bar />", None),
+        (r"<div />", None),
+        (r#"<div id="main" />"#, None),
+        (r"<div role />", None),
+        (r#"<div role="presentation" {...props} />"#, None),
+        (r"<Foo.Bar baz={true} />", None),
+        (r#"<span role="checkbox" aria-checked="true" />"#, None),
+        (r#"<span role="link" aria-label="link" />"#, None),
+        (r#"<div role={role} aria-checked="true" />"#, None),
+        (r#"<div role="unknown-role" aria-checked="true" />"#, None),
+        (r#"<span role="link" aria-foobar="true" />"#, None),
+        (r#"<div aria-checked="true" />"#, None),
+        (r#"<a href="/" aria-expanded />"#, None),
+        (r"<a aria-checked />", None),
+        (r#"<input type="checkbox" aria-checked />"#, None),
+        (r#"<input type="radio" aria-posinset="1" />"#, None),
+        (r"<input aria-required />", None),
+        (r#"<h1 aria-level="1" />"#, None),
+        (r#"<li aria-setsize="3" />"#, None),
+        (r#"<progress aria-valuenow="50" />"#, None),
+        (r"<button aria-pressed />", None),
+    ];
+
+    let fail = vec![
+        (r#"<span role="link" aria-checked="true" />"#, None),
+        (r#"<div role="LINK" aria-checked="true" />"#, None),
+        (r#"<div role="heading" aria-pressed />"#, None),
+        (r#"<a href="/" aria-checked />"#, None),
+        (r#"<area href="/" aria-checked />"#, None),
+        (r#"<input type="checkbox" aria-level="1" />"#, None),
+        (r"<input aria-checked />", None),
+        (r"<h1 aria-checked />", None),
+        (r"<ul aria-expanded />", None),
+        (r#"<button aria-level="1" />"#, None),
+    ];
+
+    Tester::new(RoleSupportsAriaProps::NAME, pass, fail)
+        .with_jsx_a11y_plugin(true)
+        .test_and_snapshot();
+}
@@ -0,0 +1,149 @@
+use oxc_ast::{ast::Statement, AstKind};
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    ast_util::outermost_paren_parent, context::LintContext, rule::Rule,
+    rules::eslint::array_callback_return::return_checker::check_function_body,
+    utils::get_promise_method_call, AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+#[error("eslint-plugin-promise(always-return): Each then() should return a value or throw.")]
+#[diagnostic(severity(warning))]
+struct AlwaysReturnDiagnostic(#[label] pub Span);
+
+#[derive(Debug, Default, Clone)]
+pub struct AlwaysReturn {
+    /// Whether to ignore the callback of a `then()` whose result is not used,
+    /// e.g. the last `then()` of a chain.
+    ignore_last_callback: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Require returning a value or throwing inside the callback of `then()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A `then()` callback which does not return resolves the next promise in the chain
+    /// with `undefined`. This is usually a mistake, e.g. a forgotten `return` before a
+    /// nested promise, which also breaks the error handling of the chain.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// myPromise.then(function (val) {});
+    /// myPromise.then(() => {
+    ///   doSomething();
+    /// });
+    /// myPromise.then((b) => {
+    ///   if (b) {
+    ///     return 'yes';
+    ///   } else {
+    ///     forgotToReturn();
+    ///   }
+    /// });
+    /// ```
+    AlwaysReturn,
+    pedantic
+);
+
+impl Rule for AlwaysReturn {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            ignore_last_callback: value
+                .get(0)
+                .and_then(|v| v.get("ignoreLastCallback"))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let (body, span) = match node.kind() {
+            // An expression body always returns.
+            AstKind::ArrowExpression(arrow) if !arrow.expression => (&arrow.body, arrow.span),
+            AstKind::Function(func) => {
+                let Some(body) = &func.body else { return };
+                (body, func.span)
+            }
+            _ => return,
+        };
+        let Some(call_node) = get_promise_method_call(node, "then", ctx) else { return };
+
+        if self.ignore_last_callback
+            && outermost_paren_parent(call_node, ctx)
+                .map_or(false, |parent| matches!(parent.kind(), AstKind::ExpressionStatement(_)))
+        {
+            return;
+        }
+
+        // The body also never falls through when it ends with a `throw`.
+        if check_function_body(body).must_return()
+            || matches!(body.statements.last(), Some(Statement::ThrowStatement(_)))
+        {
+            return;
+        }
+
+        ctx.diagnostic(AlwaysReturnDiagnostic(span));
+    }
+}
+
+#[test]
+fn test() {
+    use serde_json::json;
+
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("hey.then(x => x)", None),
+        ("hey.then(x => ({}))", None),
+        ("hey.then(x => { return; })", None),
+        ("hey.then(x => { return x * 10 })", None),
+        ("hey.then(x => { return })", None),
+        ("hey.then(function() { return 42; })", None),
+        ("hey.then(function() { return new Promise(); })", None),
+        ("hey.then(function() { return \"x\"; }).then(doSomethingWicked)", None),
+        ("hey.then(x => x).then(function() { return \"3\" })", None),
+        ("hey.then(function() { throw new Error(\"msg\"); })", None),
+        ("hey.then(function(x) { if (!x) { throw new Error(\"no x\"); } return x; })", None),
+        ("hey.then(function(x) { if (x) { return x; } throw new Error(\"no x\"); })", None),
+        ("hey.then(x => { throw new Error(\"msg\"); })", None),
+        ("hey.then(x => { if (!x) { throw new Error(\"no x\"); } return x; })", None),
+        ("hey.then(x => { var f = function() { }; return f; })", None),
+        ("hey.then(x => { if (x) { return x; } else { return x; } })", None),
+        ("hey.then(x => { return x; var y = \"unreachable\"; })", None),
+        ("hey.then(x => { return; }, err => { log(err); })", None),
+        ("hey.then(x => { return x && x(); }, err => { log(err); })", None),
+        ("hey.then(x => { return x.y || x(); }, err => { log(err); })", None),
+        ("hey.catch(x => { log(x); })", None),
+        ("hey.finally(() => { cleanup(); })", None),
+        ("hey.then(x => { log(x); })", Some(json!([{ "ignoreLastCallback": true }]))),
+        ("(hey.then(x => { log(x); }))", Some(json!([{ "ignoreLastCallback": true }]))),
+    ];
+
+    let fail = vec![
+        ("hey.then(x => {})", None),
+        ("hey.then(function() { })", None),
+        ("hey.then(function() { }).then(x)", None),
+        ("hey.then(function() { }).then(function() { })", None),
+        ("hey.then(function() { return; }).then(function() { })", None),
+        ("hey.then(function() { doSomethingWicked(); })", None),
+        ("hey.then(function() { if (x) { return x; } })", None),
+        ("hey.then(function() { if (x) { return x; } else { }})", None),
+        ("hey.then(function() { if (x) { } else { return x; }})", None),
+        ("hey.then(function() { if (x) { return you.then(function() { return x; }); } })", None),
+        ("hey.then(x => { x(); })", None),
+        ("hey.then(function() { if (x) { throw new Error(\"no x\"); } })", None),
+        ("hey.then((x => { log(x); }))", None),
+        ("const p = hey.then(x => { log(x); })", Some(json!([{ "ignoreLastCallback": true }]))),
+        ("hey.then(x => { log(x); }).catch(handle)", Some(json!([{ "ignoreLastCallback": true }]))),
+    ];
+
+    Tester::new(AlwaysReturn::NAME, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,97 @@
+use oxc_ast::AstKind;
+use oxc_diagnostics::{
+    miette::{self, Diagnostic},
+    thiserror::Error,
+};
+use oxc_macros::declare_oxc_lint;
+use oxc_span::Span;
+
+use crate::{
+    ast_util::get_enclosing_function, context::LintContext, rule::Rule,
+    utils::get_promise_method_call, AstNode,
+};
+
+#[derive(Debug, Error, Diagnostic)]
+enum NoReturnInFinallyDiagnostic {
+    #[error("eslint-plugin-promise(no-return-in-finally): No return in finally.")]
+    #[diagnostic(
+        severity(warning),
+        help("The return value of a `finally()` callback is ignored.")
+    )]
+    Return(#[label] Span),
+    #[error("eslint-plugin-promise(no-return-in-finally): No throw in finally.")]
+    #[diagnostic(
+        severity(warning),
+        help("Throwing in a `finally()` callback overrides how the promise settled.")
+    )]
+    Throw(#[label] Span),
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoReturnInFinally;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow `return` and `throw` statements inside a callback passed to `finally()`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// The value returned from a `finally()` callback is ignored, so a `return` there is
+    /// most likely a mistake. A `throw` replaces the value or reason the promise settled
+    /// with, which hides the original result.
+    ///
+    /// ### Example
+    /// ```javascript
+    /// myPromise.finally(function (val) {
+    ///   return val;
+    /// });
+    /// ```
+    NoReturnInFinally,
+    suspicious
+);
+
+impl Rule for NoReturnInFinally {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let diagnostic = match node.kind() {
+            AstKind::ReturnStatement(stmt) => NoReturnInFinallyDiagnostic::Return(stmt.span),
+            AstKind::ThrowStatement(stmt) => NoReturnInFinallyDiagnostic::Throw(stmt.span),
+            _ => return,
+        };
+        let Some(function) = get_enclosing_function(node, ctx) else { return };
+        if get_promise_method_call(function, "finally", ctx).is_some() {
+            ctx.diagnostic(diagnostic);
+        }
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "Promise.resolve(1).finally(() => { console.log(2) })",
+        "Promise.reject(4).finally(() => { console.log(2) })",
+        "Promise.reject(4).finally(() => {})",
+        "myPromise.finally(() => {});",
+        "Promise.resolve(1).finally(function () { })",
+        "Promise.resolve(1).finally(() => 1)",
+        "myPromise.then(() => { return 1 })",
+        "myPromise.catch(() => { throw new Error('foo') })",
+        "myPromise.finally(() => { [1, 2].map(x => { return x * 2 }) })",
+        "myPromise.finally(() => { function f() { return 1 } f() })",
+        "myPromise.finally(cleanup, () => { return 1 })",
+    ];
+
+    let fail = vec![
+        "Promise.resolve(1).finally(() => { return 2 })",
+        "Promise.reject(0).finally(() => { return 2 })",
+        "myPromise.finally(() => { return 2 });",
+        "Promise.resolve(1).finally(function () { return 2 })",
+        "myPromise.finally(() => { if (done) { return } cleanup() })",
+        "myPromise.finally(() => { throw new Error('foo') })",
+        "myPromise.finally((() => { return 2 }))",
+    ];
+
+    Tester::new_without_config(NoReturnInFinally::NAME, pass, fail).test_and_snapshot();
+}
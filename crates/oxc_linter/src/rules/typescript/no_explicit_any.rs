@@ -96,7 +96,7 @@ impl Rule for NoExplicitAny {
         }
 
         if self.fix_to_unknown {
-            ctx.diagnostic_with_fix(NoExplicitAnyDiagnostic(any.span), || {
+            ctx.diagnostic_with_fix(NoExplicitAnyDiagnostic(any.span), |_| {
                 Fix::new("unknown", any.span)
             });
         } else {
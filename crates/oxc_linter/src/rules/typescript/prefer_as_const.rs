@@ -119,7 +119,7 @@ fn check_and_report(
         };
         if let Some(span) = error_span {
             if can_fix {
-                ctx.diagnostic_with_fix(PreferAsConstDiagnostic(span), || {
+                ctx.diagnostic_with_fix(PreferAsConstDiagnostic(span), |_| {
                     let start = span.start;
                     let end = span.end;
                     Fix::new("const", Span { start, end })
@@ -40,7 +40,7 @@ impl Rule for EmptyBraceSpaces {
                 let Span { start, end } = static_block.span;
                 if static_block.body.is_empty() && end - start > 9 {
                     // length of "static {}"
-                    ctx.diagnostic_with_fix(EmptyBraceSpacesDiagnostic(static_block.span), || {
+                    ctx.diagnostic_with_fix(EmptyBraceSpacesDiagnostic(static_block.span), |_| {
                         Fix::new("static {}", static_block.span)
                     });
                 }
@@ -73,7 +73,7 @@ fn remove_empty_braces_spaces(ctx: &LintContext, is_empty_body: bool, span: Span
     let Span { start, end } = span;
     if is_empty_body && end - start > 2 {
         // length of "{}"
-        ctx.diagnostic_with_fix(EmptyBraceSpacesDiagnostic(span), || Fix::new("{}", span));
+        ctx.diagnostic_with_fix(EmptyBraceSpacesDiagnostic(span), |_| Fix::new("{}", span));
     }
 }
 
@@ -113,7 +113,9 @@ impl Rule for EscapeCase {
             AstKind::StringLiteral(StringLiteral { span, .. }) => {
                 let text = span.source_text(ctx.source_text());
                 if let Some(fixed) = check_case(text, false) {
-                    ctx.diagnostic_with_fix(EscapeCaseDiagnostic(*span), || Fix::new(fixed, *span));
+                    ctx.diagnostic_with_fix(EscapeCaseDiagnostic(*span), |_| {
+                        Fix::new(fixed, *span)
+                    });
                 }
             }
             AstKind::TemplateLiteral(TemplateLiteral { quasis, .. }) => {
@@ -121,7 +123,7 @@ impl Rule for EscapeCase {
                     if let Some(fixed) =
                         check_case(quasi.span.source_text(ctx.source_text()), false)
                     {
-                        ctx.diagnostic_with_fix(EscapeCaseDiagnostic(quasi.span), || {
+                        ctx.diagnostic_with_fix(EscapeCaseDiagnostic(quasi.span), |_| {
                             Fix::new(fixed, quasi.span)
                         });
                     }
@@ -130,7 +132,7 @@ impl Rule for EscapeCase {
             AstKind::RegExpLiteral(regex) => {
                 let text = regex.span.source_text(ctx.source_text());
                 if let Some(fixed) = check_case(text, true) {
-                    ctx.diagnostic_with_fix(EscapeCaseDiagnostic(regex.span), || {
+                    ctx.diagnostic_with_fix(EscapeCaseDiagnostic(regex.span), |_| {
                         Fix::new(fixed, regex.span)
                     });
                 }
@@ -140,7 +140,7 @@ fn report_diagnostic(
         literal_raw.trim().to_string()
     };
 
-    ctx.diagnostic_with_fix(NoConsoleSpacesDiagnostic(direction, ident.to_string(), span), || {
+    ctx.diagnostic_with_fix(NoConsoleSpacesDiagnostic(direction, ident.to_string(), span), |_| {
         Fix::new(fix, Span { start, end })
     });
 }
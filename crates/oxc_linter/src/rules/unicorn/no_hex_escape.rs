@@ -75,7 +75,7 @@ impl Rule for NoHexEscape {
             AstKind::StringLiteral(StringLiteral { span, .. }) => {
                 let text = span.source_text(ctx.source_text());
                 if let Some(fixed) = check_escape(&text[1..text.len() - 1]) {
-                    ctx.diagnostic_with_fix(NoHexEscapeDiagnostic(*span), || {
+                    ctx.diagnostic_with_fix(NoHexEscapeDiagnostic(*span), |_| {
                         Fix::new(format!("'{fixed}'"), *span)
                     });
                 }
@@ -83,7 +83,7 @@ impl Rule for NoHexEscape {
             AstKind::TemplateLiteral(TemplateLiteral { quasis, .. }) => {
                 quasis.iter().for_each(|quasi| {
                     if let Some(fixed) = check_escape(quasi.span.source_text(ctx.source_text())) {
-                        ctx.diagnostic_with_fix(NoHexEscapeDiagnostic(quasi.span), || {
+                        ctx.diagnostic_with_fix(NoHexEscapeDiagnostic(quasi.span), |_| {
                             Fix::new(fixed, quasi.span)
                         });
                     }
@@ -92,7 +92,7 @@ impl Rule for NoHexEscape {
             AstKind::RegExpLiteral(regex) => {
                 let text = regex.span.source_text(ctx.source_text());
                 if let Some(fixed) = check_escape(&text[1..text.len() - 1]) {
-                    ctx.diagnostic_with_fix(NoHexEscapeDiagnostic(regex.span), || {
+                    ctx.diagnostic_with_fix(NoHexEscapeDiagnostic(regex.span), |_| {
                         Fix::new(format!("/{fixed}/"), regex.span)
                     });
                 }
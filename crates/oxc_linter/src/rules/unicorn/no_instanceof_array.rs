@@ -44,7 +44,7 @@ impl Rule for NoInstanceofArray {
 
         match &expr.right.without_parenthesized() {
             Expression::Identifier(identifier) if identifier.name == "Array" => {
-                ctx.diagnostic_with_fix(NoInstanceofArrayDiagnostic(expr.span), || {
+                ctx.diagnostic_with_fix(NoInstanceofArrayDiagnostic(expr.span), |_| {
                     let modified_code = {
                         let mut formatter = ctx.formatter();
                         formatter.print_str(b"Array.isArray(");
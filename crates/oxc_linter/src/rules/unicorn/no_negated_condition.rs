@@ -119,6 +119,10 @@ fn test() {
         r"if (a !== b) {}",
         r"if (a === b) {} else {}",
         r"a ? b : c",
+        r"if(a){}else{}",
+        r"if(!a){}",
+        r"if (!a) { b(); } else if (!c) { d(); }",
+        r"if (!(a && b)) {}",
     ];
 
     let fail = vec![
@@ -140,6 +144,9 @@ fn test() {
         r"if(!a) {b()} else {c()}",
         r"if(!!a) b(); else c();",
         r"(!!a) ? b() : c();",
+        r"if(!a){}else{}",
+        r"if (a) {} else if (!b) {} else {}",
+        r"var x = !(a && b) ? c : d;",
     ];
 
     Tester::new_without_config(NoNegatedCondition::NAME, pass, fail).test_and_snapshot();
@@ -90,7 +90,7 @@ impl Rule for NoNestedTernary {
                 }
                 ctx.diagnostic_with_fix(
                     NoNestedTernaryDiagnostic::UnparenthesizedNestedTernary(cond_expr.span),
-                    || {
+                    |_| {
                         Fix::new(
                             format!("({})", cond_expr.span.source_text(ctx.source_text())),
                             cond_expr.span,
@@ -118,6 +118,7 @@ fn test() {
         r"const foo = i > 5 ? true : (i < 100 ? FOO(i > 50 ? false : true) : false);",
         r"foo ? doBar() : doBaz();",
         r"var foo = bar === baz ? qux : quxx;",
+        r"const foo = (a ? b : c) ? d : e;",
         r"
             const pluginName = isAbsolute ?
                 pluginPath.slice(pluginPath.lastIndexOf('/') + 1) :
@@ -137,6 +138,7 @@ fn test() {
         r"const foo = i > 5 ? true : i < 100 ? true : false;",
         r"foo ? bar : baz === qux ? quxx : foobar;",
         r"foo ? baz === qux ? quxx : foobar : bar;",
+        r"a ? b : c ? d : e",
         r"
         const foo = a ?
             b :
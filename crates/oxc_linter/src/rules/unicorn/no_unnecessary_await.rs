@@ -58,7 +58,7 @@ impl Rule for NoUnnecessaryAwait {
             } {
                 ctx.diagnostic(NoUnnecessaryAwaitDiagnostic(expr.span));
             } else {
-                ctx.diagnostic_with_fix(NoUnnecessaryAwaitDiagnostic(expr.span), || {
+                ctx.diagnostic_with_fix(NoUnnecessaryAwaitDiagnostic(expr.span), |_| {
                     let mut formatter = ctx.formatter();
                     expr.argument.gen(&mut formatter);
                     Fix::new(formatter.into_code(), expr.span)
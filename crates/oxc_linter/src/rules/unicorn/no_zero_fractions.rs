@@ -64,7 +64,7 @@ impl Rule for NoZeroFractions {
             } else {
                 NoZeroFractionsDiagnostic::ZeroFraction(number_literal.span, fmt.clone())
             },
-            || Fix::new(fmt, number_literal.span),
+            |_| Fix::new(fmt, number_literal.span),
         );
     }
 }
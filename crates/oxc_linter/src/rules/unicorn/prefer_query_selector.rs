@@ -88,7 +88,7 @@ impl Rule for PreferQuerySelector {
                 PreferQuerySelectorDiagnostic(preferred_selector, cur_property_name, property_span);
 
             if argument_expr.is_null() {
-                return ctx.diagnostic_with_fix(diagnostic, || {
+                return ctx.diagnostic_with_fix(diagnostic, |_| {
                     return Fix::new(*preferred_selector, property_span);
                 });
             }
@@ -106,7 +106,7 @@ impl Rule for PreferQuerySelector {
             };
 
             if let Some(literal_value) = literal_value {
-                return ctx.diagnostic_with_fix(diagnostic, || {
+                return ctx.diagnostic_with_fix(diagnostic, |_| {
                     if literal_value.is_empty() {
                         return Fix::new(*preferred_selector, property_span);
                     }
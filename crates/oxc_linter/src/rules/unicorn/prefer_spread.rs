@@ -150,7 +150,7 @@ impl Rule for PreferSpread {
 
                 ctx.diagnostic_with_fix(
                     PreferSpreadDiagnostic(call_expr.span, "string.split()"),
-                    || {
+                    |_| {
                         let callee_obj = member_expr.object().without_parenthesized();
                         Fix::new(
                             format!("[...{}]", callee_obj.span().source_text(ctx.source_text())),
@@ -62,7 +62,7 @@ impl Rule for RequireNumberToFixedDigitsArgument {
 
                     ctx.diagnostic_with_fix(
                         RequireNumberToFixedDigitsArgumentDiagnostic(parenthesis_span),
-                        || {
+                        |_| {
                             let modified_code = {
                                 let mut formatter = ctx.formatter();
 
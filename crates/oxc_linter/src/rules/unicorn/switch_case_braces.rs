@@ -57,7 +57,7 @@ impl Rule for SwitchCaseBraces {
                         if case_block.body.is_empty() {
                             ctx.diagnostic_with_fix(
                                 SwitchCaseBracesDiagnostic(case_block.span),
-                                || Fix::new("", case_block.span),
+                                |_| Fix::new("", case_block.span),
                             );
                         }
                     }
@@ -75,7 +75,7 @@ impl Rule for SwitchCaseBraces {
                             end: last_statement.span().end,
                         };
 
-                        ctx.diagnostic_with_fix(SwitchCaseBracesDiagnostic(case_body_span), || {
+                        ctx.diagnostic_with_fix(SwitchCaseBracesDiagnostic(case_body_span), |_| {
                             let modified_code = {
                                 let mut formatter = ctx.formatter();
 
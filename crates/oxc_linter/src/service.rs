@@ -1,14 +1,16 @@
 use std::{
     collections::HashMap,
     fs,
+    hash::{Hash, Hasher},
     path::Path,
     rc::Rc,
     sync::{Arc, Condvar, Mutex},
 };
 
 use dashmap::DashMap;
+use itertools::Itertools;
 use rayon::{iter::ParallelBridge, prelude::ParallelIterator};
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashSet, FxHasher};
 
 use oxc_allocator::Allocator;
 use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, FailedToOpenFileError};
@@ -17,7 +19,7 @@ use oxc_resolver::{ResolveOptions, Resolver};
 use oxc_semantic::{ModuleRecord, SemanticBuilder};
 use oxc_span::{SourceType, VALID_EXTENSIONS};
 
-use crate::{Fixer, LintContext, LintOptions, Linter, Message};
+use crate::{Fixer, LintCache, LintContext, LintOptions, Linter, Message};
 
 #[derive(Clone)]
 pub struct LintService {
@@ -37,6 +39,23 @@ impl LintService {
         Self { runtime }
     }
 
+    /// Like [`LintService::new`], but serves the diagnostics of files which have not changed
+    /// since they were last linted with the same configuration from `cache`.
+    /// The cache is not used when fixing or when the import plugin is enabled, as the
+    /// diagnostics then depend on more than the file content.
+    pub fn new_with_cache(
+        cwd: Box<Path>,
+        paths: &[Box<Path>],
+        options: LintOptions,
+        cache: Arc<LintCache>,
+    ) -> Self {
+        let linter = Linter::from_options(options);
+        let mut runtime = Runtime::new(cwd, paths, linter);
+        runtime.fingerprint = fingerprint(&runtime.linter);
+        runtime.lint_cache = Some(cache);
+        Self { runtime: Arc::new(runtime) }
+    }
+
     pub fn linter(&self) -> &Linter {
         &self.runtime.linter
     }
@@ -83,6 +102,29 @@ impl LintService {
     }
 }
 
+/// Fingerprint of everything in the configuration of `linter` which affects the diagnostics,
+/// for telling apart the entries of a [`LintCache`].
+/// Rules are identified by their name and their eslint json configuration.
+fn fingerprint(linter: &Linter) -> u64 {
+    let rules = linter
+        .rules()
+        .iter()
+        .map(|rule| {
+            let configuration =
+                linter.options().rule_configuration(rule.name()).map(ToString::to_string);
+            (rule.name(), configuration)
+        })
+        .sorted_unstable();
+
+    let mut hasher = FxHasher::default();
+    for rule in rules {
+        rule.hash(&mut hasher);
+    }
+    linter.options().env.hash(&mut hasher);
+    linter.options().globals.hash(&mut hasher);
+    hasher.finish()
+}
+
 /// `CacheState` and `CacheStateEntry` are used to fix the problem where
 /// there is a brief moment when a concurrent fetch can miss the cache.
 ///
@@ -111,6 +153,9 @@ pub struct Runtime {
     resolver: Resolver,
     module_map: ModuleMap,
     cache_state: CacheState,
+    lint_cache: Option<Arc<LintCache>>,
+    /// Fingerprint of the linter configuration for `lint_cache`.
+    fingerprint: u64,
 }
 
 impl Runtime {
@@ -122,6 +167,8 @@ impl Runtime {
             resolver: Self::resolver(),
             module_map: ModuleMap::default(),
             cache_state: CacheState::default(),
+            lint_cache: None,
+            fingerprint: 0,
         }
     }
 
@@ -153,6 +200,17 @@ impl Runtime {
             }
         };
 
+        let lint_cache = self
+            .lint_cache
+            .as_ref()
+            .filter(|_| !self.linter.options().fix && !self.linter.options().import_plugin);
+        if let Some(errors) =
+            lint_cache.and_then(|cache| cache.get(path, &source_text, self.fingerprint))
+        {
+            self.send_diagnostics(path, &source_text, errors, tx_error);
+            return;
+        }
+
         let mut messages =
             self.process_source(path, &allocator, &source_text, source_type, true, tx_error);
 
@@ -162,10 +220,23 @@ impl Runtime {
             messages = fix_result.messages;
         }
 
-        if !messages.is_empty() {
-            let errors = messages.into_iter().map(|m| m.error).collect();
+        let errors = messages.into_iter().map(|m| m.error).collect::<Vec<_>>();
+        if let Some(cache) = lint_cache {
+            cache.insert(path, &source_text, self.fingerprint, &errors);
+        }
+        self.send_diagnostics(path, &source_text, errors, tx_error);
+    }
+
+    fn send_diagnostics(
+        &self,
+        path: &Path,
+        source_text: &str,
+        errors: Vec<Error>,
+        tx_error: &DiagnosticSender,
+    ) {
+        if !errors.is_empty() {
             let path = path.strip_prefix(&self.cwd).unwrap_or(path);
-            let diagnostics = DiagnosticService::wrap_diagnostics(path, &source_text, errors);
+            let diagnostics = DiagnosticService::wrap_diagnostics(path, source_text, errors);
             tx_error.send(Some(diagnostics)).unwrap();
         }
     }
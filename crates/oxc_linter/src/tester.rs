@@ -13,7 +13,7 @@ use crate::{rules::RULES, Fixer, LintOptions, LintService, Linter, RuleEnum};
 #[derive(Eq, PartialEq)]
 enum TestResult {
     Passed,
-    Failed,
+    Failed(usize),
     Fixed(String),
 }
 
@@ -21,7 +21,8 @@ pub struct Tester {
     rule_name: &'static str,
     rule_path: PathBuf,
     expect_pass: Vec<(String, Option<Value>)>,
-    expect_fail: Vec<(String, Option<Value>)>,
+    /// Fail cases, optionally with the number of diagnostics the rule is expected to report.
+    expect_fail: Vec<(String, Option<Value>, Option<usize>)>,
     expect_fix: Vec<(String, String, Option<Value>)>,
     snapshot: String,
     current_working_directory: Box<Path>,
@@ -38,7 +39,8 @@ impl Tester {
     ) -> Self {
         let rule_path = PathBuf::from(rule_name.replace('-', "_")).with_extension("tsx");
         let expect_pass = expect_pass.into_iter().map(|(s, r)| (s.into(), r)).collect::<Vec<_>>();
-        let expect_fail = expect_fail.into_iter().map(|(s, r)| (s.into(), r)).collect::<Vec<_>>();
+        let expect_fail =
+            expect_fail.into_iter().map(|(s, r)| (s.into(), r, None)).collect::<Vec<_>>();
         let current_working_directory =
             env::current_dir().unwrap().join("fixtures/import").into_boxed_path();
         Self {
@@ -71,7 +73,17 @@ impl Tester {
         expect_fail: Vec<S>,
     ) -> Self {
         self.expect_pass = expect_pass.into_iter().map(|s| (s.into(), None)).collect::<Vec<_>>();
-        self.expect_fail = expect_fail.into_iter().map(|s| (s.into(), None)).collect::<Vec<_>>();
+        self.expect_fail =
+            expect_fail.into_iter().map(|s| (s.into(), None, None)).collect::<Vec<_>>();
+        self
+    }
+
+    /// Add fail cases that must report exactly the given number of diagnostics.
+    pub fn expect_fail_with_count<S: Into<String>>(
+        mut self,
+        expect_fail: Vec<(S, Option<Value>, usize)>,
+    ) -> Self {
+        self.expect_fail.extend(expect_fail.into_iter().map(|(s, r, n)| (s.into(), r, Some(n))));
         self
     }
 
@@ -129,10 +141,17 @@ impl Tester {
     }
 
     fn test_fail(&mut self) {
-        for (test, config) in self.expect_fail.clone() {
+        for (test, config, expected_count) in self.expect_fail.clone() {
             let result = self.run(&test, config, false);
-            let failed = result == TestResult::Failed;
-            assert!(failed, "expect test to fail: {test}");
+            let TestResult::Failed(count) = result else {
+                panic!("expect test to fail: {test}");
+            };
+            if let Some(expected_count) = expected_count {
+                assert_eq!(
+                    count, expected_count,
+                    "expect test to report {expected_count} diagnostic(s): {test}"
+                );
+            }
         }
     }
 
@@ -149,7 +168,7 @@ impl Tester {
 
     fn run(&mut self, source_text: &str, config: Option<Value>, is_fix: bool) -> TestResult {
         let allocator = Allocator::default();
-        let rule = self.find_rule().read_json(config);
+        let rule = self.find_rule().read_json(config).unwrap_or_else(|err| panic!("{err:?}"));
         let options = LintOptions::default()
             .with_fix(is_fix)
             .with_import_plugin(self.import_plugin)
@@ -185,6 +204,7 @@ impl Tester {
             &self.rule_path
         }
         .to_string_lossy();
+        let count = result.len();
         let handler = GraphicalReportHandler::new_themed(GraphicalTheme::unicode_nocolor());
         for diagnostic in result {
             let diagnostic = diagnostic.error.with_source_code(source_text.to_string());
@@ -195,7 +215,7 @@ impl Tester {
             handler.render_report(&mut self.snapshot, diagnostic.as_ref()).unwrap();
             self.snapshot.push('\n');
         }
-        TestResult::Failed
+        TestResult::Failed(count)
     }
 
     fn find_rule(&self) -> &RuleEnum {
@@ -205,3 +225,12 @@ impl Tester {
             .unwrap_or_else(|| panic!("Rule not found: {}", &self.rule_name))
     }
 }
+
+#[test]
+#[should_panic(expected = "expect test to report 1 diagnostic(s)")]
+fn test_expect_fail_with_wrong_count() {
+    let fail = vec![("var x = { a: 1, a: 2, a: 3 };", None, 1)];
+    Tester::new_without_config::<String>("no-dupe-keys", vec![], vec![])
+        .expect_fail_with_count(fail)
+        .test();
+}
@@ -0,0 +1,101 @@
+//! Getters and setters of object literals and classes,
+//! shared by `accessor-pairs` and `grouped-accessor-pairs`.
+
+use oxc_ast::ast::{
+    Class, ClassElement, MethodDefinitionKind, ObjectExpression, ObjectPropertyKind, PropertyKey,
+    PropertyKind,
+};
+use oxc_span::{Atom, GetSpan, Span};
+
+use crate::LintContext;
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub enum AccessorKey<'a> {
+    /// `get a()`, `get 'a'()` and `get ['a']()`.
+    Static(Atom),
+    /// `get #a()`.
+    Private(Atom),
+    /// `get [a]()`, compared by its source text.
+    Computed(&'a str),
+}
+
+/// A getter or a setter of an object literal or a class.
+#[derive(Debug)]
+pub struct Accessor<'a> {
+    pub key: AccessorKey<'a>,
+    pub is_static: bool,
+    pub is_getter: bool,
+    /// Position among all properties of the object or all members of the class.
+    pub index: usize,
+    pub span: Span,
+}
+
+impl<'a> Accessor<'a> {
+    fn new(
+        key: &PropertyKey,
+        is_static: bool,
+        is_getter: bool,
+        index: usize,
+        ctx: &LintContext<'a>,
+    ) -> Self {
+        let key_kind = match key {
+            PropertyKey::PrivateIdentifier(ident) => AccessorKey::Private(ident.name.clone()),
+            _ => key.static_name().map_or_else(
+                || AccessorKey::Computed(key.span().source_text(ctx.source_text())),
+                AccessorKey::Static,
+            ),
+        };
+        Self { key: key_kind, is_static, is_getter, index, span: key.span() }
+    }
+
+    pub fn is_setter(&self) -> bool {
+        !self.is_getter
+    }
+
+    /// e.g. `getter 'a'`, `static setter #b` or `getter`.
+    pub fn name(&self) -> String {
+        let kind = if self.is_getter { "getter" } else { "setter" };
+        let prefix = if self.is_static { "static " } else { "" };
+        match &self.key {
+            AccessorKey::Static(name) => format!("{prefix}{kind} '{name}'"),
+            AccessorKey::Private(name) => format!("{prefix}{kind} #{name}"),
+            AccessorKey::Computed(_) => format!("{prefix}{kind}"),
+        }
+    }
+}
+
+/// The getters and setters of an object literal, in source order.
+pub fn object_accessors<'a>(obj: &ObjectExpression, ctx: &LintContext<'a>) -> Vec<Accessor<'a>> {
+    obj.properties
+        .iter()
+        .enumerate()
+        .filter_map(|(index, prop)| {
+            let ObjectPropertyKind::ObjectProperty(prop) = prop else { return None };
+            let is_getter = match prop.kind {
+                PropertyKind::Get => true,
+                PropertyKind::Set => false,
+                PropertyKind::Init => return None,
+            };
+            Some(Accessor::new(&prop.key, false, is_getter, index, ctx))
+        })
+        .collect()
+}
+
+/// The getters and setters of a class, in source order.
+pub fn class_accessors<'a>(class: &Class, ctx: &LintContext<'a>) -> Vec<Accessor<'a>> {
+    class
+        .body
+        .body
+        .iter()
+        .enumerate()
+        .filter_map(|(index, element)| {
+            let ClassElement::MethodDefinition(method) = element else { return None };
+            let is_getter = match method.kind {
+                MethodDefinitionKind::Get => true,
+                MethodDefinitionKind::Set => false,
+                _ => return None,
+            };
+            Some(Accessor::new(&method.key, method.r#static, is_getter, index, ctx))
+        })
+        .collect()
+}
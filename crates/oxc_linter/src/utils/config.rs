@@ -0,0 +1,23 @@
+//! Helpers for reading rule options from their JSON configuration.
+
+/// The strings in the array option `key` of a rule configuration object,
+/// e.g. `["Image"]` for `{ "components": ["Image"] }`. Other values are ignored.
+pub fn get_string_array_option(config: &serde_json::Value, key: &str) -> Vec<String> {
+    config
+        .get(key)
+        .and_then(serde_json::Value::as_array)
+        .map(|values| {
+            values.iter().filter_map(serde_json::Value::as_str).map(String::from).collect()
+        })
+        .unwrap_or_default()
+}
+
+/// The limit of a rule configured as either `[4]` or `[{ "max": 4 }]`, or `default` when
+/// it is not configured.
+pub fn get_max_option(value: &serde_json::Value, default: usize) -> usize {
+    let config = value.get(0);
+    config
+        .and_then(serde_json::Value::as_u64)
+        .or_else(|| config.and_then(|v| v.get("max")).and_then(serde_json::Value::as_u64))
+        .map_or(default, |v| usize::try_from(v).unwrap_or(default))
+}
@@ -0,0 +1,462 @@
+use oxc_ast::ast::{JSXElementName, JSXOpeningElement};
+use phf::{phf_map, phf_set, Map, Set};
+
+use crate::utils::{get_literal_prop_value, has_jsx_prop_lowercase};
+
+/// All valid WAI-ARIA 1.2 states and properties.
+///
+/// ref: <https://github.com/A11yance/aria-query/blob/main/src/ariaPropsMap.js>
+pub const VALID_ARIA_PROPS: Set<&'static str> = phf_set! {
+    "aria-activedescendant",
+    "aria-atomic",
+    "aria-autocomplete",
+    "aria-braillelabel",
+    "aria-brailleroledescription",
+    "aria-busy",
+    "aria-checked",
+    "aria-colcount",
+    "aria-colindex",
+    "aria-colindextext",
+    "aria-colspan",
+    "aria-controls",
+    "aria-current",
+    "aria-describedby",
+    "aria-description",
+    "aria-details",
+    "aria-disabled",
+    "aria-dropeffect",
+    "aria-errormessage",
+    "aria-expanded",
+    "aria-flowto",
+    "aria-grabbed",
+    "aria-haspopup",
+    "aria-hidden",
+    "aria-invalid",
+    "aria-keyshortcuts",
+    "aria-label",
+    "aria-labelledby",
+    "aria-level",
+    "aria-live",
+    "aria-modal",
+    "aria-multiline",
+    "aria-multiselectable",
+    "aria-orientation",
+    "aria-owns",
+    "aria-placeholder",
+    "aria-posinset",
+    "aria-pressed",
+    "aria-readonly",
+    "aria-relevant",
+    "aria-required",
+    "aria-roledescription",
+    "aria-rowcount",
+    "aria-rowindex",
+    "aria-rowindextext",
+    "aria-rowspan",
+    "aria-selected",
+    "aria-setsize",
+    "aria-sort",
+    "aria-valuemax",
+    "aria-valuemin",
+    "aria-valuenow",
+    "aria-valuetext",
+};
+
+/// Global states and properties, supported by every role.
+///
+/// ref: <https://www.w3.org/TR/wai-aria-1.2/#global_states>
+pub const GLOBAL_ARIA_PROPS: Set<&'static str> = phf_set! {
+    "aria-atomic",
+    "aria-braillelabel",
+    "aria-brailleroledescription",
+    "aria-busy",
+    "aria-controls",
+    "aria-current",
+    "aria-describedby",
+    "aria-description",
+    "aria-details",
+    "aria-disabled",
+    "aria-dropeffect",
+    "aria-errormessage",
+    "aria-flowto",
+    "aria-grabbed",
+    "aria-haspopup",
+    "aria-hidden",
+    "aria-invalid",
+    "aria-keyshortcuts",
+    "aria-label",
+    "aria-labelledby",
+    "aria-live",
+    "aria-owns",
+    "aria-relevant",
+    "aria-roledescription",
+};
+
+const CELL_PROPS: &[&str] = &[
+    "aria-colindex",
+    "aria-colindextext",
+    "aria-colspan",
+    "aria-rowindex",
+    "aria-rowindextext",
+    "aria-rowspan",
+];
+
+const HEADER_PROPS: &[&str] = &[
+    "aria-colindex",
+    "aria-colindextext",
+    "aria-colspan",
+    "aria-expanded",
+    "aria-readonly",
+    "aria-required",
+    "aria-rowindex",
+    "aria-rowindextext",
+    "aria-rowspan",
+    "aria-selected",
+    "aria-sort",
+];
+
+const CHECKBOX_PROPS: &[&str] = &[
+    "aria-checked",
+    "aria-errormessage",
+    "aria-expanded",
+    "aria-invalid",
+    "aria-readonly",
+    "aria-required",
+];
+
+const GRID_PROPS: &[&str] = &[
+    "aria-activedescendant",
+    "aria-colcount",
+    "aria-multiselectable",
+    "aria-readonly",
+    "aria-rowcount",
+];
+
+const MENUITEM_PROPS: &[&str] =
+    &["aria-disabled", "aria-expanded", "aria-haspopup", "aria-posinset", "aria-setsize"];
+
+const MENUITEM_CHECKED_PROPS: &[&str] = &[
+    "aria-checked",
+    "aria-disabled",
+    "aria-expanded",
+    "aria-haspopup",
+    "aria-posinset",
+    "aria-setsize",
+];
+
+const RANGE_PROPS: &[&str] = &["aria-valuemax", "aria-valuemin", "aria-valuenow", "aria-valuetext"];
+
+const TEXTBOX_PROPS: &[&str] = &[
+    "aria-activedescendant",
+    "aria-autocomplete",
+    "aria-errormessage",
+    "aria-haspopup",
+    "aria-invalid",
+    "aria-multiline",
+    "aria-placeholder",
+    "aria-readonly",
+    "aria-required",
+];
+
+/// Non-global states and properties supported by each (non-abstract) WAI-ARIA role.
+/// Roles only supporting the global states and properties map to an empty slice.
+///
+/// ref: <https://github.com/A11yance/aria-query/tree/main/src/etc/roles/literal>
+pub const ROLE_SUPPORTED_ARIA_PROPS: Map<&'static str, &'static [&'static str]> = phf_map! {
+    "alert" => &[],
+    "alertdialog" => &["aria-modal"],
+    "application" => &["aria-activedescendant", "aria-expanded"],
+    "article" => &["aria-posinset", "aria-setsize"],
+    "banner" => &[],
+    "blockquote" => &[],
+    "button" => &["aria-expanded", "aria-pressed"],
+    "caption" => &[],
+    "cell" => CELL_PROPS,
+    "checkbox" => CHECKBOX_PROPS,
+    "code" => &[],
+    "columnheader" => HEADER_PROPS,
+    "combobox" => &[
+        "aria-activedescendant",
+        "aria-autocomplete",
+        "aria-errormessage",
+        "aria-expanded",
+        "aria-invalid",
+        "aria-readonly",
+        "aria-required",
+    ],
+    "complementary" => &[],
+    "contentinfo" => &[],
+    "definition" => &[],
+    "deletion" => &[],
+    "dialog" => &["aria-modal"],
+    "directory" => &[],
+    "document" => &[],
+    "emphasis" => &[],
+    "feed" => &[],
+    "figure" => &[],
+    "form" => &[],
+    "generic" => &[],
+    "grid" => GRID_PROPS,
+    "gridcell" => &[
+        "aria-colindex",
+        "aria-colindextext",
+        "aria-colspan",
+        "aria-expanded",
+        "aria-readonly",
+        "aria-required",
+        "aria-rowindex",
+        "aria-rowindextext",
+        "aria-rowspan",
+        "aria-selected",
+    ],
+    "group" => &["aria-activedescendant"],
+    "heading" => &["aria-level"],
+    "img" => &[],
+    "insertion" => &[],
+    "link" => &["aria-expanded"],
+    "list" => &[],
+    "listbox" => &[
+        "aria-activedescendant",
+        "aria-errormessage",
+        "aria-expanded",
+        "aria-invalid",
+        "aria-multiselectable",
+        "aria-orientation",
+        "aria-readonly",
+        "aria-required",
+    ],
+    "listitem" => &["aria-level", "aria-posinset", "aria-setsize"],
+    "log" => &[],
+    "main" => &[],
+    "marquee" => &[],
+    "math" => &[],
+    "menu" => &["aria-activedescendant", "aria-orientation"],
+    "menubar" => &["aria-activedescendant", "aria-orientation"],
+    "menuitem" => MENUITEM_PROPS,
+    "menuitemcheckbox" => MENUITEM_CHECKED_PROPS,
+    "menuitemradio" => MENUITEM_CHECKED_PROPS,
+    "meter" => RANGE_PROPS,
+    "navigation" => &[],
+    "none" => &[],
+    "note" => &[],
+    "option" => &["aria-checked", "aria-posinset", "aria-selected", "aria-setsize"],
+    "paragraph" => &[],
+    "presentation" => &[],
+    "progressbar" => RANGE_PROPS,
+    "radio" => &["aria-checked", "aria-posinset", "aria-setsize"],
+    "radiogroup" => &[
+        "aria-activedescendant",
+        "aria-errormessage",
+        "aria-expanded",
+        "aria-invalid",
+        "aria-orientation",
+        "aria-readonly",
+        "aria-required",
+    ],
+    "region" => &[],
+    "row" => &[
+        "aria-activedescendant",
+        "aria-colindex",
+        "aria-expanded",
+        "aria-level",
+        "aria-posinset",
+        "aria-rowindex",
+        "aria-rowindextext",
+        "aria-selected",
+        "aria-setsize",
+    ],
+    "rowgroup" => &[],
+    "rowheader" => HEADER_PROPS,
+    "scrollbar" => &[
+        "aria-controls",
+        "aria-orientation",
+        "aria-valuemax",
+        "aria-valuemin",
+        "aria-valuenow",
+        "aria-valuetext",
+    ],
+    "search" => &[],
+    "searchbox" => TEXTBOX_PROPS,
+    "separator" => &[
+        "aria-orientation",
+        "aria-valuemax",
+        "aria-valuemin",
+        "aria-valuenow",
+        "aria-valuetext",
+    ],
+    "slider" => &[
+        "aria-errormessage",
+        "aria-haspopup",
+        "aria-invalid",
+        "aria-orientation",
+        "aria-readonly",
+        "aria-valuemax",
+        "aria-valuemin",
+        "aria-valuenow",
+        "aria-valuetext",
+    ],
+    "spinbutton" => &[
+        "aria-activedescendant",
+        "aria-errormessage",
+        "aria-invalid",
+        "aria-readonly",
+        "aria-required",
+        "aria-valuemax",
+        "aria-valuemin",
+        "aria-valuenow",
+        "aria-valuetext",
+    ],
+    "status" => &[],
+    "strong" => &[],
+    "subscript" => &[],
+    "superscript" => &[],
+    "switch" => CHECKBOX_PROPS,
+    "tab" => &["aria-expanded", "aria-posinset", "aria-selected", "aria-setsize"],
+    "table" => &["aria-colcount", "aria-rowcount"],
+    "tablist" => &["aria-activedescendant", "aria-multiselectable", "aria-orientation"],
+    "tabpanel" => &[],
+    "term" => &[],
+    "textbox" => TEXTBOX_PROPS,
+    "time" => &[],
+    "timer" => &[],
+    "toolbar" => &["aria-activedescendant", "aria-orientation"],
+    "tooltip" => &[],
+    "tree" => &[
+        "aria-activedescendant",
+        "aria-errormessage",
+        "aria-invalid",
+        "aria-multiselectable",
+        "aria-orientation",
+        "aria-required",
+    ],
+    "treegrid" => &[
+        "aria-activedescendant",
+        "aria-colcount",
+        "aria-errormessage",
+        "aria-invalid",
+        "aria-multiselectable",
+        "aria-orientation",
+        "aria-readonly",
+        "aria-required",
+        "aria-rowcount",
+    ],
+    "treeitem" => &[
+        "aria-checked",
+        "aria-expanded",
+        "aria-haspopup",
+        "aria-level",
+        "aria-posinset",
+        "aria-selected",
+        "aria-setsize",
+    ],
+};
+
+/// WAI-ARIA roles which can be replaced by a single semantic HTML element,
+/// mapped to the element name and the suggested markup.
+///
+/// ref: <https://www.w3.org/TR/html-aria/#docconformance>
+pub const ROLE_TO_ELEMENT: Map<&'static str, (&'static str, &'static str)> = phf_map! {
+    "article" => ("article", "<article>"),
+    "banner" => ("header", "<header>"),
+    "button" => ("button", "<button>"),
+    "cell" => ("td", "<td>"),
+    "checkbox" => ("input", "<input type=\"checkbox\">"),
+    "columnheader" => ("th", "<th>"),
+    "complementary" => ("aside", "<aside>"),
+    "contentinfo" => ("footer", "<footer>"),
+    "definition" => ("dd", "<dd>"),
+    "dialog" => ("dialog", "<dialog>"),
+    "figure" => ("figure", "<figure>"),
+    "form" => ("form", "<form>"),
+    "img" => ("img", "<img>"),
+    "link" => ("a", "<a href>"),
+    "listitem" => ("li", "<li>"),
+    "main" => ("main", "<main>"),
+    "meter" => ("meter", "<meter>"),
+    "navigation" => ("nav", "<nav>"),
+    "option" => ("option", "<option>"),
+    "progressbar" => ("progress", "<progress>"),
+    "radio" => ("input", "<input type=\"radio\">"),
+    "region" => ("section", "<section>"),
+    "row" => ("tr", "<tr>"),
+    "rowheader" => ("th", "<th scope=\"row\">"),
+    "searchbox" => ("input", "<input type=\"search\">"),
+    "separator" => ("hr", "<hr>"),
+    "slider" => ("input", "<input type=\"range\">"),
+    "spinbutton" => ("input", "<input type=\"number\">"),
+    "status" => ("output", "<output>"),
+    "table" => ("table", "<table>"),
+    "term" => ("dfn", "<dfn>"),
+};
+
+/// Whether `role` supports the `aria-*` attribute `prop`.
+/// Returns `None` when `role` is not a known WAI-ARIA role.
+pub fn is_aria_prop_supported_by_role(role: &str, prop: &str) -> Option<bool> {
+    ROLE_SUPPORTED_ARIA_PROPS
+        .get(role)
+        .map(|props| GLOBAL_ARIA_PROPS.contains(prop) || props.contains(&prop))
+}
+
+// ref: https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/tree/main/src/util/implicitRoles
+pub fn get_implicit_role(node: &JSXOpeningElement) -> Option<&'static str> {
+    let JSXElementName::Identifier(ident) = &node.name else { return None };
+    let literal_prop = |name: &str| {
+        has_jsx_prop_lowercase(node, name).and_then(get_literal_prop_value).map(str::to_lowercase)
+    };
+
+    let role = match ident.name.as_str() {
+        "a" | "area" | "link" => {
+            has_jsx_prop_lowercase(node, "href").is_some().then_some("link")?
+        }
+        "article" => "article",
+        "aside" => "complementary",
+        "body" => "document",
+        "button" => "button",
+        "datalist" | "select" => "listbox",
+        "details" => "group",
+        "dialog" => "dialog",
+        "form" => "form",
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => "heading",
+        "hr" => "separator",
+        "img" => {
+            if literal_prop("alt").is_some_and(|alt| alt.is_empty()) {
+                "presentation"
+            } else {
+                "img"
+            }
+        }
+        "input" => match literal_prop("type").as_deref() {
+            Some("button" | "image" | "reset" | "submit") => "button",
+            Some("checkbox") => "checkbox",
+            Some("radio") => "radio",
+            Some("range") => "slider",
+            Some("number") => "spinbutton",
+            _ => {
+                if has_jsx_prop_lowercase(node, "list").is_some() {
+                    "combobox"
+                } else {
+                    "textbox"
+                }
+            }
+        },
+        "li" => "listitem",
+        "menu" => (literal_prop("type").as_deref() == Some("toolbar")).then_some("toolbar")?,
+        "menuitem" => match literal_prop("type").as_deref() {
+            Some("command") => "menuitem",
+            Some("checkbox") => "menuitemcheckbox",
+            Some("radio") => "menuitemradio",
+            _ => return None,
+        },
+        "meter" | "progress" => "progressbar",
+        "nav" => "navigation",
+        "ol" | "ul" => "list",
+        "option" => "option",
+        "output" => "status",
+        "section" => "region",
+        "tbody" | "tfoot" | "thead" => "rowgroup",
+        "textarea" => "textbox",
+        _ => return None,
+    };
+
+    Some(role)
+}
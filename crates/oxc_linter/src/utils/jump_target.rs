@@ -0,0 +1,61 @@
+//! Tracking of the statements which `break` and `continue` jump to,
+//! shared by the code path analyses of `super()` calls and of loop bodies.
+
+use std::borrow::Borrow;
+
+use oxc_span::Atom;
+
+/// A statement which `break` or `continue` can jump to.
+/// `T` describes the code paths jumping to it, and its default means that none do.
+pub struct JumpTarget<L, T> {
+    labels: Vec<L>,
+    is_loop: bool,
+    /// Whether an unlabeled `break` jumps to this statement, i.e. it is a loop or a `switch`.
+    is_breakable: bool,
+    pub breaks: T,
+    pub continues: T,
+}
+
+/// The jump targets enclosing the statement being visited, innermost last.
+pub struct JumpTargets<L, T> {
+    targets: Vec<JumpTarget<L, T>>,
+}
+
+impl<L, T> Default for JumpTargets<L, T> {
+    fn default() -> Self {
+        Self { targets: vec![] }
+    }
+}
+
+impl<L: Borrow<Atom>, T: Default> JumpTargets<L, T> {
+    pub fn push(&mut self, labels: Vec<L>, is_loop: bool, is_breakable: bool) {
+        self.targets.push(JumpTarget {
+            labels,
+            is_loop,
+            is_breakable,
+            breaks: T::default(),
+            continues: T::default(),
+        });
+    }
+
+    pub fn pop(&mut self) -> JumpTarget<L, T> {
+        self.targets.pop().expect("jump targets should be balanced")
+    }
+
+    pub fn innermost_mut(&mut self) -> Option<&mut JumpTarget<L, T>> {
+        self.targets.last_mut()
+    }
+
+    /// Finds the statement which a `break` or `continue` with `label` jumps to.
+    pub fn find(
+        &mut self,
+        label: Option<&Atom>,
+        is_continue: bool,
+    ) -> Option<&mut JumpTarget<L, T>> {
+        self.targets.iter_mut().rev().find(|target| match label {
+            Some(label) => target.labels.iter().any(|name| name.borrow() == label),
+            None if is_continue => target.is_loop,
+            None => target.is_breakable,
+        })
+    }
+}
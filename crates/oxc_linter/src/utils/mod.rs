@@ -1,5 +1,14 @@
+mod accessor;
+mod config;
 mod jest;
+mod jsx_a11y;
+mod jump_target;
+mod promise;
 mod react;
+mod super_call;
 mod unicorn;
 
-pub use self::{jest::*, react::*, unicorn::*};
+pub use self::{
+    accessor::*, config::*, jest::*, jsx_a11y::*, jump_target::*, promise::*, react::*,
+    super_call::*, unicorn::*,
+};
@@ -0,0 +1,27 @@
+use oxc_ast::{ast::Expression, AstKind};
+use oxc_semantic::AstNode;
+use oxc_span::GetSpan;
+
+use crate::{ast_util::outermost_paren, LintContext};
+
+/// Returns the call expression node if `node` is the first argument of a promise
+/// method call such as `promise.then(node)` or `promise.finally(node)`.
+pub fn get_promise_method_call<'a, 'b>(
+    node: &'b AstNode<'a>,
+    method: &str,
+    ctx: &'b LintContext<'a>,
+) -> Option<&'b AstNode<'a>> {
+    let node = outermost_paren(node, ctx);
+    let argument = ctx.nodes().parent_node(node.id())?;
+    let AstKind::Argument(argument_kind) = argument.kind() else { return None };
+    let call_node = ctx.nodes().parent_node(argument.id())?;
+    let AstKind::CallExpression(call_expr) = call_node.kind() else { return None };
+
+    if call_expr.arguments.first()?.span() != argument_kind.span() {
+        return None;
+    }
+    let Expression::MemberExpression(member_expr) = call_expr.callee.without_parenthesized() else {
+        return None;
+    };
+    (member_expr.static_property_name() == Some(method)).then_some(call_node)
+}
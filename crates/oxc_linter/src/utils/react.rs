@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use oxc_ast::{
     ast::{
         CallExpression, Expression, JSXAttributeItem, JSXAttributeName, JSXAttributeValue,
@@ -7,6 +9,7 @@ use oxc_ast::{
     AstKind,
 };
 use oxc_semantic::AstNode;
+use oxc_span::Atom;
 
 use crate::LintContext;
 
@@ -32,18 +35,27 @@ pub fn has_jsx_prop<'a, 'b>(
     })
 }
 
+/// Like [`has_jsx_prop`], but compares attribute names case-insensitively.
+/// Namespaced names such as `xlink:href` are matched as a whole.
 pub fn has_jsx_prop_lowercase<'a, 'b>(
     node: &'b JSXOpeningElement<'a>,
     target_prop: &'b str,
 ) -> Option<&'b JSXAttributeItem<'a>> {
-    node.attributes.iter().find(|attr| match attr {
-        JSXAttributeItem::SpreadAttribute(_) => false,
-        JSXAttributeItem::Attribute(attr) => {
-            let JSXAttributeName::Identifier(name) = &attr.name else { return false };
+    node.attributes.iter().find(|attr| {
+        get_jsx_attribute_name(attr).is_some_and(|name| name.eq_ignore_ascii_case(target_prop))
+    })
+}
 
-            name.name.as_str().to_lowercase() == target_prop.to_lowercase()
+/// The name of a JSX attribute, e.g. `aria-label` or `xlink:href`.
+/// Returns `None` for spread attributes.
+pub fn get_jsx_attribute_name<'a>(attr: &'a JSXAttributeItem<'_>) -> Option<Cow<'a, str>> {
+    let JSXAttributeItem::Attribute(attr) = attr else { return None };
+    match &attr.name {
+        JSXAttributeName::Identifier(name) => Some(Cow::Borrowed(name.name.as_str())),
+        JSXAttributeName::NamespacedName(name) => {
+            Some(Cow::Owned(format!("{}:{}", name.namespace.name, name.property.name)))
         }
-    })
+    }
 }
 
 pub fn get_prop_value<'a, 'b>(item: &'b JSXAttributeItem<'a>) -> Option<&'b JSXAttributeValue<'a>> {
@@ -64,6 +76,25 @@ pub fn get_literal_prop_value<'a>(item: &'a JSXAttributeItem<'_>) -> Option<&'a
     })
 }
 
+/// The static string value of a JSX attribute, e.g. `"foo"`, `{"foo"}` or ``{`foo`}``.
+/// Returns `None` for dynamic values and for attributes without a value.
+pub fn get_string_literal_prop_value<'a>(item: &'a JSXAttributeItem<'_>) -> Option<&'a str> {
+    match get_prop_value(item)? {
+        JSXAttributeValue::StringLiteral(s) => Some(s.value.as_str()),
+        JSXAttributeValue::ExpressionContainer(JSXExpressionContainer {
+            expression: JSXExpression::Expression(expr),
+            ..
+        }) => match expr.without_parenthesized() {
+            Expression::StringLiteral(s) => Some(s.value.as_str()),
+            Expression::TemplateLiteral(t) if t.is_no_substitution_template() => {
+                t.quasi().map(Atom::as_str)
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 // ref: https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/main/src/util/isHiddenFromScreenReader.js
 pub fn is_hidden_from_screen_reader(node: &JSXOpeningElement) -> bool {
     if let JSXElementName::Identifier(iden) = &node.name {
@@ -159,3 +190,70 @@ pub fn get_parent_es6_component<'a, 'b>(
         is_es6_component(ctx.nodes().get_node(node_id)).then(|| ctx.nodes().get_node(node_id))
     })
 }
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::{Expression, Statement};
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    use super::*;
+
+    fn with_opening_element(source: &str, f: impl FnOnce(&JSXOpeningElement)) {
+        let allocator = Allocator::default();
+        let source_type = SourceType::default().with_jsx(true);
+        let ret = Parser::new(&allocator, source, source_type).parse();
+        assert!(ret.errors.is_empty());
+        let Some(Statement::ExpressionStatement(stmt)) = ret.program.body.first() else {
+            unreachable!()
+        };
+        let Expression::JSXElement(element) = &stmt.expression else { unreachable!() };
+        f(&element.opening_element);
+    }
+
+    #[test]
+    fn test_namespaced_attribute() {
+        with_opening_element("<use xlink:href='#a' />", |node| {
+            let attr = has_jsx_prop_lowercase(node, "xlink:href").unwrap();
+            assert_eq!(get_jsx_attribute_name(attr).as_deref(), Some("xlink:href"));
+            assert!(has_jsx_prop_lowercase(node, "XLINK:HREF").is_some());
+            assert!(has_jsx_prop_lowercase(node, "href").is_none());
+            assert!(has_jsx_prop_lowercase(node, "xlink").is_none());
+        });
+    }
+
+    #[test]
+    fn test_hyphenated_aria_attribute() {
+        with_opening_element("<div Aria-Hidden='true' {...props} />", |node| {
+            let attr = has_jsx_prop_lowercase(node, "aria-hidden").unwrap();
+            assert_eq!(get_jsx_attribute_name(attr).as_deref(), Some("Aria-Hidden"));
+            assert_eq!(get_jsx_attribute_name(&node.attributes[1]), None);
+            assert!(has_jsx_prop_lowercase(node, "aria-label").is_none());
+        });
+    }
+
+    #[test]
+    fn test_string_literal_prop_value() {
+        let cases = [
+            ("<a title='foo' />", Some("foo")),
+            ("<a title={'foo'} />", Some("foo")),
+            ("<a title={('foo')} />", Some("foo")),
+            ("<a title={`foo`} />", Some("foo")),
+            ("<a title='' />", Some("")),
+            ("<a title={''} />", Some("")),
+            ("<a title={``} />", Some("")),
+            ("<a title={`foo${bar}`} />", None),
+            ("<a title={foo} />", None),
+            ("<a title={1} />", None),
+            ("<a title={} />", None),
+            ("<a title />", None),
+        ];
+        for (source, expected) in cases {
+            with_opening_element(source, |node| {
+                let attr = has_jsx_prop_lowercase(node, "title").unwrap();
+                assert_eq!(get_string_literal_prop_value(attr), expected, "{source}");
+            });
+        }
+    }
+}
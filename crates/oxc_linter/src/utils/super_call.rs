@@ -0,0 +1,401 @@
+//! Code path analysis of `super()` calls in class constructors,
+//! shared by `constructor-super` and `no-this-before-super`.
+
+use oxc_ast::{
+    ast::{
+        ArrowExpression, AssignmentExpression, BreakStatement, CallExpression, Class,
+        ConditionalExpression, ContinueStatement, DoWhileStatement, Expression, ForInStatement,
+        ForOfStatement, ForStatement, ForStatementLeft, Function, IfStatement, LabeledStatement,
+        LogicalExpression, ReturnStatement, Statement, Super, SwitchStatement, ThisExpression,
+        ThrowStatement, TryStatement, WhileStatement,
+    },
+    Visit,
+};
+use oxc_span::{Atom, Span};
+use oxc_syntax::scope::ScopeFlags;
+use rustc_hash::FxHashMap;
+
+use super::JumpTargets;
+
+/// The possible numbers of `super()` calls made on the code paths reaching a point of a
+/// constructor. It is empty when the point is unreachable.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SuperCalls(u8);
+
+impl SuperCalls {
+    const NONE: u8 = 1;
+    const ONCE: u8 = 2;
+    const MORE: u8 = 4;
+
+    const UNREACHABLE: Self = Self(0);
+    const START: Self = Self(Self::NONE);
+
+    pub fn is_reachable(self) -> bool {
+        self.0 != 0
+    }
+
+    /// Whether `super()` may not have been called yet.
+    pub fn may_be_missing(self) -> bool {
+        self.0 & Self::NONE != 0
+    }
+
+    /// Whether `super()` may have been called already.
+    pub fn may_be_called(self) -> bool {
+        self.0 & (Self::ONCE | Self::MORE) != 0
+    }
+
+    fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    fn after_call(self) -> Self {
+        let mut bits = 0;
+        if self.may_be_missing() {
+            bits |= Self::ONCE;
+        }
+        if self.may_be_called() {
+            bits |= Self::MORE;
+        }
+        Self(bits)
+    }
+}
+
+/// `super()` calls and `this` references of a constructor, each with the calls which may
+/// have been made before it.
+#[derive(Debug)]
+pub struct SuperCallAnalysis {
+    /// `super(...)` calls, in source order.
+    pub super_calls: Vec<(Span, SuperCalls)>,
+    /// `this` expressions, in source order.
+    pub this_expressions: Vec<(Span, SuperCalls)>,
+    /// `super` of `super.foo` and `super[foo]`, in source order.
+    pub super_members: Vec<(Span, SuperCalls)>,
+    /// The calls which may have been made when the constructor returns.
+    pub returns: SuperCalls,
+}
+
+impl SuperCallAnalysis {
+    /// Analyzes the parameters and the body of `constructor`.
+    /// Nested functions and classes are skipped, as they have their own `this`.
+    pub fn new(constructor: &Function) -> Self {
+        let mut analyzer = SuperCallAnalyzer {
+            state: SuperCalls::START,
+            returns: SuperCalls::UNREACHABLE,
+            targets: JumpTargets::default(),
+            pending_labels: vec![],
+            super_calls: FxHashMap::default(),
+            this_expressions: FxHashMap::default(),
+            super_members: FxHashMap::default(),
+        };
+        analyzer.visit_formal_parameters(&constructor.params);
+        if let Some(body) = &constructor.body {
+            analyzer.visit_statements(&body.statements);
+        }
+        let returns = analyzer.returns.union(analyzer.state);
+
+        let into_sorted_vec = |map: FxHashMap<Span, SuperCalls>| {
+            let mut vec = map.into_iter().collect::<Vec<_>>();
+            vec.sort_unstable_by_key(|(span, _)| span.start);
+            vec
+        };
+        Self {
+            super_calls: into_sorted_vec(analyzer.super_calls),
+            this_expressions: into_sorted_vec(analyzer.this_expressions),
+            super_members: into_sorted_vec(analyzer.super_members),
+            returns,
+        }
+    }
+}
+
+struct SuperCallAnalyzer {
+    /// The calls made on the paths reaching the node being visited.
+    state: SuperCalls,
+    returns: SuperCalls,
+    targets: JumpTargets<Atom, SuperCalls>,
+    /// Labels of the labeled statement being visited, consumed by the statement they label.
+    pending_labels: Vec<Atom>,
+    /// Loop bodies are visited until the state converges, so a node can be seen more than once.
+    super_calls: FxHashMap<Span, SuperCalls>,
+    this_expressions: FxHashMap<Span, SuperCalls>,
+    super_members: FxHashMap<Span, SuperCalls>,
+}
+
+impl SuperCallAnalyzer {
+    fn record(map: &mut FxHashMap<Span, SuperCalls>, span: Span, state: SuperCalls) {
+        let entry = map.entry(span).or_default();
+        *entry = entry.union(state);
+    }
+
+    fn push_target(&mut self, is_loop: bool, is_breakable: bool) {
+        self.targets.push(std::mem::take(&mut self.pending_labels), is_loop, is_breakable);
+    }
+
+    /// Takes the state of `continue`s to the innermost loop, which is being visited.
+    fn take_continues(&mut self) -> SuperCalls {
+        self.targets
+            .innermost_mut()
+            .map_or(SuperCalls::UNREACHABLE, |target| std::mem::take(&mut target.continues))
+    }
+
+    /// Visits `body` of a loop until the state at the start of each iteration converges.
+    /// `iterate` visits a single iteration and returns the state at the end of it.
+    fn visit_loop(&mut self, mut iterate: impl FnMut(&mut Self) -> SuperCalls) -> SuperCalls {
+        self.push_target(true, true);
+        let mut head = self.state;
+        loop {
+            self.state = head;
+            let back = iterate(self).union(self.take_continues());
+            let next_head = head.union(back);
+            if next_head == head {
+                break;
+            }
+            head = next_head;
+        }
+        let target = self.targets.pop();
+        target.breaks
+    }
+}
+
+impl<'a> Visit<'a> for SuperCallAnalyzer {
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: Option<ScopeFlags>) {}
+
+    fn visit_arrow_expression(&mut self, _expr: &ArrowExpression<'a>) {}
+
+    fn visit_class(&mut self, class: &Class<'a>) {
+        if let Some(super_class) = &class.super_class {
+            self.visit_expression(super_class);
+        }
+    }
+
+    fn visit_this_expression(&mut self, expr: &ThisExpression) {
+        Self::record(&mut self.this_expressions, expr.span, self.state);
+    }
+
+    // `super.foo`, as the callee of `super()` is not visited.
+    fn visit_super(&mut self, expr: &Super) {
+        Self::record(&mut self.super_members, expr.span, self.state);
+    }
+
+    fn visit_call_expression(&mut self, expr: &CallExpression<'a>) {
+        if matches!(expr.callee, Expression::Super(_)) {
+            for arg in &expr.arguments {
+                self.visit_argument(arg);
+            }
+            Self::record(&mut self.super_calls, expr.span, self.state);
+            self.state = self.state.after_call();
+            return;
+        }
+        self.visit_expression(&expr.callee);
+        for arg in &expr.arguments {
+            self.visit_argument(arg);
+        }
+    }
+
+    fn visit_logical_expression(&mut self, expr: &LogicalExpression<'a>) {
+        self.visit_expression(&expr.left);
+        let skipped = self.state;
+        self.visit_expression(&expr.right);
+        self.state = self.state.union(skipped);
+    }
+
+    fn visit_assignment_expression(&mut self, expr: &AssignmentExpression<'a>) {
+        self.visit_assignment_target(&expr.left);
+        let skipped = self.state;
+        self.visit_expression(&expr.right);
+        if expr.operator.is_logical() {
+            self.state = self.state.union(skipped);
+        }
+    }
+
+    fn visit_conditional_expression(&mut self, expr: &ConditionalExpression<'a>) {
+        self.visit_expression(&expr.test);
+        let test = self.state;
+        self.visit_expression(&expr.consequent);
+        let consequent = self.state;
+        self.state = test;
+        self.visit_expression(&expr.alternate);
+        self.state = self.state.union(consequent);
+    }
+
+    fn visit_if_statement(&mut self, stmt: &IfStatement<'a>) {
+        self.visit_expression(&stmt.test);
+        let test = self.state;
+        self.visit_statement(&stmt.consequent);
+        let consequent = self.state;
+        self.state = test;
+        if let Some(alternate) = &stmt.alternate {
+            self.visit_statement(alternate);
+        }
+        self.state = self.state.union(consequent);
+    }
+
+    fn visit_labeled_statement(&mut self, stmt: &LabeledStatement<'a>) {
+        self.pending_labels.push(stmt.label.name.clone());
+        match &stmt.body {
+            Statement::LabeledStatement(_)
+            | Statement::WhileStatement(_)
+            | Statement::DoWhileStatement(_)
+            | Statement::ForStatement(_)
+            | Statement::ForInStatement(_)
+            | Statement::ForOfStatement(_)
+            | Statement::SwitchStatement(_) => self.visit_statement(&stmt.body),
+            body => {
+                self.push_target(false, false);
+                self.visit_statement(body);
+                let target = self.targets.pop();
+                self.state = self.state.union(target.breaks);
+            }
+        }
+    }
+
+    fn visit_break_statement(&mut self, stmt: &BreakStatement) {
+        let state = self.state;
+        if let Some(target) = self.targets.find(stmt.label.as_ref().map(|label| &label.name), false)
+        {
+            target.breaks = target.breaks.union(state);
+        }
+        self.state = SuperCalls::UNREACHABLE;
+    }
+
+    fn visit_continue_statement(&mut self, stmt: &ContinueStatement) {
+        let state = self.state;
+        if let Some(target) = self.targets.find(stmt.label.as_ref().map(|label| &label.name), true)
+        {
+            target.continues = target.continues.union(state);
+        }
+        self.state = SuperCalls::UNREACHABLE;
+    }
+
+    fn visit_return_statement(&mut self, stmt: &ReturnStatement<'a>) {
+        if let Some(argument) = &stmt.argument {
+            self.visit_expression(argument);
+        }
+        self.returns = self.returns.union(self.state);
+        self.state = SuperCalls::UNREACHABLE;
+    }
+
+    fn visit_throw_statement(&mut self, stmt: &ThrowStatement<'a>) {
+        self.visit_expression(&stmt.argument);
+        self.state = SuperCalls::UNREACHABLE;
+    }
+
+    fn visit_while_statement(&mut self, stmt: &WhileStatement<'a>) {
+        let mut exit = SuperCalls::UNREACHABLE;
+        let breaks = self.visit_loop(|this| {
+            this.visit_expression(&stmt.test);
+            exit = this.state;
+            this.visit_statement(&stmt.body);
+            this.state
+        });
+        self.state = exit.union(breaks);
+    }
+
+    fn visit_do_while_statement(&mut self, stmt: &DoWhileStatement<'a>) {
+        let mut exit = SuperCalls::UNREACHABLE;
+        let breaks = self.visit_loop(|this| {
+            this.visit_statement(&stmt.body);
+            this.state = this.state.union(this.take_continues());
+            this.visit_expression(&stmt.test);
+            exit = this.state;
+            this.state
+        });
+        self.state = exit.union(breaks);
+    }
+
+    fn visit_for_statement(&mut self, stmt: &ForStatement<'a>) {
+        if let Some(init) = &stmt.init {
+            self.visit_for_statement_init(init);
+        }
+        let mut exit = SuperCalls::UNREACHABLE;
+        let breaks = self.visit_loop(|this| {
+            if let Some(test) = &stmt.test {
+                this.visit_expression(test);
+                exit = this.state;
+            }
+            this.visit_statement(&stmt.body);
+            this.state = this.state.union(this.take_continues());
+            if let Some(update) = &stmt.update {
+                this.visit_expression(update);
+            }
+            this.state
+        });
+        self.state = exit.union(breaks);
+    }
+
+    fn visit_for_in_statement(&mut self, stmt: &ForInStatement<'a>) {
+        self.visit_expression(&stmt.right);
+        self.visit_for_each_loop(&stmt.left, &stmt.body);
+    }
+
+    fn visit_for_of_statement(&mut self, stmt: &ForOfStatement<'a>) {
+        self.visit_expression(&stmt.right);
+        self.visit_for_each_loop(&stmt.left, &stmt.body);
+    }
+
+    fn visit_switch_statement(&mut self, stmt: &SwitchStatement<'a>) {
+        self.visit_expression(&stmt.discriminant);
+        let discriminant = self.state;
+        self.push_target(false, true);
+        let mut fallthrough = SuperCalls::UNREACHABLE;
+        for case in &stmt.cases {
+            self.state = discriminant;
+            if let Some(test) = &case.test {
+                self.visit_expression(test);
+            }
+            self.state = self.state.union(fallthrough);
+            self.visit_statements(&case.consequent);
+            fallthrough = self.state;
+        }
+        let target = self.targets.pop();
+        let no_match = if stmt.cases.iter().any(|case| case.test.is_none()) {
+            SuperCalls::UNREACHABLE
+        } else {
+            discriminant
+        };
+        self.state = fallthrough.union(target.breaks).union(no_match);
+    }
+
+    fn visit_try_statement(&mut self, stmt: &TryStatement<'a>) {
+        let entry = self.state;
+        self.visit_block_statement(&stmt.block);
+        let mut completion = self.state;
+        // Any point of the `try` block may throw, so the paths entering the handlers are
+        // approximated by the state before and after it.
+        let mut thrown = entry.union(completion);
+        if let Some(handler) = &stmt.handler {
+            self.state = thrown;
+            if let Some(param) = &handler.param {
+                self.visit_binding_pattern(param);
+            }
+            self.visit_statements(&handler.body.body);
+            thrown = thrown.union(self.state);
+            completion = completion.union(self.state);
+        }
+        if let Some(finalizer) = &stmt.finalizer {
+            if completion.is_reachable() {
+                self.state = completion;
+                self.visit_block_statement(finalizer);
+            } else {
+                self.state = thrown;
+                self.visit_block_statement(finalizer);
+                self.state = SuperCalls::UNREACHABLE;
+            }
+        } else {
+            self.state = completion;
+        }
+    }
+}
+
+impl SuperCallAnalyzer {
+    fn visit_for_each_loop<'a>(&mut self, left: &ForStatementLeft<'a>, body: &Statement<'a>) {
+        let mut exit = SuperCalls::UNREACHABLE;
+        let breaks = self.visit_loop(|this| {
+            // The iteration ends at the head of the loop, before the left side is assigned.
+            exit = exit.union(this.state);
+            this.visit_for_statement_left(left);
+            this.visit_statement(body);
+            this.state
+        });
+        self.state = exit.union(breaks);
+    }
+}
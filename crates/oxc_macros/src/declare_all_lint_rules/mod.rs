@@ -101,12 +101,19 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
-            pub fn read_json(&self, maybe_value: Option<serde_json::Value>) -> Self {
-                match self {
-                    #(Self::#struct_names(_) => Self::#struct_names(
-                        maybe_value.map(#struct_names::from_configuration).unwrap_or_default(),
-                    )),*
-                }
+            /// Reads the configuration of the rule, `None` for the default configuration.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error describing the first invalid option.
+            pub fn read_json(&self, maybe_value: Option<serde_json::Value>) -> Result<Self, oxc_diagnostics::Error> {
+                let rule = match self {
+                    #(Self::#struct_names(_) => match maybe_value {
+                        Some(value) => <#struct_names as Rule>::try_from_configuration(value).map(Self::#struct_names),
+                        None => Ok(Self::#struct_names(#struct_names::default())),
+                    }),*
+                };
+                rule.map_err(|err| err.wrap_err(format!("Invalid configuration for rule `{}`", self.name())))
             }
 
             pub fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>, print_execution_times: bool) {
@@ -1,5 +1,6 @@
 use oxc_allocator::Vec;
 use oxc_span::Span;
+use oxc_syntax::identifier::is_line_terminator;
 
 use crate::{
     array,
@@ -107,6 +108,39 @@ impl<'a> Prettier<'a> {
         Some(Doc::Array(parts))
     }
 
+    /// Print the comments following a list element (array element or object property) up to `end`,
+    /// e.g. `[a, /* x */ b]` or `{ a, // note`.
+    /// Only comments on the same line as the element are taken, own-line comments are left as
+    /// leading comments of the next element.
+    #[must_use]
+    pub(crate) fn print_element_trailing_comments(
+        &mut self,
+        range: Span,
+        end: u32,
+    ) -> Option<Doc<'a>> {
+        let mut parts = self.vec();
+        let mut previous_comment: Option<Comment> = None;
+        while let Some((start, comment_end, kind)) = self.trivias.peek().copied() {
+            let comment = Comment::new(start, comment_end, kind);
+            if range.end <= comment.start
+                && comment.end <= end
+                && !self.source_text[range.end as usize..comment.start as usize]
+                    .chars()
+                    .any(is_line_terminator)
+            {
+                self.trivias.next();
+                let previous = self.print_trailing_comment(&mut parts, comment, previous_comment);
+                previous_comment = Some(previous);
+            } else {
+                break;
+            }
+        }
+        if parts.is_empty() {
+            return None;
+        }
+        Some(Doc::Array(parts))
+    }
+
     fn print_trailing_comment(
         &mut self,
         parts: &mut Vec<'a, Doc<'a>>,
@@ -78,11 +78,21 @@ pub struct Group<'a> {
     pub contents: Vec<'a, Doc<'a>>,
     pub should_break: bool,
     pub id: Option<GroupId>,
+    /// Alternative layouts of a `conditionalGroup`, tried in order after `contents`.
+    /// The last one is the most expanded state.
+    pub expanded_states: Option<Vec<'a, Doc<'a>>>,
 }
 
 impl<'a> Group<'a> {
     pub fn new(contents: Vec<'a, Doc<'a>>, should_break: bool) -> Self {
-        Self { contents, should_break, id: None }
+        Self { contents, should_break, id: None, expanded_states: None }
+    }
+    /// `conditionalGroup([contents, ...expanded_states])`
+    pub fn new_conditional_group(
+        contents: Vec<'a, Doc<'a>>,
+        expanded_states: Vec<'a, Doc<'a>>,
+    ) -> Self {
+        Self { contents, should_break: false, id: None, expanded_states: Some(expanded_states) }
     }
     pub fn with_id(mut self, id: GroupId) -> Self {
         self.id = Some(id);
@@ -156,6 +166,60 @@ pub enum Separator {
     CommaLine, // [",", line]
 }
 
+impl<'a> Doc<'a> {
+    /// Whether the doc contains a forced break.
+    ///
+    /// See `willBreak` in <https://github.com/prettier/prettier/blob/main/src/document/utils.js>
+    pub fn will_break(&self) -> bool {
+        match self {
+            Doc::BreakParent => true,
+            Doc::Line(line) => line.hard,
+            Doc::Group(group) => group.should_break || group.contents.iter().any(Doc::will_break),
+            Doc::Array(docs)
+            | Doc::Indent(docs)
+            | Doc::IndentIfBreak(IndentIfBreak { contents: docs, .. })
+            | Doc::Fill(Fill { parts: docs }) => docs.iter().any(Doc::will_break),
+            Doc::IfBreak(if_break) => {
+                if_break.break_contents.will_break() || if_break.flat_content.will_break()
+            }
+            Doc::Str(_) | Doc::LineSuffix(_) => false,
+        }
+    }
+
+    /// Deep copy of the doc, for printing the same node in more than one layout.
+    pub fn clone_in(&self, allocator: &'a Allocator) -> Self {
+        let clone_vec = |docs: &Vec<'a, Doc<'a>>| {
+            let mut cloned = Vec::with_capacity_in(docs.len(), allocator);
+            cloned.extend(docs.iter().map(|doc| doc.clone_in(allocator)));
+            cloned
+        };
+        match self {
+            Doc::Str(s) => Doc::Str(s),
+            Doc::Array(docs) => Doc::Array(clone_vec(docs)),
+            Doc::Indent(docs) => Doc::Indent(clone_vec(docs)),
+            Doc::IndentIfBreak(doc) => Doc::IndentIfBreak(IndentIfBreak {
+                contents: clone_vec(&doc.contents),
+                group_id: doc.group_id,
+            }),
+            Doc::Group(group) => Doc::Group(Group {
+                contents: clone_vec(&group.contents),
+                should_break: group.should_break,
+                id: group.id,
+                expanded_states: group.expanded_states.as_ref().map(clone_vec),
+            }),
+            Doc::Line(line) => Doc::Line(*line),
+            Doc::LineSuffix(docs) => Doc::LineSuffix(clone_vec(docs)),
+            Doc::IfBreak(if_break) => Doc::IfBreak(IfBreak {
+                break_contents: Box(allocator.alloc(if_break.break_contents.clone_in(allocator))),
+                flat_content: Box(allocator.alloc(if_break.flat_content.clone_in(allocator))),
+                group_id: if_break.group_id,
+            }),
+            Doc::Fill(fill) => Doc::Fill(Fill::new(clone_vec(&fill.parts))),
+            Doc::BreakParent => Doc::BreakParent,
+        }
+    }
+}
+
 /// Doc Builder
 pub trait DocBuilder<'a> {
     fn allocator(&self) -> &'a Allocator;
@@ -251,7 +315,18 @@ fn print_doc_to_debug(doc: &Doc<'_>) -> std::string::String {
                     string.push_str(", ");
                 }
             }
-            string.push_str("], { shouldBreak: ");
+            string.push(']');
+            if let Some(expanded_states) = &group.expanded_states {
+                string.push_str(", expandedStates: [");
+                for (idx, doc) in expanded_states.iter().enumerate() {
+                    string.push_str(&print_doc_to_debug(doc));
+                    if idx != expanded_states.len() - 1 {
+                        string.push_str(", ");
+                    }
+                }
+                string.push(']');
+            }
+            string.push_str(", { shouldBreak: ");
             string.push_str(&group.should_break.to_string());
             if let Some(id) = group.id {
                 string.push_str(&format!(", id: {id}"));
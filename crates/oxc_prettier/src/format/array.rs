@@ -1,5 +1,5 @@
 use oxc_ast::ast::*;
-use oxc_span::Span;
+use oxc_span::{GetSpan, Span};
 use oxc_syntax::operator::UnaryOperator;
 
 use crate::{
@@ -36,35 +36,67 @@ impl<'a, 'b> Array<'a, 'b> {
             Self::ArrayAssignmentTarget(array) => array.span,
         }
     }
-    fn is_concisely_printed(&self) -> bool {
+    /// Classify the array in a single pass over its elements.
+    fn shape(&self) -> ArrayShape {
+        if self.len() <= 1 {
+            return ArrayShape::default();
+        }
+
         match self {
             Self::ArrayExpression(array) => {
-                if array.elements.len() <= 1 {
-                    return false;
-                }
+                let mut shape = ArrayShape { is_concisely_printed: true, should_break: true };
+                for (index, element) in array.elements.iter().enumerate() {
+                    #[cfg(test)]
+                    CLASSIFIED_ELEMENTS.with(|count| count.set(count.get() + 1));
 
-                return array.elements.iter().all(|element| {
-                    let ArrayExpressionElement::Expression(expr) = element else {
-                        return false;
-                    };
-
-                    match expr {
-                        Expression::NumberLiteral(_) => true,
-                        Expression::UnaryExpression(unary_expr) => {
-                            matches!(
-                                unary_expr.operator,
-                                UnaryOperator::UnaryPlus | UnaryOperator::UnaryNegation
-                            ) && matches!(unary_expr.argument, Expression::NumberLiteral(_))
-                        }
-                        _ => false,
+                    shape.is_concisely_printed &= is_concisely_printed_element(element);
+                    shape.should_break &=
+                        should_break_element(element, array.elements.get(index + 1));
+                    if !shape.is_concisely_printed && !shape.should_break {
+                        break;
                     }
-                });
+                }
+                shape
+            }
+            Self::TSTupleType(tuple) => {
+                ArrayShape { is_concisely_printed: false, should_break: should_break_tuple(tuple) }
             }
-            Self::ArrayPattern(_) | Self::ArrayAssignmentTarget(_) | Self::TSTupleType(_) => false,
+            Self::ArrayPattern(_) | Self::ArrayAssignmentTarget(_) => ArrayShape::default(),
         }
     }
 }
 
+/// How an array with more than one element is printed.
+#[derive(Debug, Default, Clone, Copy)]
+struct ArrayShape {
+    /// All elements are (signed) numbers, which are filled into as few lines as possible.
+    is_concisely_printed: bool,
+    /// All elements are arrays or objects with more than one element, so each goes on its own line.
+    should_break: bool,
+}
+
+#[cfg(test)]
+thread_local! {
+    /// Number of elements looked at by `Array::shape`.
+    static CLASSIFIED_ELEMENTS: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+pub(super) fn is_concisely_printed_array(array: &ArrayExpression<'_>) -> bool {
+    array.elements.len() > 1 && array.elements.iter().all(is_concisely_printed_element)
+}
+
+/// A number literal, or a number literal preceded by `+` or `-`.
+fn is_concisely_printed_element(element: &ArrayExpressionElement<'_>) -> bool {
+    match element {
+        ArrayExpressionElement::Expression(Expression::NumberLiteral(_)) => true,
+        ArrayExpressionElement::Expression(Expression::UnaryExpression(unary_expr)) => {
+            matches!(unary_expr.operator, UnaryOperator::UnaryPlus | UnaryOperator::UnaryNegation)
+                && matches!(unary_expr.argument, Expression::NumberLiteral(_))
+        }
+        _ => false,
+    }
+}
+
 pub(super) fn print_array<'a>(p: &mut Prettier<'a>, array: &Array<'a, '_>) -> Doc<'a> {
     if array.len() == 0 {
         return print_empty_array_elements(p, array);
@@ -83,7 +115,8 @@ pub(super) fn print_array<'a>(p: &mut Prettier<'a>, array: &Array<'a, '_>) -> Do
         };
 
     let id = p.next_id();
-    let should_use_concise_formatting = array.is_concisely_printed();
+    let shape = array.shape();
+    let should_use_concise_formatting = shape.is_concisely_printed;
 
     let trailing_comma_fn = |p: &Prettier<'a>| {
         if !can_have_trailing_comma {
@@ -114,8 +147,7 @@ pub(super) fn print_array<'a>(p: &mut Prettier<'a>, array: &Array<'a, '_>) -> Do
     parts.push(parts_inner);
     parts.push(softline!());
     parts.push(ss!("]"));
-    let should_break = should_break(array);
-    Doc::Group(Group::new(parts, should_break).with_id(id))
+    Doc::Group(Group::new(parts, shape.should_break).with_id(id))
 }
 
 fn print_empty_array_elements<'a>(p: &mut Prettier<'a>, array: &Array<'a, '_>) -> Doc<'a> {
@@ -131,12 +163,23 @@ fn print_elements<'a>(p: &mut Prettier<'a>, array: &Array<'a, '_>) -> Doc<'a> {
     match array {
         Array::ArrayExpression(array) => {
             for (i, element) in array.elements.iter().enumerate() {
-                if i > 0 && i < array.elements.len() {
+                if let Some(leading) = p.print_leading_comments(element.span()) {
+                    parts.push(leading);
+                }
+                parts.push(element.format(p));
+
+                let is_last = i == array.elements.len() - 1;
+                if !is_last {
                     parts.push(ss!(","));
+                }
+                let end =
+                    array.elements.get(i + 1).map_or(array.span.end, |next| next.span().start);
+                if let Some(trailing) = p.print_element_trailing_comments(element.span(), end) {
+                    parts.push(trailing);
+                }
+                if !is_last {
                     parts.push(line!());
                 }
-
-                parts.push(element.format(p));
             }
         }
         Array::TSTupleType(tuple) => {
@@ -150,14 +193,34 @@ fn print_elements<'a>(p: &mut Prettier<'a>, array: &Array<'a, '_>) -> Doc<'a> {
             }
         }
         Array::ArrayPattern(array_pat) => {
+            let elements_end =
+                array_pat.rest.as_ref().map_or(array_pat.span.end, |rest| rest.span.start);
             for (i, element) in array_pat.elements.iter().enumerate() {
-                if i > 0 && i < array_pat.elements.len() {
-                    parts.push(ss!(","));
-                    parts.push(line!());
+                if let Some(binding_pat) = element {
+                    if let Some(leading) = p.print_leading_comments(binding_pat.span()) {
+                        parts.push(leading);
+                    }
+                    parts.push(binding_pat.format(p));
                 }
 
+                let is_last = i == array_pat.elements.len() - 1;
+                if !is_last {
+                    parts.push(ss!(","));
+                }
                 if let Some(binding_pat) = element {
-                    parts.push(binding_pat.format(p));
+                    let end = array_pat
+                        .elements
+                        .get(i + 1)
+                        .and_then(Option::as_ref)
+                        .map_or(elements_end, |next| next.span().start);
+                    if let Some(trailing) =
+                        p.print_element_trailing_comments(binding_pat.span(), end)
+                    {
+                        parts.push(trailing);
+                    }
+                }
+                if !is_last {
+                    parts.push(line!());
                 }
             }
 
@@ -224,60 +287,76 @@ where
     Doc::Fill(Fill::new(parts))
 }
 
-fn should_break(array: &Array) -> bool {
-    if array.len() <= 1 {
+fn should_break_element(
+    element: &ArrayExpressionElement<'_>,
+    next_element: Option<&ArrayExpressionElement<'_>>,
+) -> bool {
+    let ArrayExpressionElement::Expression(element) = element else {
         return false;
+    };
+    if let Some(ArrayExpressionElement::Expression(next_element)) = next_element {
+        let all_array_or_object = matches!(
+            (element, next_element),
+            (Expression::ArrayExpression(_), Expression::ArrayExpression(_))
+                | (Expression::ObjectExpression(_), Expression::ObjectExpression(_))
+        );
+        if !all_array_or_object {
+            return false;
+        }
     }
 
-    match array {
-        Array::ArrayExpression(array) => {
-            array.elements.iter().enumerate().all(|(index, element)| {
-                let ArrayExpressionElement::Expression(element) = element else {
-                    return false;
-                };
-                if let Some(ArrayExpressionElement::Expression(next_element)) =
-                    array.elements.get(index + 1)
-                {
-                    let all_array_or_object = matches!(
-                        (element, next_element),
-                        (Expression::ArrayExpression(_), Expression::ArrayExpression(_))
-                            | (Expression::ObjectExpression(_), Expression::ObjectExpression(_))
-                    );
-                    if !all_array_or_object {
-                        return false;
-                    }
-                }
+    match element {
+        Expression::ArrayExpression(array) => array.elements.len() > 1,
+        Expression::ObjectExpression(object) => object.properties.len() > 1,
+        _ => false,
+    }
+}
 
-                match element {
-                    Expression::ArrayExpression(array) => array.elements.len() > 1,
-                    Expression::ObjectExpression(object) => object.properties.len() > 1,
-                    _ => false,
-                }
-            })
+fn should_break_tuple(tuple: &TSTupleType<'_>) -> bool {
+    tuple.element_types.iter().enumerate().all(|(index, element)| {
+        let TSTupleElement::TSType(element) = element else { return false };
+
+        if let Some(TSTupleElement::TSType(next_element)) = tuple.element_types.get(index + 1) {
+            if !matches!((element, next_element), (TSType::TSTupleType(_), TSType::TSTupleType(_)))
+            {
+                return false;
+            }
         }
-        Array::TSTupleType(tuple) => {
-            tuple.element_types.iter().enumerate().all(|(index, element)| {
-                let TSTupleElement::TSType(element) = element else { return false };
-
-                if let Some(TSTupleElement::TSType(next_element)) =
-                    tuple.element_types.get(index + 1)
-                {
-                    if !matches!(
-                        (element, next_element),
-                        (TSType::TSTupleType(_), TSType::TSTupleType(_))
-                    ) {
-                        return false;
-                    }
-                }
 
-                let TSType::TSTupleType(array) = element else {
-                    return false;
-                };
+        let TSType::TSTupleType(array) = element else {
+            return false;
+        };
 
-                array.element_types.len() > 1
-            })
-        }
-        Array::ArrayPattern(array) => false,
-        Array::ArrayAssignmentTarget(array) => false,
+        array.element_types.len() > 1
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    use super::CLASSIFIED_ELEMENTS;
+    use crate::{Prettier, PrettierOptions};
+
+    fn classified_elements(source_text: &str) -> usize {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::default())
+            .preserve_parens(false)
+            .parse();
+        CLASSIFIED_ELEMENTS.with(|count| count.set(0));
+        Prettier::new(&allocator, source_text, ret.trivias, PrettierOptions::default())
+            .build(&ret.program);
+        CLASSIFIED_ELEMENTS.with(std::cell::Cell::get)
+    }
+
+    #[test]
+    fn elements_are_classified_once() {
+        let numbers = (0..100).map(|i| i.to_string()).collect::<Vec<_>>().join(", ");
+        assert_eq!(classified_elements(&format!("const a = [{numbers}];")), 100);
+        assert_eq!(classified_elements("const a = [[1, 2], [3, 4], [5, 6]];"), 3 + 3 * 2);
+        // Stops once the array can neither be printed concisely nor be broken.
+        assert_eq!(classified_elements("const a = [a, 1, 2];"), 1);
     }
 }
@@ -2,7 +2,7 @@ use oxc_ast::ast::*;
 
 use crate::{
     doc::{Doc, DocBuilder},
-    ss, Format, Prettier,
+    ss, ArrowParens, Format, Prettier,
 };
 
 pub(super) fn print_arrow_function<'a>(
@@ -15,8 +15,20 @@ pub(super) fn print_arrow_function<'a>(
         parts.push(ss!("async "));
     }
 
-    let parameters = expr.params.format(p);
-    parts.push(parameters);
+    if let Some(type_parameters) = &expr.type_parameters {
+        parts.push(type_parameters.format(p));
+    }
+
+    if should_print_params_without_parens(p, expr) {
+        parts.push(expr.params.items[0].format(p));
+    } else {
+        parts.push(expr.params.format(p));
+    }
+
+    if let Some(return_type) = &expr.return_type {
+        parts.push(ss!(": "));
+        parts.push(return_type.format(p));
+    }
     parts.push(ss!(" => "));
 
     if expr.expression {
@@ -33,3 +45,22 @@ pub(super) fn print_arrow_function<'a>(
 
     Doc::Array(parts)
 }
+
+fn should_print_params_without_parens<'a>(p: &Prettier<'a>, expr: &ArrowExpression<'a>) -> bool {
+    match p.options.arrow_parens {
+        ArrowParens::Always => false,
+        ArrowParens::Avoid => can_print_params_without_parens(expr),
+    }
+}
+
+/// Only a single plain identifier parameter, e.g. `x => x`.
+/// Defaults, destructuring, rest, optional and typed parameters all need parentheses.
+fn can_print_params_without_parens(expr: &ArrowExpression<'_>) -> bool {
+    if expr.type_parameters.is_some() || expr.return_type.is_some() || expr.params.rest.is_some() {
+        return false;
+    }
+    let [param] = expr.params.items.as_slice() else { return false };
+    matches!(param.pattern.kind, BindingPatternKind::BindingIdentifier(_))
+        && param.pattern.type_annotation.is_none()
+        && !param.pattern.optional
+}
@@ -209,7 +209,7 @@ fn choose_layout<'a>(
                     | Expression::ClassExpression(_)
             ))
     {
-        return Layout::BreakAfterOperator;
+        return Layout::NeverBreakAfterOperator;
     }
 
     Layout::Fluid
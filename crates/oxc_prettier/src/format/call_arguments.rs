@@ -0,0 +1,407 @@
+use oxc_allocator::Vec;
+use oxc_ast::ast::*;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::operator::UnaryOperator;
+
+use crate::{
+    doc::{Doc, DocBuilder, Group},
+    hardline, if_break, line, softline, ss, Format, Prettier,
+};
+
+use super::{array::is_concisely_printed_array, call_expression::CallExpressionLike};
+
+/// Reference:
+/// * <https://github.com/prettier/prettier/blob/main/src/language-js/print/call-arguments.js>
+pub(super) fn print_call_arguments<'a>(
+    p: &mut Prettier<'a>,
+    expression: &CallExpressionLike<'a, '_>,
+) -> Doc<'a> {
+    let arguments = expression.arguments();
+
+    if arguments.is_empty() {
+        let callee = expression.callee();
+        let mut parts = p.vec();
+        parts.push(ss!("("));
+        parts.extend(p.print_inner_comment(Span::new(callee.span().end, expression.span().end)));
+        parts.push(ss!(")"));
+        return Doc::Array(parts);
+    }
+
+    // useEffect(() => { ... }, [foo, bar, baz])
+    if is_react_hook_call_with_deps_array(arguments) {
+        let mut parts = p.vec();
+        parts.push(ss!("("));
+        parts.push(arguments[0].format(p));
+        parts.push(ss!(", "));
+        parts.push(arguments[1].format(p));
+        parts.push(ss!(")"));
+        return Doc::Array(parts);
+    }
+
+    let mut any_arg_empty_line = false;
+    let last_index = arguments.len() - 1;
+    let mut printed_arguments = p.vec();
+    for (i, argument) in arguments.iter().enumerate() {
+        let mut parts = p.vec();
+        parts.push(argument.format(p));
+        if i == last_index {
+        } else if p.is_next_line_empty(argument.span().end) {
+            any_arg_empty_line = true;
+            parts.push(ss!(","));
+            parts.extend(hardline!());
+            parts.extend(hardline!());
+        } else {
+            parts.push(ss!(","));
+            parts.push(line!());
+        }
+        printed_arguments.push(Doc::Array(parts));
+    }
+
+    if any_arg_empty_line || is_function_composition_args(arguments) {
+        return all_args_broken_out(p, printed_arguments);
+    }
+
+    if should_group_first_arg(arguments) {
+        // Hugging is not possible when any of the other arguments has to break.
+        if printed_arguments.iter().skip(1).any(Doc::will_break) {
+            return all_args_broken_out(p, printed_arguments);
+        }
+
+        let mut hugged = p.vec();
+        hugged.push(ss!("("));
+        for (i, doc) in printed_arguments.iter().enumerate() {
+            let doc = doc.clone_in(p.allocator);
+            if i == 0 {
+                let mut group = p.vec();
+                group.push(doc);
+                hugged.push(Doc::Group(Group::new(group, true)));
+            } else {
+                hugged.push(doc);
+            }
+        }
+        hugged.push(ss!(")"));
+
+        return print_conditional_group(p, printed_arguments, hugged);
+    }
+
+    if should_group_last_arg(arguments) {
+        if printed_arguments.iter().take(last_index).any(Doc::will_break) {
+            return all_args_broken_out(p, printed_arguments);
+        }
+
+        let mut hugged = p.vec();
+        hugged.push(ss!("("));
+        for (i, doc) in printed_arguments.iter().enumerate() {
+            let doc = doc.clone_in(p.allocator);
+            if i == last_index {
+                let mut group = p.vec();
+                group.push(doc);
+                hugged.push(Doc::Group(Group::new(group, true)));
+            } else {
+                hugged.push(doc);
+            }
+        }
+        hugged.push(ss!(")"));
+
+        return print_conditional_group(p, printed_arguments, hugged);
+    }
+
+    let should_break = printed_arguments.iter().any(Doc::will_break);
+    let mut parts = p.vec();
+    parts.push(ss!("("));
+    let mut indent_parts = p.vec();
+    indent_parts.push(softline!());
+    indent_parts.extend(printed_arguments);
+    parts.push(Doc::Indent(indent_parts));
+    parts.push(if_break!(p, maybe_trailing_comma(p), "", None));
+    parts.push(softline!());
+    parts.push(ss!(")"));
+    Doc::Group(Group::new(parts, should_break))
+}
+
+/// `conditionalGroup([allArgsFlat, hugged, allArgsBrokenOut])`
+fn print_conditional_group<'a>(
+    p: &Prettier<'a>,
+    printed_arguments: Vec<'a, Doc<'a>>,
+    hugged: Vec<'a, Doc<'a>>,
+) -> Doc<'a> {
+    let will_break = printed_arguments.iter().any(Doc::will_break);
+
+    let mut all_flat = p.vec();
+    all_flat.push(ss!("("));
+    all_flat.extend(printed_arguments.iter().map(|doc| doc.clone_in(p.allocator)));
+    all_flat.push(ss!(")"));
+
+    let mut expanded_states = p.vec();
+    expanded_states.push(Doc::Array(hugged));
+    expanded_states.push(all_args_broken_out(p, printed_arguments));
+
+    let mut parts = p.vec();
+    if will_break {
+        parts.push(Doc::BreakParent);
+    }
+    parts.push(Doc::Group(Group::new_conditional_group(all_flat, expanded_states)));
+    Doc::Array(parts)
+}
+
+fn all_args_broken_out<'a>(p: &Prettier<'a>, printed_arguments: Vec<'a, Doc<'a>>) -> Doc<'a> {
+    let mut parts = p.vec();
+    parts.push(ss!("("));
+    let mut indent_parts = p.vec();
+    indent_parts.push(line!());
+    indent_parts.extend(printed_arguments);
+    indent_parts.push(ss!(maybe_trailing_comma(p)));
+    parts.push(Doc::Indent(indent_parts));
+    parts.push(line!());
+    parts.push(ss!(")"));
+    Doc::Group(Group::new(parts, true))
+}
+
+fn maybe_trailing_comma(p: &Prettier<'_>) -> &'static str {
+    if p.should_print_all_comma() {
+        ","
+    } else {
+        ""
+    }
+}
+
+fn should_group_first_arg(arguments: &Vec<'_, Argument<'_>>) -> bool {
+    let [Argument::Expression(first_arg), Argument::Expression(second_arg)] = arguments.as_slice()
+    else {
+        return false;
+    };
+
+    let first_arg_is_function = match first_arg {
+        Expression::FunctionExpression(_) => true,
+        Expression::ArrowExpression(arrow) => !arrow.expression,
+        _ => false,
+    };
+
+    first_arg_is_function
+        && !matches!(
+            second_arg,
+            Expression::FunctionExpression(_)
+                | Expression::ArrowExpression(_)
+                | Expression::ConditionalExpression(_)
+        )
+        && is_hopefully_short_call_argument(second_arg)
+        && !could_expand_arg(second_arg, false)
+}
+
+fn should_group_last_arg(arguments: &Vec<'_, Argument<'_>>) -> bool {
+    let Some(Argument::Expression(last_arg)) = arguments.last() else { return false };
+    let penultimate_arg = arguments.len().checked_sub(2).map(|i| &arguments[i]);
+
+    could_expand_arg(last_arg, false)
+        // If the last two arguments are of the same type, hug neither of them.
+        && !penultimate_arg.is_some_and(|arg| is_same_type(arg, last_arg))
+        // useMemo(() => func, [foo, bar, baz])
+        && !(arguments.len() == 2
+            && matches!(penultimate_arg, Some(Argument::Expression(Expression::ArrowExpression(_))))
+            && matches!(last_arg, Expression::ArrayExpression(_)))
+        && !(arguments.len() > 1
+            && matches!(last_arg, Expression::ArrayExpression(array) if is_concisely_printed_array(array)))
+}
+
+fn is_same_type<'a>(argument: &Argument<'a>, expr: &Expression<'a>) -> bool {
+    let Argument::Expression(argument) = argument else { return false };
+    std::mem::discriminant(argument) == std::mem::discriminant(expr)
+}
+
+fn could_expand_arg(arg: &Expression<'_>, arrow_chain_recursion: bool) -> bool {
+    match arg {
+        Expression::ObjectExpression(object) => !object.properties.is_empty(),
+        Expression::ArrayExpression(array) => !array.elements.is_empty(),
+        Expression::TSTypeAssertion(expr) => could_expand_arg(&expr.expression, false),
+        Expression::TSAsExpression(expr) => could_expand_arg(&expr.expression, false),
+        Expression::FunctionExpression(_) => true,
+        Expression::ArrowExpression(arrow) => {
+            let is_type_reference_return = arrow.return_type.as_ref().is_some_and(|return_type| {
+                matches!(return_type.type_annotation, TSType::TSTypeReference(_))
+            });
+            if is_type_reference_return && (arrow.expression || arrow.body.is_empty()) {
+                return false;
+            }
+            let Some(body) = arrow.get_expression() else { return true };
+            match body {
+                Expression::ArrowExpression(_)
+                | Expression::ObjectExpression(_)
+                | Expression::ArrayExpression(_)
+                | Expression::TSAsExpression(_)
+                | Expression::TSSatisfiesExpression(_)
+                | Expression::JSXElement(_)
+                | Expression::JSXFragment(_) => true,
+                Expression::CallExpression(_)
+                | Expression::NewExpression(_)
+                | Expression::ConditionalExpression(_) => !arrow_chain_recursion,
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+fn is_function_composition_args(arguments: &Vec<'_, Argument<'_>>) -> bool {
+    if arguments.len() <= 1 {
+        return false;
+    }
+
+    let mut count = 0;
+    for argument in arguments {
+        let Argument::Expression(expr) = argument else { continue };
+        match expr {
+            Expression::FunctionExpression(_) => {
+                count += 1;
+            }
+            Expression::ArrowExpression(arrow) if !arrow.expression => {
+                count += 1;
+            }
+            Expression::CallExpression(call) if call.arguments.iter().any(is_function_argument) => {
+                return true;
+            }
+            Expression::NewExpression(call) if call.arguments.iter().any(is_function_argument) => {
+                return true;
+            }
+            _ => {}
+        }
+        if count > 1 {
+            return true;
+        }
+    }
+    false
+}
+
+fn is_function_argument(argument: &Argument<'_>) -> bool {
+    matches!(
+        argument,
+        Argument::Expression(Expression::FunctionExpression(_) | Expression::ArrowExpression(_))
+    )
+}
+
+fn is_react_hook_call_with_deps_array(arguments: &Vec<'_, Argument<'_>>) -> bool {
+    let [Argument::Expression(Expression::ArrowExpression(arrow)), Argument::Expression(Expression::ArrayExpression(deps))] =
+        arguments.as_slice()
+    else {
+        return false;
+    };
+    arrow.params.parameters_count() == 0
+        && !arrow.expression
+        && !deps
+            .elements
+            .iter()
+            .any(|element| matches!(element, ArrayExpressionElement::SpreadElement(_)))
+}
+
+fn is_hopefully_short_call_argument(expr: &Expression<'_>) -> bool {
+    match expr {
+        Expression::BinaryExpression(binary) => {
+            is_hopefully_short_call_argument(&binary.left)
+                && is_hopefully_short_call_argument(&binary.right)
+        }
+        Expression::LogicalExpression(logical) => {
+            is_hopefully_short_call_argument(&logical.left)
+                && is_hopefully_short_call_argument(&logical.right)
+        }
+        Expression::TSAsExpression(expr) => is_hopefully_short_call_argument(&expr.expression),
+        Expression::TSSatisfiesExpression(expr) => {
+            is_hopefully_short_call_argument(&expr.expression)
+        }
+        Expression::RegExpLiteral(regex) => regex.regex.pattern.len() <= 5,
+        _ => is_simple_call_argument(expr, 0),
+    }
+}
+
+/// Reference:
+/// * <https://github.com/prettier/prettier/blob/main/src/language-js/utils/index.js> `isSimpleCallArgument`
+fn is_simple_call_argument(expr: &Expression<'_>, depth: usize) -> bool {
+    match expr {
+        Expression::RegExpLiteral(regex) => regex.regex.pattern.len() <= 5,
+        Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_)
+        | Expression::NumberLiteral(_)
+        | Expression::BigintLiteral(_)
+        | Expression::StringLiteral(_)
+        | Expression::Identifier(_)
+        | Expression::ThisExpression(_)
+        | Expression::Super(_)
+        | Expression::MetaProperty(_) => true,
+        Expression::TemplateLiteral(template) => {
+            template.quasis.iter().all(|quasi| !quasi.value.raw.contains('\n'))
+                && template.expressions.iter().all(|expr| is_simple_call_argument(expr, depth))
+        }
+        Expression::ObjectExpression(object) => {
+            object.properties.iter().all(|property| match property {
+                ObjectPropertyKind::ObjectProperty(property) => {
+                    !property.computed
+                        && (property.shorthand || is_simple_call_argument(&property.value, depth))
+                }
+                ObjectPropertyKind::SpreadProperty(spread) => {
+                    is_simple_call_argument(&spread.argument, depth)
+                }
+            })
+        }
+        Expression::ArrayExpression(array) => array.elements.iter().all(|element| match element {
+            ArrayExpressionElement::Expression(expr) => is_simple_call_argument(expr, depth),
+            ArrayExpressionElement::SpreadElement(_) => false,
+            ArrayExpressionElement::Elision(_) => true,
+        }),
+        Expression::ImportExpression(import) => {
+            depth < 2 && is_simple_call_argument(&import.source, depth + 1)
+        }
+        Expression::CallExpression(call) => {
+            depth < 2
+                && is_simple_callee(&call.callee, depth)
+                && call.arguments.iter().all(|argument| match argument {
+                    Argument::Expression(expr) => is_simple_call_argument(expr, depth + 1),
+                    Argument::SpreadElement(_) => false,
+                })
+        }
+        Expression::NewExpression(call) => {
+            depth < 2
+                && is_simple_callee(&call.callee, depth)
+                && call.arguments.iter().all(|argument| match argument {
+                    Argument::Expression(expr) => is_simple_call_argument(expr, depth + 1),
+                    Argument::SpreadElement(_) => false,
+                })
+        }
+        Expression::MemberExpression(member) => is_simple_member_expression(member, depth),
+        Expression::ChainExpression(chain) => match &chain.expression {
+            ChainElement::MemberExpression(member) => is_simple_member_expression(member, depth),
+            ChainElement::CallExpression(_) => false,
+        },
+        Expression::UnaryExpression(unary) => {
+            matches!(
+                unary.operator,
+                UnaryOperator::LogicalNot
+                    | UnaryOperator::UnaryNegation
+                    | UnaryOperator::UnaryPlus
+                    | UnaryOperator::BitwiseNot
+            ) && is_simple_call_argument(&unary.argument, depth)
+        }
+        Expression::UpdateExpression(update) => {
+            matches!(&update.argument, SimpleAssignmentTarget::AssignmentTargetIdentifier(_))
+        }
+        Expression::TSNonNullExpression(expr) => is_simple_call_argument(&expr.expression, depth),
+        _ => false,
+    }
+}
+
+fn is_simple_callee(callee: &Expression<'_>, depth: usize) -> bool {
+    match callee {
+        Expression::Identifier(_) | Expression::ThisExpression(_) | Expression::Super(_) => true,
+        Expression::MemberExpression(member) => is_simple_member_expression(member, depth),
+        _ => false,
+    }
+}
+
+fn is_simple_member_expression(member: &MemberExpression<'_>, depth: usize) -> bool {
+    let is_simple_property = match member {
+        MemberExpression::StaticMemberExpression(_)
+        | MemberExpression::PrivateFieldExpression(_) => true,
+        MemberExpression::ComputedMemberExpression(computed) => matches!(
+            computed.expression,
+            Expression::Identifier(_) | Expression::StringLiteral(_) | Expression::NumberLiteral(_)
+        ),
+    };
+    is_simple_property && is_simple_call_argument(member.object(), depth)
+}
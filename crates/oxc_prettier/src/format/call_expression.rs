@@ -1,11 +1,11 @@
-use super::misc;
+use super::call_arguments;
 use oxc_allocator::Vec;
 use oxc_ast::ast::*;
 use oxc_span::{GetSpan, Span};
 
 use crate::{
     doc::{Doc, DocBuilder, Group},
-    if_break, line, softline, ss, Format, Prettier,
+    line, ss, Format, Prettier,
 };
 
 pub(super) enum CallExpressionLike<'a, 'b> {
@@ -17,7 +17,7 @@ impl<'a, 'b> CallExpressionLike<'a, 'b> {
     fn is_new(&self) -> bool {
         matches!(self, CallExpressionLike::NewExpression(_))
     }
-    fn callee(&self) -> &Expression<'a> {
+    pub(super) fn callee(&self) -> &Expression<'a> {
         match self {
             CallExpressionLike::CallExpression(call) => &call.callee,
             CallExpressionLike::NewExpression(new) => &new.callee,
@@ -29,7 +29,7 @@ impl<'a, 'b> CallExpressionLike<'a, 'b> {
             CallExpressionLike::NewExpression(new) => false,
         }
     }
-    fn arguments(&self) -> &Vec<'a, Argument<'a>> {
+    pub(super) fn arguments(&self) -> &Vec<'a, Argument<'a>> {
         match self {
             CallExpressionLike::CallExpression(call) => &call.arguments,
             CallExpressionLike::NewExpression(new) => &new.arguments,
@@ -71,49 +71,27 @@ fn print_call_expression_arguments<'a>(
     p: &mut Prettier<'a>,
     expression: &CallExpressionLike<'a, '_>,
 ) -> Doc<'a> {
-    let mut parts = p.vec();
-    parts.push(ss!("("));
-
-    let callee = expression.callee();
     let arguments = expression.arguments();
-    let should_break = if matches!(expression, CallExpressionLike::CallExpression(_)) {
-        !is_commons_js_or_amd_call(expression.callee(), arguments)
-    } else {
-        true
-    };
+    let is_commons_js_or_amd_call = matches!(expression, CallExpressionLike::CallExpression(_))
+        && is_commons_js_or_amd_call(expression.callee(), arguments);
 
-    if arguments.is_empty() {
-        parts.extend(p.print_inner_comment(Span::new(callee.span().end, expression.span().end)));
-        parts.push(ss!(")"));
-        return Doc::Array(parts);
+    if !is_commons_js_or_amd_call || arguments.is_empty() {
+        return call_arguments::print_call_arguments(p, expression);
     }
 
-    let mut parts_inner = p.vec();
-
+    let mut parts = p.vec();
+    parts.push(ss!("("));
     for (i, element) in arguments.iter().enumerate() {
         let doc = element.format(p);
-        parts_inner.push(doc);
+        parts.push(doc);
 
         if i < arguments.len() - 1 {
-            parts_inner.push(ss!(","));
-            parts_inner.push(line!());
+            parts.push(ss!(","));
+            parts.push(line!());
         }
     }
-    if should_break {
-        parts_inner.insert(0, softline!());
-        parts.push(Doc::Indent(parts_inner));
-        parts.push(if_break!(p, ",", "", None));
-        parts.push(softline!());
-    } else {
-        parts.extend(parts_inner);
-    }
     parts.push(ss!(")"));
-
-    let should_break = should_break
-        && arguments.iter().any(|arg| {
-            misc::has_new_line_in_range(p.source_text, arg.span().start, arg.span().end)
-        });
-    Doc::Group(Group::new(parts, should_break))
+    Doc::Group(Group::new(parts, false))
 }
 
 /// https://github.com/prettier/prettier/blob/7aecca5d6473d73f562ca3af874831315f8f2581/src/language-js/print/call-expression.js#L93-L116
@@ -1,4 +1,6 @@
+use oxc_allocator::Vec;
 use oxc_ast::ast::*;
+use oxc_span::GetSpan;
 
 use crate::{
     array,
@@ -7,19 +9,50 @@ use crate::{
     hardline, ss, Format, Prettier,
 };
 
-use super::assignment::AssignmentLikeNode;
+use super::{assignment::AssignmentLikeNode, decorators};
 
 pub(super) fn print_class<'a>(p: &mut Prettier<'a>, class: &Class<'a>) -> Doc<'a> {
     let mut parts = p.vec();
+    if let Some(doc) = decorators::print_class_decorators(p, &class.decorators) {
+        parts.push(doc);
+    }
+    if class.modifiers.contains(ModifierKind::Declare) {
+        parts.push(ss!("declare "));
+    }
+    if class.modifiers.contains(ModifierKind::Abstract) {
+        parts.push(ss!("abstract "));
+    }
     parts.push(ss!("class "));
     if let Some(id) = &class.id {
         parts.push(id.format(p));
+    }
+    if let Some(type_parameters) = &class.type_parameters {
+        parts.push(type_parameters.format(p));
+    }
+    if class.id.is_some() || class.type_parameters.is_some() {
         parts.push(ss!(" "));
     }
 
     if let Some(super_class) = &class.super_class {
         parts.push(ss!("extends "));
         parts.push(super_class.format(p));
+        if let Some(super_type_parameters) = &class.super_type_parameters {
+            parts.push(super_type_parameters.format(p));
+        }
+        parts.push(ss!(" "));
+    }
+
+    if let Some(implements) = &class.implements {
+        parts.push(ss!("implements "));
+        for (i, implement) in implements.iter().enumerate() {
+            if i > 0 {
+                parts.push(ss!(", "));
+            }
+            parts.push(implement.expression.format(p));
+            if let Some(type_parameters) = &implement.type_parameters {
+                parts.push(type_parameters.format(p));
+            }
+        }
         parts.push(ss!(" "));
     }
 
@@ -33,6 +66,11 @@ pub(super) fn print_class_body<'a>(p: &mut Prettier<'a>, class_body: &ClassBody<
     for (i, node) in class_body.body.iter().enumerate() {
         parts_inner.push(node.format(p));
 
+        // Index signatures in type literals get their separators from the enclosing object.
+        if p.options.semi && matches!(node, ClassElement::TSIndexSignature(_)) {
+            parts_inner.push(ss!(";"));
+        }
+
         if !p.options.semi
             && should_print_semicolon_after_class_property(node, class_body.body.get(i + 1))
         {
@@ -42,7 +80,9 @@ pub(super) fn print_class_body<'a>(p: &mut Prettier<'a>, class_body: &ClassBody<
         if i < class_body.body.len() - 1 {
             parts_inner.extend(hardline!());
 
-            // TODO: if the next line is empty, add another hardline
+            if p.is_next_line_empty(node.span().end) {
+                parts_inner.extend(hardline!());
+            }
         }
     }
 
@@ -77,19 +117,36 @@ impl<'a, 'b> ClassMemberish<'a, 'b> {
     fn format_key(&self, p: &mut Prettier<'a>) -> Doc<'a> {
         match self {
             ClassMemberish::PropertyDefinition(property_definition) => {
-                property_definition.key.format(p)
+                print_property_key(p, &property_definition.key, property_definition.computed)
+            }
+            ClassMemberish::AccessorProperty(accessor_property) => {
+                print_property_key(p, &accessor_property.key, accessor_property.computed)
             }
-            ClassMemberish::AccessorProperty(accessor_property) => accessor_property.key.format(p),
         }
     }
 
-    fn decorators(&self) -> Option<&oxc_allocator::Vec<Decorator<'a>>> {
+    fn decorators(&self) -> Option<&Vec<'a, Decorator<'a>>> {
         match self {
             ClassMemberish::PropertyDefinition(property_definition) => {
                 Some(&property_definition.decorators)
             }
+            ClassMemberish::AccessorProperty(_) => None,
+        }
+    }
 
-            ClassMemberish::AccessorProperty(accessor_property) => None,
+    fn accessibility(&self) -> Option<TSAccessibility> {
+        match self {
+            ClassMemberish::PropertyDefinition(property_definition) => {
+                property_definition.accessibility
+            }
+            ClassMemberish::AccessorProperty(_) => None,
+        }
+    }
+
+    fn is_declare(&self) -> bool {
+        match self {
+            ClassMemberish::PropertyDefinition(property_definition) => property_definition.declare,
+            ClassMemberish::AccessorProperty(_) => false,
         }
     }
 
@@ -99,14 +156,16 @@ impl<'a, 'b> ClassMemberish<'a, 'b> {
             ClassMemberish::AccessorProperty(accessor_property) => accessor_property.r#static,
         }
     }
+
     fn is_override(&self) -> bool {
         match self {
             ClassMemberish::PropertyDefinition(property_definition) => {
                 property_definition.r#override
             }
-            ClassMemberish::AccessorProperty(accessor_property) => false,
+            ClassMemberish::AccessorProperty(_) => false,
         }
     }
+
     fn is_readonly(&self) -> bool {
         match self {
             ClassMemberish::PropertyDefinition(property_definition) => property_definition.readonly,
@@ -114,12 +173,35 @@ impl<'a, 'b> ClassMemberish<'a, 'b> {
         }
     }
 
+    fn is_optional(&self) -> bool {
+        match self {
+            ClassMemberish::PropertyDefinition(property_definition) => property_definition.optional,
+            ClassMemberish::AccessorProperty(_) => false,
+        }
+    }
+
+    fn is_definite(&self) -> bool {
+        match self {
+            ClassMemberish::PropertyDefinition(property_definition) => property_definition.definite,
+            ClassMemberish::AccessorProperty(_) => false,
+        }
+    }
+
+    fn type_annotation(&self) -> Option<&TSTypeAnnotation<'a>> {
+        match self {
+            ClassMemberish::PropertyDefinition(property_definition) => {
+                property_definition.type_annotation.as_deref()
+            }
+            ClassMemberish::AccessorProperty(_) => None,
+        }
+    }
+
     fn right_expr(&self) -> Option<&Expression<'a>> {
         match self {
             ClassMemberish::PropertyDefinition(property_definition) => {
                 property_definition.value.as_ref()
             }
-            ClassMemberish::AccessorProperty(_) => None,
+            ClassMemberish::AccessorProperty(accessor_property) => accessor_property.value.as_ref(),
         }
     }
 }
@@ -127,20 +209,32 @@ impl<'a, 'b> ClassMemberish<'a, 'b> {
 pub(super) fn print_class_property<'a>(
     p: &mut Prettier<'a>,
     node: &ClassMemberish<'a, '_>,
+    is_abstract: bool,
 ) -> Doc<'a> {
     let mut parts = p.vec();
 
-    if node.decorators().is_some_and(|x| !x.is_empty()) {
-        // TODO: print decorators
+    if let Some(decorators) = node.decorators() {
+        if let Some(doc) = decorators::print_class_member_decorators(p, decorators) {
+            parts.push(doc);
+        }
     }
 
-    // TODO: print typescript accessibility token
-    // TODO: print declare token
+    if let Some(accessibility) = node.accessibility() {
+        parts.push(print_accessibility(accessibility));
+    }
+
+    if node.is_declare() {
+        parts.push(ss!("declare "));
+    }
 
     if node.is_static() {
         parts.push(ss!("static "));
     }
 
+    if is_abstract {
+        parts.push(ss!("abstract "));
+    }
+
     if node.is_override() {
         parts.push(ss!("override "));
     }
@@ -149,17 +243,22 @@ pub(super) fn print_class_property<'a>(
         parts.push(ss!("readonly "));
     }
 
-    // TODO: print abstract token
-
     if matches!(node, ClassMemberish::AccessorProperty(_)) {
-        parts.push(ss!("readonly "));
+        parts.push(ss!("accessor "));
     }
 
     parts.push(node.format_key(p));
 
-    // TODO: print optional token
-    // TODO: print definite token
-    // TODO: print type annotation
+    if node.is_optional() {
+        parts.push(ss!("?"));
+    } else if node.is_definite() {
+        parts.push(ss!("!"));
+    }
+
+    if let Some(type_annotation) = node.type_annotation() {
+        parts.push(ss!(": "));
+        parts.push(type_annotation.format(p));
+    }
 
     let right_expr = node.right_expr();
     let node = match node {
@@ -178,6 +277,31 @@ pub(super) fn print_class_property<'a>(
     result
 }
 
+/// Print the key of a class member, wrapping computed keys in brackets.
+pub(super) fn print_property_key<'a>(
+    p: &mut Prettier<'a>,
+    key: &PropertyKey<'a>,
+    computed: bool,
+) -> Doc<'a> {
+    if !computed {
+        return key.format(p);
+    }
+    let key = match key {
+        PropertyKey::Identifier(ident) => ident.format(p),
+        PropertyKey::PrivateIdentifier(ident) => ident.format(p),
+        PropertyKey::Expression(expr) => expr.format(p),
+    };
+    array![p, ss!("["), key, ss!("]")]
+}
+
+pub(super) fn print_accessibility<'a>(accessibility: TSAccessibility) -> Doc<'a> {
+    match accessibility {
+        TSAccessibility::Private => ss!("private "),
+        TSAccessibility::Protected => ss!("protected "),
+        TSAccessibility::Public => ss!("public "),
+    }
+}
+
 fn should_print_semicolon_after_class_property<'a>(
     node: &ClassElement<'a>,
     next_node: Option<&ClassElement<'a>>,
@@ -0,0 +1,68 @@
+use oxc_allocator::Vec;
+use oxc_ast::ast::*;
+
+use crate::{
+    doc::{Doc, DocBuilder, Group},
+    hardline, line, ss, Format, Prettier,
+};
+
+/// Decorators of a class are always printed on their own lines.
+///
+/// Reference:
+/// * <https://github.com/prettier/prettier/blob/main/src/language-js/print/decorators.js>
+pub(super) fn print_class_decorators<'a>(
+    p: &mut Prettier<'a>,
+    decorators: &Vec<'a, Decorator<'a>>,
+) -> Option<Doc<'a>> {
+    if decorators.is_empty() {
+        return None;
+    }
+    let mut parts = p.vec();
+    for decorator in decorators {
+        parts.push(decorator.format(p));
+        parts.extend(hardline!());
+    }
+    Some(Doc::Array(parts))
+}
+
+/// Decorators of a class member stay on the same line as the member,
+/// unless any of them was followed by a line break in the original source.
+pub(super) fn print_class_member_decorators<'a>(
+    p: &mut Prettier<'a>,
+    decorators: &Vec<'a, Decorator<'a>>,
+) -> Option<Doc<'a>> {
+    if decorators.is_empty() {
+        return None;
+    }
+    let has_newline_between_decorators =
+        decorators.iter().any(|decorator| p.has_newline(decorator.span.end, false));
+    let mut parts = p.vec();
+    for (i, decorator) in decorators.iter().enumerate() {
+        if i > 0 {
+            parts.push(line!());
+        }
+        parts.push(decorator.format(p));
+    }
+    if has_newline_between_decorators {
+        parts.extend(hardline!());
+    } else {
+        parts.push(line!());
+    }
+    Some(Doc::Group(Group::new(parts, has_newline_between_decorators)))
+}
+
+/// Decorators of a parameter are printed inline, e.g. `constructor(@Inject() foo) {}`.
+pub(super) fn print_parameter_decorators<'a>(
+    p: &mut Prettier<'a>,
+    decorators: &Vec<'a, Decorator<'a>>,
+) -> Option<Doc<'a>> {
+    if decorators.is_empty() {
+        return None;
+    }
+    let mut parts = p.vec();
+    for decorator in decorators {
+        parts.push(decorator.format(p));
+        parts.push(ss!(" "));
+    }
+    Some(Doc::Array(parts))
+}
@@ -5,6 +5,8 @@ use crate::{
     group, ss, Format, Prettier,
 };
 
+use super::{class, decorators};
+
 pub(super) fn print_function<'a>(
     p: &mut Prettier<'a>,
     func: &Function<'a>,
@@ -26,17 +28,21 @@ pub(super) fn print_function<'a>(
         parts.push(p.str(" "));
     }
 
-    if let Some(type_params) = &func.type_parameters {
-        parts.push(type_params.format(p));
-    }
     if let Some(id) = &func.id {
         parts.push(p.str(id.name.as_str()));
     }
+    if let Some(type_params) = &func.type_parameters {
+        parts.push(type_params.format(p));
+    }
     if should_group_function_parameters(func) {
         parts.push(group!(p, func.params.format(p)));
     } else {
         parts.push(func.params.format(p));
     }
+    if let Some(return_type) = &func.return_type {
+        parts.push(ss!(": "));
+        parts.push(return_type.format(p));
+    }
     if let Some(body) = &func.body {
         parts.push(ss!(" "));
         parts.push(body.format(p));
@@ -48,13 +54,33 @@ pub(super) fn print_function<'a>(
     Doc::Array(parts)
 }
 
-pub(super) fn print_method<'a>(p: &mut Prettier<'a>, method: &MethodDefinition<'a>) -> Doc<'a> {
+pub(super) fn print_method<'a>(
+    p: &mut Prettier<'a>,
+    method: &MethodDefinition<'a>,
+    is_abstract: bool,
+) -> Doc<'a> {
     let mut parts = p.vec();
 
+    if let Some(doc) = decorators::print_class_member_decorators(p, &method.decorators) {
+        parts.push(doc);
+    }
+
+    if let Some(accessibility) = method.accessibility {
+        parts.push(class::print_accessibility(accessibility));
+    }
+
     if method.r#static {
         parts.push(ss!("static "));
     }
 
+    if is_abstract {
+        parts.push(ss!("abstract "));
+    }
+
+    if method.r#override {
+        parts.push(ss!("override "));
+    }
+
     match method.kind {
         MethodDefinitionKind::Constructor | MethodDefinitionKind::Method => {}
         MethodDefinitionKind::Get => {
@@ -73,23 +99,28 @@ pub(super) fn print_method<'a>(p: &mut Prettier<'a>, method: &MethodDefinition<'
         parts.push(ss!("*"));
     }
 
-    if method.computed {
-        parts.push(ss!("["));
-        let key = match &method.key {
-            PropertyKey::Identifier(ident) => ident.format(p),
-            PropertyKey::PrivateIdentifier(ident) => ident.format(p),
-            PropertyKey::Expression(expr) => expr.format(p),
-        };
-        parts.push(key);
-        parts.push(ss!("]"));
-    } else {
-        parts.push(method.key.format(p));
+    parts.push(class::print_property_key(p, &method.key, method.computed));
+
+    if method.optional {
+        parts.push(ss!("?"));
+    }
+
+    if let Some(type_parameters) = &method.value.type_parameters {
+        parts.push(type_parameters.format(p));
     }
 
     parts.push(method.value.params.format(p));
+
+    if let Some(return_type) = &method.value.return_type {
+        parts.push(ss!(": "));
+        parts.push(return_type.format(p));
+    }
+
     if let Some(body) = &method.value.body {
         parts.push(ss!(" "));
         parts.push(body.format(p));
+    } else if p.options.semi {
+        parts.push(ss!(";"));
     }
     Doc::Array(parts)
 }
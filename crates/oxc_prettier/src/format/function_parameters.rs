@@ -1,29 +1,87 @@
 use oxc_ast::ast::*;
 
 use crate::{
-    doc::{Doc, DocBuilder},
-    ss, Format, Prettier,
+    doc::{Doc, DocBuilder, Group},
+    if_break, line, softline, ss, Format, Prettier,
 };
 
 pub(super) fn print_function_parameters<'a>(
     p: &mut Prettier<'a>,
     params: &FormalParameters<'a>,
 ) -> Doc<'a> {
-    let mut parts = p.vec();
-    parts.push(ss!("("));
+    let mut printed = p.vec();
+    let len = params.items.len() + usize::from(params.rest.is_some());
 
     for (i, param) in params.items.iter().enumerate() {
-        parts.push(param.format(p));
-        if i < params.items.len() - 1 {
-            parts.push(ss!(", "));
+        printed.push(param.format(p));
+        if i < len - 1 {
+            printed.push(ss!(","));
+            printed.push(line!());
         }
     }
 
     if let Some(rest) = &params.rest {
-        parts.push(ss!(", "));
-        parts.push(rest.format(p));
+        printed.push(rest.format(p));
     }
 
+    // params of the form `function foo({ a, b }) {}` hug the parentheses
+    if should_hug_the_only_function_parameter(params) {
+        let mut parts = p.vec();
+        parts.push(ss!("("));
+        parts.extend(printed);
+        parts.push(ss!(")"));
+        return Doc::Group(Group::new(parts, false));
+    }
+
+    let mut parts = p.vec();
+    parts.push(ss!("("));
+    if !printed.is_empty() {
+        let mut indented = p.vec();
+        indented.push(softline!());
+        indented.extend(printed);
+        parts.push(Doc::Indent(indented));
+        if params.rest.is_none() && p.should_print_all_comma() {
+            parts.push(if_break!(p, ",", "", None));
+        }
+        parts.push(softline!());
+    }
     parts.push(ss!(")"));
-    Doc::Array(parts)
+    Doc::Group(Group::new(parts, should_break_function_parameters(params)))
+}
+
+/// `constructor(private a, public b) {}` always puts each parameter on its own line.
+fn should_break_function_parameters(params: &FormalParameters<'_>) -> bool {
+    params.parameters_count() > 1
+        && params.items.iter().any(|param| param.accessibility.is_some() || param.readonly)
+}
+
+fn should_hug_the_only_function_parameter(params: &FormalParameters<'_>) -> bool {
+    if params.parameters_count() != 1 {
+        return false;
+    }
+    let Some(param) = params.items.first() else { return false };
+    if param.accessibility.is_some() || param.readonly || !param.decorators.is_empty() {
+        return false;
+    }
+    match &param.pattern.kind {
+        BindingPatternKind::ObjectPattern(_) => true,
+        BindingPatternKind::AssignmentPattern(pattern) => {
+            matches!(pattern.left.kind, BindingPatternKind::ObjectPattern(_))
+                && match &pattern.right {
+                    Expression::Identifier(_) => true,
+                    Expression::ObjectExpression(object) => object.properties.is_empty(),
+                    Expression::ArrayExpression(array) => array.elements.is_empty(),
+                    _ => false,
+                }
+        }
+        BindingPatternKind::BindingIdentifier(_) => {
+            param.pattern.type_annotation.as_ref().is_some_and(|annotation| {
+                matches!(
+                    annotation.type_annotation,
+                    TSType::TSTypeLiteral(_) | TSType::TSMappedType(_)
+                )
+            })
+        }
+        BindingPatternKind::ArrayPattern(_) => false,
+    }
 }
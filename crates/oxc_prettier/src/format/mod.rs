@@ -10,8 +10,10 @@ mod arrow_function;
 mod assignment;
 mod binaryish;
 mod block;
+mod call_arguments;
 mod call_expression;
 mod class;
+mod decorators;
 mod function;
 mod function_parameters;
 mod misc;
@@ -21,6 +23,7 @@ mod statement;
 mod string;
 mod template_literal;
 mod ternary;
+mod ts_type;
 
 use std::borrow::Cow;
 
@@ -665,6 +668,9 @@ impl<'a> Format<'a> for TSTypeAliasDeclaration<'a> {
 
         parts.push(ss!("type "));
         parts.push(format!(p, self.id));
+        if let Some(type_parameters) = &self.type_parameters {
+            parts.push(type_parameters.format(p));
+        }
         parts.push(ss!(" = "));
         parts.push(format!(p, self.type_annotation));
 
@@ -676,6 +682,101 @@ impl<'a> Format<'a> for TSTypeAliasDeclaration<'a> {
     }
 }
 
+impl<'a> Format<'a> for TSTypeAnnotation<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        wrap!(p, self, TSTypeAnnotation, { self.type_annotation.format(p) })
+    }
+}
+
+impl<'a> Format<'a> for TSSignature<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        match self {
+            TSSignature::TSIndexSignature(signature) => signature.format(p),
+            TSSignature::TSPropertySignature(signature) => signature.format(p),
+            TSSignature::TSCallSignatureDeclaration(signature) => signature.format(p),
+            TSSignature::TSConstructSignatureDeclaration(signature) => signature.format(p),
+            TSSignature::TSMethodSignature(signature) => signature.format(p),
+        }
+    }
+}
+
+impl<'a> Format<'a> for TSPropertySignature<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        wrap!(p, self, TSPropertySignature, {
+            let mut parts = p.vec();
+            if self.readonly {
+                parts.push(ss!("readonly "));
+            }
+            parts.push(class::print_property_key(p, &self.key, self.computed));
+            if self.optional {
+                parts.push(ss!("?"));
+            }
+            if let Some(type_annotation) = &self.type_annotation {
+                parts.push(ss!(": "));
+                parts.push(type_annotation.format(p));
+            }
+            Doc::Array(parts)
+        })
+    }
+}
+
+impl<'a> Format<'a> for TSCallSignatureDeclaration<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        let mut parts = p.vec();
+        if let Some(type_parameters) = &self.type_parameters {
+            parts.push(type_parameters.format(p));
+        }
+        parts.push(self.params.format(p));
+        if let Some(return_type) = &self.return_type {
+            parts.push(ss!(": "));
+            parts.push(return_type.format(p));
+        }
+        Doc::Array(parts)
+    }
+}
+
+impl<'a> Format<'a> for TSConstructSignatureDeclaration<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        let mut parts = p.vec();
+        parts.push(ss!("new "));
+        if let Some(type_parameters) = &self.type_parameters {
+            parts.push(type_parameters.format(p));
+        }
+        parts.push(self.params.format(p));
+        if let Some(return_type) = &self.return_type {
+            parts.push(ss!(": "));
+            parts.push(return_type.format(p));
+        }
+        Doc::Array(parts)
+    }
+}
+
+impl<'a> Format<'a> for TSMethodSignature<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        wrap!(p, self, TSMethodSignature, {
+            let mut parts = p.vec();
+            match self.kind {
+                TSMethodSignatureKind::Method => {}
+                TSMethodSignatureKind::Get => parts.push(ss!("get ")),
+                TSMethodSignatureKind::Set => parts.push(ss!("set ")),
+            }
+            parts.push(class::print_property_key(p, &self.key, self.computed));
+            if self.optional {
+                parts.push(ss!("?"));
+            }
+            if let Some(type_parameters) = &self.type_parameters {
+                parts.push(type_parameters.format(p));
+            }
+            parts.push(self.params.format(p));
+            if let Some(return_type) = &self.return_type {
+                parts.push(ss!(": "));
+                parts.push(return_type.format(p));
+            }
+            Doc::Array(parts)
+        })
+    }
+}
+
 impl<'a> Format<'a> for TSType<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
         match self {
@@ -797,31 +898,61 @@ impl<'a> Format<'a> for TSVoidKeyword {
 
 impl<'a> Format<'a> for TSArrayType<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        ts_type::print_array_type(p, self)
     }
 }
 
 impl<'a> Format<'a> for TSConditionalType<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        ts_type::print_conditional_type(p, self)
     }
 }
 
 impl<'a> Format<'a> for TSConstructorType<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        let mut parts = p.vec();
+        if self.r#abstract {
+            parts.push(ss!("abstract "));
+        }
+        parts.push(ss!("new "));
+        parts.push(ts_type::print_function_type(
+            p,
+            self.type_parameters.as_deref(),
+            &self.params,
+            &self.return_type,
+        ));
+        Doc::Array(parts)
     }
 }
 
 impl<'a> Format<'a> for TSFunctionType<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        ts_type::print_function_type(
+            p,
+            self.type_parameters.as_deref(),
+            &self.params,
+            &self.return_type,
+        )
     }
 }
 
 impl<'a> Format<'a> for TSImportType<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        let mut parts = p.vec();
+        if self.is_type_of {
+            parts.push(ss!("typeof "));
+        }
+        parts.push(ss!("import("));
+        parts.push(self.parameter.format(p));
+        parts.push(ss!(")"));
+        if let Some(qualifier) = &self.qualifier {
+            parts.push(ss!("."));
+            parts.push(qualifier.format(p));
+        }
+        if let Some(type_parameters) = &self.type_parameters {
+            parts.push(type_parameters.format(p));
+        }
+        Doc::Array(parts)
     }
 }
 
@@ -844,7 +975,7 @@ impl<'a> Format<'a> for TSInferType<'a> {
 
 impl<'a> Format<'a> for TSIntersectionType<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        wrap!(p, self, TSIntersectionType, { ts_type::print_intersection_type(p, self) })
     }
 }
 
@@ -865,13 +996,22 @@ impl<'a> Format<'a> for TSLiteralType<'a> {
 
 impl<'a> Format<'a> for TSMappedType<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        ts_type::print_mapped_type(p, self)
     }
 }
 
 impl<'a> Format<'a> for TSQualifiedName<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        array![p, self.left.format(p), ss!("."), self.right.format(p)]
+    }
+}
+
+impl<'a> Format<'a> for TSTypeName<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        match self {
+            TSTypeName::IdentifierReference(ident) => ident.format(p),
+            TSTypeName::QualifiedName(name) => name.format(p),
+        }
     }
 }
 
@@ -892,37 +1032,64 @@ impl<'a> Format<'a> for TSTupleType<'a> {
 
 impl<'a> Format<'a> for TSTypeLiteral<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        wrap!(p, self, TSTypeLiteral, {
+            object::print_object_properties(p, &ObjectLike::TSTypeLiteral(self), &self.members)
+        })
     }
 }
 
 impl<'a> Format<'a> for TSTypeOperatorType<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        ts_type::print_type_operator(p, self)
     }
 }
 
 impl<'a> Format<'a> for TSTypePredicate<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        let mut parts = p.vec();
+        if self.asserts {
+            parts.push(ss!("asserts "));
+        }
+        match &self.parameter_name {
+            TSTypePredicateName::Identifier(ident) => parts.push(ident.format(p)),
+            TSTypePredicateName::This(this) => parts.push(this.format(p)),
+        }
+        if let Some(type_annotation) = &self.type_annotation {
+            parts.push(ss!(" is "));
+            parts.push(type_annotation.format(p));
+        }
+        Doc::Array(parts)
     }
 }
 
 impl<'a> Format<'a> for TSTypeQuery<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        let mut parts = p.vec();
+        parts.push(ss!("typeof "));
+        parts.push(self.expr_name.format(p));
+        if let Some(type_parameters) = &self.type_parameters {
+            parts.push(type_parameters.format(p));
+        }
+        Doc::Array(parts)
     }
 }
 
 impl<'a> Format<'a> for TSTypeReference<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        wrap!(p, self, TSTypeReference, {
+            let mut parts = p.vec();
+            parts.push(self.type_name.format(p));
+            if let Some(type_parameters) = &self.type_parameters {
+                parts.push(type_parameters.format(p));
+            }
+            Doc::Array(parts)
+        })
     }
 }
 
 impl<'a> Format<'a> for TSUnionType<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        wrap!(p, self, TSUnionType, { ts_type::print_union_type(p, self) })
     }
 }
 
@@ -964,25 +1131,47 @@ impl<'a> Format<'a> for TSImportEqualsDeclaration<'a> {
 
 impl<'a> Format<'a> for TSTypeParameter<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        wrap!(p, self, TSTypeParameter, { ts_type::print_type_parameter(p, self) })
     }
 }
 
 impl<'a> Format<'a> for TSTypeParameterDeclaration<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        wrap!(p, self, TSTypeParameterDeclaration, {
+            ts_type::print_type_parameter_declaration(p, self)
+        })
     }
 }
 
 impl<'a> Format<'a> for TSTypeParameterInstantiation<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        wrap!(p, self, TSTypeParameterInstantiation, {
+            ts_type::print_type_parameter_instantiation(p, self)
+        })
     }
 }
 
 impl<'a> Format<'a> for TSTupleElement<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        match self {
+            TSTupleElement::TSType(ty) => ty.format(p),
+            TSTupleElement::TSOptionalType(optional) => {
+                array![p, optional.type_annotation.format(p), ss!("?")]
+            }
+            TSTupleElement::TSRestType(rest) => {
+                array![p, ss!("..."), rest.type_annotation.format(p)]
+            }
+            TSTupleElement::TSNamedTupleMember(member) => {
+                let mut parts = p.vec();
+                parts.push(member.label.format(p));
+                if member.optional {
+                    parts.push(ss!("?"));
+                }
+                parts.push(ss!(": "));
+                parts.push(member.element_type.format(p));
+                Doc::Array(parts)
+            }
+        }
     }
 }
 
@@ -1014,7 +1203,18 @@ impl<'a> Format<'a> for FormalParameters<'a> {
 
 impl<'a> Format<'a> for FormalParameter<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        self.pattern.format(p)
+        let mut parts = p.vec();
+        if let Some(doc) = decorators::print_parameter_decorators(p, &self.decorators) {
+            parts.push(doc);
+        }
+        if let Some(accessibility) = self.accessibility {
+            parts.push(class::print_accessibility(accessibility));
+        }
+        if self.readonly {
+            parts.push(ss!("readonly "));
+        }
+        parts.push(self.pattern.format(p));
+        Doc::Array(parts)
     }
 }
 
@@ -1786,14 +1986,18 @@ impl<'a> Format<'a> for ImportExpression<'a> {
 
 impl<'a> Format<'a> for TemplateLiteral<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        template_literal::print_template_literal(p, &TemplateLiteralPrinter::TemplateLiteral(self))
+        wrap!(p, self, TemplateLiteral, {
+            template_literal::print_template_literal(
+                p,
+                &TemplateLiteralPrinter::TemplateLiteral(self),
+            )
+        })
     }
 }
 
 impl<'a> Format<'a> for TemplateElement {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        // TODO: `replaceEndOfLine`
-        p.str(self.value.raw.as_str())
+        template_literal::print_template_element(p, self)
     }
 }
 
@@ -2034,21 +2238,29 @@ impl<'a> Format<'a> for StaticBlock<'a> {
 
 impl<'a> Format<'a> for MethodDefinition<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        wrap!(p, self, MethodDefinition, { function::print_method(p, self) })
+        wrap!(p, self, MethodDefinition, { function::print_method(p, self, false) })
     }
 }
 
 impl<'a> Format<'a> for PropertyDefinition<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
         wrap!(p, self, PropertyDefinition, {
-            class::print_class_property(p, &class::ClassMemberish::PropertyDefinition(self))
+            class::print_class_property(
+                p,
+                &class::ClassMemberish::PropertyDefinition(self),
+                /* is_abstract */ false,
+            )
         })
     }
 }
 
 impl<'a> Format<'a> for AccessorProperty<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        class::print_class_property(p, &class::ClassMemberish::AccessorProperty(self))
+        class::print_class_property(
+            p,
+            &class::ClassMemberish::AccessorProperty(self),
+            /* is_abstract */ false,
+        )
     }
 }
 
@@ -2063,12 +2275,21 @@ impl<'a> Format<'a> for PrivateIdentifier {
 
 impl<'a> Format<'a> for BindingPattern<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        match self.kind {
+        let mut parts = p.vec();
+        parts.push(match self.kind {
             BindingPatternKind::BindingIdentifier(ref ident) => ident.format(p),
             BindingPatternKind::ObjectPattern(ref pattern) => pattern.format(p),
             BindingPatternKind::ArrayPattern(ref pattern) => pattern.format(p),
             BindingPatternKind::AssignmentPattern(ref pattern) => pattern.format(p),
+        });
+        if self.optional {
+            parts.push(ss!("?"));
         }
+        if let Some(type_annotation) = &self.type_annotation {
+            parts.push(ss!(": "));
+            parts.push(type_annotation.format(p));
+        }
+        Doc::Array(parts)
     }
 }
 
@@ -2139,18 +2360,44 @@ impl<'a> Format<'a> for RegExpFlags {
 
 impl<'a> Format<'a> for TSAbstractMethodDefinition<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        let method = &self.method_definition;
+        wrap!(p, method, MethodDefinition, { function::print_method(p, method, true) })
     }
 }
 
 impl<'a> Format<'a> for TSAbstractPropertyDefinition<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        let property = &self.property_definition;
+        wrap!(p, property, PropertyDefinition, {
+            class::print_class_property(
+                p,
+                &class::ClassMemberish::PropertyDefinition(property),
+                /* is_abstract */ true,
+            )
+        })
     }
 }
 
 impl<'a> Format<'a> for TSIndexSignature<'a> {
     fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
-        line!()
+        let mut parts = p.vec();
+        parts.push(ss!("["));
+        for (i, parameter) in self.parameters.iter().enumerate() {
+            if i > 0 {
+                parts.push(ss!(", "));
+            }
+            parts.push(p.str(parameter.name.as_str()));
+            parts.push(ss!(": "));
+            parts.push(parameter.type_annotation.format(p));
+        }
+        parts.push(ss!("]: "));
+        parts.push(self.type_annotation.format(p));
+        Doc::Array(parts)
+    }
+}
+
+impl<'a> Format<'a> for Decorator<'a> {
+    fn format(&self, p: &mut Prettier<'a>) -> Doc<'a> {
+        wrap!(p, self, Decorator, { array![p, ss!("@"), self.expression.format(p)] })
     }
 }
@@ -1,8 +1,9 @@
 use oxc_allocator::Vec;
-use oxc_ast::ast::{ObjectAssignmentTarget, ObjectExpression, ObjectPattern};
+use oxc_ast::ast::{ObjectAssignmentTarget, ObjectExpression, ObjectPattern, TSTypeLiteral};
 use oxc_span::{GetSpan, Span};
 
 use crate::{
+    array,
     doc::{Doc, DocBuilder, Group},
     group, if_break, line, softline, ss, Prettier,
 };
@@ -14,12 +15,47 @@ pub enum ObjectLike<'a, 'b> {
     ObjectExpression(&'b ObjectExpression<'a>),
     ObjectAssignmentTarget(&'b ObjectAssignmentTarget<'a>),
     ObjectPattern(&'b ObjectPattern<'a>),
+    TSTypeLiteral(&'b TSTypeLiteral<'a>),
 }
 
 impl ObjectLike<'_, '_> {
     fn is_object_pattern(&self) -> bool {
         matches!(self, ObjectLike::ObjectPattern(_))
     }
+
+    /// Members of a type literal are separated by `;` instead of `,`.
+    fn separator(&self, p: &Prettier<'_>) -> &'static str {
+        match self {
+            ObjectLike::TSTypeLiteral(_) => {
+                if p.options.semi {
+                    ";"
+                } else {
+                    ""
+                }
+            }
+            _ => ",",
+        }
+    }
+}
+
+impl<'a> ObjectLike<'a, '_> {
+    fn print_rest(&self, p: &mut Prettier<'a>) -> Option<Doc<'a>> {
+        match self {
+            ObjectLike::ObjectExpression(_) | ObjectLike::TSTypeLiteral(_) => None,
+            ObjectLike::ObjectAssignmentTarget(object) => {
+                object.rest.as_ref().map(|rest| array!(p, ss!("..."), rest.format(p)))
+            }
+            ObjectLike::ObjectPattern(object) => object.rest.as_ref().map(|rest| rest.format(p)),
+        }
+    }
+
+    fn has_rest(&self) -> bool {
+        match self {
+            ObjectLike::ObjectExpression(_) | ObjectLike::TSTypeLiteral(_) => false,
+            ObjectLike::ObjectAssignmentTarget(object) => object.rest.is_some(),
+            ObjectLike::ObjectPattern(object) => object.rest.is_some(),
+        }
+    }
 }
 
 impl ObjectLike<'_, '_> {
@@ -28,6 +64,7 @@ impl ObjectLike<'_, '_> {
             ObjectLike::ObjectExpression(object) => object.span,
             ObjectLike::ObjectAssignmentTarget(object) => object.span,
             ObjectLike::ObjectPattern(object) => object.span,
+            ObjectLike::TSTypeLiteral(object) => object.span,
         }
     }
 }
@@ -40,7 +77,7 @@ pub(super) fn print_object_properties<'a, F: Format<'a> + GetSpan>(
     let left_brace = ss!("{");
     let right_brace = ss!("}");
 
-    let content = if properties.is_empty() {
+    let content = if properties.is_empty() && !object.has_rest() {
         group![p, left_brace, softline!(), right_brace]
     } else {
         let mut parts = p.vec();
@@ -48,16 +85,37 @@ pub(super) fn print_object_properties<'a, F: Format<'a> + GetSpan>(
 
         let mut indent_parts = p.vec();
         indent_parts.push(if p.options.bracket_spacing { line!() } else { softline!() });
+        let has_rest = object.has_rest();
+        let object_end = object.span().end;
+        let separator = object.separator(p);
         for (i, prop) in properties.iter().enumerate() {
+            if let Some(leading) = p.print_leading_comments(prop.span()) {
+                indent_parts.push(leading);
+            }
             indent_parts.push(prop.format(p));
-            if i < properties.len() - 1 {
-                indent_parts.push(Doc::Str(","));
+
+            let is_last = i == properties.len() - 1 && !has_rest;
+            if !is_last {
+                indent_parts.push(Doc::Str(separator));
+            }
+            let end = properties.get(i + 1).map_or(object_end, |next| next.span().start);
+            if let Some(trailing) = p.print_element_trailing_comments(prop.span(), end) {
+                indent_parts.push(trailing);
+            }
+            if !is_last {
                 indent_parts.push(line!());
             }
         }
 
+        if let Some(rest) = object.print_rest(p) {
+            indent_parts.push(rest);
+        }
+
         parts.push(Doc::Indent(indent_parts));
-        parts.push(if_break!(p, ",", "", None));
+        // A rest element must be the last one, so it cannot be followed by a comma.
+        if !has_rest {
+            parts.push(if_break!(p, separator, "", None));
+        }
 
         if p.options.bracket_spacing {
             parts.push(line!());
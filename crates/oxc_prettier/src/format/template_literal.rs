@@ -1,7 +1,7 @@
 use oxc_ast::ast::{TemplateLiteral, *};
 
 use crate::{
-    doc::{Doc, DocBuilder},
+    doc::{Doc, DocBuilder, Line},
     format::Format,
     ss, Prettier,
 };
@@ -53,3 +53,39 @@ pub(super) fn print_template_literal<'a>(
 
     Doc::Array(parts)
 }
+
+/// The raw text of a quasi is kept verbatim, line breaks are printed as literal lines so they are
+/// neither indented nor measured as part of the current line.
+///
+/// See `replaceEndOfLine` in <https://github.com/prettier/prettier/blob/main/src/document/utils.js>
+pub(super) fn print_template_element<'a>(
+    p: &Prettier<'a>,
+    template_element: &TemplateElement,
+) -> Doc<'a> {
+    let raw = template_element.value.raw.as_str();
+    if !raw.contains(['\n', '\r']) {
+        return p.str(raw);
+    }
+
+    let mut parts = p.vec();
+    let mut start = 0;
+    let bytes = raw.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let len = match bytes[i] {
+            b'\r' if bytes.get(i + 1) == Some(&b'\n') => 2,
+            b'\r' | b'\n' => 1,
+            _ => {
+                i += 1;
+                continue;
+            }
+        };
+        parts.push(p.str(&raw[start..i]));
+        parts.push(Doc::Line(Line::literal_line_without_break_parent()));
+        parts.push(Doc::BreakParent);
+        i += len;
+        start = i;
+    }
+    parts.push(p.str(&raw[start..]));
+    Doc::Array(parts)
+}
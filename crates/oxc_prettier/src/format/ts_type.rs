@@ -0,0 +1,422 @@
+use oxc_allocator::Vec;
+use oxc_ast::{ast::*, AstKind};
+
+use crate::{
+    array,
+    doc::{Doc, DocBuilder, Group, IfBreak},
+    if_break, indent, line, softline, ss, Format, Prettier,
+};
+
+use super::misc;
+
+/// The position of a type inside its parent, used to decide whether the type needs parentheses.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TypePosition {
+    UnionMember,
+    IntersectionMember,
+    ArrayElement,
+    TypeOperand,
+    ConditionalCheck,
+    ConditionalExtends,
+}
+
+/// Reference:
+/// * <https://github.com/prettier/prettier/blob/main/src/language-js/needs-parens.js>
+fn needs_parens(ty: &TSType<'_>, position: TypePosition) -> bool {
+    match ty {
+        TSType::TSFunctionType(_) | TSType::TSConstructorType(_) => matches!(
+            position,
+            TypePosition::UnionMember
+                | TypePosition::IntersectionMember
+                | TypePosition::ArrayElement
+                | TypePosition::TypeOperand
+                | TypePosition::ConditionalCheck
+        ),
+        TSType::TSConditionalType(_) => true,
+        TSType::TSUnionType(_) => matches!(
+            position,
+            TypePosition::IntersectionMember
+                | TypePosition::ArrayElement
+                | TypePosition::TypeOperand
+        ),
+        TSType::TSIntersectionType(_) => {
+            matches!(position, TypePosition::ArrayElement | TypePosition::TypeOperand)
+        }
+        TSType::TSTypeOperatorType(_) | TSType::TSInferType(_) => {
+            position == TypePosition::ArrayElement
+        }
+        _ => false,
+    }
+}
+
+fn print_type_in<'a>(p: &mut Prettier<'a>, ty: &TSType<'a>, position: TypePosition) -> Doc<'a> {
+    let doc = ty.format(p);
+    if needs_parens(ty, position) {
+        array![p, ss!("("), doc, ss!(")")]
+    } else {
+        doc
+    }
+}
+
+/// Object types `{ a: string }` and mapped types `{ [K in T]: V }`.
+fn is_object_type(ty: &TSType<'_>) -> bool {
+    matches!(ty, TSType::TSTypeLiteral(_) | TSType::TSMappedType(_))
+}
+
+/// Keywords and type references without type arguments, e.g. `string` or `Foo.Bar`.
+fn is_simple_type(ty: &TSType<'_>) -> bool {
+    match ty {
+        TSType::TSAnyKeyword(_)
+        | TSType::TSBigIntKeyword(_)
+        | TSType::TSBooleanKeyword(_)
+        | TSType::TSNeverKeyword(_)
+        | TSType::TSNullKeyword(_)
+        | TSType::TSNumberKeyword(_)
+        | TSType::TSObjectKeyword(_)
+        | TSType::TSStringKeyword(_)
+        | TSType::TSSymbolKeyword(_)
+        | TSType::TSThisKeyword(_)
+        | TSType::TSUndefinedKeyword(_)
+        | TSType::TSUnknownKeyword(_)
+        | TSType::TSVoidKeyword(_) => true,
+        TSType::TSTypeReference(reference) => reference.type_parameters.is_none(),
+        _ => false,
+    }
+}
+
+/// Whether a type is printed without trying to break it, e.g. `{ a: string } | null`.
+fn should_hug_type(ty: &TSType<'_>) -> bool {
+    if is_simple_type(ty) || is_object_type(ty) {
+        return true;
+    }
+    if let TSType::TSUnionType(union) = ty {
+        return should_hug_union_type(union);
+    }
+    false
+}
+
+fn should_hug_union_type(union: &TSUnionType<'_>) -> bool {
+    let void_count = union
+        .types
+        .iter()
+        .filter(|ty| matches!(ty, TSType::TSVoidKeyword(_) | TSType::TSNullKeyword(_)))
+        .count();
+    let has_object = union.types.iter().any(is_object_type);
+    union.types.len() - 1 == void_count && has_object
+}
+
+/// Reference:
+/// * <https://github.com/prettier/prettier/blob/main/src/language-js/print/type-annotation.js> `printUnionType`
+pub(super) fn print_union_type<'a>(p: &mut Prettier<'a>, union: &TSUnionType<'a>) -> Doc<'a> {
+    let mut printed = p.vec();
+    for ty in &union.types {
+        printed.push(print_type_in(p, ty, TypePosition::UnionMember));
+    }
+
+    // {
+    //   a: string
+    // } | null | void
+    // should be inlined and not be printed in the multi-line variant
+    if should_hug_union_type(union) {
+        let mut parts = p.vec();
+        for (i, doc) in printed.into_iter().enumerate() {
+            if i > 0 {
+                parts.push(ss!(" | "));
+            }
+            parts.push(doc);
+        }
+        return Doc::Array(parts);
+    }
+
+    // Type arguments and tuples print each member on its own line already.
+    let should_indent = !matches!(p.parent_kind(), AstKind::TSTypeParameterInstantiation(_));
+
+    let mut code = p.vec();
+    let leading = if should_indent { array![p, line!(), ss!("| ")] } else { ss!("| ") };
+    code.push(Doc::IfBreak(IfBreak {
+        break_contents: p.boxed(leading),
+        flat_content: p.boxed(ss!("")),
+        group_id: None,
+    }));
+    for (i, doc) in printed.into_iter().enumerate() {
+        if i > 0 {
+            code.push(line!());
+            code.push(ss!("| "));
+        }
+        code.push(doc);
+    }
+
+    let mut parts = p.vec();
+    if should_indent {
+        parts.push(Doc::Indent(code));
+    } else {
+        parts.extend(code);
+    }
+    Doc::Group(Group::new(parts, false))
+}
+
+/// Reference:
+/// * <https://github.com/prettier/prettier/blob/main/src/language-js/print/type-annotation.js> `printIntersectionType`
+pub(super) fn print_intersection_type<'a>(
+    p: &mut Prettier<'a>,
+    intersection: &TSIntersectionType<'a>,
+) -> Doc<'a> {
+    let mut parts = p.vec();
+    let mut was_indented = false;
+    for (i, ty) in intersection.types.iter().enumerate() {
+        let doc = print_type_in(p, ty, TypePosition::IntersectionMember);
+        if i == 0 {
+            parts.push(doc);
+            continue;
+        }
+        let previous_is_object = is_object_type(&intersection.types[i - 1]);
+        let is_object = is_object_type(ty);
+        if !previous_is_object && !is_object {
+            // If no object is involved, go to the next line if it breaks
+            parts.push(indent![p, ss!(" &"), line!(), doc]);
+        } else if previous_is_object && is_object {
+            // If both are objects, don't indent
+            parts.push(ss!(" & "));
+            parts.push(if was_indented { indent![p, doc] } else { doc });
+        } else {
+            // If you go from object to non-object or vice versa, then inline it
+            if i > 1 {
+                was_indented = true;
+            }
+            parts.push(ss!(" & "));
+            parts.push(if i > 1 { indent![p, doc] } else { doc });
+        }
+    }
+    Doc::Group(Group::new(parts, false))
+}
+
+/// A chain of conditional types is printed as a single group which always breaks.
+///
+/// ```ts
+/// type A<T> = T extends string
+///   ? "string"
+///   : T extends number
+///     ? "number"
+///     : "other";
+/// ```
+pub(super) fn print_conditional_type<'a>(
+    p: &mut Prettier<'a>,
+    conditional: &TSConditionalType<'a>,
+) -> Doc<'a> {
+    let is_chain = matches!(conditional.true_type, TSType::TSConditionalType(_))
+        || matches!(conditional.false_type, TSType::TSConditionalType(_));
+    let parts = print_conditional_type_parts(p, conditional);
+    Doc::Group(Group::new(parts, is_chain))
+}
+
+fn print_conditional_type_parts<'a>(
+    p: &mut Prettier<'a>,
+    conditional: &TSConditionalType<'a>,
+) -> Vec<'a, Doc<'a>> {
+    let mut parts = p.vec();
+    parts.push(print_type_in(p, &conditional.check_type, TypePosition::ConditionalCheck));
+    parts.push(ss!(" extends "));
+    parts.push(print_type_in(p, &conditional.extends_type, TypePosition::ConditionalExtends));
+
+    let mut branches = p.vec();
+    branches.push(line!());
+    branches.push(ss!("? "));
+    branches.push(print_conditional_branch(p, &conditional.true_type));
+    branches.push(line!());
+    branches.push(ss!(": "));
+    branches.push(print_conditional_branch(p, &conditional.false_type));
+    parts.push(Doc::Indent(branches));
+    parts
+}
+
+fn print_conditional_branch<'a>(p: &mut Prettier<'a>, ty: &TSType<'a>) -> Doc<'a> {
+    if let TSType::TSConditionalType(conditional) = ty {
+        Doc::Array(print_conditional_type_parts(p, conditional))
+    } else {
+        ty.format(p)
+    }
+}
+
+pub(super) fn print_array_type<'a>(p: &mut Prettier<'a>, array: &TSArrayType<'a>) -> Doc<'a> {
+    let element = print_type_in(p, &array.element_type, TypePosition::ArrayElement);
+    array![p, element, ss!("[]")]
+}
+
+pub(super) fn print_type_operator<'a>(
+    p: &mut Prettier<'a>,
+    operator: &TSTypeOperatorType<'a>,
+) -> Doc<'a> {
+    let keyword = match operator.operator {
+        TSTypeOperator::Keyof => "keyof ",
+        TSTypeOperator::Unique => "unique ",
+        TSTypeOperator::Readonly => "readonly ",
+    };
+    let operand = print_type_in(p, &operator.type_annotation, TypePosition::TypeOperand);
+    array![p, ss!(keyword), operand]
+}
+
+/// `<T>(a: T) => T` and `new (a: T) => T`
+pub(super) fn print_function_type<'a>(
+    p: &mut Prettier<'a>,
+    type_parameters: Option<&TSTypeParameterDeclaration<'a>>,
+    params: &FormalParameters<'a>,
+    return_type: &TSTypeAnnotation<'a>,
+) -> Doc<'a> {
+    let mut parts = p.vec();
+    if let Some(type_parameters) = type_parameters {
+        parts.push(type_parameters.format(p));
+    }
+    parts.push(params.format(p));
+    parts.push(ss!(" => "));
+    parts.push(return_type.format(p));
+    Doc::Group(Group::new(parts, false))
+}
+
+/// Reference:
+/// * <https://github.com/prettier/prettier/blob/main/src/language-js/print/mapped-type.js>
+pub(super) fn print_mapped_type<'a>(p: &mut Prettier<'a>, mapped: &TSMappedType<'a>) -> Doc<'a> {
+    let mut inner = p.vec();
+    match mapped.readonly {
+        TSMappedTypeModifierOperator::True => inner.push(ss!("readonly ")),
+        TSMappedTypeModifierOperator::Plus => inner.push(ss!("+readonly ")),
+        TSMappedTypeModifierOperator::Minus => inner.push(ss!("-readonly ")),
+        TSMappedTypeModifierOperator::None => {}
+    }
+    inner.push(ss!("["));
+    inner.push(p.str(mapped.type_parameter.name.name.as_str()));
+    if let Some(constraint) = &mapped.type_parameter.constraint {
+        inner.push(ss!(" in "));
+        inner.push(constraint.format(p));
+    }
+    if let Some(name_type) = &mapped.name_type {
+        inner.push(ss!(" as "));
+        inner.push(name_type.format(p));
+    }
+    inner.push(ss!("]"));
+    match mapped.optional {
+        TSMappedTypeModifierOperator::True => inner.push(ss!("?")),
+        TSMappedTypeModifierOperator::Plus => inner.push(ss!("+?")),
+        TSMappedTypeModifierOperator::Minus => inner.push(ss!("-?")),
+        TSMappedTypeModifierOperator::None => {}
+    }
+    if let Some(type_annotation) = &mapped.type_annotation {
+        inner.push(ss!(": "));
+        inner.push(type_annotation.format(p));
+    }
+
+    let mut indent_parts = p.vec();
+    indent_parts.push(if p.options.bracket_spacing { line!() } else { softline!() });
+    indent_parts.push(Doc::Group(Group::new(inner, false)));
+    if p.options.semi {
+        indent_parts.push(if_break!(p, ";", "", None));
+    }
+
+    let mut parts = p.vec();
+    parts.push(ss!("{"));
+    parts.push(Doc::Indent(indent_parts));
+    parts.push(if p.options.bracket_spacing { line!() } else { softline!() });
+    parts.push(ss!("}"));
+
+    // Keep the mapped type expanded if there was a line break after the `{` in the original source.
+    let should_break = misc::has_new_line_in_range(
+        p.source_text,
+        mapped.span.start,
+        mapped.type_parameter.span.start,
+    );
+    Doc::Group(Group::new(parts, should_break))
+}
+
+/// Reference:
+/// * <https://github.com/prettier/prettier/blob/main/src/language-js/print/type-parameters.js>
+fn print_type_parameters<'a>(
+    p: &Prettier<'a>,
+    printed: Vec<'a, Doc<'a>>,
+    should_hug: bool,
+    is_declaration: bool,
+) -> Doc<'a> {
+    if printed.is_empty() {
+        return ss!("<>");
+    }
+
+    if should_hug {
+        let mut parts = p.vec();
+        parts.push(ss!("<"));
+        for (i, doc) in printed.into_iter().enumerate() {
+            if i > 0 {
+                parts.push(ss!(", "));
+            }
+            parts.push(doc);
+        }
+        parts.push(ss!(">"));
+        return Doc::Array(parts);
+    }
+
+    let mut indent_parts = p.vec();
+    indent_parts.push(softline!());
+    for (i, doc) in printed.into_iter().enumerate() {
+        if i > 0 {
+            indent_parts.push(ss!(","));
+            indent_parts.push(line!());
+        }
+        indent_parts.push(doc);
+    }
+
+    let mut parts = p.vec();
+    parts.push(ss!("<"));
+    parts.push(Doc::Indent(indent_parts));
+    if is_declaration && p.should_print_all_comma() {
+        parts.push(if_break!(p, ",", "", None));
+    }
+    parts.push(softline!());
+    parts.push(ss!(">"));
+    Doc::Group(Group::new(parts, false))
+}
+
+pub(super) fn print_type_parameter_instantiation<'a>(
+    p: &mut Prettier<'a>,
+    instantiation: &TSTypeParameterInstantiation<'a>,
+) -> Doc<'a> {
+    let should_hug = matches!(instantiation.params.as_slice(), [param] if should_hug_type(param));
+    let mut printed = p.vec();
+    for param in &instantiation.params {
+        printed.push(param.format(p));
+    }
+    print_type_parameters(p, printed, should_hug, /* is_declaration */ false)
+}
+
+pub(super) fn print_type_parameter_declaration<'a>(
+    p: &mut Prettier<'a>,
+    declaration: &TSTypeParameterDeclaration<'a>,
+) -> Doc<'a> {
+    let mut printed = p.vec();
+    for param in &declaration.params {
+        printed.push(param.format(p));
+    }
+    print_type_parameters(p, printed, /* should_hug */ false, /* is_declaration */ true)
+}
+
+pub(super) fn print_type_parameter<'a>(
+    p: &mut Prettier<'a>,
+    param: &TSTypeParameter<'a>,
+) -> Doc<'a> {
+    let mut parts = p.vec();
+    if param.r#const {
+        parts.push(ss!("const "));
+    }
+    if param.r#in {
+        parts.push(ss!("in "));
+    }
+    if param.out {
+        parts.push(ss!("out "));
+    }
+    parts.push(p.str(param.name.name.as_str()));
+    if let Some(constraint) = &param.constraint {
+        parts.push(ss!(" extends "));
+        parts.push(constraint.format(p));
+    }
+    if let Some(default) = &param.default {
+        parts.push(ss!(" = "));
+        parts.push(default.format(p));
+    }
+    Doc::Array(parts)
+}
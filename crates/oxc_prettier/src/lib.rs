@@ -125,7 +125,6 @@ impl<'a> Prettier<'a> {
         self.should_print_comma_impl(false)
     }
 
-    #[allow(unused)]
     fn should_print_all_comma(&self) -> bool {
         self.should_print_comma_impl(true)
     }
@@ -136,7 +135,9 @@ impl<'a> Prettier<'a> {
     }
 
     fn is_next_line_empty(&self, end: u32) -> bool {
-        self.source_text[end as usize..].chars().nth(1).is_some_and(|c| c == '\n')
+        let idx = self.skip_to_line_end(end);
+        let idx = self.skip_newline(idx, false);
+        idx.is_some_and(|idx| self.has_newline(idx, false))
     }
 
     #[allow(clippy::cast_possible_truncation)]
@@ -148,12 +149,26 @@ impl<'a> Prettier<'a> {
             self.source_text[start_index as usize..].chars().next()
         }?;
         if is_line_terminator(c) {
-            let len = c.len_utf8() as u32;
+            // `\r\n` is a single line break
+            let is_crlf = if backwards {
+                self.source_text[..=start_index as usize].ends_with("\r\n")
+            } else {
+                self.source_text[start_index as usize..].starts_with("\r\n")
+            };
+            let len = if is_crlf { 2 } else { c.len_utf8() as u32 };
             return Some(if backwards { start_index - len } else { start_index + len });
         }
         Some(start_index)
     }
 
+    /// Skip the separators (`,` and `;`) and spaces that follow a node on the same line.
+    #[allow(clippy::cast_possible_truncation)]
+    fn skip_to_line_end(&self, start_index: u32) -> Option<u32> {
+        self.source_text[start_index as usize..]
+            .find(|c| !matches!(c, ',' | ';' | ' ' | '\t'))
+            .map(|offset| start_index + offset as u32)
+    }
+
     fn skip_spaces(&self, start_index: u32, backwards: bool) -> Option<u32> {
         let mut index = start_index;
         if backwards {
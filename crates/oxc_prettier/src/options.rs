@@ -97,6 +97,39 @@ impl EndOfLine {
             Self::Lf | Self::Auto => "\n",
         }
     }
+
+    /// Resolve [EndOfLine::Auto] to the line ending used in `source_text`.
+    #[must_use]
+    pub fn resolve(self, source_text: &str) -> Self {
+        match self {
+            Self::Auto => Self::guess(source_text),
+            _ => self,
+        }
+    }
+
+    /// Guess the dominant line ending of `source_text`, defaults to [EndOfLine::Lf].
+    pub fn guess(source_text: &str) -> Self {
+        let (mut lf, mut crlf, mut cr) = (0usize, 0usize, 0usize);
+        let mut bytes = source_text.bytes().peekable();
+        while let Some(b) = bytes.next() {
+            match b {
+                b'\n' => lf += 1,
+                b'\r' if bytes.peek() == Some(&b'\n') => {
+                    bytes.next();
+                    crlf += 1;
+                }
+                b'\r' => cr += 1,
+                _ => {}
+            }
+        }
+        if crlf > lf && crlf >= cr {
+            Self::Crlf
+        } else if cr > lf && cr > crlf {
+            Self::Cr
+        } else {
+            Self::Lf
+        }
+    }
 }
 
 impl FromStr for EndOfLine {
@@ -170,3 +203,15 @@ pub enum ArrowParens {
     /// Omit parens when possible. `Example: x => x`
     Avoid,
 }
+
+impl FromStr for ArrowParens {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "always" => Self::Always,
+            "avoid" => Self::Avoid,
+            _ => Self::default(),
+        })
+    }
+}
@@ -60,7 +60,7 @@ impl<'a> Printer<'a> {
             cmds,
             line_suffix: vec![],
             group_mode_map: HashMap::new(),
-            new_line: options.end_of_line.as_str(),
+            new_line: options.end_of_line.resolve(source_text).as_str(),
             allocator,
         }
     }
@@ -103,7 +103,27 @@ impl<'a> Printer<'a> {
     }
 
     fn handle_str(&mut self, s: &str) {
-        self.out.extend(s.as_bytes());
+        // Strings taken from the source (comments, template literals) may contain line breaks,
+        // which need to follow `endOfLine` as well.
+        if s.contains(['\n', '\r']) {
+            let mut chars = s.chars().peekable();
+            while let Some(c) = chars.next() {
+                match c {
+                    '\r' | '\n' => {
+                        if c == '\r' && chars.peek() == Some(&'\n') {
+                            chars.next();
+                        }
+                        self.out.extend(self.new_line.as_bytes());
+                    }
+                    _ => {
+                        let mut buf = [0; 4];
+                        self.out.extend(c.encode_utf8(&mut buf).as_bytes());
+                    }
+                }
+            }
+        } else {
+            self.out.extend(s.as_bytes());
+        }
         self.pos += s.len();
     }
 
@@ -140,13 +160,33 @@ impl<'a> Printer<'a> {
                     self.cmds.push(Command::new(indent, Mode::Flat, cmd.doc));
                 } else {
                     let Doc::Group(group) = cmd.doc else { unreachable!() };
-                    self.cmds.extend(
-                        group
-                            .contents
-                            .into_iter()
-                            .rev()
-                            .map(|doc| Command::new(indent, Mode::Break, doc)),
-                    );
+                    match group.expanded_states {
+                        // `conditionalGroup`: use the first expanded state that fits,
+                        // or the most expanded one in break mode.
+                        Some(expanded_states) if !expanded_states.is_empty() => {
+                            let last = expanded_states.len() - 1;
+                            for (i, state) in expanded_states.into_iter().enumerate() {
+                                if i == last {
+                                    self.cmds.push(Command::new(indent, Mode::Break, state));
+                                    break;
+                                }
+                                let cmd = Command::new(indent, Mode::Flat, state);
+                                if !should_break && self.fits(&cmd, remaining_width) {
+                                    self.cmds.push(cmd);
+                                    break;
+                                }
+                            }
+                        }
+                        _ => {
+                            self.cmds.extend(
+                                group
+                                    .contents
+                                    .into_iter()
+                                    .rev()
+                                    .map(|doc| Command::new(indent, Mode::Break, doc)),
+                            );
+                        }
+                    }
                 }
                 self.set_group_mode_from_last_cmd(group_id);
             }
@@ -360,8 +400,15 @@ impl<'a> Printer<'a> {
                 }
                 Doc::Group(group) => {
                     let mode = if group.should_break { Mode::Break } else { mode };
-                    for d in group.contents.iter().rev() {
-                        queue.push_front((mode, d));
+                    match group.expanded_states.as_ref().and_then(|states| states.last()) {
+                        Some(most_expanded) if mode.is_break() => {
+                            queue.push_front((mode, most_expanded));
+                        }
+                        _ => {
+                            for d in group.contents.iter().rev() {
+                                queue.push_front((mode, d));
+                            }
+                        }
                     }
                 }
                 Doc::IfBreak(if_break_doc) => {
@@ -415,6 +462,18 @@ impl<'a> Printer<'a> {
     pub fn propagate_breaks(doc: &mut Doc<'_>) -> bool {
         match doc {
             Doc::BreakParent => true,
+            Doc::Group(group) if group.expanded_states.is_some() => {
+                // A `conditionalGroup` is not broken by its children, it picks a state instead.
+                for doc in group.contents.iter_mut() {
+                    Self::propagate_breaks(doc);
+                }
+                if let Some(expanded_states) = &mut group.expanded_states {
+                    for doc in expanded_states.iter_mut() {
+                        Self::propagate_breaks(doc);
+                    }
+                }
+                group.should_break
+            }
             Doc::Group(group) => {
                 let should_break =
                     group.contents.iter_mut().rev().any(|doc| Self::propagate_breaks(doc));
@@ -0,0 +1,9 @@
+compose(function () { return 1; }, function () { return 2; });
+
+someFunction(someVeryLongArgumentNumberOne, someVeryLongArgumentNumberTwo, three);
+
+foo(
+  a,
+
+  b,
+);
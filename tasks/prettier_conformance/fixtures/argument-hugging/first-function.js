@@ -0,0 +1 @@
+setTimeout(function () { thing(); }, 500);
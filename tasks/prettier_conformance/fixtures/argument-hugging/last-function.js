@@ -0,0 +1,11 @@
+arr.map((x) => { return x * 2; });
+
+promise.then(function (result) {
+  console.log(result);
+});
+
+describe("some string that is long enough to fill the line", () => {
+  it("works");
+});
+
+useEffect(() => { doSomething(a, b); }, [a, b]);
@@ -0,0 +1,3 @@
+foo(bar, { baz: 1, qux: 2, someLongPropertyName: true, anotherLongPropertyName: false });
+
+foo(bar, { baz: 1 });
@@ -0,0 +1,2 @@
+run_spec(import.meta, ["typescript"], { arrowParens: "always" });
+run_spec(import.meta, ["typescript"], { arrowParens: "avoid" });
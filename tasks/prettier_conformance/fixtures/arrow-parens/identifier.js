@@ -0,0 +1,2 @@
+x => x;
+(y) => y;
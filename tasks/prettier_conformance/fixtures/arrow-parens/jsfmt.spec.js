@@ -0,0 +1,2 @@
+run_spec(import.meta, ["babel"], { arrowParens: "always" });
+run_spec(import.meta, ["babel"], { arrowParens: "avoid" });
@@ -0,0 +1,6 @@
+(x = 1) => x;
+({ x }) => x;
+([x]) => x;
+(...x) => x;
+(x, y) => x;
+() => x;
@@ -0,0 +1,2 @@
+const {a, b: c, ...rest} = d;
+({a, ...rest} = d);
@@ -0,0 +1,2 @@
+run_spec(import.meta, ["babel"]);
+run_spec(import.meta, ["babel"], { bracketSpacing: false });
@@ -0,0 +1,4 @@
+import {a} from 'a';
+import x, {a, b} from 'a';
+export {a, b};
+export {};
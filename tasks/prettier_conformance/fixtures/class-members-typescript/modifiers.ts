@@ -0,0 +1,12 @@
+class A {
+  [key: string]: any;
+  private readonly a?: number;
+  protected b!: string;
+  declare c: boolean;
+  public static override d = 1;
+}
+
+abstract class A {
+  abstract foo(): void;
+  protected abstract bar: number;
+}
@@ -0,0 +1,7 @@
+class A {
+  constructor(private readonly a: number, public b: string) {}
+}
+
+class A {
+  constructor(@Inject(Foo) foo: string) {}
+}
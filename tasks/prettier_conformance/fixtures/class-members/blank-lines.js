@@ -0,0 +1,9 @@
+class A {
+  a = 1;
+
+
+  b = 2;
+  foo() {}
+
+  bar() {}
+}
@@ -0,0 +1,16 @@
+@dec class A {}
+
+@a
+@b(1)
+class A {}
+
+class A {
+  @dec foo() {}
+  @dec bar = 1;
+}
+
+class A {
+  @a
+  @b()
+  foo() {}
+}
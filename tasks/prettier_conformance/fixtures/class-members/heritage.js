@@ -0,0 +1,7 @@
+class A extends B {}
+
+class A extends (B ?? C) {}
+
+class A extends (foo, bar) {}
+
+const A = class extends B {};
@@ -0,0 +1,19 @@
+class A {
+  #count = 0;
+  static #instances = 0;
+  static create() {}
+  get #value() {
+    return this.#count;
+  }
+  set value(v) {
+    this.#count = v;
+  }
+  #increment() {
+    this.#count++;
+  }
+}
+
+class A {
+  [foo] = 1;
+  [bar]() {}
+}
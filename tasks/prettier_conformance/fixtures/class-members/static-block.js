@@ -0,0 +1 @@
+class A { static { init(); } static {} }
@@ -0,0 +1,14 @@
+[a, /* x */ b];
+
+[a,/* x */b];
+
+x = [a, // note
+ b];
+
+x = [
+  a,
+  // own line
+  b,
+];
+
+const [a, /* x */ b] = c;
@@ -0,0 +1,13 @@
+x = {a, // note
+ b};
+
+x = {a: 1, b: 2 // last
+};
+
+x = { a /* x */, b };
+
+x = {
+  a: 1,
+  // own line
+  b: 2,
+};
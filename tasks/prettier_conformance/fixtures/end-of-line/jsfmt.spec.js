@@ -0,0 +1,4 @@
+run_spec(import.meta, ["babel"], { endOfLine: "lf" });
+run_spec(import.meta, ["babel"], { endOfLine: "crlf" });
+run_spec(import.meta, ["babel"], { endOfLine: "cr" });
+run_spec(import.meta, ["babel"], { endOfLine: "auto" });
@@ -0,0 +1,7 @@
+const a = `result: ${ foo(a,b) + bar.baz[ 0 ] } and ${ a ? b : c }`;
+
+const a = `outer ${ `inner ${ deep }` } end`;
+
+const a = `\u{1F600} \n ${x}`;
+
+const message = `this is a very long template literal that goes past the print width ${someObject.someProperty.nested}`;
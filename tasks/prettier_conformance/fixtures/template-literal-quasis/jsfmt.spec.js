@@ -0,0 +1 @@
+run_spec(import.meta, ["babel"]);
@@ -0,0 +1,13 @@
+const a = `line one
+  line two ${x}
+line three`;
+
+function f() {
+  if (a) {
+    return `first
+second ${value} third`;
+  }
+}
+
+foo(`a
+b`, c);
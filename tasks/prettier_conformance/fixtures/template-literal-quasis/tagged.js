@@ -0,0 +1,5 @@
+const a = tag`hello ${ world } and ${ more }`;
+
+const a = styled.div`
+  color: ${(props) => props.color};
+`;
@@ -0,0 +1,5 @@
+type A<T> = T extends string ? "yes" : "no";
+
+type A<T> = T extends string ? 'string' : T extends number ? 'number' : 'other';
+
+type A<T> = T extends (infer U)[] ? U : never;
@@ -0,0 +1,7 @@
+type Fn = (a: string, b?: number) => void;
+
+type Fn = <T>(...args: T[]) => Promise<T>;
+
+type Ctor = new (a: string) => Foo;
+
+type Guard = (a: unknown) => a is string;
@@ -0,0 +1,9 @@
+let a: Array<string>;
+
+let a: Map<string, Array<number>>;
+
+let a: SomeVeryLongGenericTypeName<FirstTypeArgument, SecondTypeArgument, ThirdTypeArgument>;
+
+function foo<T extends object = {}>(a: T): T {}
+
+const foo = <T,>(a: T): T => a;
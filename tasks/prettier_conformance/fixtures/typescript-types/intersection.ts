@@ -0,0 +1,5 @@
+type A = B & C;
+
+type A = B & { c: string };
+
+type A = keyof (B & C);
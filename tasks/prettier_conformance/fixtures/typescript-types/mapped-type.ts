@@ -0,0 +1,11 @@
+type A = { [K in keyof T]: T[K] };
+
+type A = { readonly [K in keyof T]?: T[K] };
+
+type A = { -readonly [K in keyof T]-?: T[K] };
+
+type A = { [K in keyof T as `get${K}`]: () => T[K] };
+
+type A = {
+  [K in keyof T]: T[K];
+};
@@ -0,0 +1,14 @@
+type A = { a: string; b?: number; readonly c: boolean };
+
+type A = {
+  a(): void;
+  new (a: string): A;
+  (b: number): string;
+  [key: string]: any;
+};
+
+type A = [string, number?, ...boolean[]];
+
+type A = typeof foo;
+
+type A = import("./foo").Bar<string>;
@@ -0,0 +1,9 @@
+type A = "a" | "b" | null;
+
+type Long = 'first-option' | 'second-option' | 'third-option' | 'fourth-option' | 'fifth';
+
+type A = (string | number)[];
+
+type A = (() => void) | string;
+
+let a: { b: string } | null;
@@ -40,6 +40,19 @@ fn fixtures_root() -> PathBuf {
     project_root().join(root()).join("prettier/tests/format/js")
 }
 
+/// Formatting tests which are not covered by the prettier repository, laid out like its tests.
+fn oxc_fixtures_root() -> PathBuf {
+    root().join("fixtures")
+}
+
+/// Strips the fixtures root containing `path` for reporting.
+fn strip_fixtures_root(path: &Path) -> &Path {
+    [fixtures_root(), oxc_fixtures_root()]
+        .iter()
+        .find_map(|root| path.strip_prefix(root).ok())
+        .unwrap()
+}
+
 const IGNORE_TESTS: &[&str] = &[
     // non-standard syntax
     "js/deferred-import-evaluation",
@@ -76,11 +89,10 @@ impl TestRunner {
     /// # Panics
     #[allow(clippy::cast_precision_loss)]
     pub fn run(mut self) {
-        let fixture_root = fixtures_root();
         // Read the first level of directories that contain `__snapshots__`
-        let mut dirs = WalkDir::new(fixture_root)
-            .min_depth(1)
+        let mut dirs = [fixtures_root(), oxc_fixtures_root()]
             .into_iter()
+            .flat_map(|fixture_root| WalkDir::new(fixture_root).min_depth(1))
             .filter_map(Result::ok)
             .filter(|e| {
                 self.options
@@ -165,7 +177,6 @@ impl TestRunner {
         inputs: &[PathBuf],
         failed: &mut Vec<String>,
     ) {
-        let fixture_root = fixtures_root();
         let mut write_dir_info = true;
         for path in inputs {
             let input = fs::read_to_string(path).unwrap();
@@ -190,19 +201,12 @@ impl TestRunner {
                 let mut dir_info = String::new();
                 if write_dir_info {
                     dir_info.push_str(
-                        format!(
-                            "\n### {}\n",
-                            dir.strip_prefix(&fixture_root).unwrap().to_string_lossy()
-                        )
-                        .as_str(),
+                        format!("\n### {}\n", strip_fixtures_root(dir).to_string_lossy()).as_str(),
                     );
                     write_dir_info = false;
                 }
 
-                failed.push(format!(
-                    "{dir_info}* {}",
-                    path.strip_prefix(&fixture_root).unwrap().to_string_lossy()
-                ));
+                failed.push(format!("{dir_info}* {}", strip_fixtures_root(path).to_string_lossy()));
             }
         }
     }
@@ -8,7 +8,7 @@ use oxc_ast::{
     VisitMut,
 };
 use oxc_parser::Parser;
-use oxc_prettier::{EndOfLine, PrettierOptions, TrailingComma};
+use oxc_prettier::{ArrowParens, EndOfLine, PrettierOptions, TrailingComma};
 use oxc_span::{Atom, GetSpan, SourceType};
 
 #[derive(Default)]
@@ -89,6 +89,10 @@ impl VisitMut<'_> for SpecParser {
                                     options.end_of_line =
                                         EndOfLine::from_str(literal.value.as_str()).unwrap();
                                 }
+                                "arrowParens" => {
+                                    options.arrow_parens =
+                                        ArrowParens::from_str(literal.value.as_str()).unwrap();
+                                }
                                 _ => {}
                             },
                             _ => {}